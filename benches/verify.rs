@@ -0,0 +1,70 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion, Throughput};
+
+use otp_std::{batch::verify_many, Base, Secret, Totp};
+
+const ENCODED: &str = "JEQDYMZAN5YGK3RAONXXK4TDMU";
+
+const TIME: u64 = 1_234_567_890;
+
+const ITEMS: u64 = 1024;
+
+fn totp() -> Totp<'static> {
+    let secret = Secret::decode(ENCODED).unwrap();
+
+    let base = Base::builder().secret(secret).build();
+
+    Totp::builder().base(base).build()
+}
+
+fn items(totp: &Totp<'_>) -> Vec<(u64, u32)> {
+    (0..ITEMS)
+        .map(|index| {
+            let time = TIME + index * totp.period.get();
+
+            (time, totp.generate_at(time))
+        })
+        .collect()
+}
+
+fn verify(criterion: &mut Criterion) {
+    let totp = totp();
+
+    let items = items(&totp);
+
+    let mut group = criterion.benchmark_group("verify");
+
+    group.throughput(Throughput::Elements(ITEMS));
+
+    group.bench_function("verify_at", |bencher| {
+        bencher.iter(|| {
+            items
+                .iter()
+                .map(|&(time, code)| totp.verify_at(black_box(time), black_box(code)))
+                .collect::<Vec<_>>()
+        })
+    });
+
+    group.bench_function("verify_many", |bencher| {
+        bencher.iter_batched(
+            || {
+                items
+                    .iter()
+                    .map(|&(time, code)| (&totp, time, code))
+                    .collect::<Vec<_>>()
+            },
+            |batch| verify_many(black_box(batch)),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function("verify_batch", |bencher| {
+        bencher.iter(|| totp.verify_batch(black_box(&items)))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, verify);
+criterion_main!(benches);
@@ -0,0 +1,46 @@
+//! Checks that the compiler satisfies the minimum supported Rust version (MSRV).
+//!
+//! Cargo already refuses to build with older compilers, unless `--ignore-rust-version`
+//! is passed or the crate is built some other way, in which case this check reports
+//! the MSRV instead of obscure compilation errors.
+
+use std::{env, process::Command};
+
+fn minor(version: &str) -> Option<u32> {
+    let mut parts = version.trim().split('.');
+
+    if parts.next()? != "1" {
+        return None;
+    };
+
+    parts.next()?.parse().ok()
+}
+
+fn rustc_minor() -> Option<u32> {
+    let rustc = env::var_os("RUSTC")?;
+
+    let output = Command::new(rustc).arg("--version").output().ok()?;
+
+    let string = String::from_utf8(output.stdout).ok()?;
+
+    // for instance, `rustc 1.79.0 (129f3b996 2024-06-10)`
+    minor(string.split_whitespace().nth(1)?)
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let Some(msrv) = env::var("CARGO_PKG_RUST_VERSION")
+        .ok()
+        .and_then(|version| minor(&version))
+    else {
+        return;
+    };
+
+    if let Some(actual) = rustc_minor() {
+        assert!(
+            actual >= msrv,
+            "`otp-std` requires Rust 1.{msrv} or newer, found Rust 1.{actual}"
+        );
+    };
+}
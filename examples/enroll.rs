@@ -0,0 +1,69 @@
+//! Enrollment flow.
+//!
+//! The server generates the secret, hands it out as the OTP URL (along with the printable
+//! sheet containing the QR code), and only activates the configuration once the user
+//! confirms it by entering the code generated by their authenticator app.
+//!
+//! Run with `cargo run --example enroll`.
+
+use std::{env, fs};
+
+use otp_std::{Algorithm, Auth, Config, Label, Otp, Part};
+
+const ISSUER: &str = "MelodyKit";
+
+const USER: &str = "nekitdev";
+
+const TIME: u64 = 1_234_567_890;
+
+const RECOVERY_CODES: [&str; 4] = ["7DKQ-M2XP", "R9VT-4HWB", "C3NJ-8YFL", "Q6GZ-5TSE"];
+
+fn main() {
+    // generate the secret of the length recommended for the algorithm
+    let config = Config::builder().algorithm(Algorithm::Sha1).build();
+
+    let totp = config.generate_totp();
+
+    let label = Label::builder()
+        .issuer(Part::borrowed(ISSUER).unwrap())
+        .user(Part::borrowed(USER).unwrap())
+        .build();
+
+    let auth = Auth::builder().otp(totp.clone()).label(label).build();
+
+    let url = auth.build_url();
+
+    println!("provisioning URL: {url}");
+
+    // render the sheet containing the QR code, the secret and the recovery codes
+    let recovery_codes = RECOVERY_CODES.map(str::to_owned).to_vec();
+
+    let sheet = auth.render_sheet(recovery_codes).unwrap();
+
+    let path = env::temp_dir().join("otp-std-enroll.svg");
+
+    fs::write(&path, sheet).unwrap();
+
+    println!("enrollment sheet: {path}", path = path.display());
+
+    // the authenticator app scans the QR code and generates the code
+    let device = Auth::parse_url(url.as_str()).unwrap();
+
+    let Otp::Totp(app) = device.otp() else {
+        unreachable!("the configuration is TOTP");
+    };
+
+    let code = app.generate_string_at(TIME);
+
+    println!("code entered by the user: {code}");
+
+    // the server confirms the enrollment only if the code matches
+    let receipt = totp
+        .verify_string_receipt_at(TIME, &code)
+        .expect("the code matches");
+
+    println!(
+        "enrollment confirmed at time step {timestep}",
+        timestep = receipt.timestep
+    );
+}
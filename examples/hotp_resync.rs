@@ -0,0 +1,63 @@
+//! HOTP counter resynchronization.
+//!
+//! Pressing the button of the hardware token generates the code and advances its counter,
+//! even if the code is never submitted. The server then falls behind, so it looks ahead
+//! (see [`Hotp::verify_resync`]), moving its counter past the matched value on success.
+//!
+//! Run with `cargo run --example hotp_resync`.
+
+use otp_std::{Base, Counter, Hotp, LookAhead, Secret};
+
+const SECRET: &str = "JEQDYMZAN5YGK3RAONXXK4TDMU";
+
+const PRESSES: u64 = 5;
+
+fn main() {
+    let base = Base::builder()
+        .secret(Secret::decode(SECRET).unwrap())
+        .build();
+
+    let mut token = Hotp::builder().base(base.clone()).build();
+
+    let mut server = Hotp::builder()
+        .base(base)
+        .look_ahead(LookAhead::new(10))
+        .build();
+
+    // the codes generated by these presses are never submitted
+    for _ in 0..PRESSES {
+        token.next();
+    }
+
+    let code = token.next();
+
+    println!(
+        "token counter: {token}, server counter: {server}",
+        token = token.counter(),
+        server = server.counter(),
+    );
+
+    // exact verification fails, as the server is behind
+    assert!(!server.verify(code));
+
+    let matched = server
+        .verify_ahead(code)
+        .expect("the code is within the window");
+
+    println!("code {code} matched counter {matched}");
+
+    assert!(server.verify_resync(code));
+
+    assert_eq!(server.counter, Counter::new(PRESSES + 1));
+
+    println!(
+        "resynchronized, server counter: {server}",
+        server = server.counter()
+    );
+
+    // the same code can not be used again, since the counter has moved past it
+    assert!(!server.verify_resync(code));
+
+    // and the following codes are accepted exactly
+    assert!(server.verify_increment(token.next()));
+}
@@ -0,0 +1,79 @@
+//! Migrating between authenticator apps.
+//!
+//! Google Authenticator exports accounts as migration URLs (usually shown as QR codes),
+//! while Aegis uses JSON vaults. This example imports accounts from the migration URL
+//! and exports them as the plain Aegis vault, then imports the vault back.
+//!
+//! Run with `cargo run --example import_export`.
+
+use otp_std::{
+    auth::{interop::aegis::Vault, migration::google},
+    Auth, Base, Counter, Hotp, Label, Part, Secret, Totp,
+};
+
+const SECRET: &str = "JEQDYMZAN5YGK3RAONXXK4TDMU";
+
+const OTHER: &str = "KRUGKIDROVUWG2ZAMJZG653OEBTG66BA";
+
+fn label(issuer: &'static str, user: &'static str) -> Label<'static> {
+    Label::builder()
+        .issuer(Part::borrowed(issuer).unwrap())
+        .user(Part::borrowed(user).unwrap())
+        .build()
+}
+
+fn main() {
+    let base = Base::builder()
+        .secret(Secret::decode(SECRET).unwrap())
+        .build();
+
+    let totp = Totp::builder().base(base).build();
+
+    let base = Base::builder()
+        .secret(Secret::decode(OTHER).unwrap())
+        .build();
+
+    let hotp = Hotp::builder().base(base).counter(Counter::new(42)).build();
+
+    let auths = [
+        Auth::builder()
+            .otp(totp)
+            .label(label("MelodyKit", "nekitdev"))
+            .build(),
+        Auth::builder()
+            .otp(hotp)
+            .label(label("Example", "nekitdev"))
+            .build(),
+    ];
+
+    // this is what Google Authenticator shows when exporting accounts
+    let migration = google::build_url(&auths).unwrap();
+
+    println!("migration URL: {migration}");
+
+    let imported = google::parse_url(migration.as_str()).unwrap();
+
+    assert!(imported
+        .iter()
+        .zip(&auths)
+        .all(|(imported, auth)| imported.same_parameters(auth)));
+
+    let vault = Vault::from_auths(&imported);
+
+    let json = serde_json::to_string_pretty(&vault).unwrap();
+
+    println!("Aegis vault: {json}");
+
+    let parsed: Vault = serde_json::from_str(&json).unwrap();
+
+    let restored = parsed.to_auths().unwrap();
+
+    assert!(restored
+        .iter()
+        .zip(&auths)
+        .all(|(restored, auth)| restored.same_parameters(auth)));
+
+    for auth in &restored {
+        println!("restored: {url}", url = auth.build_url());
+    }
+}
@@ -0,0 +1,162 @@
+//! Server-side verification with replay protection.
+//!
+//! The handler below is framework-agnostic: with `axum` or `actix-web`, the [`Request`]
+//! would be extracted from the form or JSON body, and [`Response`] mapped to the status code.
+//! Each user has [`MemoryStore`] tracking the last accepted time step, so that intercepted
+//! codes can not be replayed within their validity window.
+//!
+//! Run with `cargo run --example server_verify`.
+
+use std::{collections::HashMap, sync::Mutex};
+
+use otp_std::{replay::MemoryStore, Base, Secret, Totp};
+
+const SECRET: &str = "JEQDYMZAN5YGK3RAONXXK4TDMU";
+
+const TIME: u64 = 1_234_567_890;
+
+/// Represents verification requests.
+struct Request<'r> {
+    user: &'r str,
+    code: &'r str,
+    time: u64,
+}
+
+/// Represents verification responses.
+#[derive(Debug, PartialEq, Eq)]
+enum Response {
+    /// `200 OK`
+    Accepted,
+    /// `401 Unauthorized`
+    Rejected,
+    /// `404 Not Found`
+    UnknownUser,
+    /// `409 Conflict`
+    Replayed,
+}
+
+impl Response {
+    const fn status(&self) -> u16 {
+        match self {
+            Self::Accepted => 200,
+            Self::Rejected => 401,
+            Self::UnknownUser => 404,
+            Self::Replayed => 409,
+        }
+    }
+}
+
+struct Account {
+    totp: Totp<'static>,
+    store: MemoryStore,
+}
+
+/// Represents the shared server state.
+#[derive(Default)]
+struct State {
+    accounts: Mutex<HashMap<String, Account>>,
+}
+
+impl State {
+    fn enroll(&self, user: &str, totp: Totp<'static>) {
+        let account = Account {
+            totp,
+            store: MemoryStore::new(),
+        };
+
+        self.accounts
+            .lock()
+            .unwrap()
+            .insert(user.to_owned(), account);
+    }
+
+    fn verify(&self, request: &Request<'_>) -> Response {
+        let mut accounts = self.accounts.lock().unwrap();
+
+        let Some(account) = accounts.get_mut(request.user) else {
+            return Response::UnknownUser;
+        };
+
+        match account
+            .totp
+            .verify_string_once(&mut account.store, request.time, request.code)
+        {
+            Ok(true) => Response::Accepted,
+            Ok(false) => Response::Rejected,
+            Err(_) => Response::Replayed,
+        }
+    }
+}
+
+fn main() {
+    let state = State::default();
+
+    let secret = Secret::decode(SECRET).unwrap();
+
+    let base = Base::builder().secret(secret).build();
+
+    let totp = Totp::builder().base(base).build();
+
+    let code = totp.generate_string_at(TIME);
+
+    // the client clock is one period behind, which is allowed by the default skew
+    let late = totp.generate_string_at(TIME - totp.period.get());
+
+    state.enroll("nekitdev", totp);
+
+    let requests = [
+        (
+            Request {
+                user: "nekitdev",
+                code: &late,
+                time: TIME,
+            },
+            Response::Accepted,
+        ),
+        (
+            Request {
+                user: "nekitdev",
+                code: &code,
+                time: TIME,
+            },
+            Response::Accepted,
+        ),
+        (
+            Request {
+                user: "nekitdev",
+                code: &code,
+                time: TIME + 1,
+            },
+            Response::Replayed,
+        ),
+        (
+            Request {
+                user: "nekitdev",
+                code: "000000",
+                time: TIME + 60,
+            },
+            Response::Rejected,
+        ),
+        (
+            Request {
+                user: "nekit",
+                code: &code,
+                time: TIME,
+            },
+            Response::UnknownUser,
+        ),
+    ];
+
+    for (request, expected) in requests {
+        let response = state.verify(&request);
+
+        println!(
+            "POST /verify user={user} code={code} -> {status} ({response:?})",
+            user = request.user,
+            code = request.code,
+            status = response.status(),
+        );
+
+        assert_eq!(response, expected);
+    }
+}
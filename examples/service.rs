@@ -0,0 +1,125 @@
+//! High-throughput verification service.
+//!
+//! Each user gets [`Verifier`] enforcing the rate limit and the replay guard,
+//! while queued requests of the same user are checked in bulk via [`Totp::verify_batch`],
+//! which prepares the HMAC key once.
+//!
+//! Run with `cargo run --release --example service`.
+
+use std::{collections::HashMap, time::Instant};
+
+use otp_std::{
+    policy::{Policy, RateLimit},
+    Base, Secret, Totp, Verifier,
+};
+
+const USERS: u64 = 1000;
+
+const REQUESTS: u64 = 16;
+
+const TIME: u64 = 1_234_567_890;
+
+struct Service {
+    verifiers: HashMap<u64, Verifier<'static>>,
+}
+
+impl Service {
+    fn new(policy: Policy) -> Self {
+        let verifiers = (0..USERS)
+            .map(|user| {
+                let secret = Secret::generate_default();
+
+                let base = Base::builder().secret(secret).build();
+
+                let totp = Totp::builder().base(base).build();
+
+                (user, Verifier::with_policy(totp, policy))
+            })
+            .collect();
+
+        Self { verifiers }
+    }
+
+    fn totp(&self, user: u64) -> &Totp<'static> {
+        self.verifiers[&user].totp()
+    }
+
+    /// Checks queued requests without touching the policy state, for instance, for auditing.
+    fn audit(&self, user: u64, requests: &[(u64, u32)]) -> usize {
+        self.totp(user)
+            .verify_batch(requests)
+            .into_iter()
+            .filter(|&valid| valid)
+            .count()
+    }
+
+    /// Verifies the login attempt, enforcing the policy.
+    fn login(&self, user: u64, time: u64, code: u32) -> bool {
+        self.verifiers[&user]
+            .verify_at(time, code)
+            .unwrap_or_default()
+    }
+}
+
+fn main() {
+    let rate_limit = RateLimit::builder().attempts(5).interval(60).build();
+
+    let policy = Policy::builder()
+        .replay_guard(true)
+        .rate_limit(rate_limit)
+        .build();
+
+    let service = Service::new(policy);
+
+    let queues: HashMap<u64, Vec<(u64, u32)>> = (0..USERS)
+        .map(|user| {
+            let totp = service.totp(user);
+
+            let requests = (0..REQUESTS)
+                .map(|index| {
+                    let time = TIME + index * totp.period.get();
+
+                    (time, totp.generate_at(time))
+                })
+                .collect();
+
+            (user, requests)
+        })
+        .collect();
+
+    let start = Instant::now();
+
+    let valid: usize = queues
+        .iter()
+        .map(|(&user, requests)| service.audit(user, requests))
+        .sum();
+
+    let elapsed = start.elapsed();
+
+    println!(
+        "audited {count} requests ({valid} valid) in {elapsed:?}",
+        count = USERS * REQUESTS
+    );
+
+    let start = Instant::now();
+
+    let mut accepted = 0;
+    let mut rejected = 0;
+
+    for (&user, requests) in &queues {
+        let &(time, code) = requests.first().expect("requests are not empty");
+
+        // the second attempt with the same code is rejected by the replay guard
+        for _ in 0..2 {
+            if service.login(user, time, code) {
+                accepted += 1;
+            } else {
+                rejected += 1;
+            };
+        }
+    }
+
+    let elapsed = start.elapsed();
+
+    println!("processed logins ({accepted} accepted, {rejected} rejected) in {elapsed:?}");
+}
@@ -2,6 +2,12 @@
 
 use std::{fmt, str::FromStr};
 
+#[cfg(feature = "blake2")]
+use blake2::{Blake2b512, Blake2s256};
+
+#[cfg(feature = "blake2")]
+use hmac::SimpleHmac;
+
 use hmac::{Hmac, Mac};
 
 use miette::Diagnostic;
@@ -14,9 +20,12 @@ use sha1::Sha1;
 #[cfg(feature = "sha2")]
 use sha2::{Sha256, Sha512};
 
+#[cfg(feature = "sha3")]
+use sha3::{Sha3_256, Sha3_512};
+
 use thiserror::Error;
 
-use crate::macros::errors;
+use crate::{macros::errors, scrub::scrub};
 
 /// HMAC type using SHA-1.
 pub type HmacSha1 = Hmac<Sha1>;
@@ -29,6 +38,22 @@ pub type HmacSha256 = Hmac<Sha256>;
 #[cfg(feature = "sha2")]
 pub type HmacSha512 = Hmac<Sha512>;
 
+/// HMAC type using SHA3-256.
+#[cfg(feature = "sha3")]
+pub type HmacSha3_256 = Hmac<Sha3_256>;
+
+/// HMAC type using SHA3-512.
+#[cfg(feature = "sha3")]
+pub type HmacSha3_512 = Hmac<Sha3_512>;
+
+/// HMAC type using BLAKE2s (256 bits).
+#[cfg(feature = "blake2")]
+pub type HmacBlake2s = SimpleHmac<Blake2s256>;
+
+/// HMAC type using BLAKE2b (512 bits).
+#[cfg(feature = "blake2")]
+pub type HmacBlake2b = SimpleHmac<Blake2b512>;
+
 /// Represents errors that occur when unknown algorithms are encountered.
 #[derive(Debug, Error, Diagnostic)]
 #[error("unknown algorithm `{unknown}`")]
@@ -57,24 +82,43 @@ pub enum Algorithm {
     /// SHA-512 algorithm.
     #[cfg(feature = "sha2")]
     Sha512,
+    /// SHA3-256 algorithm.
+    #[cfg(feature = "sha3")]
+    Sha3_256,
+    /// SHA3-512 algorithm.
+    #[cfg(feature = "sha3")]
+    Sha3_512,
+    /// BLAKE2s algorithm (256 bits).
+    #[cfg(feature = "blake2")]
+    Blake2s,
+    /// BLAKE2b algorithm (512 bits).
+    #[cfg(feature = "blake2")]
+    Blake2b,
 }
 
 impl Algorithm {
     /// The amount of algorithms available.
-    #[cfg(not(feature = "sha2"))]
-    pub const COUNT: usize = 1;
-
-    /// The array of algorithms available.
-    #[cfg(not(feature = "sha2"))]
-    pub const ARRAY: [Self; Self::COUNT] = [Self::Sha1];
-
-    /// The amount of algorithms available.
-    #[cfg(feature = "sha2")]
-    pub const COUNT: usize = 3;
+    pub const COUNT: usize = 1
+        + 2 * (cfg!(feature = "sha2") as usize)
+        + 2 * (cfg!(feature = "sha3") as usize)
+        + 2 * (cfg!(feature = "blake2") as usize);
 
     /// The array of algorithms available.
-    #[cfg(feature = "sha2")]
-    pub const ARRAY: [Self; Self::COUNT] = [Self::Sha1, Self::Sha256, Self::Sha512];
+    pub const ARRAY: [Self; Self::COUNT] = [
+        Self::Sha1,
+        #[cfg(feature = "sha2")]
+        Self::Sha256,
+        #[cfg(feature = "sha2")]
+        Self::Sha512,
+        #[cfg(feature = "sha3")]
+        Self::Sha3_256,
+        #[cfg(feature = "sha3")]
+        Self::Sha3_512,
+        #[cfg(feature = "blake2")]
+        Self::Blake2s,
+        #[cfg(feature = "blake2")]
+        Self::Blake2b,
+    ];
 }
 
 /// The `SHA1` literal.
@@ -99,6 +143,38 @@ pub const SHA256: &str = "SHA256";
 #[cfg(feature = "sha2")]
 pub const SHA512: &str = "SHA512";
 
+/// The length of the SHA3-256 hash.
+#[cfg(feature = "sha3")]
+pub const SHA3_256_LENGTH: usize = 32;
+
+/// The length of the SHA3-512 hash.
+#[cfg(feature = "sha3")]
+pub const SHA3_512_LENGTH: usize = 64;
+
+/// The `SHA3-256` literal.
+#[cfg(feature = "sha3")]
+pub const SHA3_256: &str = "SHA3-256";
+
+/// The `SHA3-512` literal.
+#[cfg(feature = "sha3")]
+pub const SHA3_512: &str = "SHA3-512";
+
+/// The length of the BLAKE2s hash.
+#[cfg(feature = "blake2")]
+pub const BLAKE2S_LENGTH: usize = 32;
+
+/// The length of the BLAKE2b hash.
+#[cfg(feature = "blake2")]
+pub const BLAKE2B_LENGTH: usize = 64;
+
+/// The `BLAKE2S` literal.
+#[cfg(feature = "blake2")]
+pub const BLAKE2S: &str = "BLAKE2S";
+
+/// The `BLAKE2B` literal.
+#[cfg(feature = "blake2")]
+pub const BLAKE2B: &str = "BLAKE2B";
+
 #[cfg(feature = "serde")]
 impl Serialize for Algorithm {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
@@ -109,9 +185,21 @@ impl Serialize for Algorithm {
 #[cfg(feature = "serde")]
 impl<'de> Deserialize<'de> for Algorithm {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        let string = <&str>::deserialize(deserializer)?;
+        struct Visitor;
+
+        impl de::Visitor<'_> for Visitor {
+            type Value = Algorithm;
 
-        string.parse().map_err(de::Error::custom)
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("algorithm name")
+            }
+
+            fn visit_str<E: de::Error>(self, string: &str) -> Result<Self::Value, E> {
+                string.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
     }
 }
 
@@ -124,6 +212,38 @@ impl Algorithm {
             Self::Sha256 => SHA256,
             #[cfg(feature = "sha2")]
             Self::Sha512 => SHA512,
+            #[cfg(feature = "sha3")]
+            Self::Sha3_256 => SHA3_256,
+            #[cfg(feature = "sha3")]
+            Self::Sha3_512 => SHA3_512,
+            #[cfg(feature = "blake2")]
+            Self::Blake2s => BLAKE2S,
+            #[cfg(feature = "blake2")]
+            Self::Blake2b => BLAKE2B,
+        }
+    }
+
+    /// Checks whether [`Self`] is the default algorithm.
+    pub const fn is_default(&self) -> bool {
+        matches!(self, Self::Sha1)
+    }
+
+    /// Returns the length of the hash (and, therefore, the HMAC) produced by [`Self`].
+    pub const fn hash_length(self) -> usize {
+        match self {
+            Self::Sha1 => SHA1_LENGTH,
+            #[cfg(feature = "sha2")]
+            Self::Sha256 => SHA256_LENGTH,
+            #[cfg(feature = "sha2")]
+            Self::Sha512 => SHA512_LENGTH,
+            #[cfg(feature = "sha3")]
+            Self::Sha3_256 => SHA3_256_LENGTH,
+            #[cfg(feature = "sha3")]
+            Self::Sha3_512 => SHA3_512_LENGTH,
+            #[cfg(feature = "blake2")]
+            Self::Blake2s => BLAKE2S_LENGTH,
+            #[cfg(feature = "blake2")]
+            Self::Blake2b => BLAKE2B_LENGTH,
         }
     }
 
@@ -135,17 +255,74 @@ impl Algorithm {
             Self::Sha256 => SHA256_LENGTH,
             #[cfg(feature = "sha2")]
             Self::Sha512 => SHA512_LENGTH,
+            #[cfg(feature = "sha3")]
+            Self::Sha3_256 => SHA3_256_LENGTH,
+            #[cfg(feature = "sha3")]
+            Self::Sha3_512 => SHA3_512_LENGTH,
+            #[cfg(feature = "blake2")]
+            Self::Blake2s => BLAKE2S_LENGTH,
+            #[cfg(feature = "blake2")]
+            Self::Blake2b => BLAKE2B_LENGTH,
         }
     }
 
     /// Computes HMAC using the [`Self`] algorithm, the key provided, and the given data.
+    ///
+    /// This method allocates the resulting value; see [`hmac_into`] for the allocation-free
+    /// primitive that the code generation is built on.
+    ///
+    /// [`hmac_into`]: Self::hmac_into
     pub fn hmac<K: AsRef<[u8]>, D: AsRef<[u8]>>(self, key: K, data: D) -> Vec<u8> {
+        let mut buffer = [0; HMAC_BUFFER_SIZE];
+
+        let length = self.hmac_into(key, data, &mut buffer);
+
+        let hmac = buffer[..length].to_vec();
+
+        scrub(&mut buffer);
+
+        hmac
+    }
+
+    /// Similar to [`hmac`], except the result is written into the given buffer,
+    /// returning the length used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{
+    ///     algorithm::{HmacBuffer, HMAC_BUFFER_SIZE},
+    ///     Algorithm,
+    /// };
+    ///
+    /// let mut buffer: HmacBuffer = [0; HMAC_BUFFER_SIZE];
+    ///
+    /// let length = Algorithm::Sha1.hmac_into(b"key", b"data", &mut buffer);
+    ///
+    /// assert_eq!(&buffer[..length], Algorithm::Sha1.hmac(b"key", b"data"));
+    /// ```
+    ///
+    /// [`hmac`]: Self::hmac
+    pub fn hmac_into<K: AsRef<[u8]>, D: AsRef<[u8]>>(
+        self,
+        key: K,
+        data: D,
+        buffer: &mut HmacBuffer,
+    ) -> usize {
         match self {
-            Self::Sha1 => hmac_sha1(key, data),
+            Self::Sha1 => hmac_into(new_hmac_sha1(key), data, buffer),
             #[cfg(feature = "sha2")]
-            Self::Sha256 => hmac_sha256(key, data),
+            Self::Sha256 => hmac_into(new_hmac_sha256(key), data, buffer),
             #[cfg(feature = "sha2")]
-            Self::Sha512 => hmac_sha512(key, data),
+            Self::Sha512 => hmac_into(new_hmac_sha512(key), data, buffer),
+            #[cfg(feature = "sha3")]
+            Self::Sha3_256 => hmac_into(new_hmac_sha3_256(key), data, buffer),
+            #[cfg(feature = "sha3")]
+            Self::Sha3_512 => hmac_into(new_hmac_sha3_512(key), data, buffer),
+            #[cfg(feature = "blake2")]
+            Self::Blake2s => hmac_into(new_hmac_blake2s(key), data, buffer),
+            #[cfg(feature = "blake2")]
+            Self::Blake2b => hmac_into(new_hmac_blake2b(key), data, buffer),
         }
     }
 }
@@ -166,6 +343,14 @@ impl FromStr for Algorithm {
             SHA256 => Ok(Self::Sha256),
             #[cfg(feature = "sha2")]
             SHA512 => Ok(Self::Sha512),
+            #[cfg(feature = "sha3")]
+            SHA3_256 => Ok(Self::Sha3_256),
+            #[cfg(feature = "sha3")]
+            SHA3_512 => Ok(Self::Sha3_512),
+            #[cfg(feature = "blake2")]
+            BLAKE2S => Ok(Self::Blake2s),
+            #[cfg(feature = "blake2")]
+            BLAKE2B => Ok(Self::Blake2b),
             _ => Err(error!(string)),
         }
     }
@@ -184,6 +369,35 @@ pub fn hmac<M: Mac, D: AsRef<[u8]>>(mut mac: M, data: D) -> Vec<u8> {
     mac.finalize().into_bytes().to_vec()
 }
 
+/// The size of buffers that can fit HMAC values of any algorithm.
+pub const HMAC_BUFFER_SIZE: usize = 64;
+
+/// Represents buffers used to compute HMAC values without allocating.
+pub type HmacBuffer = [u8; HMAC_BUFFER_SIZE];
+
+/// Computes the HMAC of the given data, writing it into the given buffer.
+///
+/// Returns the length of the HMAC value written.
+///
+/// # Panics
+///
+/// Panics if the HMAC value does not fit into the buffer, which can not happen
+/// for algorithms provided by this crate.
+pub fn hmac_into<M: Mac, D: AsRef<[u8]>>(mut mac: M, data: D, buffer: &mut HmacBuffer) -> usize {
+    mac.update(data.as_ref());
+
+    let mut output = mac.finalize().into_bytes();
+
+    let length = output.len();
+
+    buffer[..length].copy_from_slice(&output);
+
+    // the output is copied into the buffer, so there is no reason to keep it around
+    scrub(&mut output);
+
+    length
+}
+
 /// HMAC accepts any key length.
 pub const HMAC_ANY_KEY_LENGTH: &str = "hmac accepts any key length";
 
@@ -232,3 +446,67 @@ pub fn new_hmac_sha512<K: AsRef<[u8]>>(key: K) -> HmacSha512 {
 pub fn hmac_sha512<K: AsRef<[u8]>, D: AsRef<[u8]>>(key: K, data: D) -> Vec<u8> {
     hmac(new_hmac_sha512(key), data)
 }
+
+/// Creates HMAC using the SHA3-256 algorithm.
+///
+/// # Panics
+///
+/// HMAC accepts any key length, which means this function will not panic.
+#[cfg(feature = "sha3")]
+pub fn new_hmac_sha3_256<K: AsRef<[u8]>>(key: K) -> HmacSha3_256 {
+    HmacSha3_256::new_from_slice(key.as_ref()).expect(HMAC_ANY_KEY_LENGTH)
+}
+
+/// Computes the HMAC using the SHA3-256 algorithm.
+#[cfg(feature = "sha3")]
+pub fn hmac_sha3_256<K: AsRef<[u8]>, D: AsRef<[u8]>>(key: K, data: D) -> Vec<u8> {
+    hmac(new_hmac_sha3_256(key), data)
+}
+
+/// Creates HMAC using the SHA3-512 algorithm.
+///
+/// # Panics
+///
+/// HMAC accepts any key length, which means this function will not panic.
+#[cfg(feature = "sha3")]
+pub fn new_hmac_sha3_512<K: AsRef<[u8]>>(key: K) -> HmacSha3_512 {
+    HmacSha3_512::new_from_slice(key.as_ref()).expect(HMAC_ANY_KEY_LENGTH)
+}
+
+/// Computes the HMAC using the SHA3-512 algorithm.
+#[cfg(feature = "sha3")]
+pub fn hmac_sha3_512<K: AsRef<[u8]>, D: AsRef<[u8]>>(key: K, data: D) -> Vec<u8> {
+    hmac(new_hmac_sha3_512(key), data)
+}
+
+/// Creates HMAC using the BLAKE2s algorithm.
+///
+/// # Panics
+///
+/// HMAC accepts any key length, which means this function will not panic.
+#[cfg(feature = "blake2")]
+pub fn new_hmac_blake2s<K: AsRef<[u8]>>(key: K) -> HmacBlake2s {
+    HmacBlake2s::new_from_slice(key.as_ref()).expect(HMAC_ANY_KEY_LENGTH)
+}
+
+/// Computes the HMAC using the BLAKE2s algorithm.
+#[cfg(feature = "blake2")]
+pub fn hmac_blake2s<K: AsRef<[u8]>, D: AsRef<[u8]>>(key: K, data: D) -> Vec<u8> {
+    hmac(new_hmac_blake2s(key), data)
+}
+
+/// Creates HMAC using the BLAKE2b algorithm.
+///
+/// # Panics
+///
+/// HMAC accepts any key length, which means this function will not panic.
+#[cfg(feature = "blake2")]
+pub fn new_hmac_blake2b<K: AsRef<[u8]>>(key: K) -> HmacBlake2b {
+    HmacBlake2b::new_from_slice(key.as_ref()).expect(HMAC_ANY_KEY_LENGTH)
+}
+
+/// Computes the HMAC using the BLAKE2b algorithm.
+#[cfg(feature = "blake2")]
+pub fn hmac_blake2b<K: AsRef<[u8]>, D: AsRef<[u8]>>(key: K, data: D) -> Vec<u8> {
+    hmac(new_hmac_blake2b(key), data)
+}
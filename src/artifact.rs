@@ -0,0 +1,268 @@
+//! Version-tagged artifacts with integrity checks.
+//!
+//! Serialized artifacts (like sealed stores and migration payloads) are laid out as the format
+//! version byte, followed by the payload and the HMAC tag of both, computed with the
+//! user-supplied key (see [`Seal`]). Opening artifacts verifies the tag and the version,
+//! so that corrupted or tampered storage is detected early.
+
+use constant_time_eq::constant_time_eq;
+use miette::Diagnostic;
+use thiserror::Error;
+
+use crate::{
+    algorithm::Algorithm,
+    builder::Unset,
+    macros::{errors, setters},
+};
+
+/// The length of format versions.
+pub const VERSION_LENGTH: usize = 1;
+
+/// Represents errors returned when artifacts are too short to be valid.
+#[derive(Debug, Error, Diagnostic)]
+#[error("artifact of length `{length}` is too short")]
+#[diagnostic(
+    code(otp_std::artifact::truncated),
+    help("make sure the artifact is not truncated")
+)]
+pub struct TruncatedError {
+    /// The length of the artifact.
+    pub length: usize,
+}
+
+impl TruncatedError {
+    /// Constructs [`Self`].
+    pub const fn new(length: usize) -> Self {
+        Self { length }
+    }
+}
+
+/// Represents errors returned when integrity tags do not match.
+#[derive(Debug, Error, Diagnostic)]
+#[error("artifact integrity check failed")]
+#[diagnostic(
+    code(otp_std::artifact::tampered),
+    help("the artifact is corrupted, tampered with, or sealed with another key")
+)]
+pub struct TamperedError;
+
+/// Represents errors returned when format versions do not match.
+#[derive(Debug, Error, Diagnostic)]
+#[error("expected artifact version `{expected}`, got `{actual}`")]
+#[diagnostic(
+    code(otp_std::artifact::version),
+    help("make sure the artifact is produced by the compatible version")
+)]
+pub struct WrongVersionError {
+    /// The expected version.
+    pub expected: u8,
+    /// The actual version.
+    pub actual: u8,
+}
+
+impl WrongVersionError {
+    /// Constructs [`Self`].
+    pub const fn new(expected: u8, actual: u8) -> Self {
+        Self { expected, actual }
+    }
+}
+
+/// Represents sources of errors that can occur when opening artifacts.
+#[derive(Debug, Error, Diagnostic)]
+#[error(transparent)]
+#[diagnostic(transparent)]
+#[non_exhaustive]
+pub enum ErrorSource {
+    /// The artifact is too short.
+    Truncated(#[from] TruncatedError),
+    /// The integrity tag does not match.
+    Tampered(#[from] TamperedError),
+    /// The format version does not match.
+    WrongVersion(#[from] WrongVersionError),
+}
+
+/// Represents errors that can occur when opening artifacts.
+#[derive(Debug, Error, Diagnostic)]
+#[error("failed to open artifact")]
+#[diagnostic(code(otp_std::artifact), help("see the report for more information"))]
+pub struct Error {
+    /// The source of this error.
+    #[source]
+    #[diagnostic_source]
+    pub source: ErrorSource,
+}
+
+impl Error {
+    /// Constructs [`Self`].
+    pub const fn new(source: ErrorSource) -> Self {
+        Self { source }
+    }
+
+    /// Constructs [`Self`] from [`TruncatedError`].
+    pub fn truncated(error: TruncatedError) -> Self {
+        Self::new(error.into())
+    }
+
+    /// Creates [`TruncatedError`] and constructs [`Self`] from it.
+    pub fn new_truncated(length: usize) -> Self {
+        Self::truncated(TruncatedError::new(length))
+    }
+
+    /// Constructs [`Self`] from [`TamperedError`].
+    pub fn tampered(error: TamperedError) -> Self {
+        Self::new(error.into())
+    }
+
+    /// Creates [`TamperedError`] and constructs [`Self`] from it.
+    pub fn new_tampered() -> Self {
+        Self::tampered(TamperedError)
+    }
+
+    /// Constructs [`Self`] from [`WrongVersionError`].
+    pub fn wrong_version(error: WrongVersionError) -> Self {
+        Self::new(error.into())
+    }
+
+    /// Creates [`WrongVersionError`] and constructs [`Self`] from it.
+    pub fn new_wrong_version(expected: u8, actual: u8) -> Self {
+        Self::wrong_version(WrongVersionError::new(expected, actual))
+    }
+}
+
+errors! {
+    Type = Error,
+    Hack = $,
+    truncated_error => new_truncated(length),
+    tampered_error => new_tampered(),
+    wrong_version_error => new_wrong_version(expected, actual),
+}
+
+/// Represents sealing configurations, that is, the key and the algorithm used to compute tags.
+///
+/// # Examples
+///
+/// ```
+/// use otp_std::artifact::Seal;
+///
+/// let seal = Seal::new(b"key");
+///
+/// let artifact = seal.seal(1, b"payload");
+///
+/// assert_eq!(seal.open(1, &artifact).unwrap(), b"payload");
+///
+/// assert!(Seal::new(b"other").open(1, &artifact).is_err());
+/// assert!(seal.open(2, &artifact).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Seal<'s> {
+    /// The key to compute tags with.
+    pub key: &'s [u8],
+    /// The algorithm to compute tags with.
+    pub algorithm: Algorithm,
+}
+
+/// Represents builders of [`Seal`] (see [`Seal::builder`]).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SealBuilder<K = Unset> {
+    key: K,
+    algorithm: Option<Algorithm>,
+}
+
+impl Seal<'_> {
+    /// Starts building [`Seal`].
+    pub fn builder() -> SealBuilder {
+        SealBuilder::default()
+    }
+}
+
+impl SealBuilder {
+    /// Sets the `key` member.
+    pub fn key(self, key: &[u8]) -> SealBuilder<&[u8]> {
+        SealBuilder {
+            key,
+            algorithm: self.algorithm,
+        }
+    }
+}
+
+impl<K> SealBuilder<K> {
+    setters! {
+        algorithm, maybe_algorithm: Algorithm;
+    }
+}
+
+impl<'s> SealBuilder<&'s [u8]> {
+    /// Finishes building [`Seal`].
+    pub fn build(self) -> Seal<'s> {
+        Seal {
+            key: self.key,
+            algorithm: self.algorithm.unwrap_or_default(),
+        }
+    }
+}
+
+impl<'s> Seal<'s> {
+    /// Constructs [`Self`] using the default algorithm.
+    pub const fn new(key: &'s [u8]) -> Self {
+        Self {
+            key,
+            algorithm: Algorithm::Sha1,
+        }
+    }
+}
+
+impl Seal<'_> {
+    /// Returns the length of tags.
+    pub const fn tag_length(&self) -> usize {
+        self.algorithm.hash_length()
+    }
+
+    /// Computes the tag of the given data.
+    pub fn tag<D: AsRef<[u8]>>(&self, data: D) -> Vec<u8> {
+        self.algorithm.hmac(self.key, data)
+    }
+
+    /// Seals the given payload, tagging it with the given version.
+    pub fn seal<P: AsRef<[u8]>>(&self, version: u8, payload: P) -> Vec<u8> {
+        let payload = payload.as_ref();
+
+        let mut artifact = Vec::with_capacity(VERSION_LENGTH + payload.len() + self.tag_length());
+
+        artifact.push(version);
+        artifact.extend_from_slice(payload);
+
+        let tag = self.tag(&artifact);
+
+        artifact.extend_from_slice(&tag);
+
+        artifact
+    }
+
+    /// Opens the given artifact, returning its payload.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`struct@Error`] if the artifact is too short, the tag does not match,
+    /// or the version is not the expected one.
+    pub fn open<'a>(&self, version: u8, artifact: &'a [u8]) -> Result<&'a [u8], Error> {
+        let length = artifact.len();
+
+        let Some(split) = length.checked_sub(self.tag_length()) else {
+            return Err(truncated_error!(length));
+        };
+
+        let (data, tag) = artifact.split_at(split);
+
+        if !constant_time_eq(&self.tag(data), tag) {
+            return Err(tampered_error!());
+        };
+
+        let (&actual, payload) = data.split_first().ok_or_else(|| truncated_error!(length))?;
+
+        if actual != version {
+            return Err(wrong_version_error!(version, actual));
+        };
+
+        Ok(payload)
+    }
+}
@@ -1,6 +1,5 @@
 //! Core functionality for authentication.
 
-use bon::Builder;
 use miette::Diagnostic;
 
 #[cfg(feature = "serde")]
@@ -11,12 +10,18 @@ use thiserror::Error;
 use crate::{
     auth::{
         self,
+        extensions::Extensions,
         label::{self, Label},
+        options::{IssuerError, ParseOptions, StrictError},
         query::Query,
-        scheme,
-        url::{self, Url},
+        redact::{redact_secrets, Input},
+        scheme::{self, SchemePolicy},
+        url::{self, Position, Url},
     },
-    macros::errors,
+    base::SECRET,
+    builder::Unset,
+    limit::{self, SkewLimit},
+    macros::{errors, setters},
     otp::{
         self,
         core::Otp,
@@ -30,32 +35,116 @@ pub const SCHEME: &str = "otpauth";
 /// Base OTP URL is always valid.
 pub const BASE_URL_ALWAYS_VALID: &str = "OTP base URL is always valid";
 
+const fn default_emit_extensions() -> bool {
+    true
+}
+
 /// Represents OTP authentication.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Builder)]
+#[derive(Debug, Clone)]
+#[cfg_attr(not(feature = "forbid-secret-eq"), derive(PartialEq, Eq, Hash))]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Auth<'a> {
     /// The OTP configuration.
-    #[builder(into)]
     pub otp: Otp<'a>,
     /// The authentication label.
     pub label: Label<'a>,
+    /// The vendor extensions.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Extensions::is_empty")
+    )]
+    pub extensions: Extensions,
+    /// Whether to emit the vendor extensions when building OTP URLs.
+    ///
+    /// This is enabled by default, so that unrecognized parameters of parsed OTP URLs
+    /// are preserved when building URLs back.
+    #[cfg_attr(feature = "serde", serde(skip, default = "default_emit_extensions"))]
+    pub emit_extensions: bool,
+}
+
+/// Represents builders of [`Auth`] (see [`Auth::builder`]).
+#[derive(Debug, Clone, Default)]
+pub struct AuthBuilder<O = Unset, L = Unset> {
+    otp: O,
+    label: L,
+    extensions: Option<Extensions>,
+    emit_extensions: Option<bool>,
+}
+
+impl Auth<'_> {
+    /// Starts building [`Auth`].
+    pub fn builder() -> AuthBuilder {
+        AuthBuilder::default()
+    }
+}
+
+impl<L> AuthBuilder<Unset, L> {
+    /// Sets the `otp` member.
+    pub fn otp<'a, O: Into<Otp<'a>>>(self, otp: O) -> AuthBuilder<Otp<'a>, L> {
+        AuthBuilder {
+            otp: otp.into(),
+            label: self.label,
+            extensions: self.extensions,
+            emit_extensions: self.emit_extensions,
+        }
+    }
+}
+
+impl<O> AuthBuilder<O, Unset> {
+    /// Sets the `label` member.
+    pub fn label(self, label: Label<'_>) -> AuthBuilder<O, Label<'_>> {
+        AuthBuilder {
+            otp: self.otp,
+            label,
+            extensions: self.extensions,
+            emit_extensions: self.emit_extensions,
+        }
+    }
+}
+
+impl<O, L> AuthBuilder<O, L> {
+    setters! {
+        extensions, maybe_extensions: Extensions;
+        emit_extensions, maybe_emit_extensions: bool;
+    }
+}
+
+impl<'a> AuthBuilder<Otp<'a>, Label<'a>> {
+    /// Finishes building [`Auth`].
+    pub fn build(self) -> Auth<'a> {
+        Auth {
+            otp: self.otp,
+            label: self.label,
+            extensions: self.extensions.unwrap_or_default(),
+            emit_extensions: self.emit_extensions.unwrap_or_else(default_emit_extensions),
+        }
+    }
 }
 
 /// Represents sources of errors that can occur when parsing OTP URLs.
 #[derive(Debug, Error, Diagnostic)]
 #[error(transparent)]
 #[diagnostic(transparent)]
+#[non_exhaustive]
 pub enum ErrorSource {
     /// URL could not be parsed.
     Url(#[from] url::Error),
     /// Unexpected scheme found.
     Scheme(#[from] scheme::Error),
+    /// Scheme not accepted by the policy found.
+    SchemePolicy(#[from] scheme::PolicyError),
     /// OTP type extraction failed.
     TypeOf(#[from] type_of::Error),
     /// Label could not be extracted.
     Label(#[from] label::Error),
+    /// Issuer did not match the expected one.
+    Issuer(#[from] IssuerError),
+    /// Unrecognized parameters found in strict mode.
+    Strict(#[from] StrictError),
     /// OTP extraction failed.
     Otp(#[from] otp::core::Error),
+    /// Verification window exceeds the limit.
+    SkewLimit(#[from] limit::Error),
 }
 
 /// Represents errors that can occur when parsing OTP URLs.
@@ -87,6 +176,11 @@ impl Error {
         Self::new(error.into(), string)
     }
 
+    /// Constructs [`Self`] from [`scheme::PolicyError`].
+    pub fn scheme_policy(error: scheme::PolicyError, string: String) -> Self {
+        Self::new(error.into(), string)
+    }
+
     /// Constructs [`Self`] from [`type_of::Error`].
     pub fn type_of(error: type_of::Error, string: String) -> Self {
         Self::new(error.into(), string)
@@ -97,10 +191,25 @@ impl Error {
         Self::new(error.into(), string)
     }
 
+    /// Constructs [`Self`] from [`IssuerError`].
+    pub fn issuer(error: IssuerError, string: String) -> Self {
+        Self::new(error.into(), string)
+    }
+
+    /// Constructs [`Self`] from [`StrictError`].
+    pub fn strict(error: StrictError, string: String) -> Self {
+        Self::new(error.into(), string)
+    }
+
     /// Constructs [`Self`] from [`otp::core::Error`].
     pub fn otp(error: otp::core::Error, string: String) -> Self {
         Self::new(error.into(), string)
     }
+
+    /// Constructs [`Self`] from [`limit::Error`].
+    pub fn skew_limit(error: limit::Error, string: String) -> Self {
+        Self::new(error.into(), string)
+    }
 }
 
 impl Auth<'_> {
@@ -113,6 +222,67 @@ impl Auth<'_> {
     pub const fn label(&self) -> &Label<'_> {
         &self.label
     }
+
+    /// Returns the vendor extensions reference.
+    pub const fn extensions(&self) -> &Extensions {
+        &self.extensions
+    }
+}
+
+impl Auth<'_> {
+    /// Checks whether [`Self`] and the other authentication refer to the same account,
+    /// comparing their labels (see [`Label::matches`]).
+    ///
+    /// Note that the OTP configurations are not compared.
+    pub fn matches(&self, other: &Auth<'_>) -> bool {
+        self.label().matches(other.label())
+    }
+
+    /// Checks whether [`Self`] and the other authentication have the same identity,
+    /// comparing their labels exactly.
+    ///
+    /// Unlike [`matches`], labels are not normalized.
+    ///
+    /// [`matches`]: Self::matches
+    pub fn same_identity(&self, other: &Auth<'_>) -> bool {
+        self.label() == other.label()
+    }
+
+    /// Checks whether [`Self`] and the other authentication have the same parameters,
+    /// comparing everything except the secrets (see [`Otp::same_parameters`]).
+    ///
+    /// This is useful to deduplicate accounts that appear multiple times with rotated secrets,
+    /// and is available even if the `forbid-secret-eq` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{Auth, Base, Label, Part, Secret, Totp};
+    ///
+    /// let label = Label::builder()
+    ///     .issuer(Part::borrowed("MelodyKit").unwrap())
+    ///     .user(Part::borrowed("nekitdev").unwrap())
+    ///     .build();
+    ///
+    /// let build = |encoded| {
+    ///     let base = Base::builder().secret(Secret::decode(encoded).unwrap()).build();
+    ///
+    ///     let totp = Totp::builder().base(base).build();
+    ///
+    ///     Auth::builder().otp(totp).label(label.clone()).build()
+    /// };
+    ///
+    /// let auth = build("JEQDYMZAN5YGK3RAONXXK4TDMU");
+    /// let rotated = build("KRUGKIDROVUWG2ZAMJZG653OEBTG66BA");
+    ///
+    /// assert!(auth.same_identity(&rotated));
+    /// assert!(auth.same_parameters(&rotated));
+    /// ```
+    pub fn same_parameters(&self, other: &Auth<'_>) -> bool {
+        self.otp().same_parameters(other.otp())
+            && self.label() == other.label()
+            && self.extensions() == other.extensions()
+    }
 }
 
 /// Represents `(otp, label)` parts of the authentication.
@@ -150,11 +320,15 @@ impl<'a> From<Auth<'a>> for Parts<'a> {
 errors! {
     Type = Error,
     Hack = $,
-    parse_error => parse(error, string => to_owned),
-    scheme_error => scheme(error, string => to_owned),
-    type_of_error => type_of(error, string => to_owned),
-    label_error => label(error, string => to_owned),
-    otp_error => otp(error, string => to_owned),
+    parse_error => parse(error, string => redact),
+    scheme_error => scheme(error, string => redact),
+    scheme_policy_error => scheme_policy(error, string => redact),
+    type_of_error => type_of(error, string => redact),
+    label_error => label(error, string => redact),
+    issuer_error => issuer(error, string => redact),
+    strict_error => strict(error, string => redact),
+    otp_error => otp(error, string => redact),
+    skew_limit_error => skew_limit(error, string => redact),
 }
 
 impl Auth<'_> {
@@ -164,53 +338,296 @@ impl Auth<'_> {
     ///
     /// The base URL is always valid, so this method should never panic.
     pub fn base_url(&self) -> Url {
-        url::base(self.otp().type_of(), self.label())
+        self.try_base_url().expect(BASE_URL_ALWAYS_VALID)
+    }
+
+    /// Constructs the OTP URL base, using the scheme of the given policy.
+    ///
+    /// # Panics
+    ///
+    /// The base URL is always valid, so this method should never panic.
+    pub fn base_url_with(&self, policy: &SchemePolicy<'_>) -> Url {
+        self.try_base_url_with(policy).expect(BASE_URL_ALWAYS_VALID)
+    }
+
+    /// Similar to [`base_url`], except the error is returned instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`url::Error`] if the base URL can not be parsed.
+    ///
+    /// [`base_url`]: Self::base_url
+    pub fn try_base_url(&self) -> Result<Url, url::Error> {
+        url::try_base(self.otp().type_of(), self.label())
+    }
+
+    /// Similar to [`base_url_with`], except the error is returned instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`url::Error`] if the base URL can not be parsed.
+    ///
+    /// [`base_url_with`]: Self::base_url_with
+    pub fn try_base_url_with(&self, policy: &SchemePolicy<'_>) -> Result<Url, url::Error> {
+        url::try_base_with(policy, self.otp().type_of(), self.label())
     }
 
     /// Builds the OTP URL, applying query parameters to the base URL created.
+    ///
+    /// # Panics
+    ///
+    /// The base URL is always valid, so this method should never panic.
     pub fn build_url(&self) -> Url {
-        let mut url = self.base_url();
+        self.try_build_url().expect(BASE_URL_ALWAYS_VALID)
+    }
+
+    /// Similar to [`build_url`], except the error is returned instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`url::Error`] if the base URL can not be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{Auth, Base, Label, Part, Secret, Totp};
+    ///
+    /// let secret = Secret::decode("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+    ///
+    /// let base = Base::builder().secret(secret).build();
+    /// let totp = Totp::builder().base(base).build();
+    ///
+    /// let user = Part::borrowed("nekitdev").unwrap();
+    /// let label = Label::builder().user(user).build();
+    ///
+    /// let auth = Auth::builder().otp(totp).label(label).build();
+    ///
+    /// assert_eq!(auth.try_build_url().unwrap(), auth.build_url());
+    /// ```
+    ///
+    /// [`build_url`]: Self::build_url
+    pub fn try_build_url(&self) -> Result<Url, url::Error> {
+        self.try_build_url_with(&SchemePolicy::DEFAULT)
+    }
+
+    /// Similar to [`build_url_with`], except the error is returned instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`url::Error`] if the base URL can not be parsed.
+    ///
+    /// [`build_url_with`]: Self::build_url_with
+    pub fn try_build_url_with(&self, policy: &SchemePolicy<'_>) -> Result<Url, url::Error> {
+        let mut url = self.try_base_url_with(policy)?;
 
         self.query_for(&mut url);
 
-        url
+        Ok(url)
+    }
+
+    /// Builds the OTP URL using the scheme of the given policy.
+    ///
+    /// # Panics
+    ///
+    /// The base URL is always valid, so this method should never panic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{auth::scheme::SchemePolicy, Auth, Base, Label, Part, Secret, Totp};
+    ///
+    /// let secret = Secret::decode("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+    ///
+    /// let base = Base::builder().secret(secret).build();
+    /// let totp = Totp::builder().base(base).build();
+    ///
+    /// let user = Part::borrowed("nekitdev").unwrap();
+    /// let label = Label::builder().user(user).build();
+    ///
+    /// let auth = Auth::builder().otp(totp).label(label).build();
+    ///
+    /// let policy = SchemePolicy::new("otpauth-test").unwrap();
+    ///
+    /// let url = auth.build_url_with(&policy);
+    ///
+    /// assert_eq!(url.scheme(), "otpauth-test");
+    ///
+    /// let parsed = Auth::parse_url_with(url, &policy).unwrap();
+    ///
+    /// assert!(parsed.same_parameters(&auth));
+    /// ```
+    pub fn build_url_with(&self, policy: &SchemePolicy<'_>) -> Url {
+        self.try_build_url_with(policy)
+            .expect(BASE_URL_ALWAYS_VALID)
+    }
+
+    /// Builds the OTP URL string optimized for the alphanumeric mode of QR codes.
+    ///
+    /// The scheme and the type are uppercased, since both are case-insensitive, and the secret
+    /// is moved to the end of the query, so that the Base32 secret forms the longest possible
+    /// alphanumeric run. This allows QR encoders to produce smaller codes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{Auth, Base, Label, Part, Secret, Totp};
+    ///
+    /// let secret = Secret::decode("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+    ///
+    /// let base = Base::builder().secret(secret).build();
+    /// let totp = Totp::builder().base(base).build();
+    ///
+    /// let user = Part::borrowed("nekitdev").unwrap();
+    /// let label = Label::builder().user(user).build();
+    ///
+    /// let auth = Auth::builder().otp(totp).label(label).build();
+    ///
+    /// let data = auth.to_qr_data();
+    ///
+    /// assert!(data.starts_with("OTPAUTH://TOTP/nekitdev?"));
+    /// assert!(data.ends_with("&secret=JEQDYMZAN5YGK3RAONXXK4TDMU"));
+    ///
+    /// assert!(Auth::parse_url(data).unwrap().same_parameters(&auth));
+    /// ```
+    pub fn to_qr_data(&self) -> String {
+        let mut url = self.build_url();
+
+        let mut pairs: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+
+        if let Some(index) = pairs.iter().position(|(name, _)| name == SECRET) {
+            let secret = pairs.remove(index);
+
+            pairs.push(secret);
+        };
+
+        url.query_pairs_mut().clear().extend_pairs(pairs);
+
+        format!(
+            "{scheme}://{type_of}{rest}",
+            scheme = url.scheme().to_ascii_uppercase(),
+            type_of = self.otp().type_of().static_str().to_ascii_uppercase(),
+            rest = &url[Position::BeforePath..],
+        )
     }
 
     /// Applies the OTP configuration and the issuer to the given URL.
+    ///
+    /// The vendor extensions are applied as well if [`emit_extensions`] is set.
+    ///
+    /// [`emit_extensions`]: Self::emit_extensions
     pub fn query_for(&self, url: &mut Url) {
         self.otp().query_for(url);
         self.label().query_for(url);
+
+        if self.emit_extensions {
+            self.extensions().query_for(url);
+        };
     }
 
     /// Parses the OTP URL from the given string.
     ///
+    /// The verification window is checked against [`SkewLimit::DEFAULT`];
+    /// use [`parse_url_with_options`] to configure the limit.
+    ///
     /// # Errors
     ///
     /// Returns [`struct@Error`] if anything goes wrong.
+    ///
+    /// [`parse_url_with_options`]: Self::parse_url_with_options
     pub fn parse_url<S: AsRef<str>>(string: S) -> Result<Self, Error> {
-        fn parse_url_inner(string: &str) -> Result<OwnedParts, Error> {
-            let url = auth::url::parse(string).map_err(|error| parse_error!(error, string))?;
+        fn parse_url_inner(input: Input<'_>) -> Result<Owned, Error> {
+            let url = auth::url::parse(input.get()).map_err(|error| parse_error!(error, input))?;
 
-            auth::scheme::check_url(&url).map_err(|error| scheme_error!(error, string))?;
+            auth::scheme::check_url(&url).map_err(|error| scheme_error!(error, input))?;
 
-            let type_of =
-                Type::extract_from(&url).map_err(|error| type_of_error!(error, string))?;
+            let auth = extract(&url, input)?;
 
-            let mut query: Query<'_> = url.query_pairs().collect();
+            auth.otp()
+                .check_skew_limit(SkewLimit::DEFAULT)
+                .map_err(|error| skew_limit_error!(error, input))?;
 
-            let label = Label::extract_from(&mut query, &url)
-                .map_err(|error| label_error!(error, string))?;
+            Ok(auth)
+        }
 
-            let otp = Otp::extract_from(&mut query, type_of)
-                .map_err(|error| otp_error!(error, string))?;
+        parse_url_inner(Input::new(string.as_ref(), redact_secrets))
+    }
+
+    /// Parses the OTP URL from the given string, accepting schemes according to the policy.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`struct@Error`] if anything goes wrong.
+    pub fn parse_url_with<S: AsRef<str>>(
+        string: S,
+        policy: &SchemePolicy<'_>,
+    ) -> Result<Self, Error> {
+        let options = ParseOptions::builder().scheme(*policy).build();
+
+        Self::parse_url_with_options(string, &options)
+    }
 
-            Ok((otp, label))
+    /// Parses the OTP URL from the given string according to the given options.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`struct@Error`] if anything goes wrong, including the issuer mismatch,
+    /// unrecognized parameters in strict mode and verification windows exceeding the limit.
+    pub fn parse_url_with_options<S: AsRef<str>>(
+        string: S,
+        options: &ParseOptions<'_>,
+    ) -> Result<Self, Error> {
+        fn parse_url_with_options_inner(
+            input: Input<'_>,
+            options: &ParseOptions<'_>,
+        ) -> Result<Owned, Error> {
+            let url = auth::url::parse(input.get()).map_err(|error| parse_error!(error, input))?;
+
+            options
+                .scheme
+                .check_url(&url)
+                .map_err(|error| scheme_policy_error!(error, input))?;
+
+            let auth = extract(&url, input)?;
+
+            options
+                .check_issuer(auth.label())
+                .map_err(|error| issuer_error!(error, input))?;
+
+            options
+                .check_extensions(auth.extensions())
+                .map_err(|error| strict_error!(error, input))?;
+
+            options
+                .check_skew_limit(auth.otp())
+                .map_err(|error| skew_limit_error!(error, input))?;
+
+            Ok(auth)
         }
 
-        parse_url_inner(string.as_ref()).map(Self::from_parts)
+        parse_url_with_options_inner(Input::new(string.as_ref(), options.redact), options)
     }
 }
 
+fn extract(url: &Url, input: Input<'_>) -> Result<Owned, Error> {
+    let type_of = Type::extract_from(url).map_err(|error| type_of_error!(error, input))?;
+
+    let mut query: Query<'_> = url.query_pairs().collect();
+
+    let label = Label::extract_from(&mut query, url).map_err(|error| label_error!(error, input))?;
+
+    let otp = Otp::extract_from(&mut query, type_of).map_err(|error| otp_error!(error, input))?;
+
+    let extensions = Extensions::extract_from(&mut query, url);
+
+    let auth = Owned::builder()
+        .otp(otp)
+        .label(label)
+        .extensions(extensions)
+        .build();
+
+    Ok(auth)
+}
+
 /// Represents owned [`Auth`].
 pub type Owned = Auth<'static>;
 
@@ -220,6 +637,8 @@ impl Auth<'_> {
         Owned::builder()
             .otp(self.otp.into_owned())
             .label(self.label.into_owned())
+            .extensions(self.extensions)
+            .emit_extensions(self.emit_extensions)
             .build()
     }
 }
@@ -0,0 +1,365 @@
+//! Vendor extensions of OTP URLs.
+//!
+//! Some applications add non-standard query parameters to OTP URLs, for instance,
+//! [`IMAGE`] (the URL of the issuer image) and [`COLOR`] (the color hint).
+//! The [`Extensions`] type stores such parameters in the order they were encountered.
+//!
+//! When building OTP URLs, extensions are emitted after the known parameters,
+//! so that OTP URLs built by this crate round-trip byte for byte.
+//!
+//! Extensions can not use the names of the known parameters (see [`RESERVED`]),
+//! as they would otherwise replace the actual parameters when parsing OTP URLs.
+
+use std::{collections::HashSet, fmt, mem, slice, str::FromStr};
+
+use miette::Diagnostic;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use thiserror::Error;
+
+use crate::{
+    auth::{label::ISSUER, query::Query, url::Url},
+    base::{ALGORITHM, DIGITS, SECRET},
+    hotp::{COUNTER, WINDOW},
+    macros::errors,
+    totp::PERIOD,
+};
+
+/// The `image` literal.
+pub const IMAGE: &str = "image";
+
+/// The `color` literal.
+pub const COLOR: &str = "color";
+
+/// The prefix optionally used in colors.
+pub const HASH: char = '#';
+
+/// The names of the known parameters, which can not be used by extensions.
+pub const RESERVED: &[&str] = &[SECRET, ISSUER, ALGORITHM, DIGITS, PERIOD, COUNTER, WINDOW];
+
+/// Checks whether the given name is reserved (see [`RESERVED`]).
+pub fn is_reserved(name: &str) -> bool {
+    RESERVED.contains(&name)
+}
+
+/// Represents errors returned when extensions use reserved names (see [`RESERVED`]).
+#[derive(Debug, Error, Diagnostic)]
+#[error("extension name `{name}` is reserved")]
+#[diagnostic(
+    code(otp_std::auth::extensions::reserved),
+    help("extensions can not use the names of the known parameters")
+)]
+pub struct ReservedError {
+    /// The reserved name.
+    pub name: String,
+}
+
+impl ReservedError {
+    /// Constructs [`Self`].
+    pub const fn new(name: String) -> Self {
+        Self { name }
+    }
+}
+
+errors! {
+    Type = ReservedError,
+    Hack = $,
+    reserved_error => new(name => to_owned),
+}
+
+/// Checks whether the given name can be used by extensions.
+///
+/// # Errors
+///
+/// Returns [`ReservedError`] if the name is reserved.
+pub fn check(name: &str) -> Result<(), ReservedError> {
+    if is_reserved(name) {
+        return Err(reserved_error!(name));
+    };
+
+    Ok(())
+}
+
+/// Represents RGB color hints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color {
+    /// The red component.
+    pub red: u8,
+    /// The green component.
+    pub green: u8,
+    /// The blue component.
+    pub blue: u8,
+}
+
+impl Color {
+    /// Constructs [`Self`].
+    pub const fn new(red: u8, green: u8, blue: u8) -> Self {
+        Self { red, green, blue }
+    }
+
+    /// Parses [`Self`] from hexadecimal strings like `FF8000` or `#FF8000`.
+    pub fn parse(string: &str) -> Option<Self> {
+        let hex = string.strip_prefix(HASH).unwrap_or(string);
+
+        if hex.len() != 6 || !hex.is_ascii() {
+            return None;
+        };
+
+        let component = |index: usize| u8::from_str_radix(&hex[index..index + 2], 16).ok();
+
+        Some(Self::new(component(0)?, component(2)?, component(4)?))
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "{:02X}{:02X}{:02X}",
+            self.red, self.green, self.blue
+        )
+    }
+}
+
+/// Represents vendor extensions, that is, non-standard query parameters.
+///
+/// The parameters are stored in the order they were inserted.
+/// Reserved names (see [`RESERVED`]) are never stored.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "Vec<(String, String)>", try_from = "Vec<(String, String)>")
+)]
+pub struct Extensions {
+    pairs: Vec<(String, String)>,
+}
+
+impl Extensions {
+    /// Constructs empty [`Self`].
+    pub const fn new() -> Self {
+        Self { pairs: Vec::new() }
+    }
+
+    /// Returns the number of extensions.
+    pub fn len(&self) -> usize {
+        self.pairs.len()
+    }
+
+    /// Checks whether there are no extensions.
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+
+    /// Returns the value of the given extension, if present.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.pairs
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Inserts the given extension, replacing and returning the previous value, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReservedError`] if the name is reserved.
+    pub fn insert<N: Into<String>, V: Into<String>>(
+        &mut self,
+        name: N,
+        value: V,
+    ) -> Result<Option<String>, ReservedError> {
+        let name = name.into();
+
+        check(&name)?;
+
+        Ok(self.insert_unchecked(name, value.into()))
+    }
+
+    fn insert_unchecked(&mut self, name: String, value: String) -> Option<String> {
+        match self.pairs.iter_mut().find(|(key, _)| *key == name) {
+            Some((_, previous)) => Some(mem::replace(previous, value)),
+            None => {
+                self.pairs.push((name, value));
+
+                None
+            }
+        }
+    }
+
+    /// Appends the given extension, keeping any previous values.
+    ///
+    /// Unlike [`insert`], this method allows repeated names, which is used to preserve
+    /// every parameter of parsed OTP URLs.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReservedError`] if the name is reserved.
+    ///
+    /// [`insert`]: Self::insert
+    pub fn append<N: Into<String>, V: Into<String>>(
+        &mut self,
+        name: N,
+        value: V,
+    ) -> Result<(), ReservedError> {
+        let name = name.into();
+
+        check(&name)?;
+
+        self.pairs.push((name, value.into()));
+
+        Ok(())
+    }
+
+    /// Removes the given extension, returning its value, if present.
+    ///
+    /// Only the first value is removed if the name is repeated.
+    pub fn remove(&mut self, name: &str) -> Option<String> {
+        let index = self.pairs.iter().position(|(key, _)| key == name)?;
+
+        Some(self.pairs.remove(index).1)
+    }
+
+    /// Returns the iterator over the extensions.
+    pub fn iter(&self) -> slice::Iter<'_, (String, String)> {
+        self.pairs.iter()
+    }
+
+    /// Returns the raw `image` extension, if present.
+    pub fn image(&self) -> Option<&str> {
+        self.get(IMAGE)
+    }
+
+    /// Returns the `image` extension parsed into [`Url`], if present and valid.
+    pub fn image_url(&self) -> Option<Url> {
+        self.image().and_then(|image| Url::from_str(image).ok())
+    }
+
+    /// Returns the `color` extension parsed into [`Color`], if present and valid.
+    pub fn color(&self) -> Option<Color> {
+        self.get(COLOR).and_then(Color::parse)
+    }
+
+    /// Sets the `image` extension.
+    pub fn set_image(&mut self, image: &Url) {
+        // `image` is not reserved
+        self.insert_unchecked(IMAGE.to_owned(), image.as_str().to_owned());
+    }
+
+    /// Sets the `color` extension.
+    pub fn set_color(&mut self, color: Color) {
+        // `color` is not reserved
+        self.insert_unchecked(COLOR.to_owned(), color.to_string());
+    }
+
+    /// Extracts [`Self`] from parameters remaining in the given query,
+    /// preserving the order they appear in the URL, including repeated names.
+    ///
+    /// Reserved names (see [`RESERVED`]) are skipped, even if they remain in the query.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::auth::{extensions::Extensions, query::Query, url::Url};
+    ///
+    /// let url: Url = "otpauth://totp/?digits=6&tag=a&image=b&tag=c".parse().unwrap();
+    ///
+    /// let mut query: Query<'_> = url.query_pairs().collect();
+    ///
+    /// query.remove("digits");
+    ///
+    /// let extensions = Extensions::extract_from(&mut query, &url);
+    ///
+    /// let names: Vec<_> = extensions.iter().map(|(name, _)| name.as_str()).collect();
+    ///
+    /// assert_eq!(names, ["tag", "image", "tag"]);
+    ///
+    /// assert!(query.is_empty());
+    /// ```
+    pub fn extract_from(query: &mut Query<'_>, url: &Url) -> Self {
+        let names: HashSet<_> = query.drain().map(|(name, _)| name).collect();
+
+        let mut extensions = Self::new();
+
+        for (name, value) in url.query_pairs() {
+            if names.contains(&name) && !is_reserved(&name) {
+                extensions
+                    .pairs
+                    .push((name.into_owned(), value.into_owned()));
+            };
+        }
+
+        extensions
+    }
+
+    /// Returns the names of the extensions, in order and without repetitions.
+    pub fn names(&self) -> Vec<&str> {
+        let mut seen = HashSet::new();
+
+        self.iter()
+            .map(|(name, _)| name.as_str())
+            .filter(|name| seen.insert(*name))
+            .collect()
+    }
+
+    /// Returns the iterator over the extensions that can be emitted,
+    /// that is, the ones not using reserved names (see [`RESERVED`]).
+    pub fn emitted(&self) -> impl Iterator<Item = &(String, String)> {
+        self.iter().filter(|(name, _)| !is_reserved(name))
+    }
+
+    /// Applies the extensions to the given URL, in order, skipping reserved names.
+    pub fn query_for(&self, url: &mut Url) {
+        if self.is_empty() {
+            return;
+        };
+
+        let mut pairs = url.query_pairs_mut();
+
+        for (name, value) in self.emitted() {
+            pairs.append_pair(name, value);
+        }
+    }
+}
+
+/// Collects extensions, skipping reserved names (see [`RESERVED`]).
+///
+/// Use [`TryFrom`] to reject reserved names instead.
+impl<N: Into<String>, V: Into<String>> FromIterator<(N, V)> for Extensions {
+    fn from_iter<I: IntoIterator<Item = (N, V)>>(iterable: I) -> Self {
+        let mut extensions = Self::new();
+
+        for (name, value) in iterable {
+            let _ = extensions.insert(name, value);
+        }
+
+        extensions
+    }
+}
+
+impl TryFrom<Vec<(String, String)>> for Extensions {
+    type Error = ReservedError;
+
+    fn try_from(pairs: Vec<(String, String)>) -> Result<Self, Self::Error> {
+        pairs.iter().try_for_each(|(name, _)| check(name))?;
+
+        Ok(Self { pairs })
+    }
+}
+
+impl From<Extensions> for Vec<(String, String)> {
+    fn from(extensions: Extensions) -> Self {
+        extensions.pairs
+    }
+}
+
+impl<'e> IntoIterator for &'e Extensions {
+    type Item = &'e (String, String);
+    type IntoIter = slice::Iter<'e, (String, String)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
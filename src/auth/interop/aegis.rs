@@ -0,0 +1,188 @@
+//! Plain Aegis vaults.
+//!
+//! Aegis exports vaults as JSON, where the database contains the list of entries.
+//! Only plain (unencrypted) vaults are supported; deserializing encrypted ones fails.
+
+use serde::{Deserialize, Serialize};
+
+use crate::auth::{
+    core::{Auth, Owned},
+    interop::{Error, Exported, Fields},
+};
+
+/// The version of vaults.
+pub const VERSION: u64 = 1;
+
+/// The version of databases.
+pub const DATABASE_VERSION: u64 = 2;
+
+/// Represents encryption parameters, which are not supported.
+///
+/// Since this type has no values, deserializing encrypted vaults fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Encrypted {}
+
+/// Represents vault headers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct Header {
+    /// The key slots, present only in encrypted vaults.
+    pub slots: Option<Encrypted>,
+    /// The encryption parameters, present only in encrypted vaults.
+    pub params: Option<Encrypted>,
+}
+
+/// Represents OTP parameters of vault entries.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Info {
+    /// The Base32-encoded secret.
+    pub secret: String,
+    /// The algorithm name.
+    pub algo: String,
+    /// The number of digits.
+    pub digits: u8,
+    /// The period, used by TOTP entries.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub period: Option<u64>,
+    /// The counter, used by HOTP entries.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub counter: Option<u64>,
+}
+
+/// Represents vault entries.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Entry {
+    /// The entry type, like `totp` or `hotp`.
+    #[serde(rename = "type")]
+    pub type_of: String,
+    /// The unique identifier of the entry, generated by Aegis if empty.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub uuid: String,
+    /// The name of the entry, that is, the user.
+    pub name: String,
+    /// The issuer of the entry.
+    #[serde(default)]
+    pub issuer: String,
+    /// The note attached to the entry.
+    #[serde(default)]
+    pub note: String,
+    /// Whether the entry is marked as favorite.
+    #[serde(default)]
+    pub favorite: bool,
+    /// The OTP parameters.
+    pub info: Info,
+}
+
+impl Entry {
+    /// Converts [`Self`] into [`Owned`] authentication.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`struct@Error`] if the entry can not be represented.
+    pub fn to_auth(&self) -> Result<Owned, Error> {
+        let info = &self.info;
+
+        Fields {
+            type_of: self.type_of.as_str(),
+            secret: info.secret.as_str(),
+            algorithm: info.algo.as_str(),
+            digits: info.digits,
+            period: info.period,
+            counter: info.counter,
+            issuer: self.issuer.as_str(),
+            user: self.name.as_str(),
+        }
+        .into_auth()
+    }
+
+    /// Constructs [`Self`] from the given authentication.
+    pub fn from_auth(auth: &Auth<'_>) -> Self {
+        let exported = Exported::from_auth(auth);
+
+        let info = Info {
+            secret: exported.secret,
+            algo: exported.algorithm.to_owned(),
+            digits: exported.digits,
+            period: exported.period,
+            counter: exported.counter,
+        };
+
+        Self {
+            type_of: exported.type_of.to_string(),
+            uuid: String::new(),
+            name: exported.user,
+            issuer: exported.issuer,
+            note: String::new(),
+            favorite: false,
+            info,
+        }
+    }
+}
+
+/// Represents vault databases.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Database {
+    /// The database version.
+    pub version: u64,
+    /// The entries.
+    pub entries: Vec<Entry>,
+}
+
+/// Represents plain vaults.
+///
+/// # Examples
+///
+/// ```
+/// use otp_std::{auth::interop::aegis::Vault, Auth, Base, Label, Part, Secret, Totp};
+///
+/// let secret = Secret::decode("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+///
+/// let base = Base::builder().secret(secret).build();
+/// let totp = Totp::builder().base(base).build();
+///
+/// let issuer = Part::borrowed("MelodyKit").unwrap();
+/// let user = Part::borrowed("nekitdev").unwrap();
+///
+/// let label = Label::builder().issuer(issuer).user(user).build();
+///
+/// let auth = Auth::builder().otp(totp).label(label).build();
+///
+/// let vault = Vault::from_auths([&auth]);
+///
+/// let auths = vault.to_auths().unwrap();
+///
+/// assert!(auths[0].same_parameters(&auth));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Vault {
+    /// The vault version.
+    pub version: u64,
+    /// The vault header.
+    pub header: Header,
+    /// The vault database.
+    pub db: Database,
+}
+
+impl Vault {
+    /// Converts entries of [`Self`] into [`Owned`] authentications.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`struct@Error`] if any entry can not be represented.
+    pub fn to_auths(&self) -> Result<Vec<Owned>, Error> {
+        self.db.entries.iter().map(Entry::to_auth).collect()
+    }
+
+    /// Constructs [`Self`] from the given authentications.
+    pub fn from_auths<'a, 'b: 'a, I: IntoIterator<Item = &'a Auth<'b>>>(auths: I) -> Self {
+        let entries = auths.into_iter().map(Entry::from_auth).collect();
+
+        Self {
+            version: VERSION,
+            header: Header::default(),
+            db: Database {
+                version: DATABASE_VERSION,
+                entries,
+            },
+        }
+    }
+}
@@ -0,0 +1,109 @@
+//! andOTP JSON backups.
+//!
+//! andOTP exports backups as JSON arrays of entries (see [`from_auths`] and [`to_auths`]).
+
+use serde::{Deserialize, Serialize};
+
+use crate::auth::{
+    core::{Auth, Owned},
+    interop::{Error, Exported, Fields},
+};
+
+/// The default thumbnail of entries.
+pub const DEFAULT_THUMBNAIL: &str = "Default";
+
+fn default_thumbnail() -> String {
+    DEFAULT_THUMBNAIL.to_owned()
+}
+
+/// Represents backup entries.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Entry {
+    /// The Base32-encoded secret.
+    pub secret: String,
+    /// The issuer of the entry.
+    #[serde(default)]
+    pub issuer: String,
+    /// The label of the entry, that is, the user.
+    pub label: String,
+    /// The number of digits.
+    pub digits: u8,
+    /// The entry type, like `TOTP` or `HOTP`.
+    #[serde(rename = "type")]
+    pub type_of: String,
+    /// The algorithm name.
+    pub algorithm: String,
+    /// The thumbnail of the entry.
+    #[serde(default = "default_thumbnail")]
+    pub thumbnail: String,
+    /// The time the entry was last used at, in milliseconds.
+    #[serde(default)]
+    pub last_used: u64,
+    /// The number of times the entry was used.
+    #[serde(default)]
+    pub used_frequency: u64,
+    /// The period, used by TOTP entries.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub period: Option<u64>,
+    /// The counter, used by HOTP entries.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub counter: Option<u64>,
+    /// The tags of the entry.
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+impl Entry {
+    /// Converts [`Self`] into [`Owned`] authentication.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`struct@Error`] if the entry can not be represented.
+    pub fn to_auth(&self) -> Result<Owned, Error> {
+        Fields {
+            type_of: self.type_of.as_str(),
+            secret: self.secret.as_str(),
+            algorithm: self.algorithm.as_str(),
+            digits: self.digits,
+            period: self.period,
+            counter: self.counter,
+            issuer: self.issuer.as_str(),
+            user: self.label.as_str(),
+        }
+        .into_auth()
+    }
+
+    /// Constructs [`Self`] from the given authentication.
+    pub fn from_auth(auth: &Auth<'_>) -> Self {
+        let exported = Exported::from_auth(auth);
+
+        Self {
+            secret: exported.secret,
+            issuer: exported.issuer,
+            label: exported.user,
+            digits: exported.digits,
+            type_of: exported.type_of.static_str().to_ascii_uppercase(),
+            algorithm: exported.algorithm.to_owned(),
+            thumbnail: default_thumbnail(),
+            last_used: 0,
+            used_frequency: 0,
+            period: exported.period,
+            counter: exported.counter,
+            tags: Vec::new(),
+        }
+    }
+}
+
+/// Converts the given entries into [`Owned`] authentications.
+///
+/// # Errors
+///
+/// Returns [`struct@Error`] if any entry can not be represented.
+pub fn to_auths(entries: &[Entry]) -> Result<Vec<Owned>, Error> {
+    entries.iter().map(Entry::to_auth).collect()
+}
+
+/// Converts the given authentications into entries.
+pub fn from_auths<'a, 'b: 'a, I: IntoIterator<Item = &'a Auth<'b>>>(auths: I) -> Vec<Entry> {
+    auths.into_iter().map(Entry::from_auth).collect()
+}
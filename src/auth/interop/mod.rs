@@ -0,0 +1,212 @@
+//! Interoperability with authenticator app backups.
+//!
+//! This module provides [`serde`] types for the backup formats of popular authenticator apps,
+//! convertible to and from [`Owned`] authentications:
+//!
+//! - [`aegis`] for plain (unencrypted) Aegis vaults;
+//! - [`andotp`] for andOTP JSON backups.
+//!
+//! Fields not representable by [`Auth`] (like icons or usage statistics) are ignored
+//! when importing and set to their defaults when exporting.
+
+pub mod aegis;
+pub mod andotp;
+
+use miette::Diagnostic;
+use thiserror::Error;
+
+use crate::{
+    algorithm::{self, Algorithm},
+    auth::{
+        core::{Auth, Owned},
+        label::Label,
+        part::{self, Part},
+    },
+    base::Base,
+    counter::Counter,
+    digits::{self, Digits},
+    hotp::Hotp,
+    otp::{
+        core::Otp,
+        type_of::{self, Type},
+    },
+    period::{self, Period},
+    secret::core::{self as secret, Secret},
+    totp::Totp,
+};
+
+/// Represents sources of errors that can occur when converting backup entries.
+#[derive(Debug, Error, Diagnostic)]
+#[error(transparent)]
+#[diagnostic(transparent)]
+#[non_exhaustive]
+pub enum ErrorSource {
+    /// The type is not supported.
+    Type(#[from] type_of::ParseError),
+    /// The secret is invalid.
+    Secret(#[from] secret::Error),
+    /// The algorithm is not supported.
+    Algorithm(#[from] algorithm::Error),
+    /// The number of digits is out of range.
+    Digits(#[from] digits::Error),
+    /// The period is invalid.
+    Period(#[from] period::Error),
+    /// The issuer or the user is invalid.
+    Part(#[from] part::Error),
+}
+
+/// Represents errors that can occur when converting backup entries.
+#[derive(Debug, Error, Diagnostic)]
+#[error("failed to convert backup entry")]
+#[diagnostic(
+    code(otp_std::auth::interop),
+    help("see the report for more information")
+)]
+pub struct Error {
+    /// The source of this error.
+    #[source]
+    #[diagnostic_source]
+    pub source: ErrorSource,
+}
+
+impl Error {
+    /// Constructs [`Self`].
+    pub const fn new(source: ErrorSource) -> Self {
+        Self { source }
+    }
+
+    /// Constructs [`Self`] from [`type_of::ParseError`].
+    pub fn type_of(error: type_of::ParseError) -> Self {
+        Self::new(error.into())
+    }
+
+    /// Constructs [`Self`] from [`secret::Error`].
+    pub fn secret(error: secret::Error) -> Self {
+        Self::new(error.into())
+    }
+
+    /// Constructs [`Self`] from [`algorithm::Error`].
+    pub fn algorithm(error: algorithm::Error) -> Self {
+        Self::new(error.into())
+    }
+
+    /// Constructs [`Self`] from [`digits::Error`].
+    pub fn digits(error: digits::Error) -> Self {
+        Self::new(error.into())
+    }
+
+    /// Constructs [`Self`] from [`period::Error`].
+    pub fn period(error: period::Error) -> Self {
+        Self::new(error.into())
+    }
+
+    /// Constructs [`Self`] from [`part::Error`].
+    pub fn part(error: part::Error) -> Self {
+        Self::new(error.into())
+    }
+}
+
+/// The fields shared by backup formats.
+struct Fields<'f> {
+    type_of: &'f str,
+    secret: &'f str,
+    algorithm: &'f str,
+    digits: u8,
+    period: Option<u64>,
+    counter: Option<u64>,
+    issuer: &'f str,
+    user: &'f str,
+}
+
+impl Fields<'_> {
+    fn into_auth(self) -> Result<Owned, Error> {
+        let type_of: Type = self
+            .type_of
+            .to_ascii_lowercase()
+            .parse()
+            .map_err(Error::type_of)?;
+
+        let secret = Secret::decode(self.secret).map_err(Error::secret)?;
+
+        let algorithm: Algorithm = self.algorithm.parse().map_err(Error::algorithm)?;
+
+        let digits = Digits::new(self.digits).map_err(Error::digits)?;
+
+        let base = Base::builder()
+            .secret(secret)
+            .algorithm(algorithm)
+            .digits(digits)
+            .build();
+
+        let otp: Otp<'_> = if type_of.requires_counter() {
+            Hotp::builder()
+                .base(base)
+                .counter(Counter::new(self.counter.unwrap_or_default()))
+                .build()
+                .into()
+        } else {
+            let period = self
+                .period
+                .map(Period::new)
+                .transpose()
+                .map_err(Error::period)?
+                .unwrap_or_default();
+
+            Totp::builder().base(base).period(period).build().into()
+        };
+
+        let issuer = if self.issuer.is_empty() {
+            None
+        } else {
+            Some(Part::owned(self.issuer.to_owned()).map_err(Error::part)?)
+        };
+
+        let user = Part::owned(self.user.to_owned()).map_err(Error::part)?;
+
+        let label = Label::builder().maybe_issuer(issuer).user(user).build();
+
+        let auth = Auth::builder().otp(otp).label(label).build();
+
+        Ok(auth.into_owned())
+    }
+}
+
+/// The fields exported into backup formats.
+struct Exported {
+    type_of: Type,
+    secret: String,
+    algorithm: &'static str,
+    digits: u8,
+    period: Option<u64>,
+    counter: Option<u64>,
+    issuer: String,
+    user: String,
+}
+
+impl Exported {
+    fn from_auth(auth: &Auth<'_>) -> Self {
+        let otp = auth.otp();
+        let base = otp.base();
+        let label = auth.label();
+
+        let (period, counter) = match otp {
+            Otp::Hotp(hotp) => (None, Some(hotp.counter.get())),
+            Otp::Totp(totp) => (Some(totp.period.get()), None),
+        };
+
+        Self {
+            type_of: otp.type_of(),
+            secret: base.secret.encode(),
+            algorithm: base.algorithm.static_str(),
+            digits: base.digits.get(),
+            period,
+            counter,
+            issuer: label
+                .issuer
+                .as_ref()
+                .map(|issuer| issuer.as_str().to_owned())
+                .unwrap_or_default(),
+            user: label.user.as_str().to_owned(),
+        }
+    }
+}
@@ -1,8 +1,7 @@
 //! Authentication labels.
 
-use std::{fmt, str::FromStr};
+use std::{borrow::Cow, fmt, str::FromStr};
 
-use bon::Builder;
 use const_macros::const_early;
 
 use miette::Diagnostic;
@@ -19,7 +18,8 @@ use crate::{
         url::{self, Url},
         utf8,
     },
-    macros::errors,
+    builder::Unset,
+    macros::{errors, setters},
 };
 
 /// Represents errors that occur when the label is empty.
@@ -35,6 +35,7 @@ pub struct EmptyError;
 #[derive(Debug, Error, Diagnostic)]
 #[error(transparent)]
 #[diagnostic(transparent)]
+#[non_exhaustive]
 pub enum ParseErrorSource {
     /// The label is empty.
     Empty(#[from] EmptyError),
@@ -46,7 +47,7 @@ pub enum ParseErrorSource {
 #[derive(Debug, Error, Diagnostic)]
 #[error("failed to parse label")]
 #[diagnostic(
-    code(otp_std::auth::label),
+    code(otp_std::auth::label::parse),
     help("make sure the label is formatted correctly")
 )]
 pub struct ParseError {
@@ -79,7 +80,7 @@ impl ParseError {
 }
 
 /// Represents authentication labels.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Builder)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Label<'l> {
     /// The authentication issuer.
@@ -88,6 +89,46 @@ pub struct Label<'l> {
     pub user: Part<'l>,
 }
 
+/// Represents builders of [`Label`] (see [`Label::builder`]).
+#[derive(Debug, Clone, Default)]
+pub struct LabelBuilder<'l, U = Unset> {
+    issuer: Option<Part<'l>>,
+    user: U,
+}
+
+impl<'l> Label<'l> {
+    /// Starts building [`Label`].
+    pub fn builder() -> LabelBuilder<'l> {
+        LabelBuilder::default()
+    }
+}
+
+impl<'l> LabelBuilder<'l> {
+    /// Sets the `user` member.
+    pub fn user(self, user: Part<'l>) -> LabelBuilder<'l, Part<'l>> {
+        LabelBuilder {
+            issuer: self.issuer,
+            user,
+        }
+    }
+}
+
+impl<'l, U> LabelBuilder<'l, U> {
+    setters! {
+        issuer, maybe_issuer: Part<'l>;
+    }
+}
+
+impl<'l> LabelBuilder<'l, Part<'l>> {
+    /// Finishes building [`Label`].
+    pub fn build(self) -> Label<'l> {
+        Label {
+            issuer: self.issuer,
+            user: self.user,
+        }
+    }
+}
+
 /// Represents `(issuer, user)` parts of the label.
 pub type Parts<'p> = (Option<Part<'p>>, Part<'p>);
 
@@ -161,6 +202,7 @@ impl FromStr for Label<'_> {
 #[derive(Debug, Error, Diagnostic)]
 #[error(transparent)]
 #[diagnostic(transparent)]
+#[non_exhaustive]
 pub enum DecodeErrorSource {
     /// The label is not valid UTF-8.
     Utf8(#[from] utf8::Error),
@@ -218,8 +260,19 @@ impl Label<'_> {
 
 impl Label<'_> {
     /// Encodes the label.
+    ///
+    /// Both parts are percent-encoded separately, so that characters like `/`, `?` and `#`
+    /// do not break the path of OTP URLs, while the separator is kept as-is.
     pub fn encode(&self) -> String {
-        self.to_string()
+        let user = self.user.encode();
+
+        let Some(issuer) = self.issuer.as_ref() else {
+            return user.into_owned();
+        };
+
+        let issuer = issuer.encode();
+
+        format!("{issuer}{SEPARATOR}{user}")
     }
 }
 
@@ -273,6 +326,7 @@ pub fn try_match<'p>(
 #[derive(Debug, Error, Diagnostic)]
 #[error(transparent)]
 #[diagnostic(transparent)]
+#[non_exhaustive]
 pub enum ErrorSource {
     /// The label could not be decoded.
     Decode(#[from] DecodeError),
@@ -361,6 +415,72 @@ impl Label<'_> {
 /// Represents owned [`Label`].
 pub type Owned = Label<'static>;
 
+fn decode_part(part: &Part<'_>) -> String {
+    let string = part.as_str();
+
+    url::decode(string).map_or_else(|_| string.to_owned(), Cow::into_owned)
+}
+
+fn normalize_part(string: &str) -> Result<part::Owned, part::Error> {
+    Part::owned(string.trim().to_lowercase())
+}
+
+impl Label<'_> {
+    /// Normalizes [`Self`] for comparison purposes.
+    ///
+    /// Both parts are percent-decoded, trimmed and lowercased. Labels without issuers
+    /// having users like `Example%3Aalice` are split into `example` and `alice`.
+    ///
+    /// If the normalized parts are not valid (for instance, when the decoded user contains
+    /// the separator while the issuer is present), [`Self`] is returned as-is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{Label, Part};
+    ///
+    /// let label = Label::builder()
+    ///     .issuer(Part::borrowed("Example").unwrap())
+    ///     .user(Part::borrowed("Alice").unwrap())
+    ///     .build();
+    ///
+    /// let other = Label::builder()
+    ///     .user(Part::borrowed("example%3A%20alice").unwrap())
+    ///     .build();
+    ///
+    /// assert_eq!(label.normalize(), other.normalize());
+    ///
+    /// assert!(label.matches(&other));
+    /// ```
+    pub fn normalize(&self) -> Owned {
+        let user = decode_part(&self.user);
+
+        let (issuer, user) = match self.issuer.as_ref() {
+            Some(issuer) => (Some(decode_part(issuer)), user),
+            None => match user.split_once(SEPARATOR) {
+                Some((issuer, user)) => (Some(issuer.to_owned()), user.to_owned()),
+                None => (None, user),
+            },
+        };
+
+        let normalized = issuer
+            .as_deref()
+            .map(normalize_part)
+            .transpose()
+            .and_then(|issuer| Ok((issuer, normalize_part(&user)?)));
+
+        normalized.map_or_else(|_| self.clone().into_owned(), Owned::from_parts)
+    }
+
+    /// Checks whether [`Self`] and the other label refer to the same account,
+    /// comparing their normalized forms (see [`normalize`]).
+    ///
+    /// [`normalize`]: Self::normalize
+    pub fn matches(&self, other: &Label<'_>) -> bool {
+        self.normalize() == other.normalize()
+    }
+}
+
 impl Label<'_> {
     /// Converts [`Self`] into [`Owned`].
     pub fn into_owned(self) -> Owned {
@@ -0,0 +1,253 @@
+//! Lenient TOTP provisioning parsing.
+//!
+//! Some providers encode only the Base32 secret in their QR codes, or use OTP URLs
+//! with empty labels (like `otpauth://totp/?secret=...`). The [`parse_totp`] function
+//! accepts such inputs, returning [`Lenient`] values that contain the [`Warning`] list
+//! instead of failing.
+
+use std::fmt;
+
+use miette::Diagnostic;
+use thiserror::Error;
+
+use crate::{
+    auth::{
+        label::{Label, SLASH},
+        query::Query,
+        scheme,
+        url::{self, Url},
+    },
+    base::Base,
+    macros::errors,
+    otp::type_of::{self, Type},
+    secret::core::{self as secret, Secret},
+    totp::{self, Totp},
+};
+
+/// Represents warnings produced during lenient parsing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Warning {
+    /// Only the secret was provided; defaults are used for everything else.
+    SecretOnly,
+    /// The label is missing from the OTP URL.
+    MissingLabel,
+    /// The label is present in the OTP URL, but could not be extracted.
+    InvalidLabel,
+}
+
+impl Warning {
+    /// Returns the message describing [`Self`].
+    pub const fn message(self) -> &'static str {
+        match self {
+            Self::SecretOnly => "only the secret was provided",
+            Self::MissingLabel => "the label is missing",
+            Self::InvalidLabel => "the label is invalid",
+        }
+    }
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.message().fmt(formatter)
+    }
+}
+
+/// Represents errors returned when HOTP URLs are encountered.
+#[derive(Debug, Error, Diagnostic)]
+#[error("expected TOTP, got HOTP")]
+#[diagnostic(
+    code(otp_std::auth::lenient::hotp),
+    help("only TOTP configurations can be parsed leniently")
+)]
+pub struct HotpError;
+
+/// Represents sources of errors that can occur when parsing leniently.
+#[derive(Debug, Error, Diagnostic)]
+#[error(transparent)]
+#[diagnostic(transparent)]
+#[non_exhaustive]
+pub enum ErrorSource {
+    /// The secret-only input could not be decoded.
+    Secret(#[from] secret::Error),
+    /// URL could not be parsed.
+    Url(#[from] url::Error),
+    /// Unexpected scheme found.
+    Scheme(#[from] scheme::Error),
+    /// OTP type extraction failed.
+    TypeOf(#[from] type_of::Error),
+    /// HOTP URL found.
+    Hotp(#[from] HotpError),
+    /// TOTP extraction failed.
+    Totp(#[from] totp::Error),
+}
+
+/// Represents errors that can occur when parsing leniently.
+#[derive(Debug, Error, Diagnostic)]
+#[error("failed to leniently parse TOTP")]
+#[diagnostic(
+    code(otp_std::auth::lenient),
+    help("see the report for more information")
+)]
+pub struct Error {
+    /// The source of this error.
+    #[source]
+    #[diagnostic_source]
+    pub source: ErrorSource,
+}
+
+impl Error {
+    /// Constructs [`Self`].
+    pub const fn new(source: ErrorSource) -> Self {
+        Self { source }
+    }
+
+    /// Constructs [`Self`] from [`secret::Error`].
+    pub fn secret(error: secret::Error) -> Self {
+        Self::new(error.into())
+    }
+
+    /// Constructs [`Self`] from [`url::Error`].
+    pub fn url(error: url::Error) -> Self {
+        Self::new(error.into())
+    }
+
+    /// Constructs [`Self`] from [`scheme::Error`].
+    pub fn scheme(error: scheme::Error) -> Self {
+        Self::new(error.into())
+    }
+
+    /// Constructs [`Self`] from [`type_of::Error`].
+    pub fn type_of(error: type_of::Error) -> Self {
+        Self::new(error.into())
+    }
+
+    /// Constructs [`Self`] from [`HotpError`].
+    pub fn hotp(error: HotpError) -> Self {
+        Self::new(error.into())
+    }
+
+    /// Creates [`HotpError`] and constructs [`Self`] from it.
+    pub fn new_hotp() -> Self {
+        Self::hotp(HotpError)
+    }
+
+    /// Constructs [`Self`] from [`totp::Error`].
+    pub fn totp(error: totp::Error) -> Self {
+        Self::new(error.into())
+    }
+}
+
+errors! {
+    Type = Error,
+    Hack = $,
+    hotp_error => new_hotp(),
+}
+
+/// Represents results of lenient parsing.
+#[derive(Debug, Clone)]
+#[cfg_attr(not(feature = "forbid-secret-eq"), derive(PartialEq, Eq, Hash))]
+pub struct Lenient<'l> {
+    /// The TOTP configuration.
+    pub totp: Totp<'l>,
+    /// The label, if it was extracted.
+    pub label: Option<Label<'l>>,
+    /// The warnings produced.
+    pub warnings: Vec<Warning>,
+}
+
+/// Represents owned [`Lenient`].
+pub type Owned = Lenient<'static>;
+
+/// The separator between schemes and the rest of URLs.
+pub const SCHEME_SEPARATOR: &str = "://";
+
+fn parse_secret(string: &str) -> Result<Owned, Error> {
+    let secret = Secret::decode(string).map_err(Error::secret)?;
+
+    let base = Base::builder().secret(secret).build();
+
+    let lenient = Lenient {
+        totp: Totp::builder().base(base).build(),
+        label: None,
+        warnings: vec![Warning::SecretOnly],
+    };
+
+    Ok(lenient)
+}
+
+fn parse_url(url: &Url) -> Result<Owned, Error> {
+    scheme::check_url(url).map_err(Error::scheme)?;
+
+    let type_of = Type::extract_from(url).map_err(Error::type_of)?;
+
+    if type_of.requires_counter() {
+        return Err(hotp_error!());
+    };
+
+    let mut query: Query<'_> = url.query_pairs().collect();
+
+    let mut warnings = Vec::new();
+
+    let label = match Label::extract_from(&mut query, url) {
+        Ok(label) => Some(label.into_owned()),
+        Err(_) => {
+            let warning = if url.path().trim_start_matches(SLASH).is_empty() {
+                Warning::MissingLabel
+            } else {
+                Warning::InvalidLabel
+            };
+
+            warnings.push(warning);
+
+            None
+        }
+    };
+
+    let totp = Totp::extract_from(&mut query).map_err(Error::totp)?;
+
+    let lenient = Lenient {
+        totp,
+        label,
+        warnings,
+    };
+
+    Ok(lenient)
+}
+
+/// Leniently parses TOTP configurations from the given string.
+///
+/// The string can either be the OTP URL (possibly without the label) or the Base32 secret.
+///
+/// # Errors
+///
+/// Returns [`struct@Error`] if the secret or the TOTP configuration can not be extracted.
+///
+/// # Examples
+///
+/// ```
+/// use otp_std::auth::lenient::{parse_totp, Warning};
+///
+/// let lenient = parse_totp("otpauth://totp/?secret=JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+///
+/// assert!(lenient.label.is_none());
+/// assert_eq!(lenient.warnings, [Warning::MissingLabel]);
+///
+/// let lenient = parse_totp("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+///
+/// assert_eq!(lenient.warnings, [Warning::SecretOnly]);
+/// ```
+pub fn parse_totp<S: AsRef<str>>(string: S) -> Result<Owned, Error> {
+    fn parse_totp_inner(string: &str) -> Result<Owned, Error> {
+        let string = string.trim();
+
+        if !string.contains(SCHEME_SEPARATOR) {
+            return parse_secret(string);
+        };
+
+        let url = url::parse(string).map_err(Error::url)?;
+
+        parse_url(&url)
+    }
+
+    parse_totp_inner(string.as_ref())
+}
@@ -0,0 +1,157 @@
+//! Algorithm migration.
+//!
+//! Moving existing users to another algorithm requires re-enrolling them, since the secret
+//! (and the algorithm) stored in their authenticator apps has to change. The
+//! [`Auth::with_algorithm`] method produces [`Migration`] values that contain the validated
+//! configuration, the URL to enroll it with, and the [`Compatibility`] guidance.
+//!
+//! Importing and exporting Google Authenticator migration payloads is implemented
+//! in the [`google`] module.
+
+pub mod google;
+
+use crate::{
+    algorithm::Algorithm,
+    auth::{core::Auth, url::Url},
+    base::ValidationError,
+    secret::core::Secret,
+};
+
+/// Represents authenticator apps known to ignore the `algorithm` parameter of OTP URLs,
+/// always using SHA-1 instead.
+pub const IGNORING_ALGORITHM: &[&str] = &["Google Authenticator", "Microsoft Authenticator"];
+
+/// Represents compatibility of configurations with authenticator apps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Compatibility {
+    /// The configuration uses the default algorithm, which is supported everywhere.
+    Universal,
+    /// The configuration uses the non-default algorithm, which some apps ignore
+    /// (see [`IGNORING_ALGORITHM`]), silently generating invalid codes.
+    Limited,
+}
+
+impl Compatibility {
+    /// Returns the compatibility of the given algorithm.
+    pub const fn of(algorithm: Algorithm) -> Self {
+        if algorithm.is_default() {
+            Self::Universal
+        } else {
+            Self::Limited
+        }
+    }
+
+    /// Checks whether [`Self`] is [`Universal`].
+    ///
+    /// [`Universal`]: Self::Universal
+    pub const fn is_universal(self) -> bool {
+        matches!(self, Self::Universal)
+    }
+
+    /// Returns the authenticator apps that are known to generate invalid codes.
+    pub const fn incompatible_apps(self) -> &'static [&'static str] {
+        match self {
+            Self::Universal => &[],
+            Self::Limited => IGNORING_ALGORITHM,
+        }
+    }
+}
+
+/// Represents algorithm migrations, that is, re-enrollment configurations.
+#[derive(Debug, Clone)]
+#[cfg_attr(not(feature = "forbid-secret-eq"), derive(PartialEq, Eq, Hash))]
+pub struct Migration<'m> {
+    /// The authentication using the new algorithm.
+    pub auth: Auth<'m>,
+    /// The URL to re-enroll with.
+    pub url: Url,
+    /// The compatibility of the new configuration.
+    pub compatibility: Compatibility,
+}
+
+impl<'a> Auth<'a> {
+    /// Migrates [`Self`] to the given algorithm, optionally replacing the secret.
+    ///
+    /// The resulting configuration is validated (see [`Base::validate`]), therefore
+    /// the secret has to be long enough for the new algorithm.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValidationError`] if the new configuration is not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{auth::migration::Compatibility, Algorithm, Auth, Base, Label, Part, Secret, Totp};
+    ///
+    /// let secret = Secret::decode("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+    ///
+    /// let base = Base::builder().secret(secret).build();
+    /// let totp = Totp::builder().base(base).build();
+    ///
+    /// let user = Part::borrowed("nekitdev").unwrap();
+    /// let label = Label::builder().user(user).build();
+    ///
+    /// let auth = Auth::builder().otp(totp).label(label).build();
+    ///
+    /// // the secret is too short for SHA-1
+    /// assert!(auth.with_algorithm(Algorithm::Sha1, None).is_err());
+    ///
+    /// let secret = Secret::generate_for(Algorithm::Sha1);
+    ///
+    /// let migration = auth.with_algorithm(Algorithm::Sha1, Some(secret)).unwrap();
+    ///
+    /// assert_eq!(migration.compatibility, Compatibility::Universal);
+    /// ```
+    ///
+    /// [`Base::validate`]: crate::base::Base::validate
+    pub fn with_algorithm(
+        &self,
+        algorithm: Algorithm,
+        secret: Option<Secret<'a>>,
+    ) -> Result<Migration<'a>, ValidationError> {
+        let mut auth = self.clone();
+
+        let base = auth.otp.base_mut();
+
+        base.algorithm = algorithm;
+
+        if let Some(secret) = secret {
+            base.secret = secret;
+        };
+
+        base.validate()?;
+
+        let url = auth.build_url();
+
+        let migration = Migration {
+            auth,
+            url,
+            compatibility: Compatibility::of(algorithm),
+        };
+
+        Ok(migration)
+    }
+}
+
+#[cfg(feature = "generate-secret")]
+impl Auth<'_> {
+    /// Migrates [`Self`] to the given algorithm, generating the secret of
+    /// the recommended length for it.
+    ///
+    /// # Panics
+    ///
+    /// This function can not panic because generated secrets are always valid.
+    pub fn with_algorithm_generated(&self, algorithm: Algorithm) -> Migration<'static> {
+        let secret = Secret::generate_for(algorithm);
+
+        self.clone()
+            .into_owned()
+            .with_algorithm(algorithm, Some(secret))
+            .expect(GENERATED_ALWAYS_VALID)
+    }
+}
+
+/// The message indicating that configurations with generated secrets are always valid.
+#[cfg(feature = "generate-secret")]
+pub const GENERATED_ALWAYS_VALID: &str = "configurations with generated secrets are always valid";
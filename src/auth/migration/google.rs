@@ -0,0 +1,729 @@
+//! Google Authenticator migration payloads.
+//!
+//! Google Authenticator exports accounts as `otpauth-migration://offline?data=...` URLs,
+//! where the data is the Base64-encoded protobuf message. The [`parse_url`] function imports
+//! such payloads into [`Auth`] values, while [`build_url`] exports them back.
+//!
+//! Migration payloads can not represent every configuration: periods are always `30` seconds
+//! and only `6` and `8` digits are supported.
+//!
+//! Payloads can also be sealed into version-tagged artifacts via [`seal`] and [`open`].
+
+use miette::Diagnostic;
+use thiserror::Error;
+
+use crate::{
+    algorithm::Algorithm,
+    artifact::{self, Seal},
+    auth::{
+        core::{Auth, Owned},
+        label::{self, Label},
+        part::{self, Part},
+        url::{self, Url},
+    },
+    base::Base,
+    counter::Counter,
+    digits::Digits,
+    hotp::Hotp,
+    macros::errors,
+    otp::core::Otp,
+    period::Period,
+    secret::{
+        core::Secret,
+        encoding::{decode_base64, encode_base64},
+        length,
+    },
+    totp::Totp,
+};
+
+/// The scheme of migration URLs.
+pub const SCHEME: &str = "otpauth-migration";
+
+/// The host of migration URLs.
+pub const HOST: &str = "offline";
+
+/// The `data` literal.
+pub const DATA: &str = "data";
+
+/// The payload version written by [`build_url`].
+pub const VERSION: u64 = 1;
+
+/// The message indicating that the migration base URL is always valid.
+pub const BASE_ALWAYS_VALID: &str = "migration base URL is always valid";
+
+/// Represents errors returned when URLs are not migration URLs.
+#[derive(Debug, Error, Diagnostic)]
+#[error("expected `{SCHEME}://{HOST}?{DATA}=...` URL")]
+#[diagnostic(
+    code(otp_std::auth::migration::google::format),
+    help("make sure the URL is exported from Google Authenticator")
+)]
+pub struct FormatError;
+
+/// Represents errors returned when payloads are not valid Base64.
+#[derive(Debug, Error, Diagnostic)]
+#[error("invalid base64 in migration payload")]
+#[diagnostic(
+    code(otp_std::auth::migration::google::base64),
+    help("make sure the payload is not truncated")
+)]
+pub struct Base64Error;
+
+/// Represents errors returned when payloads are malformed.
+#[derive(Debug, Error, Diagnostic)]
+#[error("malformed migration payload")]
+#[diagnostic(
+    code(otp_std::auth::migration::google::payload),
+    help("make sure the payload is not truncated")
+)]
+pub struct PayloadError;
+
+/// Represents errors returned when payloads contain unsupported values.
+#[derive(Debug, Error, Diagnostic)]
+#[error("unsupported `{field}` value `{value}` in migration payload")]
+#[diagnostic(
+    code(otp_std::auth::migration::google::unsupported),
+    help("the account can not be imported")
+)]
+pub struct UnsupportedError {
+    /// The field name.
+    pub field: &'static str,
+    /// The unsupported value.
+    pub value: u64,
+}
+
+impl UnsupportedError {
+    /// Constructs [`Self`].
+    pub const fn new(field: &'static str, value: u64) -> Self {
+        Self { field, value }
+    }
+}
+
+/// Represents errors returned when configurations can not be represented in payloads.
+#[derive(Debug, Error, Diagnostic)]
+#[error("`{field}` value `{value}` can not be represented in migration payloads")]
+#[diagnostic(
+    code(otp_std::auth::migration::google::unrepresentable),
+    help("migration payloads only support SHA-1 and SHA-2, 30 second periods and 6 or 8 digits")
+)]
+pub struct UnrepresentableError {
+    /// The field name.
+    pub field: &'static str,
+    /// The unrepresentable value.
+    pub value: String,
+}
+
+impl UnrepresentableError {
+    /// Constructs [`Self`].
+    pub const fn new(field: &'static str, value: String) -> Self {
+        Self { field, value }
+    }
+}
+
+/// Represents sources of errors that can occur when importing or exporting payloads.
+#[derive(Debug, Error, Diagnostic)]
+#[error(transparent)]
+#[diagnostic(transparent)]
+#[non_exhaustive]
+pub enum ErrorSource {
+    /// The URL could not be parsed.
+    Url(#[from] url::Error),
+    /// The URL is not the migration URL.
+    Format(#[from] FormatError),
+    /// The payload is not valid Base64.
+    Base64(#[from] Base64Error),
+    /// The payload is malformed.
+    Payload(#[from] PayloadError),
+    /// The payload contains unsupported values.
+    Unsupported(#[from] UnsupportedError),
+    /// The configuration can not be represented.
+    Unrepresentable(#[from] UnrepresentableError),
+    /// The secret has invalid length.
+    Secret(#[from] length::Error),
+    /// The label could not be parsed.
+    Label(#[from] label::ParseError),
+    /// The issuer is invalid.
+    Issuer(#[from] part::Error),
+    /// The sealed artifact could not be opened.
+    Artifact(#[from] artifact::Error),
+}
+
+/// Represents errors that can occur when importing or exporting payloads.
+#[derive(Debug, Error, Diagnostic)]
+#[error("failed to process migration payload")]
+#[diagnostic(
+    code(otp_std::auth::migration::google),
+    help("see the report for more information")
+)]
+pub struct Error {
+    /// The source of this error.
+    #[source]
+    #[diagnostic_source]
+    pub source: ErrorSource,
+}
+
+impl Error {
+    /// Constructs [`Self`].
+    pub const fn new(source: ErrorSource) -> Self {
+        Self { source }
+    }
+
+    /// Constructs [`Self`] from [`url::Error`].
+    pub fn url(error: url::Error) -> Self {
+        Self::new(error.into())
+    }
+
+    /// Constructs [`Self`] from [`FormatError`].
+    pub fn format(error: FormatError) -> Self {
+        Self::new(error.into())
+    }
+
+    /// Creates [`FormatError`] and constructs [`Self`] from it.
+    pub fn new_format() -> Self {
+        Self::format(FormatError)
+    }
+
+    /// Constructs [`Self`] from [`Base64Error`].
+    pub fn base64(error: Base64Error) -> Self {
+        Self::new(error.into())
+    }
+
+    /// Creates [`Base64Error`] and constructs [`Self`] from it.
+    pub fn new_base64() -> Self {
+        Self::base64(Base64Error)
+    }
+
+    /// Constructs [`Self`] from [`PayloadError`].
+    pub fn payload(error: PayloadError) -> Self {
+        Self::new(error.into())
+    }
+
+    /// Creates [`PayloadError`] and constructs [`Self`] from it.
+    pub fn new_payload() -> Self {
+        Self::payload(PayloadError)
+    }
+
+    /// Constructs [`Self`] from [`UnsupportedError`].
+    pub fn unsupported(error: UnsupportedError) -> Self {
+        Self::new(error.into())
+    }
+
+    /// Creates [`UnsupportedError`] and constructs [`Self`] from it.
+    pub fn new_unsupported(field: &'static str, value: u64) -> Self {
+        Self::unsupported(UnsupportedError::new(field, value))
+    }
+
+    /// Constructs [`Self`] from [`UnrepresentableError`].
+    pub fn unrepresentable(error: UnrepresentableError) -> Self {
+        Self::new(error.into())
+    }
+
+    /// Creates [`UnrepresentableError`] and constructs [`Self`] from it.
+    pub fn new_unrepresentable(field: &'static str, value: String) -> Self {
+        Self::unrepresentable(UnrepresentableError::new(field, value))
+    }
+
+    /// Constructs [`Self`] from [`length::Error`].
+    pub fn secret(error: length::Error) -> Self {
+        Self::new(error.into())
+    }
+
+    /// Constructs [`Self`] from [`label::ParseError`].
+    pub fn label(error: label::ParseError) -> Self {
+        Self::new(error.into())
+    }
+
+    /// Constructs [`Self`] from [`part::Error`].
+    pub fn issuer(error: part::Error) -> Self {
+        Self::new(error.into())
+    }
+
+    /// Constructs [`Self`] from [`artifact::Error`].
+    pub fn artifact(error: artifact::Error) -> Self {
+        Self::new(error.into())
+    }
+}
+
+errors! {
+    Type = Error,
+    Hack = $,
+    format_error => new_format(),
+    payload_error => new_payload(),
+    unsupported_error => new_unsupported(field, value),
+    unrepresentable_error => new_unrepresentable(field, value),
+}
+
+/// The `algorithm` field name.
+pub const ALGORITHM: &str = "algorithm";
+
+/// The `digits` field name.
+pub const DIGITS: &str = "digits";
+
+/// The `type` field name.
+pub const TYPE: &str = "type";
+
+/// The `period` field name.
+pub const PERIOD: &str = "period";
+
+const VARINT: u64 = 0;
+const LENGTH_DELIMITED: u64 = 2;
+const FIXED_64: u64 = 1;
+const FIXED_32: u64 = 5;
+
+const WIRE_BITS: u32 = 3;
+const WIRE_MASK: u64 = 0x7;
+
+const SEPTET: u64 = 0x7F;
+const CONTINUE: u8 = 0x80;
+
+struct Reader<'r> {
+    bytes: &'r [u8],
+}
+
+impl<'r> Reader<'r> {
+    const fn new(bytes: &'r [u8]) -> Self {
+        Self { bytes }
+    }
+
+    const fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    fn take(&mut self, count: usize) -> Option<&'r [u8]> {
+        if self.bytes.len() < count {
+            return None;
+        };
+
+        let (taken, rest) = self.bytes.split_at(count);
+
+        self.bytes = rest;
+
+        Some(taken)
+    }
+
+    fn varint(&mut self) -> Option<u64> {
+        let mut value = 0;
+
+        for shift in (0..64).step_by(7) {
+            let (&byte, rest) = self.bytes.split_first()?;
+
+            self.bytes = rest;
+
+            value |= (u64::from(byte) & SEPTET) << shift;
+
+            if byte & CONTINUE == 0 {
+                return Some(value);
+            };
+        }
+
+        None
+    }
+
+    fn length_delimited(&mut self) -> Option<&'r [u8]> {
+        let length = self.varint()?.try_into().ok()?;
+
+        self.take(length)
+    }
+
+    fn key(&mut self) -> Option<(u64, u64)> {
+        let key = self.varint()?;
+
+        Some((key >> WIRE_BITS, key & WIRE_MASK))
+    }
+
+    fn skip(&mut self, wire: u64) -> Option<()> {
+        match wire {
+            VARINT => self.varint().map(drop),
+            FIXED_64 => self.take(8).map(drop),
+            LENGTH_DELIMITED => self.length_delimited().map(drop),
+            FIXED_32 => self.take(4).map(drop),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Default)]
+struct Writer {
+    bytes: Vec<u8>,
+}
+
+impl Writer {
+    fn varint(&mut self, mut value: u64) {
+        while value > SEPTET {
+            // truncation is intended here
+            #[allow(clippy::cast_possible_truncation)]
+            self.bytes.push((value & SEPTET) as u8 | CONTINUE);
+
+            value >>= 7;
+        }
+
+        // the value fits into seven bits here
+        #[allow(clippy::cast_possible_truncation)]
+        self.bytes.push(value as u8);
+    }
+
+    fn key(&mut self, field: u64, wire: u64) {
+        self.varint(field << WIRE_BITS | wire);
+    }
+
+    fn varint_field(&mut self, field: u64, value: u64) {
+        if value != 0 {
+            self.key(field, VARINT);
+            self.varint(value);
+        };
+    }
+
+    fn bytes_field(&mut self, field: u64, bytes: &[u8]) {
+        if !bytes.is_empty() {
+            self.key(field, LENGTH_DELIMITED);
+            self.varint(bytes.len() as u64);
+            self.bytes.extend_from_slice(bytes);
+        };
+    }
+}
+
+mod fields {
+    pub const OTP_PARAMETERS: u64 = 1;
+    pub const VERSION: u64 = 2;
+    pub const BATCH_SIZE: u64 = 3;
+
+    pub const SECRET: u64 = 1;
+    pub const NAME: u64 = 2;
+    pub const ISSUER: u64 = 3;
+    pub const ALGORITHM: u64 = 4;
+    pub const DIGITS: u64 = 5;
+    pub const TYPE: u64 = 6;
+    pub const COUNTER: u64 = 7;
+}
+
+mod values {
+    pub const UNSPECIFIED: u64 = 0;
+
+    pub const SHA1: u64 = 1;
+    #[cfg(feature = "sha2")]
+    pub const SHA256: u64 = 2;
+    #[cfg(feature = "sha2")]
+    pub const SHA512: u64 = 3;
+
+    pub const SIX: u64 = 1;
+    pub const EIGHT: u64 = 2;
+
+    pub const HOTP: u64 = 1;
+    pub const TOTP: u64 = 2;
+}
+
+#[derive(Default)]
+struct Parameters<'p> {
+    secret: &'p [u8],
+    name: &'p [u8],
+    issuer: &'p [u8],
+    algorithm: u64,
+    digits: u64,
+    type_of: u64,
+    counter: u64,
+}
+
+fn read_parameters(bytes: &[u8]) -> Option<Parameters<'_>> {
+    let mut reader = Reader::new(bytes);
+
+    let mut parameters = Parameters::default();
+
+    while !reader.is_empty() {
+        match reader.key()? {
+            (fields::SECRET, LENGTH_DELIMITED) => parameters.secret = reader.length_delimited()?,
+            (fields::NAME, LENGTH_DELIMITED) => parameters.name = reader.length_delimited()?,
+            (fields::ISSUER, LENGTH_DELIMITED) => parameters.issuer = reader.length_delimited()?,
+            (fields::ALGORITHM, VARINT) => parameters.algorithm = reader.varint()?,
+            (fields::DIGITS, VARINT) => parameters.digits = reader.varint()?,
+            (fields::TYPE, VARINT) => parameters.type_of = reader.varint()?,
+            (fields::COUNTER, VARINT) => parameters.counter = reader.varint()?,
+            (_, wire) => reader.skip(wire)?,
+        }
+    }
+
+    Some(parameters)
+}
+
+fn read_payload(bytes: &[u8]) -> Option<Vec<Parameters<'_>>> {
+    let mut reader = Reader::new(bytes);
+
+    let mut entries = Vec::new();
+
+    while !reader.is_empty() {
+        match reader.key()? {
+            (fields::OTP_PARAMETERS, LENGTH_DELIMITED) => {
+                entries.push(read_parameters(reader.length_delimited()?)?);
+            }
+            (_, wire) => reader.skip(wire)?,
+        }
+    }
+
+    Some(entries)
+}
+
+fn string_from(bytes: &[u8]) -> Result<&str, Error> {
+    std::str::from_utf8(bytes).map_err(|_| payload_error!())
+}
+
+fn import(parameters: &Parameters<'_>) -> Result<Owned, Error> {
+    let algorithm = match parameters.algorithm {
+        values::UNSPECIFIED | values::SHA1 => Algorithm::Sha1,
+        #[cfg(feature = "sha2")]
+        values::SHA256 => Algorithm::Sha256,
+        #[cfg(feature = "sha2")]
+        values::SHA512 => Algorithm::Sha512,
+        other => return Err(unsupported_error!(ALGORITHM, other)),
+    };
+
+    let digits = match parameters.digits {
+        values::UNSPECIFIED | values::SIX => Digits::DEFAULT,
+        values::EIGHT => Digits::MAX,
+        other => return Err(unsupported_error!(DIGITS, other)),
+    };
+
+    let secret = Secret::owned(parameters.secret.to_vec()).map_err(Error::secret)?;
+
+    let base = Base::builder()
+        .secret(secret)
+        .algorithm(algorithm)
+        .digits(digits)
+        .build();
+
+    let otp: Otp<'_> = match parameters.type_of {
+        values::HOTP => Hotp::builder()
+            .base(base)
+            .counter(Counter::new(parameters.counter))
+            .build()
+            .into(),
+        values::UNSPECIFIED | values::TOTP => Totp::builder().base(base).build().into(),
+        other => return Err(unsupported_error!(TYPE, other)),
+    };
+
+    let name = string_from(parameters.name)?;
+    let issuer = string_from(parameters.issuer)?;
+
+    let mut label: Label<'_> = name.parse().map_err(Error::label)?;
+
+    if label.issuer.is_none() && !issuer.is_empty() {
+        let part = Part::owned(issuer.to_owned()).map_err(Error::issuer)?;
+
+        label.issuer = Some(part);
+    };
+
+    let auth = Auth::builder().otp(otp).label(label).build();
+
+    Ok(auth.into_owned())
+}
+
+/// Parses the given migration URL into [`Auth`] values.
+///
+/// # Errors
+///
+/// Returns [`struct@Error`] if the URL is not the valid migration URL
+/// or any of the accounts can not be imported.
+///
+/// # Examples
+///
+/// ```
+/// use otp_std::{auth::migration::google, Auth, Base, Label, Part, Secret, Totp};
+///
+/// let secret = Secret::decode("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+///
+/// let base = Base::builder().secret(secret).build();
+/// let totp = Totp::builder().base(base).build();
+///
+/// let issuer = Part::borrowed("MelodyKit").unwrap();
+/// let user = Part::borrowed("nekitdev").unwrap();
+///
+/// let label = Label::builder().issuer(issuer).user(user).build();
+///
+/// let auth = Auth::builder().otp(totp).label(label).build();
+///
+/// let url = google::build_url([&auth]).unwrap();
+///
+/// let auths = google::parse_url(url).unwrap();
+///
+/// assert!(auths[0].same_parameters(&auth));
+/// ```
+pub fn parse_url<S: AsRef<str>>(string: S) -> Result<Vec<Owned>, Error> {
+    fn parse_url_inner(string: &str) -> Result<Vec<Owned>, Error> {
+        let url = url::parse(string).map_err(Error::url)?;
+
+        if url.scheme() != SCHEME || url.host_str() != Some(HOST) {
+            return Err(format_error!());
+        };
+
+        let data = url
+            .query_pairs()
+            .find(|(name, _)| name == DATA)
+            .map(|(_, value)| value)
+            .ok_or_else(Error::new_format)?;
+
+        // spaces appear when pluses are not percent-encoded in URLs
+        let data = data.replace(' ', "+");
+
+        let bytes = decode_base64(data).ok_or_else(Error::new_base64)?;
+
+        decode_payload(&bytes)
+    }
+
+    parse_url_inner(string.as_ref())
+}
+
+fn decode_payload(bytes: &[u8]) -> Result<Vec<Owned>, Error> {
+    let entries = read_payload(bytes).ok_or_else(Error::new_payload)?;
+
+    entries.iter().map(import).collect()
+}
+
+fn export(writer: &mut Writer, auth: &Auth<'_>) -> Result<(), Error> {
+    let base = auth.otp().base();
+
+    let algorithm = match base.algorithm {
+        Algorithm::Sha1 => values::SHA1,
+        #[cfg(feature = "sha2")]
+        Algorithm::Sha256 => values::SHA256,
+        #[cfg(feature = "sha2")]
+        Algorithm::Sha512 => values::SHA512,
+        #[allow(unreachable_patterns)]
+        other => return Err(unrepresentable_error!(ALGORITHM, other.to_string())),
+    };
+
+    let digits = match base.digits {
+        Digits::DEFAULT => values::SIX,
+        Digits::MAX => values::EIGHT,
+        other => return Err(unrepresentable_error!(DIGITS, other.to_string())),
+    };
+
+    let (type_of, counter) = match auth.otp() {
+        Otp::Hotp(hotp) => (values::HOTP, hotp.counter.get()),
+        Otp::Totp(totp) => {
+            if totp.period != Period::DEFAULT {
+                return Err(unrepresentable_error!(PERIOD, totp.period.to_string()));
+            };
+
+            (values::TOTP, 0)
+        }
+    };
+
+    let label = auth.label();
+
+    let name = label.to_string();
+
+    let mut parameters = Writer::default();
+
+    parameters.bytes_field(fields::SECRET, base.secret.as_bytes());
+    parameters.bytes_field(fields::NAME, name.as_bytes());
+
+    if let Some(issuer) = label.issuer.as_ref() {
+        parameters.bytes_field(fields::ISSUER, issuer.as_str().as_bytes());
+    };
+
+    parameters.varint_field(fields::ALGORITHM, algorithm);
+    parameters.varint_field(fields::DIGITS, digits);
+    parameters.varint_field(fields::TYPE, type_of);
+    parameters.varint_field(fields::COUNTER, counter);
+
+    writer.key(fields::OTP_PARAMETERS, LENGTH_DELIMITED);
+    writer.varint(parameters.bytes.len() as u64);
+    writer.bytes.extend_from_slice(&parameters.bytes);
+
+    Ok(())
+}
+
+fn encode_payload<'a, 'i, I: IntoIterator<Item = &'i Auth<'a>>>(auths: I) -> Result<Vec<u8>, Error>
+where
+    'a: 'i,
+{
+    let mut writer = Writer::default();
+
+    for auth in auths {
+        export(&mut writer, auth)?;
+    }
+
+    writer.varint_field(fields::VERSION, VERSION);
+    writer.varint_field(fields::BATCH_SIZE, 1);
+
+    Ok(writer.bytes)
+}
+
+/// Builds the migration URL containing the given [`Auth`] values.
+///
+/// # Errors
+///
+/// Returns [`struct@Error`] if any of the configurations can not be represented
+/// (see the [module documentation] for more information).
+///
+/// # Panics
+///
+/// This function can not panic because the migration base URL is always valid.
+///
+/// [module documentation]: self
+pub fn build_url<'a, 'i, I: IntoIterator<Item = &'i Auth<'a>>>(auths: I) -> Result<Url, Error>
+where
+    'a: 'i,
+{
+    let bytes = encode_payload(auths)?;
+
+    let mut url = url::parse(format!("{SCHEME}://{HOST}")).expect(BASE_ALWAYS_VALID);
+
+    url.query_pairs_mut()
+        .append_pair(DATA, encode_base64(bytes).as_str());
+
+    Ok(url)
+}
+
+/// The format version of sealed migration payloads.
+pub const ARTIFACT_VERSION: u8 = 1;
+
+/// Seals the migration payload containing the given [`Auth`] values
+/// into the version-tagged artifact (see [`artifact`] for more information).
+///
+/// # Errors
+///
+/// Returns [`struct@Error`] if any of the configurations can not be represented.
+///
+/// # Examples
+///
+/// ```
+/// use otp_std::{artifact::Seal, auth::migration::google, Auth};
+///
+/// let url = "otpauth://totp/MelodyKit:nekitdev?secret=JEQDYMZAN5YGK3RAONXXK4TDMU&issuer=MelodyKit";
+///
+/// let auth = Auth::parse_url(url).unwrap();
+///
+/// let seal = Seal::new(b"key");
+///
+/// let sealed = google::seal(&seal, [&auth]).unwrap();
+///
+/// let auths = google::open(&seal, &sealed).unwrap();
+///
+/// assert!(auths[0].same_parameters(&auth));
+/// ```
+///
+/// [`artifact`]: crate::artifact
+pub fn seal<'a, 'i, I: IntoIterator<Item = &'i Auth<'a>>>(
+    seal: &Seal<'_>,
+    auths: I,
+) -> Result<Vec<u8>, Error>
+where
+    'a: 'i,
+{
+    let bytes = encode_payload(auths)?;
+
+    Ok(seal.seal(ARTIFACT_VERSION, bytes))
+}
+
+/// Opens the sealed migration payload, returning the contained [`Auth`] values.
+///
+/// # Errors
+///
+/// Returns [`struct@Error`] if the artifact can not be opened
+/// or any of the accounts can not be imported.
+pub fn open(seal: &Seal<'_>, artifact: &[u8]) -> Result<Vec<Owned>, Error> {
+    let bytes = seal
+        .open(ARTIFACT_VERSION, artifact)
+        .map_err(Error::artifact)?;
+
+    decode_payload(bytes)
+}
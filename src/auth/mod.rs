@@ -1,15 +1,35 @@
 //! One-Time Password authentication.
 
 pub mod core;
+pub mod extensions;
+
+#[cfg(feature = "serde")]
+pub mod interop;
+
 pub mod label;
+pub mod lenient;
+pub mod migration;
+pub mod options;
 pub mod part;
+pub mod profile;
+
+#[cfg(feature = "qr-decode")]
+pub mod qr;
+
 pub mod query;
+pub mod redact;
+pub mod rotation;
 pub mod scheme;
+pub mod store;
 pub mod url;
 pub mod utf8;
+pub mod writer;
 
 pub use core::{Auth, Owned};
+pub use extensions::Extensions;
 pub use label::{Label, Owned as OwnedLabel};
 pub use part::{Owned as OwnedPart, Part};
+pub use rotation::Rotation;
 pub use scheme::SCHEME;
+pub use store::{Owned as OwnedStore, Store};
 pub use url::Url;
@@ -0,0 +1,224 @@
+//! OTP URL parsing options.
+
+use miette::Diagnostic;
+use thiserror::Error;
+
+use crate::{
+    auth::{
+        extensions::Extensions,
+        label::Label,
+        part::Part,
+        redact::{self, Hook},
+        scheme::SchemePolicy,
+    },
+    limit::{self, SkewLimit},
+    macros::{errors, setters},
+    otp::core::Otp,
+};
+
+/// Represents errors returned when the issuer does not match the expected one.
+#[derive(Debug, Error, Diagnostic)]
+#[error("expected issuer `{expected}`, got {}", display_actual(.actual.as_deref()))]
+#[diagnostic(
+    code(otp_std::auth::options::issuer),
+    help("make sure the OTP URL is issued by the expected issuer")
+)]
+pub struct IssuerError {
+    /// The expected issuer.
+    pub expected: String,
+    /// The actual issuer, if any.
+    pub actual: Option<String>,
+}
+
+fn display_actual(actual: Option<&str>) -> String {
+    actual.map_or_else(|| "no issuer".to_owned(), |actual| format!("`{actual}`"))
+}
+
+impl IssuerError {
+    /// Constructs [`Self`].
+    pub const fn new(expected: String, actual: Option<String>) -> Self {
+        Self { expected, actual }
+    }
+}
+
+errors! {
+    Type = IssuerError,
+    Hack = $,
+    issuer_error => new(expected => to_owned, actual),
+}
+
+/// Represents errors returned when unrecognized parameters are found in strict mode.
+#[derive(Debug, Error, Diagnostic)]
+#[error("unrecognized parameters: {}", .names.join(", "))]
+#[diagnostic(
+    code(otp_std::auth::options::strict),
+    help("remove the parameters or disable strict mode to keep them as extensions")
+)]
+pub struct StrictError {
+    /// The names of the unrecognized parameters.
+    pub names: Vec<String>,
+}
+
+impl StrictError {
+    /// Constructs [`Self`].
+    pub const fn new(names: Vec<String>) -> Self {
+        Self { names }
+    }
+}
+
+/// Represents options used when parsing OTP URLs.
+///
+/// # Examples
+///
+/// ```
+/// use otp_std::{auth::options::ParseOptions, Auth, Part};
+///
+/// let issuer = Part::borrowed("MelodyKit").unwrap();
+///
+/// let options = ParseOptions::builder().expected_issuer(issuer).build();
+///
+/// let string = "otpauth://totp/Other:nekitdev?secret=JEQDYMZAN5YGK3RAONXXK4TDMU";
+///
+/// assert!(Auth::parse_url_with_options(string, &options).is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ParseOptions<'o> {
+    /// The scheme policy to use.
+    pub scheme: SchemePolicy<'o>,
+    /// The issuer that OTP URLs are expected to have.
+    pub expected_issuer: Option<Part<'o>>,
+    /// Whether to reject unrecognized parameters.
+    ///
+    /// When disabled (the default), such parameters are collected into [`Extensions`].
+    pub strict: bool,
+    /// The limit of verification windows, defaulting to [`SkewLimit::DEFAULT`].
+    pub skew_limit: SkewLimit,
+    /// The hook used to redact input strings stored in errors,
+    /// defaulting to [`redact_secrets`](redact::redact_secrets).
+    pub redact: Hook,
+}
+
+impl Default for ParseOptions<'_> {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+/// Represents builders of [`ParseOptions`] (see [`ParseOptions::builder`]).
+#[derive(Debug, Clone, Default)]
+pub struct ParseOptionsBuilder<'o> {
+    scheme: Option<SchemePolicy<'o>>,
+    expected_issuer: Option<Part<'o>>,
+    strict: Option<bool>,
+    skew_limit: Option<SkewLimit>,
+    redact: Option<Hook>,
+}
+
+impl<'o> ParseOptions<'o> {
+    /// Starts building [`ParseOptions`].
+    pub fn builder() -> ParseOptionsBuilder<'o> {
+        ParseOptionsBuilder::default()
+    }
+}
+
+impl<'o> ParseOptionsBuilder<'o> {
+    setters! {
+        scheme, maybe_scheme: SchemePolicy<'o>;
+        expected_issuer, maybe_expected_issuer: Part<'o>;
+        strict, maybe_strict: bool;
+        skew_limit, maybe_skew_limit: SkewLimit;
+        redact, maybe_redact: Hook;
+    }
+
+    /// Finishes building [`ParseOptions`].
+    pub fn build(self) -> ParseOptions<'o> {
+        ParseOptions {
+            scheme: self.scheme.unwrap_or_default(),
+            expected_issuer: self.expected_issuer,
+            strict: self.strict.unwrap_or_default(),
+            skew_limit: self.skew_limit.unwrap_or_default(),
+            redact: self.redact.unwrap_or(redact::redact_secrets),
+        }
+    }
+}
+
+impl ParseOptions<'_> {
+    /// Checks whether the issuer of the given label matches the expected one, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IssuerError`] if the issuer does not match the expected one.
+    pub fn check_issuer(&self, label: &Label<'_>) -> Result<(), IssuerError> {
+        let Some(expected) = self.expected_issuer.as_ref() else {
+            return Ok(());
+        };
+
+        let actual = label.issuer.as_ref();
+
+        if actual != Some(expected) {
+            let actual = actual.map(|part| part.as_str().to_owned());
+
+            return Err(issuer_error!(expected.as_str(), actual));
+        };
+
+        Ok(())
+    }
+
+    /// Checks whether the given extensions are allowed, that is,
+    /// either strict mode is disabled or there are no extensions.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StrictError`] if strict mode is enabled and there are extensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{auth::options::ParseOptions, Auth};
+    ///
+    /// let options = ParseOptions::builder().strict(true).build();
+    ///
+    /// let string = "otpauth://totp/nekitdev?secret=JEQDYMZAN5YGK3RAONXXK4TDMU&image=x";
+    ///
+    /// let error = Auth::parse_url_with_options(string, &options).unwrap_err();
+    ///
+    /// assert_eq!(error.string, "otpauth://totp/nekitdev?secret=****&image=x");
+    ///
+    /// let auth = Auth::parse_url_with_options(string, &ParseOptions::default()).unwrap();
+    ///
+    /// assert_eq!(auth.extensions().image(), Some("x"));
+    /// ```
+    pub fn check_extensions(&self, extensions: &Extensions) -> Result<(), StrictError> {
+        if self.strict && !extensions.is_empty() {
+            let names = extensions.names().into_iter().map(str::to_owned).collect();
+
+            return Err(StrictError::new(names));
+        };
+
+        Ok(())
+    }
+
+    /// Checks whether the verification window of the given OTP configuration
+    /// is within the limit (see [`Otp::check_skew_limit`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`limit::Error`] if the verification window exceeds the limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{auth::options::ParseOptions, Auth, SkewLimit};
+    ///
+    /// let string = "otpauth://hotp/nekitdev?secret=JEQDYMZAN5YGK3RAONXXK4TDMU&counter=0&window=100";
+    ///
+    /// assert!(Auth::parse_url_with_options(string, &ParseOptions::default()).is_err());
+    ///
+    /// let options = ParseOptions::builder().skew_limit(SkewLimit::new(100)).build();
+    ///
+    /// assert!(Auth::parse_url_with_options(string, &options).is_ok());
+    /// ```
+    pub const fn check_skew_limit(&self, otp: &Otp<'_>) -> Result<(), limit::Error> {
+        otp.check_skew_limit(self.skew_limit)
+    }
+}
@@ -73,6 +73,7 @@ impl SeparatorError {
 #[derive(Debug, Error, Diagnostic)]
 #[error(transparent)]
 #[diagnostic(transparent)]
+#[non_exhaustive]
 pub enum ErrorSource {
     /// The part is empty.
     Empty(#[from] EmptyError),
@@ -242,6 +243,7 @@ impl<'p> Part<'p> {
 #[derive(Debug, Error, Diagnostic)]
 #[error(transparent)]
 #[diagnostic(transparent)]
+#[non_exhaustive]
 pub enum DecodeErrorSource {
     /// The part contains invalid UTF-8.
     Utf8(#[from] utf8::Error),
@@ -297,6 +299,9 @@ impl Part<'_> {
 
 impl Part<'_> {
     /// Encodes the contained string.
+    ///
+    /// All reserved characters (including `/`) are percent-encoded, meaning that
+    /// the encoded string can be safely used as the single path segment.
     pub fn encode(&self) -> Cow<'_, str> {
         url::encode(self.as_str())
     }
@@ -0,0 +1,270 @@
+//! Provisioning profiles of authenticator apps.
+//!
+//! Authenticator apps have their quirks: some silently ignore non-default parameters,
+//! generating codes that never match, while others do not support HOTP at all.
+//! [`Profile`] describes such quirks, building OTP URLs adjusted for the target app
+//! along with [`Report`] that lists the warnings about the parameters the app will ignore.
+//!
+//! These profiles are unrelated to validation ones (see [`crate::profile`]).
+
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    algorithm::Algorithm,
+    auth::{core::Auth, url::Url},
+    digits::Digits,
+    otp::{core::Otp, type_of::Type},
+    period::Period,
+};
+
+/// The `google-authenticator` literal.
+pub const GOOGLE_AUTHENTICATOR: &str = "google-authenticator";
+
+/// The `microsoft-authenticator` literal.
+pub const MICROSOFT_AUTHENTICATOR: &str = "microsoft-authenticator";
+
+/// The `yubico-authenticator` literal.
+pub const YUBICO_AUTHENTICATOR: &str = "yubico-authenticator";
+
+/// The periods supported by Yubico Authenticator.
+pub const YUBICO_PERIODS: [u64; 3] = [15, 30, 60];
+
+/// Represents provisioning profiles of authenticator apps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+pub enum Profile {
+    /// Google Authenticator, which ignores everything except the default algorithm,
+    /// digits and period, and groups accounts by issuers.
+    GoogleAuthenticator,
+    /// Microsoft Authenticator, which only supports TOTP with the default algorithm,
+    /// digits and period, and groups accounts by issuers.
+    MicrosoftAuthenticator,
+    /// Yubico Authenticator, which supports SHA-1 and SHA-2 algorithms
+    /// along with periods of 15, 30 and 60 seconds.
+    YubicoAuthenticator,
+}
+
+impl Profile {
+    /// Returns the static string representation of [`Self`].
+    pub const fn static_str(self) -> &'static str {
+        match self {
+            Self::GoogleAuthenticator => GOOGLE_AUTHENTICATOR,
+            Self::MicrosoftAuthenticator => MICROSOFT_AUTHENTICATOR,
+            Self::YubicoAuthenticator => YUBICO_AUTHENTICATOR,
+        }
+    }
+}
+
+impl fmt::Display for Profile {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.static_str().fmt(formatter)
+    }
+}
+
+/// Represents warnings about parameters that target apps will not handle as expected.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Warning {
+    /// The OTP type is not supported.
+    Type(Type),
+    /// The algorithm is ignored, meaning the codes generated will not match.
+    Algorithm(Algorithm),
+    /// The number of digits is ignored, meaning the codes generated will not match.
+    Digits(Digits),
+    /// The period is ignored, meaning the codes generated will not match.
+    Period(Period),
+    /// The issuer is missing, while the app relies on it to group accounts.
+    MissingIssuer,
+    /// The vendor extension is ignored, and therefore omitted from the OTP URL.
+    Extension(String),
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Type(type_of) => write!(formatter, "type `{type_of}` is not supported"),
+            Self::Algorithm(algorithm) => write!(formatter, "algorithm `{algorithm}` is ignored"),
+            Self::Digits(digits) => write!(formatter, "digits `{digits}` are ignored"),
+            Self::Period(period) => write!(formatter, "period `{period}` is ignored"),
+            Self::MissingIssuer => formatter.write_str("the issuer is missing"),
+            Self::Extension(name) => write!(formatter, "extension `{name}` is ignored"),
+        }
+    }
+}
+
+/// Represents OTP URLs adjusted for target apps, along with the warnings found.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Report {
+    /// The profile used.
+    pub profile: Profile,
+    /// The adjusted OTP URL.
+    pub url: Url,
+    /// The warnings found.
+    pub warnings: Vec<Warning>,
+}
+
+impl Report {
+    /// Constructs [`Self`].
+    pub const fn new(profile: Profile, url: Url, warnings: Vec<Warning>) -> Self {
+        Self {
+            profile,
+            url,
+            warnings,
+        }
+    }
+
+    /// Checks whether there are no warnings.
+    pub fn is_clean(&self) -> bool {
+        self.warnings.is_empty()
+    }
+}
+
+impl Profile {
+    /// Checks whether [`Self`] supports the given OTP type.
+    pub const fn supports_type(self, type_of: Type) -> bool {
+        match self {
+            Self::MicrosoftAuthenticator => matches!(type_of, Type::Totp),
+            Self::GoogleAuthenticator | Self::YubicoAuthenticator => true,
+        }
+    }
+
+    /// Checks whether [`Self`] supports the given algorithm.
+    pub const fn supports_algorithm(self, algorithm: Algorithm) -> bool {
+        match self {
+            Self::GoogleAuthenticator | Self::MicrosoftAuthenticator => {
+                matches!(algorithm, Algorithm::Sha1)
+            }
+            #[allow(unreachable_patterns)]
+            Self::YubicoAuthenticator => match algorithm {
+                Algorithm::Sha1 => true,
+                #[cfg(feature = "sha2")]
+                Algorithm::Sha256 | Algorithm::Sha512 => true,
+                _ => false,
+            },
+        }
+    }
+
+    /// Checks whether [`Self`] supports the given number of digits.
+    pub const fn supports_digits(self, digits: Digits) -> bool {
+        match self {
+            Self::GoogleAuthenticator | Self::MicrosoftAuthenticator => digits.is_default(),
+            Self::YubicoAuthenticator => true,
+        }
+    }
+
+    /// Checks whether [`Self`] supports the given period.
+    pub const fn supports_period(self, period: Period) -> bool {
+        let value = period.get();
+
+        match self {
+            Self::GoogleAuthenticator | Self::MicrosoftAuthenticator => period.is_default(),
+            Self::YubicoAuthenticator => {
+                value == YUBICO_PERIODS[0]
+                    || value == YUBICO_PERIODS[1]
+                    || value == YUBICO_PERIODS[2]
+            }
+        }
+    }
+
+    /// Checks whether [`Self`] relies on issuers to group accounts.
+    pub const fn requires_issuer(self) -> bool {
+        matches!(
+            self,
+            Self::GoogleAuthenticator | Self::MicrosoftAuthenticator
+        )
+    }
+
+    /// Checks the given authentication against [`Self`], returning the warnings found.
+    pub fn check(self, auth: &Auth<'_>) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+
+        let otp = auth.otp();
+
+        let type_of = otp.type_of();
+
+        if !self.supports_type(type_of) {
+            warnings.push(Warning::Type(type_of));
+        };
+
+        let base = otp.base();
+
+        if !self.supports_algorithm(base.algorithm) {
+            warnings.push(Warning::Algorithm(base.algorithm));
+        };
+
+        if !self.supports_digits(base.digits) {
+            warnings.push(Warning::Digits(base.digits));
+        };
+
+        if let Otp::Totp(totp) = otp {
+            if !self.supports_period(totp.period) {
+                warnings.push(Warning::Period(totp.period));
+            };
+        };
+
+        if self.requires_issuer() && auth.label().issuer.is_none() {
+            warnings.push(Warning::MissingIssuer);
+        };
+
+        // none of the supported apps handle vendor extensions
+        for (name, _) in auth.extensions() {
+            warnings.push(Warning::Extension(name.clone()));
+        }
+
+        warnings
+    }
+
+    /// Builds the OTP URL adjusted for [`Self`], returning it along with the warnings found.
+    ///
+    /// The issuer is included both in the label and in the query, as expected by most apps,
+    /// while vendor extensions are omitted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{
+    ///     auth::profile::{Profile, Warning},
+    ///     Auth, Base, Digits, Label, Part, Secret, Totp,
+    /// };
+    ///
+    /// let secret = Secret::decode("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+    ///
+    /// let base = Base::builder().secret(secret).digits(Digits::MAX).build();
+    ///
+    /// let totp = Totp::builder().base(base).build();
+    ///
+    /// let label = Label::builder()
+    ///     .issuer(Part::borrowed("MelodyKit").unwrap())
+    ///     .user(Part::borrowed("nekitdev").unwrap())
+    ///     .build();
+    ///
+    /// let auth = Auth::builder().otp(totp).label(label).build();
+    ///
+    /// let report = Profile::GoogleAuthenticator.adjust(&auth);
+    ///
+    /// assert_eq!(report.warnings, [Warning::Digits(Digits::MAX)]);
+    ///
+    /// assert!(Profile::YubicoAuthenticator.adjust(&auth).is_clean());
+    /// ```
+    pub fn adjust(self, auth: &Auth<'_>) -> Report {
+        let mut url = auth.base_url();
+
+        auth.otp().query_for(&mut url);
+        auth.label().query_for(&mut url);
+
+        Report::new(self, url, self.check(auth))
+    }
+}
+
+impl Auth<'_> {
+    /// Builds the OTP URL adjusted for the given profile (see [`Profile::adjust`]).
+    pub fn build_url_for(&self, profile: Profile) -> Report {
+        profile.adjust(self)
+    }
+}
@@ -0,0 +1,186 @@
+//! Reading OTP URLs from QR code images.
+//!
+//! Import tools often start from screenshots or photos of QR codes. The [`read`] function
+//! decodes PNG and JPEG images, detecting QR codes and returning their contents,
+//! while [`Auth::from_qr_image`] goes further and parses the OTP URL contained.
+
+use image::ImageError as SourceImageError;
+use miette::Diagnostic;
+use rqrr::{DeQRError, PreparedImage};
+use thiserror::Error;
+
+use crate::{
+    auth::core::{self, Auth, Owned},
+    macros::errors,
+};
+
+/// Represents errors returned when images can not be decoded.
+#[derive(Debug, Error, Diagnostic)]
+#[error("failed to decode image")]
+#[diagnostic(
+    code(otp_std::auth::qr::image),
+    help("make sure the image is either PNG or JPEG")
+)]
+pub struct ImageError {
+    /// The source of this error.
+    #[source]
+    pub source: SourceImageError,
+}
+
+impl ImageError {
+    /// Constructs [`Self`].
+    pub const fn new(source: SourceImageError) -> Self {
+        Self { source }
+    }
+}
+
+/// Represents errors returned when images do not contain QR codes.
+#[derive(Debug, Error, Diagnostic)]
+#[error("no QR codes found in image")]
+#[diagnostic(
+    code(otp_std::auth::qr::not_found),
+    help("make sure the QR code is in focus and not cropped")
+)]
+pub struct NotFoundError;
+
+/// Represents errors returned when QR codes can not be decoded.
+#[derive(Debug, Error, Diagnostic)]
+#[error("failed to decode QR code")]
+#[diagnostic(
+    code(otp_std::auth::qr::decode),
+    help("make sure the QR code is not damaged")
+)]
+pub struct DecodeError {
+    /// The source of this error.
+    #[source]
+    pub source: DeQRError,
+}
+
+impl DecodeError {
+    /// Constructs [`Self`].
+    pub const fn new(source: DeQRError) -> Self {
+        Self { source }
+    }
+}
+
+/// Represents sources of errors that can occur when reading QR code images.
+#[derive(Debug, Error, Diagnostic)]
+#[error(transparent)]
+#[diagnostic(transparent)]
+#[non_exhaustive]
+pub enum ErrorSource {
+    /// The image could not be decoded.
+    Image(#[from] ImageError),
+    /// The image does not contain QR codes.
+    NotFound(#[from] NotFoundError),
+    /// The QR code could not be decoded.
+    Decode(#[from] DecodeError),
+    /// The OTP URL could not be parsed.
+    Auth(#[from] core::Error),
+}
+
+/// Represents errors that can occur when reading QR code images.
+#[derive(Debug, Error, Diagnostic)]
+#[error("failed to read QR code image")]
+#[diagnostic(code(otp_std::auth::qr), help("see the report for more information"))]
+pub struct Error {
+    /// The source of this error.
+    #[source]
+    #[diagnostic_source]
+    pub source: ErrorSource,
+}
+
+impl Error {
+    /// Constructs [`Self`].
+    pub const fn new(source: ErrorSource) -> Self {
+        Self { source }
+    }
+
+    /// Constructs [`Self`] from [`ImageError`].
+    pub fn image(error: ImageError) -> Self {
+        Self::new(error.into())
+    }
+
+    /// Creates [`ImageError`] and constructs [`Self`] from it.
+    pub fn new_image(source: SourceImageError) -> Self {
+        Self::image(ImageError::new(source))
+    }
+
+    /// Constructs [`Self`] from [`NotFoundError`].
+    pub fn not_found(error: NotFoundError) -> Self {
+        Self::new(error.into())
+    }
+
+    /// Creates [`NotFoundError`] and constructs [`Self`] from it.
+    pub fn new_not_found() -> Self {
+        Self::not_found(NotFoundError)
+    }
+
+    /// Constructs [`Self`] from [`DecodeError`].
+    pub fn decode(error: DecodeError) -> Self {
+        Self::new(error.into())
+    }
+
+    /// Creates [`DecodeError`] and constructs [`Self`] from it.
+    pub fn new_decode(source: DeQRError) -> Self {
+        Self::decode(DecodeError::new(source))
+    }
+
+    /// Constructs [`Self`] from [`core::Error`].
+    pub fn auth(error: core::Error) -> Self {
+        Self::new(error.into())
+    }
+}
+
+errors! {
+    Type = Error,
+    Hack = $,
+    image_error => new_image(source),
+    decode_error => new_decode(source),
+}
+
+/// Reads the contents of the first QR code found in the given image.
+///
+/// # Errors
+///
+/// Returns [`struct@Error`] if the image can not be decoded, if it does not contain
+/// QR codes or if none of the QR codes found can be decoded.
+pub fn read(bytes: &[u8]) -> Result<String, Error> {
+    let image = image::load_from_memory(bytes)
+        .map_err(|source| image_error!(source))?
+        .to_luma8();
+
+    let (width, height) = image.dimensions();
+
+    let mut prepared = PreparedImage::prepare_from_greyscale(
+        width as usize,
+        height as usize,
+        // the coordinates never exceed the dimensions, which are `u32`
+        |x, y| image.get_pixel(x as u32, y as u32).0[0],
+    );
+
+    let mut last = None;
+
+    for grid in prepared.detect_grids() {
+        match grid.decode() {
+            Ok((_, content)) => return Ok(content),
+            Err(source) => last = Some(source),
+        }
+    }
+
+    Err(last.map_or_else(Error::new_not_found, |source| decode_error!(source)))
+}
+
+impl Auth<'_> {
+    /// Reads the OTP URL from the given QR code image and parses it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`struct@Error`] if the QR code can not be read (see [`read`])
+    /// or if the OTP URL can not be parsed.
+    pub fn from_qr_image(bytes: &[u8]) -> Result<Owned, Error> {
+        let string = read(bytes)?;
+
+        Owned::parse_url(string).map_err(Error::auth)
+    }
+}
@@ -1,6 +1,82 @@
 //! URL queries.
+//!
+//! This module also provides [`extract_parse`], the shared helper used to extract
+//! and parse parameters from queries, and the [`Parameter`] type used in extraction errors
+//! to capture the names and the raw values of the parameters.
 
-use std::{borrow::Cow, collections::HashMap};
+use std::{borrow::Cow, collections::HashMap, fmt, str::FromStr};
 
 /// Represents URL queries.
 pub type Query<'q> = HashMap<Cow<'q, str>, Cow<'q, str>>;
+
+/// Removes the given key from the query, parsing its value if present.
+///
+/// Parse errors of parameters are expected to capture the raw value,
+/// while the key is added by extraction errors (see [`Parameter`]).
+///
+/// # Errors
+///
+/// Returns [`T::Err`] if the value is present, but can not be parsed.
+///
+/// # Examples
+///
+/// ```
+/// use otp_std::{
+///     auth::query::{extract_parse, Query},
+///     Digits,
+/// };
+///
+/// let mut query: Query<'_> = [("digits".into(), "8".into())].into_iter().collect();
+///
+/// let digits: Option<Digits> = extract_parse(&mut query, "digits").unwrap();
+///
+/// assert_eq!(digits, Some(Digits::MAX));
+///
+/// assert!(query.is_empty());
+///
+/// let digits: Option<Digits> = extract_parse(&mut query, "digits").unwrap();
+///
+/// assert_eq!(digits, None);
+/// ```
+///
+/// [`T::Err`]: FromStr::Err
+pub fn extract_parse<T: FromStr>(query: &mut Query<'_>, key: &str) -> Result<Option<T>, T::Err> {
+    query.remove(key).map(|value| value.parse()).transpose()
+}
+
+/// Represents OTP URL parameters that caused extraction errors.
+///
+/// The raw value is never included for the secret parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Parameter<'p> {
+    /// The name of the parameter.
+    pub name: &'static str,
+    /// The raw value of the parameter, if present and safe to display.
+    pub value: Option<&'p str>,
+}
+
+impl<'p> Parameter<'p> {
+    /// Constructs [`Self`].
+    pub const fn new(name: &'static str, value: Option<&'p str>) -> Self {
+        Self { name, value }
+    }
+
+    /// Constructs [`Self`] without the value.
+    pub const fn name_only(name: &'static str) -> Self {
+        Self::new(name, None)
+    }
+
+    /// Constructs [`Self`] with the given value.
+    pub const fn with_value(name: &'static str, value: &'p str) -> Self {
+        Self::new(name, Some(value))
+    }
+}
+
+impl fmt::Display for Parameter<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.value {
+            Some(value) => write!(formatter, "`{}` = `{value}`", self.name),
+            None => write!(formatter, "`{}`", self.name),
+        }
+    }
+}
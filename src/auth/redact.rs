@@ -0,0 +1,129 @@
+//! Redaction of OTP URLs stored in errors.
+//!
+//! Errors returned when parsing OTP URLs store the input string, which may contain the secret.
+//! Before being stored, strings are passed through the redaction hook, which defaults to
+//! [`redact_secrets`], replacing values of `secret` query parameters with [`REDACTED`].
+//!
+//! The hook is configured per call via [`ParseOptions::redact`], for instance, to redact
+//! vendor extensions too, or to disable redaction by using [`keep`].
+//!
+//! # Examples
+//!
+//! ```
+//! use otp_std::Auth;
+//!
+//! let string = "otpauth://totp/nekitdev?secret=JEQDYMZAN5YGK3RAONXXK4TDMU&digits=x";
+//!
+//! let error = Auth::parse_url(string).unwrap_err();
+//!
+//! assert_eq!(error.string, "otpauth://totp/nekitdev?secret=****&digits=x");
+//! ```
+//!
+//! [`ParseOptions::redact`]: crate::auth::options::ParseOptions::redact
+
+use url::form_urlencoded;
+
+use crate::{
+    auth::writer::{AND, EQUALS, QUERY},
+    base::SECRET,
+    secret::core::REDACTED,
+};
+
+/// The separator between queries and fragments.
+pub const FRAGMENT: char = '#';
+
+/// Represents redaction hooks.
+pub type Hook = fn(&str) -> String;
+
+/// The characters removed from URLs before parsing (the same way the `url` crate does).
+pub const STRIPPED: [char; 3] = ['\t', '\n', '\r'];
+
+/// Replaces values of `secret` query parameters in the given string with [`REDACTED`].
+///
+/// Parameter names are stripped of [`STRIPPED`] characters and percent-decoded
+/// (the same way URLs are parsed) and compared case-insensitively,
+/// and strings without queries are returned as-is.
+///
+/// # Examples
+///
+/// ```
+/// use otp_std::auth::redact::redact_secrets;
+///
+/// assert_eq!(
+///     redact_secrets("otpauth://totp/nekitdev?secret=JEQDYMZA&digits=8"),
+///     "otpauth://totp/nekitdev?secret=****&digits=8",
+/// );
+///
+/// assert_eq!(
+///     redact_secrets("otpauth://totp/nekitdev?%73ecret=JEQDYMZA"),
+///     "otpauth://totp/nekitdev?%73ecret=****",
+/// );
+/// ```
+pub fn redact_secrets(string: &str) -> String {
+    let Some((head, rest)) = string.split_once(QUERY) else {
+        return string.to_owned();
+    };
+
+    let (query, fragment) = rest
+        .find(FRAGMENT)
+        .map_or((rest, ""), |index| rest.split_at(index));
+
+    let mut redacted = String::with_capacity(string.len());
+
+    redacted.push_str(head);
+    redacted.push(QUERY);
+
+    for (index, pair) in query.split(AND).enumerate() {
+        if index > 0 {
+            redacted.push(AND);
+        };
+
+        match pair.split_once(EQUALS) {
+            Some((name, _)) if is_secret(name) => {
+                redacted.push_str(name);
+                redacted.push(EQUALS);
+                redacted.push_str(REDACTED);
+            }
+            _ => redacted.push_str(pair),
+        }
+    }
+
+    redacted.push_str(fragment);
+
+    redacted
+}
+
+// names are stripped and decoded exactly like the `url` crate parses query pairs
+fn is_secret(name: &str) -> bool {
+    let name = name.replace(STRIPPED, "");
+
+    form_urlencoded::parse(name.as_bytes())
+        .next()
+        .is_some_and(|(decoded, _)| decoded.eq_ignore_ascii_case(SECRET))
+}
+
+/// Returns the given string as-is, which disables redaction when used as the hook.
+pub fn keep(string: &str) -> String {
+    string.to_owned()
+}
+
+/// Represents input strings along with the hooks used to redact them in errors.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Input<'i> {
+    string: &'i str,
+    hook: Hook,
+}
+
+impl<'i> Input<'i> {
+    pub(crate) const fn new(string: &'i str, hook: Hook) -> Self {
+        Self { string, hook }
+    }
+
+    pub(crate) const fn get(self) -> &'i str {
+        self.string
+    }
+
+    pub(crate) fn redact(self) -> String {
+        (self.hook)(self.string)
+    }
+}
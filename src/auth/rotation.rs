@@ -0,0 +1,196 @@
+//! Secret rotation.
+//!
+//! Rotating secrets produces [`Rotation`] values that contain both the old and the new
+//! authentication, the [`Cutover`] policy and the URL used to enroll the new secret.
+
+use miette::Diagnostic;
+use thiserror::Error;
+
+use crate::{
+    auth::{core::Auth, url::Url},
+    otp::core::Otp,
+    secret::core::Secret,
+    time::expect_current,
+};
+
+/// Represents errors returned when the new secret is the same as the old one.
+#[derive(Debug, Error, Diagnostic)]
+#[error("the new secret is the same as the old one")]
+#[diagnostic(
+    code(otp_std::auth::rotation),
+    help("make sure to generate the new secret")
+)]
+pub struct Error;
+
+/// Represents cutover policies, that is, whether the old secret is still accepted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Cutover {
+    /// The old secret is not accepted anymore.
+    #[default]
+    Immediate,
+    /// The old secret is accepted before the given time.
+    At(u64),
+}
+
+impl Cutover {
+    /// Checks whether the old secret is accepted at the given time.
+    pub const fn accepts_old_at(self, time: u64) -> bool {
+        match self {
+            Self::Immediate => false,
+            Self::At(cutover) => time < cutover,
+        }
+    }
+}
+
+/// Represents which secret matched the code during verification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Matched {
+    /// The new secret matched.
+    New,
+    /// The old secret matched (before the cutover).
+    Old,
+}
+
+/// Represents secret rotations.
+#[derive(Debug, Clone)]
+#[cfg_attr(not(feature = "forbid-secret-eq"), derive(PartialEq, Eq, Hash))]
+pub struct Rotation<'r> {
+    /// The authentication using the old secret.
+    pub old: Auth<'r>,
+    /// The authentication using the new secret.
+    pub new: Auth<'r>,
+    /// The cutover policy.
+    pub cutover: Cutover,
+    /// The URL to enroll the new secret with.
+    pub url: Url,
+}
+
+fn verify_at(otp: &Otp<'_>, time: u64, code: u32) -> bool {
+    match otp {
+        Otp::Hotp(hotp) => hotp.verify(code),
+        Otp::Totp(totp) => totp.verify_at(time, code),
+    }
+}
+
+fn verify_string_at(otp: &Otp<'_>, time: u64, code: &str) -> bool {
+    match otp {
+        Otp::Hotp(hotp) => hotp.verify_string(code),
+        Otp::Totp(totp) => totp.verify_string_at(time, code),
+    }
+}
+
+impl Rotation<'_> {
+    /// Returns [`Self`] with the given cutover policy.
+    #[must_use]
+    pub const fn with_cutover(mut self, cutover: Cutover) -> Self {
+        self.cutover = cutover;
+
+        self
+    }
+
+    /// Verifies the given code for the given time, returning which secret matched, if any.
+    ///
+    /// The time is ignored for HOTP configurations.
+    pub fn verify_at(&self, time: u64, code: u32) -> Option<Matched> {
+        if verify_at(self.new.otp(), time, code) {
+            Some(Matched::New)
+        } else if self.cutover.accepts_old_at(time) && verify_at(self.old.otp(), time, code) {
+            Some(Matched::Old)
+        } else {
+            None
+        }
+    }
+
+    /// Verifies the given string code for the given time, returning which secret matched, if any.
+    ///
+    /// The time is ignored for HOTP configurations.
+    pub fn verify_string_at<S: AsRef<str>>(&self, time: u64, code: S) -> Option<Matched> {
+        let code = code.as_ref();
+
+        if verify_string_at(self.new.otp(), time, code) {
+            Some(Matched::New)
+        } else if self.cutover.accepts_old_at(time) && verify_string_at(self.old.otp(), time, code)
+        {
+            Some(Matched::Old)
+        } else {
+            None
+        }
+    }
+
+    /// Verifies the given code for the current time, returning which secret matched, if any.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the system time is before the epoch.
+    pub fn verify(&self, code: u32) -> Option<Matched> {
+        self.verify_at(expect_current(), code)
+    }
+
+    /// Verifies the given string code for the current time, returning which secret matched.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the system time is before the epoch.
+    pub fn verify_string<S: AsRef<str>>(&self, code: S) -> Option<Matched> {
+        self.verify_string_at(expect_current(), code)
+    }
+}
+
+impl<'a> Auth<'a> {
+    /// Rotates the secret, returning [`Rotation`] with the [`Cutover::Immediate`] policy.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`struct@Error`] if the new secret is the same as the old one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{auth::rotation::{Cutover, Matched}, Auth, Base, Label, Part, Secret, Totp};
+    ///
+    /// let secret = Secret::decode("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+    ///
+    /// let base = Base::builder().secret(secret).build();
+    /// let totp = Totp::builder().base(base).build();
+    ///
+    /// let user = Part::borrowed("nekitdev").unwrap();
+    /// let label = Label::builder().user(user).build();
+    ///
+    /// let auth = Auth::builder().otp(totp).label(label).build();
+    ///
+    /// let new = Secret::decode("GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ").unwrap();
+    ///
+    /// let time = 1_234_567_890;
+    ///
+    /// let rotation = auth
+    ///     .secret_rotated(new)
+    ///     .unwrap()
+    ///     .with_cutover(Cutover::At(time + 1));
+    ///
+    /// let code = rotation.old.otp.base().generate(time / 30);
+    ///
+    /// assert_eq!(rotation.verify_at(time, code), Some(Matched::Old));
+    /// ```
+    pub fn secret_rotated(&self, secret: Secret<'a>) -> Result<Rotation<'a>, Error> {
+        if self.otp().base().secret.constant_time_eq(&secret) {
+            return Err(Error);
+        };
+
+        let old = self.clone();
+
+        let mut new = self.clone();
+
+        new.otp.base_mut().secret = secret;
+
+        let url = new.build_url();
+
+        let rotation = Rotation {
+            old,
+            new,
+            cutover: Cutover::default(),
+            url,
+        };
+
+        Ok(rotation)
+    }
+}
@@ -57,3 +57,188 @@ pub fn check<S: AsRef<str>>(scheme: S) -> Result<(), Error> {
 pub fn check_url(url: &Url) -> Result<(), Error> {
     check(url.scheme())
 }
+
+/// Represents errors that occur when invalid schemes are used in [`SchemePolicy`].
+#[derive(Debug, Error, Diagnostic)]
+#[error("invalid scheme `{scheme}`")]
+#[diagnostic(
+    code(otp_std::auth::scheme::invalid),
+    help("schemes must start with letters, followed by letters, digits, `+`, `-` or `.`")
+)]
+pub struct InvalidError {
+    /// The invalid scheme.
+    pub scheme: String,
+}
+
+impl InvalidError {
+    /// Constructs [`Self`].
+    pub const fn new(scheme: String) -> Self {
+        Self { scheme }
+    }
+}
+
+/// Represents errors that occur when schemes are not accepted by [`SchemePolicy`].
+#[derive(Debug, Error, Diagnostic)]
+#[error("unexpected scheme `{scheme}`; expected `{expected}`")]
+#[diagnostic(
+    code(otp_std::auth::scheme::policy),
+    help("make sure the scheme is accepted by the policy")
+)]
+pub struct PolicyError {
+    /// The unexpected scheme.
+    pub scheme: String,
+    /// The scheme expected by the policy.
+    pub expected: String,
+}
+
+impl PolicyError {
+    /// Constructs [`Self`].
+    pub const fn new(scheme: String, expected: String) -> Self {
+        Self { scheme, expected }
+    }
+}
+
+errors! {
+    Type = InvalidError,
+    Hack = $,
+    invalid_error => new(scheme => to_owned),
+}
+
+errors! {
+    Type = PolicyError,
+    Hack = $,
+    policy_error => new(scheme => to_owned, expected => to_owned),
+}
+
+/// Checks whether the given string is a valid URL scheme.
+pub fn is_valid(scheme: &str) -> bool {
+    let mut chars = scheme.chars();
+
+    chars
+        .next()
+        .is_some_and(|character| character.is_ascii_alphabetic())
+        && chars.all(|character| {
+            character.is_ascii_alphanumeric() || matches!(character, '+' | '-' | '.')
+        })
+}
+
+/// Represents policies for schemes used when building and parsing OTP URLs.
+///
+/// By default, only [`SCHEME`] is used and accepted.
+///
+/// Schemes are case-insensitive, and parsed URLs always have their schemes lowercased,
+/// so schemes are compared ignoring ASCII case and written in lowercase.
+///
+/// # Examples
+///
+/// ```
+/// use otp_std::auth::scheme::SchemePolicy;
+///
+/// let policy = SchemePolicy::new("otpauth-test").unwrap().accept_default(true);
+///
+/// assert!(policy.accepts("otpauth-test"));
+/// assert!(policy.accepts("otpauth"));
+/// assert!(policy.accepts("OTPAUTH-TEST"));
+/// assert!(!policy.accepts("https"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SchemePolicy<'p> {
+    scheme: &'p str,
+    accept_default: bool,
+}
+
+impl Default for SchemePolicy<'_> {
+    fn default() -> Self {
+        SchemePolicy::DEFAULT
+    }
+}
+
+impl<'p> SchemePolicy<'p> {
+    /// Constructs [`Self`] using the given scheme, which is the only one accepted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidError`] if the scheme is not valid.
+    pub fn new(scheme: &'p str) -> Result<Self, InvalidError> {
+        if !is_valid(scheme) {
+            return Err(invalid_error!(scheme));
+        };
+
+        // SAFETY: the scheme is valid
+        Ok(unsafe { Self::new_unchecked(scheme) })
+    }
+
+    /// Constructs [`Self`] without checking the scheme.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the scheme is valid (see [`is_valid`]).
+    pub const unsafe fn new_unchecked(scheme: &'p str) -> Self {
+        Self {
+            scheme,
+            accept_default: false,
+        }
+    }
+
+    /// Returns [`Self`] that also accepts (or does not accept) [`SCHEME`] when parsing.
+    #[must_use]
+    pub const fn accept_default(mut self, accept_default: bool) -> Self {
+        self.accept_default = accept_default;
+
+        self
+    }
+
+    /// Returns the scheme, as given.
+    ///
+    /// Note that OTP URLs are built using the lowercased scheme (see [`to_lowercase`]).
+    ///
+    /// [`to_lowercase`]: Self::to_lowercase
+    pub const fn scheme(&self) -> &'p str {
+        self.scheme
+    }
+
+    /// Returns the lowercased scheme, which is used when building OTP URLs.
+    pub fn to_lowercase(&self) -> String {
+        self.scheme.to_ascii_lowercase()
+    }
+
+    /// Checks whether [`SCHEME`] is accepted in addition to the policy scheme.
+    pub const fn accepts_default(&self) -> bool {
+        self.accept_default
+    }
+
+    /// Checks whether the given scheme is accepted, ignoring ASCII case.
+    pub fn accepts(&self, scheme: &str) -> bool {
+        scheme.eq_ignore_ascii_case(self.scheme)
+            || (self.accept_default && scheme.eq_ignore_ascii_case(SCHEME))
+    }
+
+    /// Checks whether the given scheme is accepted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PolicyError`] if the scheme is not accepted.
+    pub fn check<S: AsRef<str>>(&self, scheme: S) -> Result<(), PolicyError> {
+        let scheme = scheme.as_ref();
+
+        if !self.accepts(scheme) {
+            return Err(policy_error!(scheme, self.scheme));
+        };
+
+        Ok(())
+    }
+
+    /// Checks whether the scheme of the given URL is accepted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PolicyError`] if the scheme is not accepted.
+    pub fn check_url(&self, url: &Url) -> Result<(), PolicyError> {
+        self.check(url.scheme())
+    }
+}
+
+impl SchemePolicy<'static> {
+    /// The default [`Self`], using [`SCHEME`].
+    pub const DEFAULT: Self = unsafe { Self::new_unchecked(SCHEME) };
+}
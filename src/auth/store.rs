@@ -0,0 +1,410 @@
+//! Authentication stores.
+//!
+//! The [`Store`] type holds collections of [`Auth`] entries, for instance, loaded from vaults.
+//!
+//! Stores can be sealed into version-tagged artifacts (see [`artifact`] for more information),
+//! containing OTP URLs of the entries, one per line.
+//!
+//! [`artifact`]: crate::artifact
+
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    slice, vec,
+};
+
+use miette::Diagnostic;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use thiserror::Error;
+
+use crate::{
+    artifact::{self, Seal},
+    auth::{
+        core::{self, Auth},
+        label, utf8,
+    },
+    secret::fingerprint::Fingerprint,
+};
+
+/// Represents collections of [`Auth`] entries.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(not(feature = "forbid-secret-eq"), derive(PartialEq, Eq, Hash))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Store<'s> {
+    entries: Vec<Auth<'s>>,
+}
+
+/// Represents groups of entries sharing the same secret.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Duplicates {
+    /// The fingerprint of the shared secret (see [`Fingerprint::compute`]).
+    pub fingerprint: Fingerprint,
+    /// The indices of the entries sharing the secret, in ascending order.
+    pub indices: Vec<usize>,
+}
+
+impl<'s> Store<'s> {
+    /// Constructs [`Self`] from the given entries.
+    pub const fn new(entries: Vec<Auth<'s>>) -> Self {
+        Self { entries }
+    }
+
+    /// Constructs empty [`Self`].
+    pub const fn empty() -> Self {
+        Self::new(Vec::new())
+    }
+
+    /// Adds the given entry to [`Self`].
+    pub fn push(&mut self, entry: Auth<'s>) {
+        self.entries.push(entry);
+    }
+
+    /// Returns the entries contained in [`Self`].
+    pub fn entries(&self) -> &[Auth<'s>] {
+        &self.entries
+    }
+
+    /// Consumes [`Self`], returning the contained entries.
+    pub fn into_entries(self) -> Vec<Auth<'s>> {
+        self.entries
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Checks whether [`Self`] is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the iterator over the entries.
+    pub fn iter(&self) -> slice::Iter<'_, Auth<'s>> {
+        self.entries.iter()
+    }
+
+    /// Returns the iterator over the entries with the given issuer.
+    ///
+    /// Entries without issuers are never yielded.
+    pub fn entries_for_issuer<'q>(&'q self, issuer: &'q str) -> impl Iterator<Item = &'q Auth<'s>> {
+        self.iter()
+            .filter(move |entry| issuer_of(entry) == Some(issuer))
+    }
+
+    /// Returns the distinct issuers of the entries, sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{auth::store::Store, Auth, Base, Label, Part, Secret, Totp};
+    ///
+    /// let build = |issuer, user| {
+    ///     let secret = Secret::generate_default();
+    ///
+    ///     let base = Base::builder().secret(secret).build();
+    ///     let totp = Totp::builder().base(base).build();
+    ///
+    ///     let label = Label::builder()
+    ///         .issuer(Part::borrowed(issuer).unwrap())
+    ///         .user(Part::borrowed(user).unwrap())
+    ///         .build();
+    ///
+    ///     Auth::builder().otp(totp).label(label).build()
+    /// };
+    ///
+    /// let store = Store::new(vec![
+    ///     build("MelodyKit", "nekitdev"),
+    ///     build("Example", "nekitdev"),
+    ///     build("MelodyKit", "alice"),
+    /// ]);
+    ///
+    /// assert_eq!(store.issuers(), ["Example", "MelodyKit"]);
+    ///
+    /// assert_eq!(store.entries_for_issuer("MelodyKit").count(), 2);
+    /// ```
+    pub fn issuers(&self) -> Vec<&str> {
+        let issuers: BTreeSet<&str> = self.iter().filter_map(issuer_of).collect();
+
+        issuers.into_iter().collect()
+    }
+
+    /// Returns the page of entries with the given index and size.
+    ///
+    /// The last page can contain less than `size` entries,
+    /// and pages past the end are empty.
+    pub fn page(&self, index: usize, size: usize) -> &[Auth<'s>] {
+        let start = index.saturating_mul(size).min(self.len());
+        let end = start.saturating_add(size).min(self.len());
+
+        &self.entries[start..end]
+    }
+
+    /// Returns the iterator over the pages of entries with the given size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    pub fn pages(&self, size: usize) -> slice::Chunks<'_, Auth<'s>> {
+        self.entries.chunks(size)
+    }
+}
+
+fn issuer_of<'e>(entry: &'e Auth<'_>) -> Option<&'e str> {
+    entry.label().issuer.as_ref().map(|issuer| issuer.as_str())
+}
+
+impl Store<'_> {
+    /// Finds entries that share the same secret, grouping them by secret fingerprints.
+    ///
+    /// Reused secrets across accounts are a security smell, as compromising one account
+    /// compromises all of them.
+    ///
+    /// Groups are ordered by the index of their first entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{auth::store::Store, Auth, Base, Label, Part, Secret, Totp};
+    ///
+    /// let build = |user| {
+    ///     let secret = Secret::decode("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+    ///
+    ///     let base = Base::builder().secret(secret).build();
+    ///     let totp = Totp::builder().base(base).build();
+    ///
+    ///     let label = Label::builder().user(Part::borrowed(user).unwrap()).build();
+    ///
+    ///     Auth::builder().otp(totp).label(label).build()
+    /// };
+    ///
+    /// let store = Store::new(vec![build("alice"), build("bob")]);
+    ///
+    /// let duplicates = store.find_duplicates();
+    ///
+    /// assert_eq!(duplicates.len(), 1);
+    /// assert_eq!(duplicates[0].indices, [0, 1]);
+    /// ```
+    pub fn find_duplicates(&self) -> Vec<Duplicates> {
+        let mut groups: Vec<Duplicates> = Vec::new();
+
+        let mut seen: HashMap<Fingerprint, usize> = HashMap::new();
+
+        for (index, entry) in self.iter().enumerate() {
+            let fingerprint = entry.otp().base().secret.fingerprint();
+
+            if let Some(&group) = seen.get(&fingerprint) {
+                groups[group].indices.push(index);
+            } else {
+                seen.insert(fingerprint, groups.len());
+
+                groups.push(Duplicates {
+                    fingerprint,
+                    indices: vec![index],
+                });
+            }
+        }
+
+        groups.retain(|duplicates| duplicates.indices.len() > 1);
+
+        groups
+    }
+}
+
+impl Store<'_> {
+    /// Removes entries referring to the same accounts as the previous ones,
+    /// keeping the first entry of each account (see [`Auth::matches`]).
+    ///
+    /// This is useful when merging entries imported from multiple sources.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{auth::store::Store, Auth, Base, Label, Part, Secret, Totp};
+    ///
+    /// let build = |issuer: Option<&'static str>, user| {
+    ///     let secret = Secret::generate_default();
+    ///
+    ///     let base = Base::builder().secret(secret).build();
+    ///     let totp = Totp::builder().base(base).build();
+    ///
+    ///     let label = Label::builder()
+    ///         .maybe_issuer(issuer.map(|issuer| Part::borrowed(issuer).unwrap()))
+    ///         .user(Part::borrowed(user).unwrap())
+    ///         .build();
+    ///
+    ///     Auth::builder().otp(totp).label(label).build()
+    /// };
+    ///
+    /// let mut store = Store::new(vec![
+    ///     build(Some("Example"), "alice"),
+    ///     build(None, "example%3Aalice"),
+    ///     build(Some("example"), "ALICE"),
+    ///     build(Some("Example"), "bob"),
+    /// ]);
+    ///
+    /// store.dedup();
+    ///
+    /// assert_eq!(store.len(), 2);
+    /// ```
+    pub fn dedup(&mut self) {
+        let mut seen: HashSet<label::Owned> = HashSet::new();
+
+        self.entries
+            .retain(|entry| seen.insert(entry.label().normalize()));
+    }
+}
+
+impl<'s> FromIterator<Auth<'s>> for Store<'s> {
+    fn from_iter<I: IntoIterator<Item = Auth<'s>>>(iterable: I) -> Self {
+        Self::new(iterable.into_iter().collect())
+    }
+}
+
+impl<'s> IntoIterator for Store<'s> {
+    type Item = Auth<'s>;
+    type IntoIter = vec::IntoIter<Auth<'s>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+impl<'a, 's> IntoIterator for &'a Store<'s> {
+    type Item = &'a Auth<'s>;
+    type IntoIter = slice::Iter<'a, Auth<'s>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Represents owned [`Store`].
+pub type Owned = Store<'static>;
+
+impl Store<'_> {
+    /// Converts [`Self`] into [`Owned`].
+    pub fn into_owned(self) -> Owned {
+        self.into_iter().map(Auth::into_owned).collect()
+    }
+}
+
+/// The format version of sealed stores.
+pub const ARTIFACT_VERSION: u8 = 1;
+
+/// The separator between entries of sealed stores.
+pub const NEWLINE: char = '\n';
+
+/// Represents sources of errors that can occur when opening sealed stores.
+#[derive(Debug, Error, Diagnostic)]
+#[error(transparent)]
+#[diagnostic(transparent)]
+#[non_exhaustive]
+pub enum ErrorSource {
+    /// The artifact could not be opened.
+    Artifact(#[from] artifact::Error),
+    /// The payload is not valid UTF-8.
+    Utf8(#[from] utf8::Error),
+    /// The entry could not be parsed.
+    Auth(#[from] core::Error),
+}
+
+/// Represents errors that can occur when opening sealed stores.
+#[derive(Debug, Error, Diagnostic)]
+#[error("failed to open sealed store")]
+#[diagnostic(
+    code(otp_std::auth::store),
+    help("see the report for more information")
+)]
+pub struct Error {
+    /// The source of this error.
+    #[source]
+    #[diagnostic_source]
+    pub source: ErrorSource,
+}
+
+impl Error {
+    /// Constructs [`Self`].
+    pub const fn new(source: ErrorSource) -> Self {
+        Self { source }
+    }
+
+    /// Constructs [`Self`] from [`artifact::Error`].
+    pub fn artifact(error: artifact::Error) -> Self {
+        Self::new(error.into())
+    }
+
+    /// Constructs [`Self`] from [`utf8::Error`].
+    pub fn utf8(error: utf8::Error) -> Self {
+        Self::new(error.into())
+    }
+
+    /// Constructs [`Self`] from [`core::Error`].
+    pub fn auth(error: core::Error) -> Self {
+        Self::new(error.into())
+    }
+}
+
+impl Store<'_> {
+    /// Seals [`Self`] into the version-tagged artifact.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{artifact::Seal, auth::Store, Auth};
+    ///
+    /// let url = "otpauth://totp/MelodyKit:nekitdev?secret=JEQDYMZAN5YGK3RAONXXK4TDMU&issuer=MelodyKit";
+    ///
+    /// let store: Store<'_> = [Auth::parse_url(url).unwrap()].into_iter().collect();
+    ///
+    /// let seal = Seal::new(b"key");
+    ///
+    /// let sealed = store.seal(&seal);
+    ///
+    /// let opened = Store::open(&seal, &sealed).unwrap();
+    ///
+    /// assert!(opened.entries()[0].same_parameters(&store.entries()[0]));
+    /// ```
+    pub fn seal(&self, seal: &Seal<'_>) -> Vec<u8> {
+        let mut payload = String::new();
+
+        for entry in self {
+            if !payload.is_empty() {
+                payload.push(NEWLINE);
+            };
+
+            payload.push_str(entry.build_url().as_str());
+        }
+
+        seal.seal(ARTIFACT_VERSION, payload)
+    }
+
+    /// Opens the sealed store artifact.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`struct@Error`] if the artifact can not be opened
+    /// or any of the entries can not be parsed.
+    pub fn open(seal: &Seal<'_>, artifact: &[u8]) -> Result<Owned, Error> {
+        let payload = seal
+            .open(ARTIFACT_VERSION, artifact)
+            .map_err(Error::artifact)?;
+
+        let string = String::from_utf8(payload.to_vec())
+            .map_err(utf8::wrap)
+            .map_err(Error::utf8)?;
+
+        string
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                Auth::parse_url(line)
+                    .map(Auth::into_owned)
+                    .map_err(Error::auth)
+            })
+            .collect()
+    }
+}
@@ -1,16 +1,16 @@
 //! URL functionality.
 //!
-//! This module re-exports [`url::Url`] and provides the [`struct@Error`] type
-//! that wraps [`url::ParseError`] to provide diagnostics.
+//! This module re-exports [`url::Url`] and [`url::Position`], and provides
+//! the [`struct@Error`] type that wraps [`url::ParseError`] to provide diagnostics.
 
 use miette::Diagnostic;
 use thiserror::Error;
 
-pub use url::Url;
+pub use url::{Position, Url};
 pub use urlencoding::{decode, encode};
 
 use crate::{
-    auth::{label::Label, scheme::SCHEME},
+    auth::{label::Label, scheme::SchemePolicy},
     otp::Type,
 };
 
@@ -38,7 +38,42 @@ pub const BASE_ALWAYS_VALID: &str = "OTP base URL is always valid";
 ///
 /// This function can not panic because the base URL is always valid.
 pub fn base(type_of: Type, label: &Label<'_>) -> Url {
-    let string = format!("{SCHEME}://{type_of}/{label}");
+    try_base(type_of, label).expect(BASE_ALWAYS_VALID)
+}
+
+/// Returns the base OTP URL for the given type and label, using the policy scheme.
+///
+/// # Panics
+///
+/// This function can not panic because the policy scheme is always valid.
+pub fn base_with(policy: &SchemePolicy<'_>, type_of: Type, label: &Label<'_>) -> Url {
+    try_base_with(policy, type_of, label).expect(BASE_ALWAYS_VALID)
+}
+
+/// Similar to [`base`], except the error is returned instead of panicking.
+///
+/// # Errors
+///
+/// Returns [`struct@Error`] if the base URL can not be parsed.
+pub fn try_base(type_of: Type, label: &Label<'_>) -> Result<Url, Error> {
+    try_base_with(&SchemePolicy::DEFAULT, type_of, label)
+}
+
+/// Similar to [`base_with`], except the error is returned instead of panicking.
+///
+/// # Errors
+///
+/// Returns [`struct@Error`] if the base URL can not be parsed.
+pub fn try_base_with(
+    policy: &SchemePolicy<'_>,
+    type_of: Type,
+    label: &Label<'_>,
+) -> Result<Url, Error> {
+    let string = format!(
+        "{scheme}://{type_of}/{label}",
+        scheme = policy.to_lowercase(),
+        label = label.encode()
+    );
 
-    parse(string).expect(BASE_ALWAYS_VALID)
+    parse(string)
 }
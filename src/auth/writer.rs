@@ -0,0 +1,243 @@
+//! Lightweight OTP URL writing.
+//!
+//! Building OTP URLs via [`Auth::build_url`] constructs [`Url`] values, parsing the base URL
+//! and applying query parameters one by one. The [`write_uri`] function instead writes
+//! the provisioning URI directly into any [`fmt::Write`] implementation, percent-encoding
+//! components along the way. The output is identical to the one of [`Auth::build_url`].
+//!
+//! [`Url`]: crate::auth::url::Url
+
+use std::fmt;
+
+use crate::{
+    auth::{
+        core::Auth,
+        label::{Label, ISSUER},
+        part::SEPARATOR,
+        scheme::SchemePolicy,
+    },
+    base::{Base, ALGORITHM, DIGITS, SECRET},
+    hotp::{COUNTER, WINDOW},
+    otp::core::Otp,
+    totp::PERIOD,
+};
+
+/// The separator between schemes and the rest of URLs.
+pub const SCHEME_SEPARATOR: &str = "://";
+
+/// The separator between paths and queries.
+pub const QUERY: char = '?';
+
+/// The separator between query pairs.
+pub const AND: char = '&';
+
+/// The separator between names and values in query pairs.
+pub const EQUALS: char = '=';
+
+/// The character used to encode spaces in query components.
+pub const PLUS: char = '+';
+
+/// Checks whether the given byte can be written as-is in path segments.
+pub const fn is_path_safe(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~')
+}
+
+/// Checks whether the given byte can be written as-is in query components.
+pub const fn is_query_safe(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || matches!(byte, b'*' | b'-' | b'.' | b'_')
+}
+
+fn write_escaped<W: fmt::Write>(writer: &mut W, byte: u8) -> fmt::Result {
+    write!(writer, "%{byte:02X}")
+}
+
+/// Writes the given string as the path segment, percent-encoding it.
+///
+/// # Errors
+///
+/// Returns [`fmt::Error`] if writing fails.
+pub fn write_path<W: fmt::Write>(writer: &mut W, string: &str) -> fmt::Result {
+    for byte in string.bytes() {
+        if is_path_safe(byte) {
+            writer.write_char(byte.into())?;
+        } else {
+            write_escaped(writer, byte)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes the given string as the query component, using `application/x-www-form-urlencoded`.
+///
+/// # Errors
+///
+/// Returns [`fmt::Error`] if writing fails.
+pub fn write_query<W: fmt::Write>(writer: &mut W, string: &str) -> fmt::Result {
+    for byte in string.bytes() {
+        if is_query_safe(byte) {
+            writer.write_char(byte.into())?;
+        } else if byte == b' ' {
+            writer.write_char(PLUS)?;
+        } else {
+            write_escaped(writer, byte)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn write_label<W: fmt::Write>(writer: &mut W, label: &Label<'_>) -> fmt::Result {
+    if let Some(issuer) = label.issuer.as_ref() {
+        write_path(writer, issuer.as_str())?;
+
+        writer.write_str(SEPARATOR)?;
+    };
+
+    write_path(writer, label.user.as_str())
+}
+
+struct Pairs<'w, W: fmt::Write> {
+    writer: &'w mut W,
+    first: bool,
+}
+
+impl<'w, W: fmt::Write> Pairs<'w, W> {
+    fn new(writer: &'w mut W) -> Self {
+        Self {
+            writer,
+            first: true,
+        }
+    }
+
+    fn append(&mut self, name: &str, value: &str) -> fmt::Result {
+        let separator = if self.first { QUERY } else { AND };
+
+        self.first = false;
+
+        self.writer.write_char(separator)?;
+
+        write_query(self.writer, name)?;
+
+        self.writer.write_char(EQUALS)?;
+
+        write_query(self.writer, value)
+    }
+}
+
+fn write_base<W: fmt::Write>(pairs: &mut Pairs<'_, W>, base: &Base<'_>) -> fmt::Result {
+    let secret = base.secret.encode();
+
+    let digits = base.digits.to_string();
+
+    pairs.append(SECRET, secret.as_str())?;
+    pairs.append(ALGORITHM, base.algorithm.static_str())?;
+    pairs.append(DIGITS, digits.as_str())
+}
+
+fn write_otp<W: fmt::Write>(pairs: &mut Pairs<'_, W>, otp: &Otp<'_>) -> fmt::Result {
+    write_base(pairs, otp.base())?;
+
+    match otp {
+        Otp::Hotp(hotp) => {
+            pairs.append(COUNTER, hotp.counter.to_string().as_str())?;
+
+            if hotp.look_ahead.is_default() {
+                return Ok(());
+            };
+
+            pairs.append(WINDOW, hotp.look_ahead.to_string().as_str())
+        }
+        Otp::Totp(totp) => pairs.append(PERIOD, totp.period.to_string().as_str()),
+    }
+}
+
+/// Writes the OTP URL of the given [`Auth`] into the writer.
+///
+/// # Errors
+///
+/// Returns [`fmt::Error`] if writing fails.
+///
+/// # Examples
+///
+/// ```
+/// use otp_std::{auth::writer::write_uri, Auth, Base, Label, Part, Secret, Totp};
+///
+/// let secret = Secret::decode("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+///
+/// let base = Base::builder().secret(secret).build();
+/// let totp = Totp::builder().base(base).build();
+///
+/// let user = Part::borrowed("nekitdev").unwrap();
+/// let label = Label::builder().user(user).build();
+///
+/// let auth = Auth::builder().otp(totp).label(label).build();
+///
+/// let mut string = String::new();
+///
+/// write_uri(&auth, &mut string).unwrap();
+///
+/// assert_eq!(string, auth.build_url().as_str());
+/// ```
+pub fn write_uri<W: fmt::Write>(auth: &Auth<'_>, writer: &mut W) -> fmt::Result {
+    write_uri_with(&SchemePolicy::DEFAULT, auth, writer)
+}
+
+/// Writes the OTP URL of the given [`Auth`] into the writer, using the scheme of the policy.
+///
+/// # Errors
+///
+/// Returns [`fmt::Error`] if writing fails.
+pub fn write_uri_with<W: fmt::Write>(
+    policy: &SchemePolicy<'_>,
+    auth: &Auth<'_>,
+    writer: &mut W,
+) -> fmt::Result {
+    let otp = auth.otp();
+    let label = auth.label();
+
+    write!(
+        writer,
+        "{scheme}{SCHEME_SEPARATOR}{type_of}/",
+        scheme = policy.to_lowercase(),
+        type_of = otp.type_of()
+    )?;
+
+    write_label(writer, label)?;
+
+    let mut pairs = Pairs::new(writer);
+
+    write_otp(&mut pairs, otp)?;
+
+    if let Some(issuer) = label.issuer.as_ref() {
+        let mut encoded = String::new();
+
+        write_path(&mut encoded, issuer.as_str())?;
+
+        pairs.append(ISSUER, encoded.as_str())?;
+    };
+
+    if auth.emit_extensions {
+        for (name, value) in auth.extensions().emitted() {
+            pairs.append(name, value)?;
+        }
+    };
+
+    Ok(())
+}
+
+/// Returns the OTP URL string of the given [`Auth`].
+///
+/// # Panics
+///
+/// This function can not panic because writing to strings never fails.
+pub fn to_uri(auth: &Auth<'_>) -> String {
+    let mut string = String::new();
+
+    write_uri(auth, &mut string).expect(WRITING_NEVER_FAILS);
+
+    string
+}
+
+/// The message indicating that writing to strings never fails.
+pub const WRITING_NEVER_FAILS: &str = "writing to strings never fails";
@@ -2,69 +2,238 @@
 
 use std::array;
 
-use bon::Builder;
 use constant_time_eq::constant_time_eq;
 
-#[cfg(feature = "auth")]
 use miette::Diagnostic;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-#[cfg(feature = "auth")]
 use thiserror::Error;
 
-use crate::{algorithm::Algorithm, digits::Digits, secret::core::Secret};
+use crate::{
+    algorithm::{Algorithm, HmacBuffer, HMAC_BUFFER_SIZE},
+    builder::Unset,
+    digits::{self, Buffer, Digits},
+    macros::{errors, setters},
+    options::VerifyOptions,
+    profile::{self, Profile},
+    scrub::scrub,
+    secret::core::Secret,
+};
 
 #[cfg(feature = "auth")]
 use crate::{
     algorithm,
-    auth::{query::Query, url::Url},
-    digits, secret,
+    auth::{
+        query::{extract_parse, Query},
+        url::Url,
+    },
+    secret,
 };
 
+#[cfg(feature = "auth")]
+pub use crate::auth::query::Parameter;
+
 /// Represents OTP base configuration.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Builder)]
+#[derive(Debug, Clone)]
+#[cfg_attr(not(feature = "forbid-secret-eq"), derive(PartialEq, Eq, Hash))]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Base<'b> {
     /// The secret to use as the key.
     pub secret: Secret<'b>,
     /// The algorithm to use.
-    #[builder(default)]
     #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(
+        feature = "compact-serde",
+        serde(skip_serializing_if = "Algorithm::is_default")
+    )]
     pub algorithm: Algorithm,
     /// The number of digits to return.
-    #[builder(default)]
     #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(
+        feature = "compact-serde",
+        serde(skip_serializing_if = "Digits::is_default")
+    )]
     pub digits: Digits,
 }
 
-/// The mask used to extract relevant bits.
-pub const MASK: u32 = 0x7FFF_FFFF;
+/// Represents builders of [`Base`] (see [`Base::builder`]).
+#[derive(Debug, Clone, Default)]
+pub struct BaseBuilder<S = Unset> {
+    secret: S,
+    algorithm: Option<Algorithm>,
+    digits: Option<Digits>,
+}
 
-/// The half byte to extract the offset.
-pub const HALF_BYTE: u8 = 0xF;
+/// Represents builders of checked [`Base`] (see [`Base::builder_checked`]).
+#[derive(Debug, Clone, Default)]
+pub struct BaseCheckedBuilder<S = Unset> {
+    secret: S,
+    algorithm: Option<Algorithm>,
+    digits: Option<Digits>,
+}
 
 impl Base<'_> {
-    /// Generates codes based on the given input.
+    /// Starts building [`Base`].
+    pub fn builder() -> BaseBuilder {
+        BaseBuilder::default()
+    }
+
+    /// Starts building checked [`Base`] (see [`checked`] for more information).
     ///
-    /// # Panics
+    /// [`checked`]: Self::checked
+    pub fn builder_checked() -> BaseCheckedBuilder {
+        BaseCheckedBuilder::default()
+    }
+}
+
+impl BaseBuilder {
+    /// Sets the `secret` member.
+    pub fn secret(self, secret: Secret<'_>) -> BaseBuilder<Secret<'_>> {
+        BaseBuilder {
+            secret,
+            algorithm: self.algorithm,
+            digits: self.digits,
+        }
+    }
+}
+
+impl<S> BaseBuilder<S> {
+    setters! {
+        algorithm, maybe_algorithm: Algorithm;
+        digits, maybe_digits: Digits;
+    }
+}
+
+impl<'b> BaseBuilder<Secret<'b>> {
+    /// Finishes building [`Base`].
+    pub fn build(self) -> Base<'b> {
+        Base {
+            secret: self.secret,
+            algorithm: self.algorithm.unwrap_or_default(),
+            digits: self.digits.unwrap_or_default(),
+        }
+    }
+}
+
+impl BaseCheckedBuilder {
+    /// Sets the `secret` member.
+    pub fn secret(self, secret: Secret<'_>) -> BaseCheckedBuilder<Secret<'_>> {
+        BaseCheckedBuilder {
+            secret,
+            algorithm: self.algorithm,
+            digits: self.digits,
+        }
+    }
+}
+
+impl<S> BaseCheckedBuilder<S> {
+    setters! {
+        algorithm, maybe_algorithm: Algorithm;
+        digits, maybe_digits: Digits;
+    }
+}
+
+impl<'b> BaseCheckedBuilder<Secret<'b>> {
+    /// Finishes building and validates [`Base`].
     ///
-    /// Even though [`unwrap`] and indexing are used, the code will never panic,
-    /// provided the HMAC implementation is correct.
+    /// # Errors
     ///
-    /// [`unwrap`]: Option::unwrap
-    pub fn generate(&self, input: u64) -> u32 {
-        let hmac = self
-            .algorithm
-            .hmac(self.secret.as_ref(), input.to_be_bytes());
+    /// Returns [`ValidationError`] if validation fails.
+    pub fn build(self) -> Result<Base<'b>, ValidationError> {
+        Base::checked(
+            self.secret,
+            self.algorithm.unwrap_or_default(),
+            self.digits.unwrap_or_default(),
+        )
+    }
+}
+
+/// The mask used to extract relevant bits.
+pub const MASK: u32 = 0x7FFF_FFFF;
+
+/// The half byte to extract the offset.
+pub const HALF_BYTE: u8 = 0xF;
 
+/// Dynamically truncates the given HMAC value, returning the code with the given digits.
+///
+/// See [`Output::truncate`] for the variant that is checked at compile time.
+///
+/// # Panics
+///
+/// Even though [`unwrap`] and indexing are used, the code will never panic,
+/// provided the HMAC value is at least [`MIN_LENGTH`] bytes long.
+///
+/// [`Output::truncate`]: crate::output::Output::truncate
+/// [`MIN_LENGTH`]: crate::output::MIN_LENGTH
+/// [`unwrap`]: Option::unwrap
+pub fn truncate<H: AsRef<[u8]>>(hmac: H, digits: Digits) -> u32 {
+    fn truncate_inner(hmac: &[u8], digits: Digits) -> u32 {
         let offset = (hmac.last().unwrap() & HALF_BYTE) as usize;
         let bytes = array::from_fn(|index| hmac[offset + index]);
 
         let value = u32::from_be_bytes(bytes) & MASK;
 
-        value % self.digits.power()
+        // the remainder is less than the value itself, therefore it always fits into `u32`
+        (u64::from(value) % digits.power_u64()) as u32
+    }
+
+    truncate_inner(hmac.as_ref(), digits)
+}
+
+/// Generates codes using the given key, input, digits and algorithm,
+/// computing HMAC values into the given buffer.
+///
+/// This function never allocates, which makes it suitable for high-throughput verifiers
+/// that reuse buffers across calls. The HMAC value is left in the buffer, so callers
+/// are responsible for scrubbing it if needed.
+///
+/// # Examples
+///
+/// ```
+/// use otp_std::{
+///     algorithm::{HmacBuffer, HMAC_BUFFER_SIZE},
+///     base::{generate, generate_into},
+///     Algorithm, Digits,
+/// };
+///
+/// let mut buffer: HmacBuffer = [0; HMAC_BUFFER_SIZE];
+///
+/// let code = generate_into(b"key", 0, Digits::default(), Algorithm::Sha1, &mut buffer);
+///
+/// assert_eq!(code, generate(b"key", 0, Digits::default(), Algorithm::Sha1));
+/// ```
+pub fn generate_into<K: AsRef<[u8]>>(
+    key: K,
+    input: u64,
+    digits: Digits,
+    algorithm: Algorithm,
+    buffer: &mut HmacBuffer,
+) -> u32 {
+    let length = algorithm.hmac_into(key, input.to_be_bytes(), buffer);
+
+    truncate(&buffer[..length], digits)
+}
+
+/// Generates codes using the given key, input, digits and algorithm.
+///
+/// Unlike [`Base::generate`], this function does not check the key length.
+///
+/// HMAC values are computed into [`AnyOutput`] on the stack, so no allocation happens.
+/// Since their lengths are encoded in types, truncating them never panics.
+///
+/// [`AnyOutput`]: crate::output::AnyOutput
+pub fn generate<K: AsRef<[u8]>>(key: K, input: u64, digits: Digits, algorithm: Algorithm) -> u32 {
+    algorithm.compute(key, input.to_be_bytes()).truncate(digits)
+}
+
+impl Base<'_> {
+    /// Generates codes based on the given input.
+    ///
+    /// This method does not allocate and never panics (see [`generate`]).
+    pub fn generate(&self, input: u64) -> u32 {
+        generate(self.secret.as_ref(), input, self.digits, self.algorithm)
     }
 
     /// Calls [`generate`] and returns the string representation of the resulting code.
@@ -84,12 +253,76 @@ impl Base<'_> {
 
     /// Verifies that the given string code matches the given input in constant time.
     ///
-    /// This method exists to simplify verification.
+    /// This method exists to simplify verification. The expected code is formatted
+    /// into the stack buffer (see [`format_fixed`]), so no allocation happens.
+    ///
+    /// [`format_fixed`]: Digits::format_fixed
     pub fn verify_string<S: AsRef<str>>(&self, input: u64, code: S) -> bool {
-        constant_time_eq(
-            self.generate_string(input).as_bytes(),
-            code.as_ref().as_bytes(),
-        )
+        let mut buffer = Buffer::default();
+
+        let expected = self.digits.format_fixed(self.generate(input), &mut buffer);
+
+        constant_time_eq(expected, code.as_ref().as_bytes())
+    }
+
+    /// Similar to [`verify_string`], except the intermediate buffers are scrubbed afterwards.
+    ///
+    /// See [`scrub`] for more information.
+    ///
+    /// [`verify_string`]: Self::verify_string
+    /// [`scrub`]: crate::scrub
+    pub fn verify_string_scrubbed<S: AsRef<str>>(&self, input: u64, code: S) -> bool {
+        let mut hmac = [0; HMAC_BUFFER_SIZE];
+
+        let generated = generate_into(
+            self.secret.as_ref(),
+            input,
+            self.digits,
+            self.algorithm,
+            &mut hmac,
+        );
+
+        scrub(&mut hmac);
+
+        let mut buffer = Buffer::default();
+
+        let expected = self.digits.format_fixed(generated, &mut buffer);
+
+        let valid = constant_time_eq(expected, code.as_ref().as_bytes());
+
+        scrub(&mut buffer);
+
+        valid
+    }
+
+    /// Verifies the given string code, assuming it is already normalized according to
+    /// the options, scrubbing the intermediate buffers if [`scrub`] is set.
+    ///
+    /// [`scrub`]: VerifyOptions::scrub
+    pub fn verify_normalized(&self, input: u64, code: &str, options: VerifyOptions) -> bool {
+        if options.scrub {
+            self.verify_string_scrubbed(input, code)
+        } else {
+            self.verify_string(input, code)
+        }
+    }
+
+    /// Similar to [`verify_string`], except the code is normalized according to the options.
+    ///
+    /// [`verify_string`]: Self::verify_string
+    pub fn verify_string_with<S: AsRef<str>>(
+        &self,
+        input: u64,
+        code: S,
+        options: VerifyOptions,
+    ) -> bool {
+        let code = options.normalize(code.as_ref());
+
+        let valid = self.verify_normalized(input, &code, options);
+
+        options.discard(code);
+
+        valid
     }
 }
 
@@ -105,6 +338,194 @@ pub const ALGORITHM: &str = "algorithm";
 #[cfg(feature = "auth")]
 pub const DIGITS: &str = "digits";
 
+/// Represents errors returned when secrets are shorter than recommended for algorithms.
+#[derive(Debug, Error, Diagnostic)]
+#[error(
+    "secret of length `{length}` is shorter than `{recommended}` recommended for `{algorithm}`"
+)]
+#[diagnostic(
+    code(otp_std::base::weak_secret),
+    help("generate secrets of recommended lengths using `Secret::generate_for`")
+)]
+pub struct WeakSecretError {
+    /// The actual length of the secret.
+    pub length: usize,
+    /// The length recommended for the algorithm.
+    pub recommended: usize,
+    /// The algorithm used.
+    pub algorithm: Algorithm,
+}
+
+impl WeakSecretError {
+    /// Constructs [`Self`].
+    pub const fn new(length: usize, recommended: usize, algorithm: Algorithm) -> Self {
+        Self {
+            length,
+            recommended,
+            algorithm,
+        }
+    }
+}
+
+/// Represents sources of errors that can occur when validating base configurations.
+#[derive(Debug, Error, Diagnostic)]
+#[error(transparent)]
+#[diagnostic(transparent)]
+#[non_exhaustive]
+pub enum ValidationErrorSource {
+    /// The secret is shorter than recommended.
+    WeakSecret(#[from] WeakSecretError),
+    /// The number of digits is out of range.
+    Digits(#[from] digits::Error),
+    /// The configuration violates the profile.
+    Profile(#[from] profile::Error),
+}
+
+/// Represents errors that can occur when validating base configurations.
+#[derive(Debug, Error, Diagnostic)]
+#[error("base configuration is invalid")]
+#[diagnostic(
+    code(otp_std::base::validate),
+    help("see the report for more information")
+)]
+pub struct ValidationError {
+    /// The source of this error.
+    #[source]
+    #[diagnostic_source]
+    pub source: ValidationErrorSource,
+}
+
+impl ValidationError {
+    /// Constructs [`Self`].
+    pub const fn new(source: ValidationErrorSource) -> Self {
+        Self { source }
+    }
+
+    /// Constructs [`Self`] from [`WeakSecretError`].
+    pub fn weak_secret(error: WeakSecretError) -> Self {
+        Self::new(error.into())
+    }
+
+    /// Creates [`WeakSecretError`] and constructs [`Self`] from it.
+    pub fn new_weak_secret(length: usize, recommended: usize, algorithm: Algorithm) -> Self {
+        Self::weak_secret(WeakSecretError::new(length, recommended, algorithm))
+    }
+
+    /// Constructs [`Self`] from [`digits::Error`].
+    pub fn digits(error: digits::Error) -> Self {
+        Self::new(error.into())
+    }
+
+    /// Constructs [`Self`] from [`profile::Error`].
+    pub fn profile(error: profile::Error) -> Self {
+        Self::new(error.into())
+    }
+}
+
+errors! {
+    Type = ValidationError,
+    Hack = $,
+    weak_secret_error => new_weak_secret(length, recommended, algorithm),
+}
+
+impl Base<'_> {
+    /// Validates [`Self`], checking the secret length against the one recommended
+    /// for the algorithm, as well as the number of digits.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValidationError`] if the secret is shorter than recommended
+    /// or the number of digits is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{Algorithm, Base, Secret};
+    ///
+    /// let secret = Secret::decode("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+    ///
+    /// let base = Base::builder().secret(secret).build();
+    ///
+    /// // the secret is 16 bytes long, while 20 bytes are recommended for SHA-1
+    /// assert!(base.validate().is_err());
+    ///
+    /// let secret = Secret::generate_for(Algorithm::Sha1);
+    ///
+    /// let base = Base::builder().secret(secret).build();
+    ///
+    /// assert!(base.validate().is_ok());
+    /// ```
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        Digits::check(self.digits.get()).map_err(ValidationError::digits)?;
+
+        let length = self.secret.as_bytes().len();
+
+        let recommended = self.algorithm.recommended_length();
+
+        if length < recommended {
+            return Err(weak_secret_error!(length, recommended, self.algorithm));
+        };
+
+        Ok(())
+    }
+
+    /// Validates [`Self`] (see [`validate`]), additionally enforcing the given profile.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValidationError`] if regular validation fails or the profile is violated.
+    ///
+    /// [`validate`]: Self::validate
+    pub fn validate_with(&self, profile: Profile) -> Result<(), ValidationError> {
+        self.validate()?;
+
+        profile.enforce_base(self).map_err(ValidationError::profile)
+    }
+}
+
+impl<'b> Base<'b> {
+    /// Constructs and validates [`Self`] (see [`validate`] for more information).
+    ///
+    /// The builder is started with [`builder_checked`] and finished with `build`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValidationError`] if validation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{Algorithm, Base, Secret};
+    ///
+    /// let secret = Secret::generate_for(Algorithm::Sha1);
+    ///
+    /// let result = Base::builder_checked()
+    ///     .secret(secret)
+    ///     .algorithm(Algorithm::Sha1)
+    ///     .build();
+    ///
+    /// assert!(result.is_ok());
+    /// ```
+    ///
+    /// [`validate`]: Self::validate
+    /// [`builder_checked`]: Self::builder_checked
+    pub fn checked(
+        secret: Secret<'b>,
+        algorithm: Algorithm,
+        digits: Digits,
+    ) -> Result<Self, ValidationError> {
+        let base = Self {
+            secret,
+            algorithm,
+            digits,
+        };
+
+        base.validate()?;
+
+        Ok(base)
+    }
+}
+
 /// Represents errors returned when the secret is not found in the OTP URL.
 #[cfg(feature = "auth")]
 #[derive(Debug, Error, Diagnostic)]
@@ -118,6 +539,7 @@ pub struct SecretNotFoundError;
 #[derive(Debug, Error, Diagnostic)]
 #[error(transparent)]
 #[diagnostic(transparent)]
+#[non_exhaustive]
 pub enum ErrorSource {
     /// The secret was not found in the OTP URL.
     SecretNotFound(#[from] SecretNotFoundError),
@@ -129,10 +551,22 @@ pub enum ErrorSource {
     Digits(#[from] digits::ParseError),
 }
 
+#[cfg(feature = "auth")]
+impl ErrorSource {
+    /// Returns the parameter that caused this error.
+    pub fn parameter(&self) -> Parameter<'_> {
+        match self {
+            Self::SecretNotFound(_) | Self::Secret(_) => Parameter::name_only(SECRET),
+            Self::Algorithm(error) => Parameter::with_value(ALGORITHM, &error.unknown),
+            Self::Digits(error) => Parameter::with_value(DIGITS, &error.string),
+        }
+    }
+}
+
 /// Represents errors that can occur when extracting the base from OTP URLs.
 #[cfg(feature = "auth")]
 #[derive(Debug, Error, Diagnostic)]
-#[error("failed to extract base from OTP URL")]
+#[error("failed to extract base from OTP URL (parameter {})", .source.parameter())]
 #[diagnostic(
     code(otp_std::base::extract),
     help("see the report for more information")
@@ -175,6 +609,11 @@ impl Error {
     pub fn digits(error: digits::ParseError) -> Self {
         Self::new(error.into())
     }
+
+    /// Returns the parameter that caused this error.
+    pub fn parameter(&self) -> Parameter<'_> {
+        self.source.parameter()
+    }
 }
 
 #[cfg(feature = "auth")]
@@ -199,23 +638,14 @@ impl Base<'_> {
     ///
     /// Returns [`struct@Error`] if the base configuration can not be extracted.
     pub fn extract_from(query: &mut Query<'_>) -> Result<Self, Error> {
-        let secret = query
-            .remove(SECRET)
-            .ok_or_else(Error::new_secret_not_found)?
-            .parse()
-            .map_err(Error::secret)?;
-
-        let maybe_algorithm = query
-            .remove(ALGORITHM)
-            .map(|string| string.parse())
-            .transpose()
-            .map_err(Error::algorithm)?;
-
-        let maybe_digits = query
-            .remove(DIGITS)
-            .map(|string| string.parse())
-            .transpose()
-            .map_err(Error::digits)?;
+        let secret = extract_parse(query, SECRET)
+            .map_err(secret::core::Error::redacted)
+            .map_err(Error::secret)?
+            .ok_or_else(Error::new_secret_not_found)?;
+
+        let maybe_algorithm = extract_parse(query, ALGORITHM).map_err(Error::algorithm)?;
+
+        let maybe_digits = extract_parse(query, DIGITS).map_err(Error::digits)?;
 
         let base = Self::builder()
             .secret(secret)
@@ -227,6 +657,58 @@ impl Base<'_> {
     }
 }
 
+impl Base<'_> {
+    /// Checks whether [`Self`] and the other configuration have the same parameters,
+    /// comparing everything except the secrets.
+    pub fn same_parameters(&self, other: &Base<'_>) -> bool {
+        self.algorithm == other.algorithm && self.digits == other.digits
+    }
+}
+
+impl Base<'_> {
+    /// Returns [`Self`] with the given algorithm.
+    #[must_use]
+    pub const fn with_algorithm(mut self, algorithm: Algorithm) -> Self {
+        self.algorithm = algorithm;
+
+        self
+    }
+
+    /// Returns [`Self`] with the given number of digits.
+    #[must_use]
+    pub const fn with_digits(mut self, digits: Digits) -> Self {
+        self.digits = digits;
+
+        self
+    }
+
+    /// Returns [`Self`] with the given secret.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{Algorithm, Base, Digits, Secret};
+    ///
+    /// let secret = Secret::decode("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+    ///
+    /// let base = Base::builder().secret(secret).build();
+    ///
+    /// let rotated = Secret::generate_for(Algorithm::Sha1);
+    ///
+    /// let adjusted = base.with_secret(rotated).with_digits(Digits::MAX);
+    ///
+    /// assert_eq!(adjusted.digits, Digits::MAX);
+    /// ```
+    #[must_use]
+    pub fn with_secret<'s>(self, secret: Secret<'s>) -> Base<'s> {
+        Base {
+            secret,
+            algorithm: self.algorithm,
+            digits: self.digits,
+        }
+    }
+}
+
 /// Represents owned [`Base`].
 pub type Owned = Base<'static>;
 
@@ -0,0 +1,182 @@
+//! Batch verification.
+//!
+//! Servers verifying codes of many users at once can use [`verify_many`] instead of
+//! calling [`Totp::verify_at`] in a loop. The HMAC buffer is set up once and reused for
+//! every item (see [`generate_into`]), so no allocation happens besides the resulting vector.
+//!
+//! Verifying many codes against the same configuration (for instance, replaying queued requests)
+//! is done via [`Totp::verify_batch`], which additionally prepares the HMAC key once
+//! (see [`Prepared`]).
+//!
+//! With the `rayon` feature enabled, [`par_verify_many`] spreads verification across threads,
+//! reusing one buffer per thread.
+//!
+//! [`generate_into`]: crate::base::generate_into
+//! [`Prepared`]: crate::prepared::Prepared
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::{
+    algorithm::{HmacBuffer, HMAC_BUFFER_SIZE},
+    base::generate_into,
+    prepared::Prepared,
+    scrub::scrub,
+    totp::Totp,
+};
+
+/// Represents batch verification items, that is, configurations along with times and codes.
+pub type Item<'i, 't> = (&'i Totp<'t>, u64, u32);
+
+/// Represents reusable HMAC buffers, scrubbed when dropped.
+struct Scratch {
+    buffer: HmacBuffer,
+}
+
+impl Scratch {
+    const fn new() -> Self {
+        Self {
+            buffer: [0; HMAC_BUFFER_SIZE],
+        }
+    }
+
+    fn verify(&mut self, totp: &Totp<'_>, time: u64, code: u32) -> bool {
+        let base = totp.base();
+
+        totp.skew.apply(totp.input_at(time)).any(|input| {
+            generate_into(
+                base.secret.as_ref(),
+                input,
+                base.digits,
+                base.algorithm,
+                &mut self.buffer,
+            ) == code
+        })
+    }
+}
+
+impl Scratch {
+    fn verify_prepared(
+        &mut self,
+        totp: &Totp<'_>,
+        prepared: &Prepared,
+        time: u64,
+        code: u32,
+    ) -> bool {
+        let digits = totp.base().digits;
+
+        totp.skew
+            .apply(totp.input_at(time))
+            .any(|input| prepared.generate_into(input, digits, &mut self.buffer) == code)
+    }
+}
+
+impl Drop for Scratch {
+    fn drop(&mut self) {
+        scrub(&mut self.buffer);
+    }
+}
+
+/// Verifies the given items, accounting for *skews*, and appends the results to the vector.
+///
+/// Results are in the same order as items.
+pub fn verify_many_into<'i, 't: 'i, I: IntoIterator<Item = Item<'i, 't>>>(
+    items: I,
+    results: &mut Vec<bool>,
+) {
+    let mut scratch = Scratch::new();
+
+    results.extend(
+        items
+            .into_iter()
+            .map(|(totp, time, code)| scratch.verify(totp, time, code)),
+    );
+}
+
+/// Verifies the given items, accounting for *skews*, and returns the results.
+///
+/// Results are in the same order as items; each one is equal to
+/// the one returned by [`Totp::verify_at`].
+///
+/// # Examples
+///
+/// ```
+/// use otp_std::{batch::verify_many, Algorithm, Base, Secret, Totp};
+///
+/// let secret = Secret::generate_for(Algorithm::Sha1);
+///
+/// let base = Base::builder().secret(secret).build();
+///
+/// let totp = Totp::builder().base(base).build();
+///
+/// let time = 1_234_567_890;
+///
+/// let code = totp.generate_at(time);
+///
+/// let results = verify_many([(&totp, time, code), (&totp, time + 3600, code)]);
+///
+/// assert_eq!(results, [true, false]);
+/// ```
+pub fn verify_many<'i, 't: 'i, I: IntoIterator<Item = Item<'i, 't>>>(items: I) -> Vec<bool> {
+    let items = items.into_iter();
+
+    let mut results = Vec::with_capacity(items.size_hint().0);
+
+    verify_many_into(items, &mut results);
+
+    results
+}
+
+/// Similar to [`verify_many`], except verification is done in parallel.
+///
+/// Each thread reuses its own HMAC buffer.
+#[cfg(feature = "rayon")]
+pub fn par_verify_many(items: &[Item<'_, '_>]) -> Vec<bool> {
+    items
+        .par_iter()
+        .map_init(Scratch::new, |scratch, &(totp, time, code)| {
+            scratch.verify(totp, time, code)
+        })
+        .collect()
+}
+
+impl Totp<'_> {
+    /// Verifies the given times and codes, accounting for *skews*, and returns the results.
+    ///
+    /// The HMAC key is prepared once and reused for every item, along with the HMAC buffer.
+    ///
+    /// Results are in the same order as items; each one is equal to
+    /// the one returned by [`verify_at`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{Base, Secret, Totp};
+    ///
+    /// let secret = Secret::decode("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+    ///
+    /// let base = Base::builder().secret(secret).build();
+    ///
+    /// let totp = Totp::builder().base(base).build();
+    ///
+    /// let time = 1_234_567_890;
+    ///
+    /// let code = totp.generate_at(time);
+    ///
+    /// assert_eq!(totp.verify_batch(&[(time, code), (time + 3600, code)]), [true, false]);
+    /// ```
+    ///
+    /// [`verify_at`]: Self::verify_at
+    pub fn verify_batch(&self, items: &[(u64, u32)]) -> Vec<bool> {
+        let base = self.base();
+
+        let prepared = base.algorithm.prepare(base.secret.as_ref());
+
+        let mut scratch = Scratch::new();
+
+        items
+            .iter()
+            .map(|&(time, code)| scratch.verify_prepared(self, &prepared, time, code))
+            .collect()
+    }
+}
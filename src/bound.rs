@@ -0,0 +1,117 @@
+//! Transaction-bound codes.
+//!
+//! **This functionality is non-standard** and is *not* compatible with RFC 4226 or RFC 6238
+//! authenticators. It is similar in spirit to OCRA (RFC 6287), but much simpler.
+//!
+//! Bound codes mix transaction data (like amounts and recipients, or nonces) into the HMAC input,
+//! so that codes issued for one transaction can not be used to confirm another one.
+//!
+//! The HMAC input is constructed as follows (all integers are big-endian):
+//!
+//! ```text
+//! input (8 bytes) || binding length (8 bytes) || binding
+//! ```
+//!
+//! The binding length is always included, so bound codes never coincide with standard ones,
+//! even if the binding is empty.
+
+use constant_time_eq::constant_time_eq;
+
+use crate::{
+    algorithm::{Algorithm, HMAC_BUFFER_SIZE},
+    base::{truncate, Base},
+    digits::{Buffer, Digits},
+};
+
+/// Constructs the HMAC input for the given input and binding.
+pub fn bound_input(input: u64, binding: &[u8]) -> Vec<u8> {
+    let length = binding.len() as u64;
+
+    let mut data = Vec::with_capacity(16 + binding.len());
+
+    data.extend_from_slice(&input.to_be_bytes());
+    data.extend_from_slice(&length.to_be_bytes());
+    data.extend_from_slice(binding);
+
+    data
+}
+
+/// Generates bound codes using the given key, input, binding, digits and algorithm.
+///
+/// Unlike [`Base::generate_bound`], this function does not check the key length.
+pub fn generate_bound<K: AsRef<[u8]>, B: AsRef<[u8]>>(
+    key: K,
+    input: u64,
+    binding: B,
+    digits: Digits,
+    algorithm: Algorithm,
+) -> u32 {
+    let mut buffer = [0; HMAC_BUFFER_SIZE];
+
+    let data = bound_input(input, binding.as_ref());
+
+    let length = algorithm.hmac_into(key, data, &mut buffer);
+
+    truncate(&buffer[..length], digits)
+}
+
+impl Base<'_> {
+    /// Generates codes based on the given input, bound to the given transaction data.
+    ///
+    /// This method is **non-standard**; see [`bound`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{Base, Secret};
+    ///
+    /// let secret = Secret::decode("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+    ///
+    /// let base = Base::builder().secret(secret).build();
+    ///
+    /// let code = base.generate_bound(13, b"transfer 100 EUR to nekitdev");
+    ///
+    /// assert!(base.verify_bound(13, b"transfer 100 EUR to nekitdev", code));
+    /// assert!(!base.verify_bound(13, b"transfer 999 EUR to attacker", code));
+    /// ```
+    ///
+    /// [`bound`]: crate::bound
+    pub fn generate_bound<B: AsRef<[u8]>>(&self, input: u64, binding: B) -> u32 {
+        generate_bound(
+            self.secret.as_ref(),
+            input,
+            binding,
+            self.digits,
+            self.algorithm,
+        )
+    }
+
+    /// Calls [`generate_bound`] and returns the string representation of the resulting code.
+    ///
+    /// [`generate_bound`]: Self::generate_bound
+    pub fn generate_bound_string<B: AsRef<[u8]>>(&self, input: u64, binding: B) -> String {
+        self.digits.string(self.generate_bound(input, binding))
+    }
+
+    /// Verifies that the given code matches the given input and binding.
+    pub fn verify_bound<B: AsRef<[u8]>>(&self, input: u64, binding: B, code: u32) -> bool {
+        self.generate_bound(input, binding) == code
+    }
+
+    /// Verifies that the given string code matches the given input and binding
+    /// in constant time.
+    pub fn verify_bound_string<B: AsRef<[u8]>, S: AsRef<str>>(
+        &self,
+        input: u64,
+        binding: B,
+        code: S,
+    ) -> bool {
+        let mut buffer = Buffer::default();
+
+        let expected = self
+            .digits
+            .format_fixed(self.generate_bound(input, binding), &mut buffer);
+
+        constant_time_eq(expected, code.as_ref().as_bytes())
+    }
+}
@@ -0,0 +1,36 @@
+//! Builder support.
+//!
+//! Builders check that required members are set at compile time. Each required member
+//! is tracked by its own type parameter, which is [`Unset`] until the member is set,
+//! and the type of the member afterwards. Finishing builders is only possible
+//! once all required members are set.
+//!
+//! Optional members come with pairs of setters, for instance, `digits` and `maybe_digits`,
+//! where the latter accepts [`Option`] values.
+//!
+//! # Examples
+//!
+//! ```
+//! use otp_std::{Base, Digits, Secret};
+//!
+//! let secret = Secret::decode("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+//!
+//! let base = Base::builder()
+//!     .digits(Digits::MAX)
+//!     .secret(secret)
+//!     .build();
+//!
+//! assert_eq!(base.digits, Digits::MAX);
+//! ```
+//!
+//! Finishing builders without setting required members results in compilation errors:
+//!
+//! ```compile_fail
+//! use otp_std::{Base, Digits};
+//!
+//! let base = Base::builder().digits(Digits::MAX).build();
+//! ```
+
+/// Represents required builder members that are not set yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Unset;
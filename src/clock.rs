@@ -0,0 +1,180 @@
+//! Time sources.
+//!
+//! Methods like [`Totp::generate`] and [`Totp::verify`] use the ambient system clock
+//! (see [`now`]). The [`Clock`] trait allows injecting time instead, which is useful
+//! for testing ([`FixedClock`]) and for servers correcting their clocks ([`OffsetClock`]).
+//!
+//! With the `coarse-clock` feature enabled, `CoarseClock` provides the cached time
+//! (see `now_coarse`), which is cheaper to fetch on high-throughput servers.
+//!
+//! [`now`]: crate::time::now
+
+use crate::{
+    time::{self, now, CURRENT_TIME_BEFORE_EPOCH},
+    totp::Totp,
+};
+
+/// Represents sources of the current time.
+pub trait Clock {
+    /// Returns the current time as seconds since the epoch.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`time::Error`] if the current time is before the epoch.
+    fn now(&self) -> Result<u64, time::Error>;
+
+    /// Similar to [`now`], but panics if the current time is before the epoch.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the current time is before the epoch.
+    ///
+    /// [`now`]: Self::now
+    fn expect_now(&self) -> u64 {
+        self.now().expect(CURRENT_TIME_BEFORE_EPOCH)
+    }
+}
+
+/// Represents the system clock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Result<u64, time::Error> {
+        now()
+    }
+}
+
+/// Represents the coarse clock, which returns the cached time updated once per second.
+///
+/// See [`now_coarse`] for more information.
+///
+/// [`now_coarse`]: crate::time::now_coarse
+#[cfg(feature = "coarse-clock")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct CoarseClock;
+
+#[cfg(feature = "coarse-clock")]
+impl Clock for CoarseClock {
+    fn now(&self) -> Result<u64, time::Error> {
+        time::now_coarse()
+    }
+}
+
+/// Represents clocks that always return the same time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct FixedClock {
+    /// The time as seconds since the epoch.
+    pub time: u64,
+}
+
+impl FixedClock {
+    /// Constructs [`Self`].
+    pub const fn new(time: u64) -> Self {
+        Self { time }
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> Result<u64, time::Error> {
+        Ok(self.time)
+    }
+}
+
+/// Represents clocks that apply the offset to the time of other clocks.
+///
+/// For instance, servers can apply the offset measured via NTP to the system clock.
+///
+/// The resulting time saturates at the bounds of [`u64`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct OffsetClock<C: Clock = SystemClock> {
+    /// The clock to apply the offset to.
+    pub clock: C,
+    /// The offset, in seconds.
+    pub offset: i64,
+}
+
+impl<C: Clock> OffsetClock<C> {
+    /// Constructs [`Self`].
+    pub const fn new(clock: C, offset: i64) -> Self {
+        Self { clock, offset }
+    }
+}
+
+impl OffsetClock {
+    /// Constructs [`Self`] applying the offset to [`SystemClock`].
+    pub const fn system(offset: i64) -> Self {
+        Self::new(SystemClock, offset)
+    }
+}
+
+impl<C: Clock> Clock for OffsetClock<C> {
+    fn now(&self) -> Result<u64, time::Error> {
+        self.clock
+            .now()
+            .map(|time| time.saturating_add_signed(self.offset))
+    }
+}
+
+impl Totp<'_> {
+    /// Tries to generate the code for the current time of the given clock.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`time::Error`] if the current time is before the epoch.
+    pub fn try_generate_with<C: Clock + ?Sized>(&self, clock: &C) -> Result<u32, time::Error> {
+        clock.now().map(|time| self.generate_at(time))
+    }
+
+    /// Generates the code for the current time of the given clock.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the current time is before the epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{clock::FixedClock, Base, Secret, Totp};
+    ///
+    /// let secret = Secret::decode("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+    ///
+    /// let base = Base::builder().secret(secret).build();
+    ///
+    /// let totp = Totp::builder().base(base).build();
+    ///
+    /// let clock = FixedClock::new(1_234_567_890);
+    ///
+    /// let code = totp.generate_with(&clock);
+    ///
+    /// assert_eq!(code, totp.generate_at(1_234_567_890));
+    ///
+    /// assert!(totp.verify_with(&clock, code));
+    /// ```
+    pub fn generate_with<C: Clock + ?Sized>(&self, clock: &C) -> u32 {
+        self.generate_at(clock.expect_now())
+    }
+
+    /// Tries to verify the given code for the current time of the given clock,
+    /// accounting for *skews*.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`time::Error`] if the current time is before the epoch.
+    pub fn try_verify_with<C: Clock + ?Sized>(
+        &self,
+        clock: &C,
+        code: u32,
+    ) -> Result<bool, time::Error> {
+        clock.now().map(|time| self.verify_at(time, code))
+    }
+
+    /// Verifies the given code for the current time of the given clock, accounting for *skews*.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the current time is before the epoch.
+    pub fn verify_with<C: Clock + ?Sized>(&self, clock: &C, code: u32) -> bool {
+        self.verify_at(clock.expect_now(), code)
+    }
+}
@@ -0,0 +1,271 @@
+//! Structured codes.
+//!
+//! Codes are commonly passed around as [`u32`] or [`String`] values, where the former
+//! loses the number of digits (and therefore the leading zeros), while the latter
+//! has to be validated again. [`Code`] stores the value along with its [`Digits`].
+
+use std::{fmt, str::FromStr};
+
+use miette::Diagnostic;
+
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use thiserror::Error;
+
+use crate::{
+    base::Base,
+    digits::{Digits, MAX, MIN},
+    hotp::Hotp,
+    macros::errors,
+    time::{self, current, expect_current},
+    totp::Totp,
+};
+
+/// Represents errors returned when code values do not fit into the given digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error, Diagnostic)]
+#[error("code `{value}` does not fit into `{digits}` digits")]
+#[diagnostic(
+    code(otp_std::code),
+    help("make sure the code is less than `10` raised to the power of digits")
+)]
+pub struct Error {
+    /// The value that does not fit.
+    pub value: u32,
+    /// The digits that the value does not fit into.
+    pub digits: Digits,
+}
+
+impl Error {
+    /// Constructs [`Self`].
+    pub const fn new(value: u32, digits: Digits) -> Self {
+        Self { value, digits }
+    }
+}
+
+/// Represents errors returned when parsing codes fails.
+#[derive(Debug, Clone, PartialEq, Eq, Error, Diagnostic)]
+#[error("failed to parse `{string}` code")]
+#[diagnostic(
+    code(otp_std::code::parse),
+    help("codes must consist of `{MIN}` to `{MAX}` ASCII digits")
+)]
+pub struct ParseError {
+    /// The string that could not be parsed.
+    pub string: String,
+}
+
+impl ParseError {
+    /// Constructs [`Self`].
+    pub const fn new(string: String) -> Self {
+        Self { string }
+    }
+}
+
+errors! {
+    Type = ParseError,
+    Hack = $,
+    parse_error => new(string => to_owned),
+}
+
+/// Represents codes along with their digits.
+///
+/// Formatting codes pads them with zeros according to their digits.
+///
+/// # Examples
+///
+/// ```
+/// use otp_std::{code::Code, Digits};
+///
+/// let code = Code::new(42, Digits::DEFAULT).unwrap();
+///
+/// assert_eq!(code.to_string(), "000042");
+///
+/// assert_eq!("000042".parse::<Code>().unwrap(), code);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Code {
+    value: u32,
+    digits: Digits,
+}
+
+impl Code {
+    /// Constructs [`Self`], if possible.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`struct@Error`] if the value does not fit into the digits.
+    pub const fn new(value: u32, digits: Digits) -> Result<Self, Error> {
+        if value >= digits.power() {
+            return Err(Error::new(value, digits));
+        };
+
+        // SAFETY: the value fits into the digits
+        Ok(unsafe { Self::new_unchecked(value, digits) })
+    }
+
+    /// Constructs [`Self`] without checking that the value fits into the digits.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the value is less than [`Digits::power`].
+    pub const unsafe fn new_unchecked(value: u32, digits: Digits) -> Self {
+        Self { value, digits }
+    }
+
+    /// Returns the value of [`Self`].
+    pub const fn value(self) -> u32 {
+        self.value
+    }
+
+    /// Returns the digits of [`Self`].
+    pub const fn digits(self) -> Digits {
+        self.digits
+    }
+}
+
+impl fmt::Display for Code {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "{value:0count$}",
+            value = self.value,
+            count = self.digits.count()
+        )
+    }
+}
+
+impl FromStr for Code {
+    type Err = ParseError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        if !string.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(parse_error!(string));
+        };
+
+        let digits = u8::try_from(string.len())
+            .ok()
+            .and_then(Digits::new_ok)
+            .ok_or_else(|| parse_error!(string))?;
+
+        let value = string.parse().map_err(|_| parse_error!(string))?;
+
+        // SAFETY: the value has exactly `digits` digits
+        Ok(unsafe { Self::new_unchecked(value, digits) })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Code {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_string().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Code {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+
+        string.parse().map_err(de::Error::custom)
+    }
+}
+
+impl Base<'_> {
+    /// Generates the code for the given input.
+    pub fn generate_code(&self, input: u64) -> Code {
+        // SAFETY: generated codes always fit into the digits
+        unsafe { Code::new_unchecked(self.generate(input), self.digits) }
+    }
+
+    /// Verifies that the given code matches the given input.
+    ///
+    /// Codes with digits different from the configured ones never match.
+    pub fn verify_code(&self, input: u64, code: Code) -> bool {
+        self.digits == code.digits() && self.verify(input, code.value())
+    }
+}
+
+impl Hotp<'_> {
+    /// Generates the code for the current counter value *without* advancing the counter.
+    ///
+    /// See [`peek`] for more information.
+    ///
+    /// [`peek`]: Self::peek
+    pub fn peek_code(&self) -> Code {
+        self.base.generate_code(self.counter())
+    }
+
+    /// Verifies the code for the current counter value.
+    pub fn verify_code(&self, code: Code) -> bool {
+        self.base.verify_code(self.counter(), code)
+    }
+}
+
+impl Totp<'_> {
+    /// Generates the code for the given time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{Base, Secret, Totp};
+    ///
+    /// let secret = Secret::decode("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+    ///
+    /// let base = Base::builder().secret(secret).build();
+    ///
+    /// let totp = Totp::builder().base(base).build();
+    ///
+    /// let time = 1_234_567_890;
+    ///
+    /// let code = totp.generate_code_at(time);
+    ///
+    /// assert_eq!(code.to_string(), totp.generate_string_at(time));
+    ///
+    /// assert!(totp.verify_code_at(time, code));
+    /// ```
+    pub fn generate_code_at(&self, time: u64) -> Code {
+        self.base.generate_code(self.input_at(time))
+    }
+
+    /// Tries to generate the code for the current time.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`time::Error`] if the system time is before the epoch.
+    pub fn try_generate_code(&self) -> Result<Code, time::Error> {
+        current().map(|time| self.generate_code_at(time))
+    }
+
+    /// Generates the code for the current time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the system time is before the epoch.
+    pub fn generate_code(&self) -> Code {
+        self.generate_code_at(expect_current())
+    }
+
+    /// Verifies the given code for the given time, accounting for *skews*.
+    pub fn verify_code_at(&self, time: u64, code: Code) -> bool {
+        self.base.digits == code.digits() && self.verify_at(time, code.value())
+    }
+
+    /// Tries to verify the given code for the current time, accounting for *skews*.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`time::Error`] if the system time is before the epoch.
+    pub fn try_verify_code(&self, code: Code) -> Result<bool, time::Error> {
+        current().map(|time| self.verify_code_at(time, code))
+    }
+
+    /// Verifies the given code for the current time, accounting for *skews*.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the system time is before the epoch.
+    pub fn verify_code(&self, code: Code) -> bool {
+        self.verify_code_at(expect_current(), code)
+    }
+}
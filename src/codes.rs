@@ -0,0 +1,164 @@
+//! Streams of TOTP codes.
+//!
+//! User interfaces commonly display the current and the next codes along with countdowns.
+//! [`Totp::codes_from`] iterates over [`Window`] values of consecutive windows, each carrying
+//! the code along with the window start and expiry times, so that period arithmetic
+//! is not reimplemented.
+
+use std::iter::FusedIterator;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    code::Code,
+    time::{self, current, expect_current},
+    totp::Totp,
+};
+
+/// Represents codes of specific windows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Window {
+    /// The code.
+    pub code: Code,
+    /// The start time of the window, inclusive.
+    pub start: u64,
+    /// The expiry time of the window, exclusive.
+    pub expiry: u64,
+}
+
+impl Window {
+    /// Constructs [`Self`].
+    pub const fn new(code: Code, start: u64, expiry: u64) -> Self {
+        Self {
+            code,
+            start,
+            expiry,
+        }
+    }
+
+    /// Checks whether [`Self`] is valid at the given time.
+    pub const fn is_valid_at(&self, time: u64) -> bool {
+        self.start <= time && time < self.expiry
+    }
+
+    /// Returns the time to live of [`Self`] at the given time, saturating at zero.
+    pub const fn time_to_live_at(&self, time: u64) -> u64 {
+        self.expiry.saturating_sub(time)
+    }
+}
+
+/// Represents iterators over codes of consecutive windows.
+///
+/// This iterator is created by [`Totp::codes_from`].
+#[derive(Debug, Clone)]
+pub struct Codes<'c, 't> {
+    totp: &'c Totp<'t>,
+    input: Option<u64>,
+}
+
+impl<'c, 't> Codes<'c, 't> {
+    /// Constructs [`Self`], starting from the window of the given input.
+    pub const fn new(totp: &'c Totp<'t>, input: u64) -> Self {
+        Self {
+            totp,
+            input: Some(input),
+        }
+    }
+}
+
+impl Iterator for Codes<'_, '_> {
+    type Item = Window;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let input = self.input?;
+
+        let period = self.totp.period.get();
+
+        let Some(start) = input.checked_mul(period) else {
+            self.input = None;
+
+            return None;
+        };
+
+        self.input = input.checked_add(1);
+
+        let code = self.totp.base.generate_code(input);
+
+        Some(Window::new(code, start, start.saturating_add(period)))
+    }
+}
+
+impl FusedIterator for Codes<'_, '_> {}
+
+impl Totp<'_> {
+    /// Returns the iterator over codes of consecutive windows,
+    /// starting from the window containing the given time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{Base, Secret, Totp};
+    ///
+    /// let secret = Secret::decode("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+    ///
+    /// let base = Base::builder().secret(secret).build();
+    ///
+    /// let totp = Totp::builder().base(base).build();
+    ///
+    /// let time = 1_234_567_890;
+    ///
+    /// let mut codes = totp.codes_from(time);
+    ///
+    /// let current = codes.next().unwrap();
+    ///
+    /// assert_eq!(current.code, totp.generate_code_at(time));
+    /// assert_eq!(current.expiry, totp.next_period_at(time));
+    ///
+    /// let next = codes.next().unwrap();
+    ///
+    /// assert_eq!(next.start, current.expiry);
+    /// ```
+    pub fn codes_from(&self, time: u64) -> Codes<'_, '_> {
+        Codes::new(self, self.input_at(time))
+    }
+
+    /// Returns the codes of the window containing the given time and the next one.
+    pub fn current_and_next_at(&self, time: u64) -> (Window, Window) {
+        let input = self.input_at(time);
+
+        let period = self.period.get();
+
+        let start = input * period;
+        let expiry = start.saturating_add(period);
+
+        let current = Window::new(self.base.generate_code(input), start, expiry);
+
+        let next = Window::new(
+            self.base.generate_code(input.saturating_add(1)),
+            expiry,
+            expiry.saturating_add(period),
+        );
+
+        (current, next)
+    }
+
+    /// Tries to return the codes of the current window and the next one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`time::Error`] if the system time is before the epoch.
+    pub fn try_current_and_next(&self) -> Result<(Window, Window), time::Error> {
+        current().map(|time| self.current_and_next_at(time))
+    }
+
+    /// Returns the codes of the current window and the next one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the system time is before the epoch.
+    pub fn current_and_next(&self) -> (Window, Window) {
+        self.current_and_next_at(expect_current())
+    }
+}
@@ -0,0 +1,50 @@
+//! One-off One-Time Password (OTP) computations.
+//!
+//! The functions provided here compute codes directly from raw secret bytes, without constructing
+//! [`Base`], [`Hotp`] or [`Totp`] configurations. This is useful in scripts and tests.
+//!
+//! Note that secret lengths are *not* checked here.
+//!
+//! [`Base`]: crate::base::Base
+//! [`Hotp`]: crate::hotp::Hotp
+//! [`Totp`]: crate::totp::Totp
+
+use crate::{algorithm::Algorithm, base::generate, digits::Digits, period::Period};
+
+/// Computes the HOTP code for the given secret, counter, digits and algorithm.
+///
+/// # Examples
+///
+/// ```
+/// use otp_std::{compute::hotp, Algorithm, Digits};
+///
+/// let code = hotp(b"12345678901234567890", 0, Digits::DEFAULT, Algorithm::Sha1);
+///
+/// assert_eq!(code, 755224);
+/// ```
+pub fn hotp<S: AsRef<[u8]>>(secret: S, counter: u64, digits: Digits, algorithm: Algorithm) -> u32 {
+    generate(secret, counter, digits, algorithm)
+}
+
+/// Computes the TOTP code for the given secret, time, period, digits and algorithm.
+///
+/// # Examples
+///
+/// ```
+/// use otp_std::{compute::totp, Algorithm, Digits, Period};
+///
+/// let digits = Digits::new(8).unwrap();
+///
+/// let code = totp(b"12345678901234567890", 59, Period::DEFAULT, digits, Algorithm::Sha1);
+///
+/// assert_eq!(code, 94287082);
+/// ```
+pub fn totp<S: AsRef<[u8]>>(
+    secret: S,
+    time: u64,
+    period: Period,
+    digits: Digits,
+    algorithm: Algorithm,
+) -> u32 {
+    generate(secret, time / period.get(), digits, algorithm)
+}
@@ -0,0 +1,174 @@
+//! Application-wide OTP configuration.
+//!
+//! The [`Config`] type holds default parameters, allowing applications to define them once
+//! and use them to construct [`Base`], [`Hotp`] and [`Totp`] configurations.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    algorithm::Algorithm, base::Base, digits::Digits, hotp::Hotp, macros::setters, period::Period,
+    secret::core::Secret, skew::Skew, totp::Totp,
+};
+
+#[cfg(feature = "generate-secret")]
+use crate::{base, hotp, secret, totp};
+
+/// Represents application-wide OTP configurations.
+///
+/// # Examples
+///
+/// ```
+/// use otp_std::{Config, Digits, Secret};
+///
+/// let config = Config::builder().digits(Digits::MAX).build();
+///
+/// let secret = Secret::decode("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+///
+/// let totp = config.new_totp(secret);
+///
+/// assert_eq!(totp.base.digits, Digits::MAX);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Config {
+    /// The algorithm to use.
+    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(
+        feature = "compact-serde",
+        serde(skip_serializing_if = "Algorithm::is_default")
+    )]
+    pub algorithm: Algorithm,
+    /// The number of digits to use.
+    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(
+        feature = "compact-serde",
+        serde(skip_serializing_if = "Digits::is_default")
+    )]
+    pub digits: Digits,
+    /// The period to use in TOTP configurations.
+    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(
+        feature = "compact-serde",
+        serde(skip_serializing_if = "Period::is_default")
+    )]
+    pub period: Period,
+    /// The skew to use in TOTP configurations.
+    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(
+        feature = "compact-serde",
+        serde(skip_serializing_if = "Skew::is_default")
+    )]
+    pub skew: Skew,
+}
+
+/// Represents builders of [`Config`] (see [`Config::builder`]).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ConfigBuilder {
+    algorithm: Option<Algorithm>,
+    digits: Option<Digits>,
+    period: Option<Period>,
+    skew: Option<Skew>,
+}
+
+impl Config {
+    /// Starts building [`Config`].
+    pub fn builder() -> ConfigBuilder {
+        ConfigBuilder::default()
+    }
+}
+
+impl ConfigBuilder {
+    setters! {
+        algorithm, maybe_algorithm: Algorithm;
+        digits, maybe_digits: Digits;
+        period, maybe_period: Period;
+        skew, maybe_skew: Skew;
+    }
+
+    /// Finishes building [`Config`].
+    pub fn build(self) -> Config {
+        Config {
+            algorithm: self.algorithm.unwrap_or_default(),
+            digits: self.digits.unwrap_or_default(),
+            period: self.period.unwrap_or_default(),
+            skew: self.skew.unwrap_or_default(),
+        }
+    }
+}
+
+impl Config {
+    /// Constructs [`Base`] with the given secret, according to [`Self`].
+    pub fn new_base<'b>(&self, secret: Secret<'b>) -> Base<'b> {
+        Base::builder()
+            .secret(secret)
+            .algorithm(self.algorithm)
+            .digits(self.digits)
+            .build()
+    }
+
+    /// Constructs [`Hotp`] with the given secret and the default counter, according to [`Self`].
+    pub fn new_hotp<'h>(&self, secret: Secret<'h>) -> Hotp<'h> {
+        Hotp::builder().base(self.new_base(secret)).build()
+    }
+
+    /// Constructs [`Totp`] with the given secret, according to [`Self`].
+    pub fn new_totp<'t>(&self, secret: Secret<'t>) -> Totp<'t> {
+        Totp::builder()
+            .base(self.new_base(secret))
+            .skew(self.skew)
+            .period(self.period)
+            .build()
+    }
+}
+
+#[cfg(feature = "generate-secret")]
+impl Config {
+    /// Generates the secret of the length recommended for the algorithm of [`Self`].
+    pub fn generate_secret(&self) -> secret::Owned {
+        Secret::generate_for(self.algorithm)
+    }
+
+    /// Constructs [`Base`] with the generated secret, according to [`Self`].
+    ///
+    /// The secret length is chosen via [`generate_secret`].
+    ///
+    /// [`generate_secret`]: Self::generate_secret
+    pub fn generate_base(&self) -> base::Owned {
+        self.new_base(self.generate_secret())
+    }
+
+    /// Constructs [`Hotp`] with the generated secret and the default counter,
+    /// according to [`Self`].
+    ///
+    /// The secret length is chosen via [`generate_secret`].
+    ///
+    /// [`generate_secret`]: Self::generate_secret
+    pub fn generate_hotp(&self) -> hotp::Owned {
+        self.new_hotp(self.generate_secret())
+    }
+
+    /// Constructs [`Totp`] with the generated secret, according to [`Self`].
+    ///
+    /// The secret length is chosen via [`generate_secret`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{Algorithm, Config, Length};
+    ///
+    /// let config = Config::builder().algorithm(Algorithm::Sha256).build();
+    ///
+    /// let totp = config.generate_totp();
+    ///
+    /// assert_eq!(
+    ///     totp.base.secret.as_bytes().len(),
+    ///     Length::recommended_for(Algorithm::Sha256).get(),
+    /// );
+    /// ```
+    ///
+    /// [`generate_secret`]: Self::generate_secret
+    pub fn generate_totp(&self) -> totp::Owned {
+        self.new_totp(self.generate_secret())
+    }
+}
@@ -7,13 +7,13 @@ use const_macros::const_none;
 use miette::Diagnostic;
 
 #[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
 use thiserror::Error;
 
 use crate::{
     int::{self, ParseError},
-    macros::errors,
+    macros::{const_expect, errors},
 };
 
 /// The default counter value.
@@ -40,13 +40,20 @@ impl Error {
 }
 
 /// Represents counters.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[cfg_attr(feature = "serde", serde(from = "u64", into = "u64"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(into = "u64"))]
 pub struct Counter {
     value: u64,
 }
 
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Counter {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        int::deserialize_u64(deserializer).map(Self::new)
+    }
+}
+
 errors! {
     Type = Error,
     Hack = $,
@@ -57,11 +64,7 @@ impl FromStr for Counter {
     type Err = Error;
 
     fn from_str(string: &str) -> Result<Self, Self::Err> {
-        let value = string
-            .parse()
-            .map_err(|error| error!(int::wrap(error), string))?;
-
-        Ok(Self::new(value))
+        Self::parse_str(string).map_err(|error| error!(error, string))
     }
 }
 
@@ -98,11 +101,29 @@ impl Counter {
         Self { value }
     }
 
+    /// Parses [`Self`] from the given string without allocating on errors.
+    ///
+    /// Unlike [`from_str`], the error returned does not contain the input string.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError`] if the string is not a valid integer.
+    ///
+    /// [`from_str`]: FromStr::from_str
+    pub fn parse_str(string: &str) -> Result<Self, ParseError> {
+        string.parse().map(Self::new).map_err(int::wrap)
+    }
+
     /// Returns the value of this counter.
     pub const fn get(self) -> u64 {
         self.value
     }
 
+    /// Checks whether [`Self`] is equal to [`DEFAULT`](Self::DEFAULT).
+    pub const fn is_default(&self) -> bool {
+        self.get() == DEFAULT
+    }
+
     /// Returns the incremented counter while checking for overflows.
     ///
     /// # Note
@@ -165,7 +186,30 @@ impl Counter {
     /// ```
     #[must_use = "this method returns the incremented counter instead of modifying the original"]
     pub const fn next(self) -> Self {
-        self.try_next().expect(OVERFLOW)
+        const_expect!(self.try_next(), OVERFLOW)
+    }
+
+    /// Merges the remote and local counters, returning the greater one.
+    ///
+    /// Taking the maximum ensures that counters synced between devices never regress,
+    /// as regressing to smaller counters would allow replaying already used codes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::Counter;
+    ///
+    /// let remote = Counter::new(13);
+    /// let local = Counter::new(7);
+    ///
+    /// assert_eq!(Counter::merge(remote, local), remote);
+    /// ```
+    pub const fn merge(remote: Self, local: Self) -> Self {
+        if remote.get() > local.get() {
+            remote
+        } else {
+            local
+        }
     }
 
     /// The default [`Self`] value.
@@ -0,0 +1,53 @@
+//! Re-exported dependencies.
+//!
+//! Some APIs of this crate accept or return types defined by its dependencies.
+//! This module re-exports them, so that applications do not need to depend on matching
+//! versions of those crates in order to interoperate.
+//!
+//! ```
+//! use otp_std::deps::{hmac::Mac, HmacSha1};
+//!
+//! let mut mac = HmacSha1::new_from_slice(b"key").unwrap();
+//!
+//! mac.update(b"data");
+//!
+//! let bytes = mac.finalize().into_bytes();
+//!
+//! assert_eq!(bytes.as_slice(), otp_std::Algorithm::Sha1.hmac(b"key", b"data"));
+//! ```
+
+pub use base32::{self, Alphabet};
+pub use hmac::{self, digest, Hmac, Mac, SimpleHmac};
+pub use sha1::{self, Sha1};
+
+pub use crate::algorithm::HmacSha1;
+
+#[cfg(feature = "sha2")]
+pub use sha2::{self, Sha256, Sha512};
+
+#[cfg(feature = "sha2")]
+pub use crate::algorithm::{HmacSha256, HmacSha512};
+
+#[cfg(feature = "sha3")]
+pub use sha3::{self, Sha3_256, Sha3_512};
+
+#[cfg(feature = "sha3")]
+pub use crate::algorithm::{HmacSha3_256, HmacSha3_512};
+
+#[cfg(feature = "blake2")]
+pub use blake2::{self, Blake2b512, Blake2s256};
+
+#[cfg(feature = "blake2")]
+pub use crate::algorithm::{HmacBlake2b, HmacBlake2s};
+
+#[cfg(feature = "auth")]
+pub use url::{self, Url};
+
+#[cfg(feature = "print")]
+pub use qrcode;
+
+#[cfg(feature = "qr-decode")]
+pub use image;
+
+#[cfg(feature = "qr-decode")]
+pub use rqrr;
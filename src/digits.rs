@@ -11,7 +11,10 @@ use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 use thiserror::Error;
 
-use crate::{int, macros::errors};
+use crate::{
+    int,
+    macros::{const_unwrap, errors},
+};
 
 /// The minimum digits value.
 pub const MIN: u8 = 6;
@@ -19,13 +22,19 @@ pub const MIN: u8 = 6;
 /// The maximum digits value.
 pub const MAX: u8 = 8;
 
+/// The size of buffers that can fit any formatted code.
+pub const BUFFER_SIZE: usize = 10;
+
+/// Represents buffers used to format codes without allocating.
+pub type Buffer = [u8; BUFFER_SIZE];
+
 /// The default digits value.
 pub const DEFAULT: u8 = MIN;
 
 /// Represents errors that can occur during digits creation.
 ///
 /// This error is returned when the given value is less than [`MIN`] or greater than [`MAX`].
-#[derive(Debug, Error, Diagnostic)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error, Diagnostic)]
 #[error("expected digits in `[{MIN}, {MAX}]` range, got `{value}`")]
 #[diagnostic(
     code(otp_std::digits),
@@ -44,9 +53,10 @@ impl Error {
 }
 
 /// Represents sources of errors that can occur when parsing [`Digits`] values.
-#[derive(Debug, Error, Diagnostic)]
+#[derive(Debug, Clone, PartialEq, Eq, Error, Diagnostic)]
 #[error(transparent)]
 #[diagnostic(transparent)]
+#[non_exhaustive]
 pub enum ParseErrorSource {
     /// Invalid digits value.
     Digits(#[from] Error),
@@ -103,7 +113,7 @@ impl Serialize for Digits {
 #[cfg(feature = "serde")]
 impl<'de> Deserialize<'de> for Digits {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        let value = u8::deserialize(deserializer)?;
+        let value = int::deserialize_u8(deserializer)?;
 
         Self::new(value).map_err(de::Error::custom)
     }
@@ -112,19 +122,14 @@ impl<'de> Deserialize<'de> for Digits {
 errors! {
     Type = ParseError,
     Hack = $,
-    digits_error => digits(error, string => to_owned),
-    int_error => int(error, string => to_owned),
+    parse_error => new(source, string => to_owned),
 }
 
 impl FromStr for Digits {
     type Err = ParseError;
 
     fn from_str(string: &str) -> Result<Self, Self::Err> {
-        let value = string
-            .parse()
-            .map_err(|error| int_error!(int::wrap(error), string))?;
-
-        Self::new(value).map_err(|error| digits_error!(error, string))
+        Self::parse_str(string).map_err(|source| parse_error!(source, string))
     }
 }
 
@@ -182,6 +187,24 @@ impl Digits {
         const_ok!(Self::new(value))
     }
 
+    /// Parses [`Self`] from the given string without allocating on errors.
+    ///
+    /// Unlike [`from_str`], the error returned does not contain the input string.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseErrorSource`] if the string is not a valid integer
+    /// or if the value is not valid for [`Self`].
+    ///
+    /// [`from_str`]: FromStr::from_str
+    pub fn parse_str(string: &str) -> Result<Self, ParseErrorSource> {
+        let value = string.parse().map_err(int::wrap)?;
+
+        let digits = Self::new(value)?;
+
+        Ok(digits)
+    }
+
     /// Checks if the provided value is valid for [`Self`].
     ///
     /// # Errors
@@ -207,13 +230,18 @@ impl Digits {
     }
 
     /// The minimum [`Self`] value.
-    pub const MIN: Self = Self::new_ok(MIN).unwrap();
+    pub const MIN: Self = const_unwrap!(Self::new_ok(MIN));
 
     /// The maximum [`Self`] value.
-    pub const MAX: Self = Self::new_ok(MAX).unwrap();
+    pub const MAX: Self = const_unwrap!(Self::new_ok(MAX));
 
     /// The default [`Self`] value.
-    pub const DEFAULT: Self = Self::new_ok(DEFAULT).unwrap();
+    pub const DEFAULT: Self = const_unwrap!(Self::new_ok(DEFAULT));
+
+    /// Checks whether [`Self`] is equal to [`DEFAULT`](Self::DEFAULT).
+    pub const fn is_default(&self) -> bool {
+        self.get() == DEFAULT
+    }
 
     /// Returns the value wrapped in [`Self`] as [`usize`].
     pub const fn count(self) -> usize {
@@ -226,14 +254,104 @@ impl Digits {
     }
 
     /// Raises `10` to the power of the value wrapped in [`Self`].
+    ///
+    /// The result fits into [`u32`] for the current range of digits; see [`power_u64`]
+    /// for the overflow-safe variant used in code generation.
+    ///
+    /// [`power_u64`]: Self::power_u64
     pub const fn power(self) -> u32 {
         10u32.pow(self.get() as u32)
     }
 
+    /// Similar to [`power`], except the result is returned as [`u64`].
+    ///
+    /// Unlike [`u32`], [`u64`] can fit `10` raised to the power of up to `19`,
+    /// which leaves room for extended digits and checksums.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::Digits;
+    ///
+    /// assert_eq!(Digits::MAX.power_u64(), 100_000_000);
+    /// assert_eq!(Digits::MAX.power_u64(), u64::from(Digits::MAX.power()));
+    /// ```
+    ///
+    /// [`power`]: Self::power
+    pub const fn power_u64(self) -> u64 {
+        10u64.pow(self.get() as u32)
+    }
+
     /// Formats the given code, padding it to the length returned from [`count`].
     ///
     /// [`count`]: Self::count
     pub fn string(self, code: u32) -> String {
         format!("{code:0count$}", count = self.count())
     }
+
+    /// Similar to [`string`], except the code is formatted into the given buffer,
+    /// returning the resulting bytes without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{digits::Buffer, Digits};
+    ///
+    /// let mut buffer = Buffer::default();
+    ///
+    /// assert_eq!(Digits::DEFAULT.format_into(42, &mut buffer), b"000042");
+    /// ```
+    ///
+    /// [`string`]: Self::string
+    pub fn format_into(self, code: u32, buffer: &mut Buffer) -> &[u8] {
+        let end = BUFFER_SIZE - self.count();
+
+        let mut start = BUFFER_SIZE;
+        let mut value = code;
+
+        // `u32` values have at most `BUFFER_SIZE` digits, so `start` never underflows
+        while start > end || value > 0 {
+            start -= 1;
+
+            buffer[start] = b'0' + (value % 10) as u8;
+
+            value /= 10;
+        }
+
+        &buffer[start..]
+    }
+
+    /// Similar to [`format_into`], except the code is formatted in constant time.
+    ///
+    /// Every digit of the buffer is written regardless of the code, so that the time taken
+    /// does not depend on it, which is why this method is used in verification paths.
+    /// Only the last [`count`] digits are returned, meaning that codes not less than [`power`]
+    /// are truncated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{digits::Buffer, Digits};
+    ///
+    /// let mut buffer = Buffer::default();
+    ///
+    /// assert_eq!(Digits::DEFAULT.format_fixed(42, &mut buffer), b"000042");
+    /// ```
+    ///
+    /// [`format_into`]: Self::format_into
+    /// [`count`]: Self::count
+    /// [`power`]: Self::power
+    pub fn format_fixed(self, code: u32, buffer: &mut Buffer) -> &[u8] {
+        let mut value = code;
+
+        // division by constants compiles to multiplications, which take constant time;
+        // `u32` values have at most `BUFFER_SIZE` digits, so every digit is written
+        for byte in buffer.iter_mut().rev() {
+            *byte = b'0' + (value % 10) as u8;
+
+            value /= 10;
+        }
+
+        &buffer[BUFFER_SIZE - self.count()..]
+    }
 }
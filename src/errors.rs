@@ -0,0 +1,482 @@
+//! Diagnostic codes.
+//!
+//! This module lists every diagnostic code used by this crate along with the error types
+//! that use them. The codes are considered stable, meaning they will not change without
+//! a breaking release; applications can rely on them (for instance, in telemetry).
+//!
+//! This module also provides the unified [`enum@Error`] type, which wraps errors of every module,
+//! so that applications can use one error type in their signatures while keeping diagnostics.
+
+use miette::Diagnostic;
+use thiserror::Error;
+
+use crate::{
+    algorithm, artifact, base, code, counter, digits, limit, look_ahead,
+    otp::type_of,
+    period, policy, profile,
+    secret::{core as secret, length},
+    skew, time,
+};
+
+#[cfg(feature = "auth")]
+use crate::{
+    auth::{
+        self, extensions, label, lenient, migration::google, part, rotation, scheme, store, url,
+    },
+    hotp,
+    otp::{self, url as otp_url},
+    totp,
+};
+
+#[cfg(all(feature = "auth", feature = "serde"))]
+use crate::auth::interop;
+
+#[cfg(feature = "json")]
+use crate::otp::json;
+
+#[cfg(feature = "mnemonic")]
+use crate::secret::mnemonic;
+
+#[cfg(feature = "secure-memory")]
+use crate::secret::locked;
+
+#[cfg(feature = "print")]
+use crate::print;
+
+#[cfg(feature = "qr-decode")]
+use crate::auth::qr;
+
+/// Represents diagnostic code entries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Entry {
+    /// The diagnostic code.
+    pub code: &'static str,
+    /// The path to the error type.
+    pub type_name: &'static str,
+}
+
+impl Entry {
+    /// Constructs [`Self`].
+    pub const fn new(code: &'static str, type_name: &'static str) -> Self {
+        Self { code, type_name }
+    }
+}
+
+/// Diagnostic codes that are always available.
+pub const CODES: &[Entry] = &[
+    Entry::new("otp_std::algorithm", "otp_std::algorithm::Error"),
+    Entry::new("otp_std::artifact", "otp_std::artifact::Error"),
+    Entry::new(
+        "otp_std::artifact::tampered",
+        "otp_std::artifact::TamperedError",
+    ),
+    Entry::new(
+        "otp_std::artifact::truncated",
+        "otp_std::artifact::TruncatedError",
+    ),
+    Entry::new(
+        "otp_std::artifact::version",
+        "otp_std::artifact::WrongVersionError",
+    ),
+    Entry::new("otp_std::base::validate", "otp_std::base::ValidationError"),
+    Entry::new(
+        "otp_std::base::weak_secret",
+        "otp_std::base::WeakSecretError",
+    ),
+    Entry::new("otp_std::code", "otp_std::code::Error"),
+    Entry::new("otp_std::code::parse", "otp_std::code::ParseError"),
+    Entry::new("otp_std::counter", "otp_std::counter::Error"),
+    Entry::new("otp_std::digits", "otp_std::digits::Error"),
+    Entry::new("otp_std::digits::parse", "otp_std::digits::ParseError"),
+    Entry::new("otp_std::int::parse", "otp_std::int::ParseError"),
+    Entry::new("otp_std::limit", "otp_std::limit::Error"),
+    Entry::new("otp_std::look_ahead", "otp_std::look_ahead::Error"),
+    Entry::new(
+        "otp_std::otp::type_of::parse",
+        "otp_std::otp::type_of::ParseError",
+    ),
+    Entry::new("otp_std::period", "otp_std::period::Error"),
+    Entry::new(
+        "otp_std::period::duration",
+        "otp_std::period::DurationError",
+    ),
+    Entry::new("otp_std::period::parse", "otp_std::period::ParseError"),
+    Entry::new("otp_std::policy", "otp_std::policy::Error"),
+    Entry::new(
+        "otp_std::policy::rate_limit",
+        "otp_std::policy::RateLimitError",
+    ),
+    Entry::new("otp_std::policy::replay", "otp_std::policy::ReplayError"),
+    Entry::new("otp_std::policy::window", "otp_std::policy::WindowError"),
+    Entry::new("otp_std::profile", "otp_std::profile::Error"),
+    Entry::new("otp_std::secret", "otp_std::secret::core::Error"),
+    Entry::new(
+        "otp_std::secret::encoding",
+        "otp_std::secret::encoding::Error",
+    ),
+    Entry::new(
+        "otp_std::secret::encoding::any",
+        "otp_std::secret::encoding::AnyError",
+    ),
+    Entry::new(
+        "otp_std::secret::encoding::format",
+        "otp_std::secret::encoding::FormatError",
+    ),
+    Entry::new(
+        "otp_std::secret::length::policy",
+        "otp_std::secret::length::PolicyError",
+    ),
+    Entry::new("otp_std::skew", "otp_std::skew::Error"),
+    Entry::new("otp_std::time", "otp_std::time::Error"),
+];
+
+/// Diagnostic codes that are available unless the `unsafe-length` feature is enabled.
+#[cfg(not(feature = "unsafe-length"))]
+pub const LENGTH_CODES: &[Entry] = &[Entry::new(
+    "otp_std::secret::length",
+    "otp_std::secret::length::Error",
+)];
+
+/// Diagnostic codes that are available with the `auth` feature.
+#[cfg(feature = "auth")]
+pub const AUTH_CODES: &[Entry] = &[
+    Entry::new("otp_std::auth::core", "otp_std::auth::core::Error"),
+    Entry::new(
+        "otp_std::auth::extensions::reserved",
+        "otp_std::auth::extensions::ReservedError",
+    ),
+    Entry::new("otp_std::auth::label", "otp_std::auth::label::Error"),
+    Entry::new(
+        "otp_std::auth::label::decode",
+        "otp_std::auth::label::DecodeError",
+    ),
+    Entry::new(
+        "otp_std::auth::label::empty",
+        "otp_std::auth::label::EmptyError",
+    ),
+    Entry::new(
+        "otp_std::auth::label::mismatch",
+        "otp_std::auth::label::MismatchError",
+    ),
+    Entry::new(
+        "otp_std::auth::label::parse",
+        "otp_std::auth::label::ParseError",
+    ),
+    Entry::new("otp_std::auth::lenient", "otp_std::auth::lenient::Error"),
+    Entry::new(
+        "otp_std::auth::lenient::hotp",
+        "otp_std::auth::lenient::HotpError",
+    ),
+    Entry::new(
+        "otp_std::auth::migration::google",
+        "otp_std::auth::migration::google::Error",
+    ),
+    Entry::new(
+        "otp_std::auth::migration::google::base64",
+        "otp_std::auth::migration::google::Base64Error",
+    ),
+    Entry::new(
+        "otp_std::auth::migration::google::format",
+        "otp_std::auth::migration::google::FormatError",
+    ),
+    Entry::new(
+        "otp_std::auth::migration::google::payload",
+        "otp_std::auth::migration::google::PayloadError",
+    ),
+    Entry::new(
+        "otp_std::auth::migration::google::unrepresentable",
+        "otp_std::auth::migration::google::UnrepresentableError",
+    ),
+    Entry::new(
+        "otp_std::auth::migration::google::unsupported",
+        "otp_std::auth::migration::google::UnsupportedError",
+    ),
+    Entry::new(
+        "otp_std::auth::options::issuer",
+        "otp_std::auth::options::IssuerError",
+    ),
+    Entry::new(
+        "otp_std::auth::options::strict",
+        "otp_std::auth::options::StrictError",
+    ),
+    Entry::new("otp_std::auth::part", "otp_std::auth::part::Error"),
+    Entry::new(
+        "otp_std::auth::part::decode",
+        "otp_std::auth::part::DecodeError",
+    ),
+    Entry::new(
+        "otp_std::auth::part::empty",
+        "otp_std::auth::part::EmptyError",
+    ),
+    Entry::new(
+        "otp_std::auth::part::separator",
+        "otp_std::auth::part::SeparatorError",
+    ),
+    Entry::new("otp_std::auth::rotation", "otp_std::auth::rotation::Error"),
+    Entry::new("otp_std::auth::scheme", "otp_std::auth::scheme::Error"),
+    Entry::new(
+        "otp_std::auth::scheme::invalid",
+        "otp_std::auth::scheme::InvalidError",
+    ),
+    Entry::new(
+        "otp_std::auth::scheme::policy",
+        "otp_std::auth::scheme::PolicyError",
+    ),
+    Entry::new("otp_std::auth::store", "otp_std::auth::store::Error"),
+    Entry::new("otp_std::auth::url", "otp_std::auth::url::Error"),
+    Entry::new("otp_std::auth::utf8", "otp_std::auth::utf8::Error"),
+    Entry::new("otp_std::base::extract", "otp_std::base::Error"),
+    Entry::new(
+        "otp_std::base::secret",
+        "otp_std::base::SecretNotFoundError",
+    ),
+    Entry::new(
+        "otp_std::hotp::counter",
+        "otp_std::hotp::CounterNotFoundError",
+    ),
+    Entry::new("otp_std::hotp::extract", "otp_std::hotp::Error"),
+    Entry::new("otp_std::otp", "otp_std::otp::core::Error"),
+    Entry::new("otp_std::otp::type_of", "otp_std::otp::type_of::Error"),
+    Entry::new(
+        "otp_std::otp::type_of::not_found",
+        "otp_std::otp::type_of::NotFoundError",
+    ),
+    Entry::new("otp_std::otp::url", "otp_std::otp::url::Error"),
+    Entry::new(
+        "otp_std::secret::query",
+        "otp_std::secret::core::QueryError",
+    ),
+    Entry::new("otp_std::totp", "otp_std::totp::Error"),
+];
+
+/// Diagnostic codes that are available with both the `auth` and `serde` features.
+#[cfg(all(feature = "auth", feature = "serde"))]
+pub const INTEROP_CODES: &[Entry] = &[Entry::new(
+    "otp_std::auth::interop",
+    "otp_std::auth::interop::Error",
+)];
+
+/// Diagnostic codes that are available with the `json` feature.
+#[cfg(feature = "json")]
+pub const JSON_CODES: &[Entry] = &[
+    Entry::new("otp_std::otp::json", "otp_std::otp::json::Error"),
+    Entry::new(
+        "otp_std::otp::json::deserialize",
+        "otp_std::otp::json::DeserializeError",
+    ),
+    Entry::new(
+        "otp_std::otp::json::object",
+        "otp_std::otp::json::ObjectError",
+    ),
+];
+
+/// Diagnostic codes that are available with the `mnemonic` feature.
+#[cfg(feature = "mnemonic")]
+pub const MNEMONIC_CODES: &[Entry] = &[
+    Entry::new(
+        "otp_std::secret::mnemonic::length",
+        "otp_std::secret::mnemonic::LengthError",
+    ),
+    Entry::new(
+        "otp_std::secret::mnemonic::parse",
+        "otp_std::secret::mnemonic::ParseError",
+    ),
+];
+
+/// Diagnostic codes that are available with the `secure-memory` feature.
+#[cfg(feature = "secure-memory")]
+pub const SECURE_MEMORY_CODES: &[Entry] = &[Entry::new(
+    "otp_std::secret::locked",
+    "otp_std::secret::locked::Error",
+)];
+
+/// Diagnostic codes that are available with the `print` feature.
+#[cfg(feature = "print")]
+pub const PRINT_CODES: &[Entry] = &[Entry::new("otp_std::print", "otp_std::print::Error")];
+
+/// Diagnostic codes that are available with the `qr-decode` feature.
+#[cfg(feature = "qr-decode")]
+pub const QR_DECODE_CODES: &[Entry] = &[
+    Entry::new("otp_std::auth::qr", "otp_std::auth::qr::Error"),
+    Entry::new(
+        "otp_std::auth::qr::decode",
+        "otp_std::auth::qr::DecodeError",
+    ),
+    Entry::new("otp_std::auth::qr::image", "otp_std::auth::qr::ImageError"),
+    Entry::new(
+        "otp_std::auth::qr::not_found",
+        "otp_std::auth::qr::NotFoundError",
+    ),
+];
+
+/// Returns all diagnostic codes available, sorted by code.
+pub fn all_codes() -> Vec<Entry> {
+    let mut codes = CODES.to_vec();
+
+    #[cfg(not(feature = "unsafe-length"))]
+    codes.extend_from_slice(LENGTH_CODES);
+
+    #[cfg(feature = "auth")]
+    codes.extend_from_slice(AUTH_CODES);
+
+    #[cfg(all(feature = "auth", feature = "serde"))]
+    codes.extend_from_slice(INTEROP_CODES);
+
+    #[cfg(feature = "json")]
+    codes.extend_from_slice(JSON_CODES);
+
+    #[cfg(feature = "mnemonic")]
+    codes.extend_from_slice(MNEMONIC_CODES);
+
+    #[cfg(feature = "secure-memory")]
+    codes.extend_from_slice(SECURE_MEMORY_CODES);
+
+    #[cfg(feature = "print")]
+    codes.extend_from_slice(PRINT_CODES);
+
+    #[cfg(feature = "qr-decode")]
+    codes.extend_from_slice(QR_DECODE_CODES);
+
+    codes.sort_unstable_by_key(|entry| entry.code);
+
+    codes
+}
+
+/// Represents errors of any module of this crate.
+///
+/// Every variant is transparent, meaning that both messages and diagnostics
+/// (including codes) are forwarded to the errors wrapped.
+///
+/// # Examples
+///
+/// ```
+/// use miette::Diagnostic;
+/// use otp_std::{Error, Secret};
+///
+/// fn decode(string: &str) -> Result<Secret<'_>, Error> {
+///     let secret = Secret::decode(string)?;
+///
+///     Ok(secret)
+/// }
+///
+/// let error = decode("?").unwrap_err();
+///
+/// assert!(error.code().is_some());
+/// ```
+#[derive(Debug, Error, Diagnostic)]
+#[error(transparent)]
+#[diagnostic(transparent)]
+#[non_exhaustive]
+pub enum Error {
+    /// Algorithm errors.
+    Algorithm(#[from] algorithm::Error),
+    /// Artifact errors.
+    Artifact(#[from] artifact::Error),
+    /// Validation errors.
+    Validation(#[from] base::ValidationError),
+    /// Code errors.
+    Code(#[from] code::Error),
+    /// Code parsing errors.
+    CodeParse(#[from] code::ParseError),
+    /// Counter errors.
+    Counter(#[from] counter::Error),
+    /// Digits errors.
+    Digits(#[from] digits::Error),
+    /// Digits parsing errors.
+    DigitsParse(#[from] digits::ParseError),
+    /// Verification window limit errors.
+    Limit(#[from] limit::Error),
+    /// Look-ahead errors.
+    LookAhead(#[from] look_ahead::Error),
+    /// Period errors.
+    Period(#[from] period::Error),
+    /// Period parsing errors.
+    PeriodParse(#[from] period::ParseError),
+    /// Period duration errors.
+    PeriodDuration(#[from] period::DurationError),
+    /// Policy errors.
+    Policy(#[from] policy::Error),
+    /// Profile errors.
+    Profile(#[from] profile::Error),
+    /// Secret errors.
+    Secret(#[from] secret::Error),
+    /// Secret length errors.
+    Length(#[from] length::Error),
+    /// Secret length policy errors.
+    LengthPolicy(#[from] length::PolicyError),
+    /// Skew errors.
+    Skew(#[from] skew::Error),
+    /// Time errors.
+    Time(#[from] time::Error),
+    /// Type parsing errors.
+    Type(#[from] type_of::ParseError),
+    /// Authentication errors.
+    #[cfg(feature = "auth")]
+    Auth(#[from] auth::core::Error),
+    /// Base extraction errors.
+    #[cfg(feature = "auth")]
+    Base(#[from] base::Error),
+    /// HOTP extraction errors.
+    #[cfg(feature = "auth")]
+    Hotp(#[from] hotp::Error),
+    /// TOTP extraction errors.
+    #[cfg(feature = "auth")]
+    Totp(#[from] totp::Error),
+    /// OTP extraction errors.
+    #[cfg(feature = "auth")]
+    Otp(#[from] otp::core::Error),
+    /// OTP URL errors.
+    #[cfg(feature = "auth")]
+    OtpUrl(#[from] otp_url::Error),
+    /// Reserved extension name errors.
+    #[cfg(feature = "auth")]
+    Extension(#[from] extensions::ReservedError),
+    /// Label errors.
+    #[cfg(feature = "auth")]
+    Label(#[from] label::ParseError),
+    /// Label part errors.
+    #[cfg(feature = "auth")]
+    Part(#[from] part::Error),
+    /// Lenient parsing errors.
+    #[cfg(feature = "auth")]
+    Lenient(#[from] lenient::Error),
+    /// Google Authenticator migration errors.
+    #[cfg(feature = "auth")]
+    Migration(#[from] google::Error),
+    /// Rotation errors.
+    #[cfg(feature = "auth")]
+    Rotation(#[from] rotation::Error),
+    /// Secret query value errors.
+    #[cfg(feature = "auth")]
+    SecretQuery(#[from] secret::QueryError),
+    /// Scheme errors.
+    #[cfg(feature = "auth")]
+    Scheme(#[from] scheme::Error),
+    /// Store errors.
+    #[cfg(feature = "auth")]
+    Store(#[from] store::Error),
+    /// URL errors.
+    #[cfg(feature = "auth")]
+    Url(#[from] url::Error),
+    /// Interoperability errors.
+    #[cfg(all(feature = "auth", feature = "serde"))]
+    Interop(#[from] interop::Error),
+    /// Partial JSON configuration errors.
+    #[cfg(feature = "json")]
+    Json(#[from] json::Error),
+    /// Mnemonic parsing errors.
+    #[cfg(feature = "mnemonic")]
+    Mnemonic(#[from] mnemonic::ParseError),
+    /// Mnemonic length errors.
+    #[cfg(feature = "mnemonic")]
+    MnemonicLength(#[from] mnemonic::LengthError),
+    /// Secure memory errors.
+    #[cfg(feature = "secure-memory")]
+    Locked(#[from] locked::Error),
+    /// Printing errors.
+    #[cfg(feature = "print")]
+    Print(#[from] print::Error),
+    /// QR code image reading errors.
+    #[cfg(feature = "qr-decode")]
+    Qr(#[from] qr::Error),
+}
@@ -1,6 +1,6 @@
 //! Hmac-based One-Time Password (HOTP) functionality.
 
-use bon::Builder;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 #[cfg(feature = "auth")]
 use miette::Diagnostic;
@@ -11,25 +11,94 @@ use serde::{Deserialize, Serialize};
 #[cfg(feature = "auth")]
 use thiserror::Error;
 
-use crate::{base::Base, counter::Counter};
+use crate::{
+    algorithm::Algorithm,
+    base::Base,
+    builder::Unset,
+    counter::{Counter, OVERFLOW},
+    digits::Digits,
+    look_ahead::LookAhead,
+    macros::setters,
+    options::VerifyOptions,
+    secret::core::Secret,
+};
 
 #[cfg(feature = "auth")]
 use crate::{
-    auth::{query::Query, url::Url},
-    base, counter,
+    auth::{
+        query::{extract_parse, Query},
+        url::Url,
+    },
+    base::{self, Parameter},
+    counter, look_ahead,
 };
 
 /// Represents HOTP configuration.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Builder)]
+#[derive(Debug, Clone)]
+#[cfg_attr(not(feature = "forbid-secret-eq"), derive(PartialEq, Eq, Hash))]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Hotp<'h> {
     /// The base configuration.
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub base: Base<'h>,
     /// The counter used to generate codes.
-    #[builder(default)]
     #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(
+        feature = "compact-serde",
+        serde(skip_serializing_if = "Counter::is_default")
+    )]
     pub counter: Counter,
+    /// The look-ahead used by resynchronizing verification.
+    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(
+        feature = "compact-serde",
+        serde(skip_serializing_if = "LookAhead::is_default")
+    )]
+    pub look_ahead: LookAhead,
+}
+
+/// Represents builders of [`Hotp`] (see [`Hotp::builder`]).
+#[derive(Debug, Clone, Default)]
+pub struct HotpBuilder<B = Unset> {
+    base: B,
+    counter: Option<Counter>,
+    look_ahead: Option<LookAhead>,
+}
+
+impl Hotp<'_> {
+    /// Starts building [`Hotp`].
+    pub fn builder() -> HotpBuilder {
+        HotpBuilder::default()
+    }
+}
+
+impl HotpBuilder {
+    /// Sets the `base` member.
+    pub fn base(self, base: Base<'_>) -> HotpBuilder<Base<'_>> {
+        HotpBuilder {
+            base,
+            counter: self.counter,
+            look_ahead: self.look_ahead,
+        }
+    }
+}
+
+impl<B> HotpBuilder<B> {
+    setters! {
+        counter, maybe_counter: Counter;
+        look_ahead, maybe_look_ahead: LookAhead;
+    }
+}
+
+impl<'h> HotpBuilder<Base<'h>> {
+    /// Finishes building [`Hotp`].
+    pub fn build(self) -> Hotp<'h> {
+        Hotp {
+            base: self.base,
+            counter: self.counter.unwrap_or_default(),
+            look_ahead: self.look_ahead.unwrap_or_default(),
+        }
+    }
 }
 
 impl<'h> Hotp<'h> {
@@ -49,6 +118,50 @@ impl<'h> Hotp<'h> {
     }
 }
 
+impl Hotp<'_> {
+    /// Returns [`Self`] with the given counter.
+    #[must_use]
+    pub const fn with_counter(mut self, counter: Counter) -> Self {
+        self.counter = counter;
+
+        self
+    }
+
+    /// Returns [`Self`] with the given look-ahead.
+    #[must_use]
+    pub const fn with_look_ahead(mut self, look_ahead: LookAhead) -> Self {
+        self.look_ahead = look_ahead;
+
+        self
+    }
+
+    /// Returns [`Self`] with the given algorithm (see [`Base::with_algorithm`]).
+    #[must_use]
+    pub const fn with_algorithm(mut self, algorithm: Algorithm) -> Self {
+        self.base.algorithm = algorithm;
+
+        self
+    }
+
+    /// Returns [`Self`] with the given number of digits (see [`Base::with_digits`]).
+    #[must_use]
+    pub const fn with_digits(mut self, digits: Digits) -> Self {
+        self.base.digits = digits;
+
+        self
+    }
+
+    /// Returns [`Self`] with the given secret (see [`Base::with_secret`]).
+    #[must_use]
+    pub fn with_secret<'s>(self, secret: Secret<'s>) -> Hotp<'s> {
+        Hotp {
+            base: self.base.with_secret(secret),
+            counter: self.counter,
+            look_ahead: self.look_ahead,
+        }
+    }
+}
+
 impl Hotp<'_> {
     /// Returns the current counter value.
     pub const fn counter(&self) -> u64 {
@@ -76,15 +189,98 @@ impl Hotp<'_> {
     }
 
     /// Generates the code for the current counter value.
+    #[deprecated(note = "use `peek` to generate without advancing or `next` to advance")]
     pub fn generate(&self) -> u32 {
-        self.base.generate(self.counter())
+        self.peek()
     }
 
     /// Generates the string code for the current counter value.
+    #[deprecated(
+        note = "use `peek_string` to generate without advancing or `next_string` to advance"
+    )]
     pub fn generate_string(&self) -> String {
+        self.peek_string()
+    }
+
+    /// Generates the code for the current counter value *without* advancing the counter.
+    ///
+    /// This is typically used on the verifying side, as the issuing side needs to call
+    /// [`next`] in order to never display the same code twice.
+    ///
+    /// [`next`]: Self::next
+    pub fn peek(&self) -> u32 {
+        self.base.generate(self.counter())
+    }
+
+    /// Generates the string code for the current counter value *without* advancing the counter.
+    ///
+    /// See [`peek`] for more information.
+    ///
+    /// [`peek`]: Self::peek
+    pub fn peek_string(&self) -> String {
         self.base.generate_string(self.counter())
     }
 
+    /// Generates the code for the current counter value and then advances the counter,
+    /// returning [`None`] if the counter would overflow (in which case it is not advanced).
+    pub fn try_next(&mut self) -> Option<u32> {
+        let code = self.peek();
+
+        self.try_increment().then_some(code)
+    }
+
+    /// Generates the code for the current counter value and then advances the counter.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the counter overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{Base, Hotp, Secret};
+    ///
+    /// let secret = Secret::decode("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+    ///
+    /// let base = Base::builder().secret(secret).build();
+    ///
+    /// let mut hotp = Hotp::builder().base(base).build();
+    ///
+    /// let peeked = hotp.peek();
+    ///
+    /// assert_eq!(hotp.next(), peeked);
+    /// assert_eq!(hotp.counter(), 1);
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> u32 {
+        let code = self.peek();
+
+        self.increment();
+
+        code
+    }
+
+    /// Generates the string code for the current counter value and then advances the counter,
+    /// returning [`None`] if the counter would overflow (in which case it is not advanced).
+    pub fn try_next_string(&mut self) -> Option<String> {
+        let code = self.peek_string();
+
+        self.try_increment().then_some(code)
+    }
+
+    /// Generates the string code for the current counter value and then advances the counter.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the counter overflows.
+    pub fn next_string(&mut self) -> String {
+        let code = self.peek_string();
+
+        self.increment();
+
+        code
+    }
+
     /// Verifies the code for the current counter value.
     pub fn verify(&self, code: u32) -> bool {
         self.base.verify(self.counter(), code)
@@ -94,12 +290,400 @@ impl Hotp<'_> {
     pub fn verify_string<S: AsRef<str>>(&self, code: S) -> bool {
         self.base.verify_string(self.counter(), code)
     }
+
+    /// Similar to [`verify_string`], except the code is normalized according to the options.
+    ///
+    /// [`verify_string`]: Self::verify_string
+    pub fn verify_string_with<S: AsRef<str>>(&self, code: S, options: VerifyOptions) -> bool {
+        self.base.verify_string_with(self.counter(), code, options)
+    }
+
+    fn verify_ahead_with<F: Fn(&Base<'_>, u64) -> bool>(&self, verify: F) -> Option<Counter> {
+        self.look_ahead
+            .apply(self.counter())
+            .find(|&counter| verify(&self.base, counter))
+            .map(Counter::new)
+    }
+
+    fn verify_resync_with<F: Fn(&Base<'_>, u64) -> bool>(&mut self, verify: F) -> bool {
+        let Some(next) = self
+            .verify_ahead_with(verify)
+            .and_then(|counter| counter.get().checked_add(1))
+        else {
+            return false;
+        };
+
+        self.counter = Counter::new(next);
+
+        true
+    }
+
+    /// Verifies the code for the counter values within the look-ahead window,
+    /// returning the matched counter value, if any.
+    ///
+    /// The counter is not advanced; see [`verify_resync`] for that.
+    ///
+    /// [`verify_resync`]: Self::verify_resync
+    pub fn verify_ahead(&self, code: u32) -> Option<Counter> {
+        self.verify_ahead_with(|base, input| base.verify(input, code))
+    }
+
+    /// Verifies the string code for the counter values within the look-ahead window,
+    /// returning the matched counter value, if any.
+    pub fn verify_string_ahead<S: AsRef<str>>(&self, code: S) -> Option<Counter> {
+        let code = code.as_ref();
+
+        self.verify_ahead_with(|base, input| base.verify_string(input, code))
+    }
+
+    /// Verifies the code for the counter values within the look-ahead window,
+    /// setting the counter past the matched value on success.
+    ///
+    /// Returns [`false`] if the counter would overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{Base, Counter, Hotp, LookAhead, Secret};
+    ///
+    /// let secret = Secret::decode("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+    ///
+    /// let base = Base::builder().secret(secret).build();
+    ///
+    /// let mut token = Hotp::builder().base(base.clone()).build();
+    ///
+    /// let mut hotp = Hotp::builder()
+    ///     .base(base)
+    ///     .look_ahead(LookAhead::new(3))
+    ///     .build();
+    ///
+    /// // the token generated codes that were never used
+    /// token.next();
+    /// token.next();
+    ///
+    /// assert!(hotp.verify_resync(token.next()));
+    ///
+    /// assert_eq!(hotp.counter, Counter::new(3));
+    /// ```
+    pub fn verify_resync(&mut self, code: u32) -> bool {
+        self.verify_resync_with(|base, input| base.verify(input, code))
+    }
+
+    /// Verifies the string code for the counter values within the look-ahead window,
+    /// setting the counter past the matched value on success.
+    ///
+    /// Returns [`false`] if the counter would overflow.
+    pub fn verify_string_resync<S: AsRef<str>>(&mut self, code: S) -> bool {
+        let code = code.as_ref();
+
+        self.verify_resync_with(|base, input| base.verify_string(input, code))
+    }
+
+    fn verify_increment_with<F: Fn(&Base<'_>, u64) -> bool>(&mut self, verify: F) -> bool {
+        verify(&self.base, self.counter()) && self.try_increment()
+    }
+
+    fn verify_increment_grace_with<F: Fn(&Base<'_>, u64) -> bool>(
+        &mut self,
+        grace: &mut Grace,
+        verify: F,
+    ) -> bool {
+        if self.verify_increment_with(&verify) {
+            return true;
+        };
+
+        let Some(previous) = self.counter().checked_sub(1) else {
+            return false;
+        };
+
+        if grace.is_used(previous) || !verify(&self.base, previous) {
+            return false;
+        };
+
+        grace.use_previous(previous);
+
+        true
+    }
+
+    /// Verifies the code for the current counter value, incrementing the counter on success.
+    ///
+    /// Returns [`false`] if the counter would overflow.
+    pub fn verify_increment(&mut self, code: u32) -> bool {
+        self.verify_increment_with(|base, input| base.verify(input, code))
+    }
+
+    /// Verifies the string code for the current counter value, incrementing the counter on success.
+    ///
+    /// Returns [`false`] if the counter would overflow.
+    pub fn verify_string_increment<S: AsRef<str>>(&mut self, code: S) -> bool {
+        let code = code.as_ref();
+
+        self.verify_increment_with(|base, input| base.verify_string(input, code))
+    }
+
+    /// Similar to [`verify_string_increment`], except the code is normalized
+    /// according to the options.
+    ///
+    /// [`verify_string_increment`]: Self::verify_string_increment
+    pub fn verify_string_increment_with<S: AsRef<str>>(
+        &mut self,
+        code: S,
+        options: VerifyOptions,
+    ) -> bool {
+        let code = options.normalize(code.as_ref());
+
+        let valid =
+            self.verify_increment_with(|base, input| base.verify_normalized(input, &code, options));
+
+        options.discard(code);
+
+        valid
+    }
+
+    /// Similar to [`verify_increment`], except the code for the previous counter value
+    /// is accepted *once*, without moving the counter backwards.
+    ///
+    /// This is useful for hardware tokens that display the same code after timeouts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{hotp::Grace, Base, Hotp, Secret};
+    ///
+    /// let secret = Secret::decode("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+    ///
+    /// let base = Base::builder().secret(secret).build();
+    ///
+    /// let mut hotp = Hotp::builder().base(base).build();
+    /// let mut grace = Grace::new();
+    ///
+    /// let code = hotp.peek();
+    ///
+    /// assert!(hotp.verify_increment_grace(code, &mut grace));
+    ///
+    /// // the token displays the same code again
+    /// assert!(hotp.verify_increment_grace(code, &mut grace));
+    ///
+    /// // however, it can not be replayed
+    /// assert!(!hotp.verify_increment_grace(code, &mut grace));
+    ///
+    /// assert_eq!(hotp.counter(), 1);
+    /// ```
+    ///
+    /// [`verify_increment`]: Self::verify_increment
+    pub fn verify_increment_grace(&mut self, code: u32, grace: &mut Grace) -> bool {
+        self.verify_increment_grace_with(grace, |base, input| base.verify(input, code))
+    }
+
+    /// Similar to [`verify_string_increment`], except the string code for the previous
+    /// counter value is accepted *once*, without moving the counter backwards.
+    ///
+    /// [`verify_string_increment`]: Self::verify_string_increment
+    pub fn verify_string_increment_grace<S: AsRef<str>>(
+        &mut self,
+        code: S,
+        grace: &mut Grace,
+    ) -> bool {
+        let code = code.as_ref();
+
+        self.verify_increment_grace_with(grace, |base, input| base.verify_string(input, code))
+    }
+
+    /// Similar to [`verify_string_increment_grace`], except the code is normalized
+    /// according to the options.
+    ///
+    /// [`verify_string_increment_grace`]: Self::verify_string_increment_grace
+    pub fn verify_string_increment_grace_with<S: AsRef<str>>(
+        &mut self,
+        code: S,
+        grace: &mut Grace,
+        options: VerifyOptions,
+    ) -> bool {
+        let code = options.normalize(code.as_ref());
+
+        let valid = self.verify_increment_grace_with(grace, |base, input| {
+            base.verify_normalized(input, &code, options)
+        });
+
+        options.discard(code);
+
+        valid
+    }
+}
+
+/// Represents HOTP grace period states.
+///
+/// This state tracks the previous counter value that was already accepted,
+/// protecting against replaying its code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Grace {
+    /// The previous counter value that was already accepted, if any.
+    pub used: Option<u64>,
+}
+
+impl Grace {
+    /// Constructs [`Self`].
+    pub const fn new() -> Self {
+        Self { used: None }
+    }
+
+    /// Checks whether the code for the given previous counter value was already accepted.
+    pub const fn is_used(&self, previous: u64) -> bool {
+        matches!(self.used, Some(used) if used == previous)
+    }
+
+    /// Marks the code for the given previous counter value as accepted.
+    pub fn use_previous(&mut self, previous: u64) {
+        self.used = Some(previous);
+    }
+}
+
+/// Represents stores of HOTP counters shared between threads.
+///
+/// Implementations can persist the counter, for instance, in databases,
+/// as long as consuming values is atomic.
+pub trait CounterStore {
+    /// Atomically consumes the current counter value, advancing the counter.
+    ///
+    /// Returns [`None`] if the counter would overflow (in which case it is not advanced).
+    fn consume(&self) -> Option<u64>;
+}
+
+/// Represents in-memory counter stores backed by [`AtomicU64`].
+#[derive(Debug, Default)]
+pub struct AtomicCounter {
+    value: AtomicU64,
+}
+
+impl AtomicCounter {
+    /// Constructs [`Self`] starting from the given counter.
+    pub const fn new(counter: Counter) -> Self {
+        Self {
+            value: AtomicU64::new(counter.get()),
+        }
+    }
+
+    /// Returns the current counter.
+    pub fn load(&self) -> Counter {
+        Counter::new(self.value.load(Ordering::Acquire))
+    }
+}
+
+impl CounterStore for AtomicCounter {
+    fn consume(&self) -> Option<u64> {
+        self.value
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |value| {
+                value.checked_add(1)
+            })
+            .ok()
+    }
+}
+
+/// Represents codes generated by [`Generator`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Generated {
+    /// The generated code.
+    pub code: u32,
+    /// The new counter, that is, the one following the counter used to generate the code.
+    pub counter: Counter,
+}
+
+impl Generated {
+    /// Constructs [`Self`].
+    pub const fn new(code: u32, counter: Counter) -> Self {
+        Self { code, counter }
+    }
+}
+
+/// Represents thread-safe auto-incrementing HOTP generators.
+///
+/// Unlike [`Hotp::next`], generating codes only requires shared references,
+/// and each counter value is consumed exactly once, even when generating concurrently.
+///
+/// # Examples
+///
+/// ```
+/// use otp_std::{hotp::Generator, Base, Hotp, Secret};
+///
+/// let secret = Secret::decode("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+///
+/// let base = Base::builder().secret(secret).build();
+///
+/// let hotp = Hotp::builder().base(base).build();
+///
+/// let generator = Generator::from_hotp(hotp.clone());
+///
+/// let generated = generator.next_code();
+///
+/// assert_eq!(generated.code, hotp.peek());
+/// assert_eq!(generated.counter.get(), 1);
+/// ```
+#[derive(Debug)]
+pub struct Generator<'g, S: CounterStore = AtomicCounter> {
+    base: Base<'g>,
+    store: S,
+}
+
+impl<'g, S: CounterStore> Generator<'g, S> {
+    /// Constructs [`Self`].
+    pub const fn new(base: Base<'g>, store: S) -> Self {
+        Self { base, store }
+    }
+
+    /// Returns the base configuration.
+    pub const fn base(&self) -> &Base<'g> {
+        &self.base
+    }
+
+    /// Returns the counter store.
+    pub const fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// Consumes [`Self`], returning the base configuration and the counter store.
+    pub fn into_parts(self) -> (Base<'g>, S) {
+        (self.base, self.store)
+    }
+
+    /// Atomically consumes the counter value, generating its code.
+    ///
+    /// Returns [`None`] if the counter would overflow.
+    pub fn try_next_code(&self) -> Option<Generated> {
+        let counter = self.store.consume()?;
+
+        let code = self.base.generate(counter);
+
+        Some(Generated::new(code, Counter::new(counter + 1)))
+    }
+
+    /// Atomically consumes the counter value, generating its code.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the counter overflows.
+    pub fn next_code(&self) -> Generated {
+        self.try_next_code().expect(OVERFLOW)
+    }
+}
+
+impl<'g> Generator<'g> {
+    /// Constructs [`Self`] from the given [`Hotp`], starting from its counter.
+    pub fn from_hotp(hotp: Hotp<'g>) -> Self {
+        Self::new(hotp.base, AtomicCounter::new(hotp.counter))
+    }
 }
 
 /// The `counter` literal.
 #[cfg(feature = "auth")]
 pub const COUNTER: &str = "counter";
 
+/// The look-ahead parameter name.
+///
+/// This is a vendor parameter, which is only emitted when the look-ahead is not disabled.
+#[cfg(feature = "auth")]
+pub const WINDOW: &str = "window";
+
 /// Represents errors returned when the counter is not found in the OTP URL.
 #[cfg(feature = "auth")]
 #[derive(Debug, Error, Diagnostic)]
@@ -112,6 +696,7 @@ pub struct CounterNotFoundError;
 #[derive(Debug, Error, Diagnostic)]
 #[error(transparent)]
 #[diagnostic(transparent)]
+#[non_exhaustive]
 pub enum ErrorSource {
     /// The base configuration could not be extracted from the OTP URL.
     Base(#[from] base::Error),
@@ -119,12 +704,27 @@ pub enum ErrorSource {
     CounterNotFound(#[from] CounterNotFoundError),
     /// The counter was found, but could not be parsed.
     Counter(#[from] counter::Error),
+    /// The look-ahead was found, but could not be parsed.
+    LookAhead(#[from] look_ahead::Error),
+}
+
+#[cfg(feature = "auth")]
+impl ErrorSource {
+    /// Returns the parameter that caused this error.
+    pub fn parameter(&self) -> Parameter<'_> {
+        match self {
+            Self::Base(error) => error.parameter(),
+            Self::CounterNotFound(_) => Parameter::name_only(COUNTER),
+            Self::Counter(error) => Parameter::with_value(COUNTER, &error.string),
+            Self::LookAhead(error) => Parameter::with_value(WINDOW, &error.string),
+        }
+    }
 }
 
 /// Represents errors that can occur when extracting HOTP configurations from OTP URLs.
 #[cfg(feature = "auth")]
 #[derive(Debug, Error, Diagnostic)]
-#[error("failed to extract HOTP from OTP URL")]
+#[error("failed to extract HOTP from OTP URL (parameter {})", .source.parameter())]
 #[diagnostic(
     code(otp_std::hotp::extract),
     help("see the report for more information")
@@ -162,6 +762,16 @@ impl Error {
     pub fn counter(error: counter::Error) -> Self {
         Self::new(error.into())
     }
+
+    /// Constructs [`Self`] from [`look_ahead::Error`].
+    pub fn look_ahead(error: look_ahead::Error) -> Self {
+        Self::new(error.into())
+    }
+
+    /// Returns the parameter that caused this error.
+    pub fn parameter(&self) -> Parameter<'_> {
+        self.source.parameter()
+    }
 }
 
 #[cfg(feature = "auth")]
@@ -173,6 +783,13 @@ impl Hotp<'_> {
         let counter = self.counter.to_string();
 
         url.query_pairs_mut().append_pair(COUNTER, counter.as_str());
+
+        if !self.look_ahead.is_default() {
+            let look_ahead = self.look_ahead.to_string();
+
+            url.query_pairs_mut()
+                .append_pair(WINDOW, look_ahead.as_str());
+        };
     }
 
     /// Extracts the HOTP configuration from the given query.
@@ -183,18 +800,32 @@ impl Hotp<'_> {
     pub fn extract_from(query: &mut Query<'_>) -> Result<Self, Error> {
         let base = Base::extract_from(query).map_err(Error::base)?;
 
-        let counter = query
-            .remove(COUNTER)
-            .ok_or_else(Error::new_counter_not_found)?
-            .parse()
-            .map_err(Error::counter)?;
+        let counter = extract_parse(query, COUNTER)
+            .map_err(Error::counter)?
+            .ok_or_else(Error::new_counter_not_found)?;
+
+        let look_ahead = extract_parse(query, WINDOW).map_err(Error::look_ahead)?;
 
-        let hotp = Self::builder().base(base).counter(counter).build();
+        let hotp = Self::builder()
+            .base(base)
+            .counter(counter)
+            .maybe_look_ahead(look_ahead)
+            .build();
 
         Ok(hotp)
     }
 }
 
+impl Hotp<'_> {
+    /// Checks whether [`Self`] and the other configuration have the same parameters,
+    /// comparing everything except the secrets (see [`Base::same_parameters`]).
+    pub fn same_parameters(&self, other: &Hotp<'_>) -> bool {
+        self.base.same_parameters(&other.base)
+            && self.counter == other.counter
+            && self.look_ahead == other.look_ahead
+    }
+}
+
 /// Represents owned [`Hotp`].
 pub type Owned = Hotp<'static>;
 
@@ -204,6 +835,61 @@ impl Hotp<'_> {
         Owned::builder()
             .base(self.base.into_owned())
             .counter(self.counter)
+            .look_ahead(self.look_ahead)
             .build()
     }
 }
+
+/// Constructs [`Hotp`] from literals, validated at compile time.
+///
+/// The secret is given as Base32-encoded string (see [`secret!`]), followed by
+/// optional `algorithm`, `digits` and `counter`, in this order.
+///
+/// Invalid secrets and digits result in compilation errors.
+///
+/// # Examples
+///
+/// ```
+/// use otp_std::hotp;
+///
+/// let hotp = hotp!("JEQDYMZAN5YGK3RAONXXK4TDMU", digits = 8, counter = 13);
+///
+/// assert_eq!(hotp.base.digits.get(), 8);
+/// assert_eq!(hotp.counter.get(), 13);
+/// ```
+///
+/// Invalid digits are rejected at compile time:
+///
+/// ```compile_fail
+/// use otp_std::hotp;
+///
+/// let hotp = hotp!("JEQDYMZAN5YGK3RAONXXK4TDMU", digits = 42);
+/// ```
+///
+/// [`secret!`]: crate::secret!
+#[macro_export]
+macro_rules! hotp {
+    (
+        $secret: expr
+        $(, algorithm = $algorithm: expr)?
+        $(, digits = $digits: expr)?
+        $(, counter = $counter: expr)?
+        $(,)?
+    ) => {
+        $crate::hotp::Hotp::builder()
+            .base(
+                $crate::base::Base::builder()
+                    .secret($crate::secret!($secret))
+                    $(.algorithm($algorithm))?
+                    $(.digits({
+                        const DIGITS: $crate::digits::Digits =
+                            $crate::digits::Digits::new_ok($digits).expect("invalid digits");
+
+                        DIGITS
+                    }))?
+                    .build(),
+            )
+            $(.counter($crate::counter::Counter::new($counter)))?
+            .build()
+    };
+}
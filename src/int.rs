@@ -6,7 +6,7 @@ use miette::Diagnostic;
 use thiserror::Error;
 
 /// Wraps [`ParseIntError`] to provide diagnostics.
-#[derive(Debug, Error, Diagnostic)]
+#[derive(Debug, Clone, PartialEq, Eq, Error, Diagnostic)]
 #[error("failed to parse integer")]
 #[diagnostic(code(otp_std::int::parse), help("ensure the input is valid"))]
 pub struct ParseError(#[from] pub ParseIntError);
@@ -15,3 +15,75 @@ pub struct ParseError(#[from] pub ParseIntError);
 pub const fn wrap(error: ParseIntError) -> ParseError {
     ParseError(error)
 }
+
+/// Deserializes [`u64`] values from either numbers or numeric strings.
+///
+/// Numeric strings are only accepted by human-readable formats (like JSON), since data
+/// exported from JavaScript ecosystems frequently has numbers stringified.
+///
+/// # Errors
+///
+/// Returns [`D::Error`] if the value is neither an unsigned integer nor a numeric string.
+///
+/// [`D::Error`]: serde::Deserializer::Error
+#[cfg(feature = "serde")]
+pub fn deserialize_u64<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    use std::fmt;
+
+    use serde::{de, Deserialize};
+
+    struct Visitor;
+
+    impl de::Visitor<'_> for Visitor {
+        type Value = u64;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("unsigned integer or numeric string")
+        }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+            Ok(value)
+        }
+
+        fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+            value
+                .try_into()
+                .map_err(|_| E::invalid_value(de::Unexpected::Signed(value), &self))
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+            value.parse().map_err(E::custom)
+        }
+    }
+
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(Visitor)
+    } else {
+        u64::deserialize(deserializer)
+    }
+}
+
+/// Deserializes [`u8`] values from either numbers or numeric strings.
+///
+/// See [`deserialize_u64`] for more information.
+///
+/// # Errors
+///
+/// Returns [`D::Error`] if the value is neither an unsigned integer nor a numeric string,
+/// or if it does not fit into [`u8`].
+///
+/// [`D::Error`]: serde::Deserializer::Error
+#[cfg(feature = "serde")]
+pub fn deserialize_u8<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<u8, D::Error> {
+    use serde::{de, Deserialize};
+
+    if deserializer.is_human_readable() {
+        let value = deserialize_u64(deserializer)?;
+
+        value.try_into().map_err(|_| {
+            de::Error::invalid_value(de::Unexpected::Unsigned(value), &"unsigned 8-bit integer")
+        })
+    } else {
+        u8::deserialize(deserializer)
+    }
+}
@@ -6,6 +6,8 @@
 pub mod algorithm;
 pub mod counter;
 pub mod digits;
+pub mod limit;
+pub mod look_ahead;
 pub mod period;
 pub mod secret;
 pub mod skew;
@@ -13,16 +15,26 @@ pub mod skew;
 pub use algorithm::Algorithm;
 pub use counter::Counter;
 pub use digits::Digits;
+pub use limit::SkewLimit;
+pub use look_ahead::LookAhead;
 pub use period::Period;
 pub use secret::{Length, Owned as OwnedSecret, Secret};
 pub use skew::Skew;
 
 pub mod time;
 
-pub use time::{expect_now, now};
+pub use time::{expect_now, expect_now_millis, now, now_clamped, now_millis};
+
+pub mod clock;
+
+pub use clock::Clock;
 
 pub mod int;
 
+pub mod output;
+
+pub mod builder;
+
 pub mod base;
 pub mod hotp;
 pub mod totp;
@@ -31,6 +43,42 @@ pub use base::{Base, Owned as OwnedBase};
 pub use hotp::{Hotp, Owned as OwnedHotp};
 pub use totp::{Owned as OwnedTotp, Totp};
 
+pub mod artifact;
+pub mod batch;
+pub mod bound;
+pub mod code;
+pub mod codes;
+pub mod compute;
+
+pub mod config;
+
+pub use config::Config;
+
+pub mod options;
+pub mod prepared;
+pub mod scrub;
+
+pub use options::VerifyOptions;
+
+pub mod policy;
+pub mod profile;
+pub mod receipt;
+pub mod remaining;
+pub mod replay;
+pub mod verifier;
+
+pub use policy::Policy;
+pub use profile::Profile;
+pub use verifier::{Owned as OwnedVerifier, Verifier};
+
+pub mod verify;
+
+pub use verify::{OtpVerify, VerifyOutcome};
+
+pub mod sync;
+
+pub use sync::SyncState;
+
 pub mod otp;
 
 pub use otp::{Otp, Owned as OwnedOtp, Type};
@@ -41,4 +89,23 @@ pub mod auth;
 #[cfg(feature = "auth")]
 pub use auth::{Auth, Label, Owned as OwnedAuth, OwnedLabel, OwnedPart, Part};
 
-pub mod macros;
+#[cfg(feature = "icons")]
+pub mod providers;
+
+#[cfg(feature = "print")]
+pub mod print;
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
+#[cfg(feature = "wasm-bindgen")]
+pub mod wasm;
+
+pub mod deps;
+pub mod errors;
+
+pub use errors::Error;
+
+pub mod prelude;
+
+pub(crate) mod macros;
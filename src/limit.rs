@@ -0,0 +1,210 @@
+//! Limits of verification windows.
+//!
+//! Skews (see [`Skew`]) and look-aheads (see [`LookAhead`]) are read from OTP URLs
+//! (the `window` vendor parameter) and configuration files, where absurd values would silently
+//! widen verification windows, accepting far more codes than intended. [`SkewLimit`] bounds
+//! such values, returning [`struct@Error`] if they are exceeded.
+//!
+//! Deserialized skews and look-aheads are always checked against [`SkewLimit::DEFAULT`].
+//!
+//! # Examples
+//!
+//! ```
+//! use otp_std::{limit::SkewLimit, LookAhead, Skew};
+//!
+//! let limit = SkewLimit::DEFAULT;
+//!
+//! assert!(limit.check_skew(Skew::new(2)).is_ok());
+//! assert!(limit.check_look_ahead(LookAhead::new(1_000_000)).is_err());
+//! ```
+
+use std::fmt;
+
+use miette::Diagnostic;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use thiserror::Error;
+
+use crate::{hotp::Hotp, look_ahead::LookAhead, otp::core::Otp, skew::Skew, totp::Totp};
+
+/// The default maximum number of steps.
+pub const DEFAULT: u64 = 10;
+
+/// The `skew` literal.
+pub const SKEW: &str = "skew";
+
+/// The `look-ahead` literal.
+pub const LOOK_AHEAD: &str = "look-ahead";
+
+/// Represents kinds of verification windows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Kind {
+    /// TOTP skew (see [`Skew`]).
+    Skew,
+    /// HOTP look-ahead (see [`LookAhead`]).
+    LookAhead,
+}
+
+impl Kind {
+    /// Returns the static string representation of [`Self`].
+    pub const fn static_str(self) -> &'static str {
+        match self {
+            Self::Skew => SKEW,
+            Self::LookAhead => LOOK_AHEAD,
+        }
+    }
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.static_str().fmt(formatter)
+    }
+}
+
+/// Represents errors returned when verification windows exceed their limits.
+#[derive(Debug, Error, Diagnostic)]
+#[error("{kind} of {value} steps exceeds the limit of {max} steps")]
+#[diagnostic(
+    code(otp_std::limit),
+    help("make sure the verification window is not tampered with")
+)]
+pub struct Error {
+    /// The kind of the verification window.
+    pub kind: Kind,
+    /// The number of steps found.
+    pub value: u64,
+    /// The maximum number of steps allowed.
+    pub max: u64,
+}
+
+impl Error {
+    /// Constructs [`Self`].
+    pub const fn new(kind: Kind, value: u64, max: u64) -> Self {
+        Self { kind, value, max }
+    }
+}
+
+/// Represents limits of verification windows, in steps.
+///
+/// Skews are limited in each direction separately, so that [`Skew::new`] with the limit
+/// is still accepted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "u64", into = "u64"))]
+pub struct SkewLimit {
+    max: u64,
+}
+
+impl SkewLimit {
+    /// Constructs [`Self`].
+    pub const fn new(max: u64) -> Self {
+        Self { max }
+    }
+
+    /// Returns the maximum number of steps.
+    pub const fn get(self) -> u64 {
+        self.max
+    }
+
+    /// Checks whether [`Self`] is equal to [`DEFAULT`](Self::DEFAULT).
+    pub const fn is_default(&self) -> bool {
+        self.get() == DEFAULT
+    }
+
+    /// The default [`Self`].
+    pub const DEFAULT: Self = Self::new(DEFAULT);
+
+    /// The unlimited [`Self`], which accepts everything.
+    pub const UNLIMITED: Self = Self::new(u64::MAX);
+}
+
+impl Default for SkewLimit {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl From<u64> for SkewLimit {
+    fn from(max: u64) -> Self {
+        Self::new(max)
+    }
+}
+
+impl From<SkewLimit> for u64 {
+    fn from(limit: SkewLimit) -> Self {
+        limit.get()
+    }
+}
+
+impl SkewLimit {
+    const fn check(self, kind: Kind, value: u64) -> Result<(), Error> {
+        let max = self.get();
+
+        if value > max {
+            return Err(Error::new(kind, value, max));
+        };
+
+        Ok(())
+    }
+
+    /// Checks whether the given skew is within [`Self`] in both directions.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`struct@Error`] if the skew exceeds the limit.
+    pub const fn check_skew(self, skew: Skew) -> Result<(), Error> {
+        self.check(Kind::Skew, skew.get())
+    }
+
+    /// Checks whether the given look-ahead is within [`Self`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`struct@Error`] if the look-ahead exceeds the limit.
+    pub const fn check_look_ahead(self, look_ahead: LookAhead) -> Result<(), Error> {
+        self.check(Kind::LookAhead, look_ahead.get())
+    }
+}
+
+impl Totp<'_> {
+    /// Checks whether the skew is within the given limit (see [`SkewLimit::check_skew`]).
+    ///
+    /// This is useful when loading configurations from untrusted storage.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`struct@Error`] if the skew exceeds the limit.
+    pub const fn check_skew_limit(&self, limit: SkewLimit) -> Result<(), Error> {
+        limit.check_skew(self.skew)
+    }
+}
+
+impl Hotp<'_> {
+    /// Checks whether the look-ahead is within the given limit
+    /// (see [`SkewLimit::check_look_ahead`]).
+    ///
+    /// This is useful when loading configurations from untrusted storage.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`struct@Error`] if the look-ahead exceeds the limit.
+    pub const fn check_skew_limit(&self, limit: SkewLimit) -> Result<(), Error> {
+        limit.check_look_ahead(self.look_ahead)
+    }
+}
+
+impl Otp<'_> {
+    /// Checks whether the verification window is within the given limit.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`struct@Error`] if the verification window exceeds the limit.
+    pub const fn check_skew_limit(&self, limit: SkewLimit) -> Result<(), Error> {
+        match self {
+            Self::Hotp(hotp) => hotp.check_skew_limit(limit),
+            Self::Totp(totp) => totp.check_skew_limit(limit),
+        }
+    }
+}
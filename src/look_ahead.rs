@@ -0,0 +1,191 @@
+//! HMAC-based One-Time Password (HOTP) look-ahead windows.
+//!
+//! HOTP tokens advance their counters every time codes are generated, regardless of whether
+//! the codes are actually used, so the counters on both sides drift apart.
+//! [`LookAhead`] specifies how many counter values past the current one are accepted,
+//! allowing verifiers to resynchronize.
+
+use std::{fmt, str::FromStr};
+
+use miette::Diagnostic;
+
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize};
+
+use thiserror::Error;
+
+#[cfg(feature = "serde")]
+use crate::limit::SkewLimit;
+
+use crate::{
+    int::{self, ParseError},
+    macros::errors,
+};
+
+/// The disabled look-ahead value.
+pub const DISABLED: u64 = 0;
+
+/// The default look-ahead value.
+pub const DEFAULT: u64 = DISABLED;
+
+/// Represents errors that can occur when parsing look-aheads.
+#[derive(Debug, Error, Diagnostic)]
+#[error("failed to parse `{string}` to look-ahead")]
+#[diagnostic(code(otp_std::look_ahead), help("see the report for more information"))]
+pub struct Error {
+    /// The source of this error.
+    #[source]
+    #[diagnostic_source]
+    pub source: ParseError,
+    /// The string that could not be parsed.
+    pub string: String,
+}
+
+impl Error {
+    /// Constructs [`Self`].
+    pub const fn new(source: ParseError, string: String) -> Self {
+        Self { source, string }
+    }
+}
+
+/// Represents look-ahead windows (see [`apply`] for more information).
+///
+/// [`apply`]: Self::apply
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(into = "u64"))]
+pub struct LookAhead {
+    value: u64,
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for LookAhead {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let look_ahead = int::deserialize_u64(deserializer).map(Self::new)?;
+
+        SkewLimit::DEFAULT
+            .check_look_ahead(look_ahead)
+            .map_err(de::Error::custom)?;
+
+        Ok(look_ahead)
+    }
+}
+
+errors! {
+    Type = Error,
+    Hack = $,
+    error => new(error, string => to_owned),
+}
+
+impl FromStr for LookAhead {
+    type Err = Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        Self::parse_str(string).map_err(|error| error!(error, string))
+    }
+}
+
+impl fmt::Display for LookAhead {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.get().fmt(formatter)
+    }
+}
+
+impl From<u64> for LookAhead {
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<LookAhead> for u64 {
+    fn from(look_ahead: LookAhead) -> Self {
+        look_ahead.get()
+    }
+}
+
+impl Default for LookAhead {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl LookAhead {
+    /// Constructs [`Self`].
+    pub const fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Parses [`Self`] from the given string without allocating on errors.
+    ///
+    /// Unlike [`from_str`], the error returned does not contain the input string.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError`] if the string is not a valid integer.
+    ///
+    /// [`from_str`]: FromStr::from_str
+    pub fn parse_str(string: &str) -> Result<Self, ParseError> {
+        string.parse().map(Self::new).map_err(int::wrap)
+    }
+
+    /// Returns the value wrapped in [`Self`].
+    pub const fn get(self) -> u64 {
+        self.value
+    }
+
+    /// Checks whether [`Self`] is equal to [`DEFAULT`](Self::DEFAULT).
+    pub const fn is_default(&self) -> bool {
+        self.get() == DEFAULT
+    }
+
+    /// Returns the disabled [`Self`].
+    pub const fn disabled() -> Self {
+        Self::DISABLED
+    }
+
+    /// Returns the number of values yielded by [`apply`] at most, that is, `n + 1`.
+    ///
+    /// The result saturates at [`u64::MAX`].
+    ///
+    /// [`apply`]: Self::apply
+    pub const fn window_size(self) -> u64 {
+        self.get().saturating_add(1)
+    }
+
+    /// Applies the look-ahead to the given counter.
+    ///
+    /// Given some look-ahead `n` and counter `c`, this method returns an iterator that yields
+    ///
+    /// ```text
+    /// c, c + 1, ..., c + n
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// In case of overflows, the iterator will skip the values that would cause them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::LookAhead;
+    ///
+    /// let look_ahead = LookAhead::new(2);
+    ///
+    /// let values: Vec<_> = look_ahead.apply(13).collect();
+    ///
+    /// assert_eq!(values, [13, 14, 15]);
+    ///
+    /// let values: Vec<_> = look_ahead.apply(u64::MAX).collect();
+    ///
+    /// assert_eq!(values, [u64::MAX]);
+    /// ```
+    pub fn apply(self, counter: u64) -> impl Iterator<Item = u64> {
+        (0..=self.get()).map_while(move |offset| counter.checked_add(offset))
+    }
+
+    /// The disabled [`Self`] value.
+    pub const DISABLED: Self = Self::new(DISABLED);
+
+    /// The default [`Self`] value.
+    pub const DEFAULT: Self = Self::new(DEFAULT);
+}
@@ -33,3 +33,47 @@ macro_rules! errors {
 }
 
 pub(crate) use errors;
+
+macro_rules! setters {
+    (
+        $(
+            $name: ident, $maybe: ident: $type: ty
+        );*
+        $(;)?
+    ) => {
+        $(
+            #[doc = concat!("Sets the `", stringify!($name), "` member.")]
+            pub fn $name(self, $name: $type) -> Self {
+                self.$maybe(Some($name))
+            }
+
+            #[doc = concat!("Sets the `", stringify!($name), "` member, if the value is given.")]
+            pub fn $maybe(mut self, $name: Option<$type>) -> Self {
+                self.$name = $name;
+
+                self
+            }
+        )*
+    };
+}
+
+pub(crate) use setters;
+
+macro_rules! const_expect {
+    ($option: expr, $message: expr $(,)?) => {
+        match $option {
+            Some(value) => value,
+            None => panic!("{}", $message),
+        }
+    };
+}
+
+pub(crate) use const_expect;
+
+macro_rules! const_unwrap {
+    ($option: expr $(,)?) => {
+        $crate::macros::const_expect!($option, "called `Option::unwrap()` on a `None` value")
+    };
+}
+
+pub(crate) use const_unwrap;
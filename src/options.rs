@@ -0,0 +1,118 @@
+//! Verification options.
+//!
+//! Codes entered by users often contain surrounding whitespace (for instance, trailing newlines
+//! when pasting) or separators (like `123 456` or `123-456`). The [`VerifyOptions`] type allows
+//! opting into normalizing such codes before verifying them, as well as scrubbing
+//! intermediate buffers afterwards (see [`scrub`] for more information).
+//!
+//! [`scrub`]: crate::scrub
+
+use std::borrow::Cow;
+
+use crate::{macros::setters, scrub::scrub_string};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The separators that can be stripped from codes.
+pub const SEPARATORS: [char; 2] = [' ', '-'];
+
+/// Represents options used when verifying string codes.
+///
+/// By default, no normalization is performed.
+///
+/// # Examples
+///
+/// ```
+/// use otp_std::VerifyOptions;
+///
+/// let options = VerifyOptions::builder()
+///     .trim(true)
+///     .strip_separators(true)
+///     .build();
+///
+/// assert_eq!(options.normalize(" 123-456\n"), "123456");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VerifyOptions {
+    /// Whether to trim leading and trailing whitespace.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub trim: bool,
+    /// Whether to strip [`SEPARATORS`] from codes.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub strip_separators: bool,
+    /// Whether to scrub intermediate buffers and normalized codes after verifying.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub scrub: bool,
+}
+
+/// Represents builders of [`VerifyOptions`] (see [`VerifyOptions::builder`]).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct VerifyOptionsBuilder {
+    trim: Option<bool>,
+    strip_separators: Option<bool>,
+    scrub: Option<bool>,
+}
+
+impl VerifyOptions {
+    /// Starts building [`VerifyOptions`].
+    pub fn builder() -> VerifyOptionsBuilder {
+        VerifyOptionsBuilder::default()
+    }
+}
+
+impl VerifyOptionsBuilder {
+    setters! {
+        trim, maybe_trim: bool;
+        strip_separators, maybe_strip_separators: bool;
+        scrub, maybe_scrub: bool;
+    }
+
+    /// Finishes building [`VerifyOptions`].
+    pub fn build(self) -> VerifyOptions {
+        VerifyOptions {
+            trim: self.trim.unwrap_or_default(),
+            strip_separators: self.strip_separators.unwrap_or_default(),
+            scrub: self.scrub.unwrap_or_default(),
+        }
+    }
+}
+
+impl VerifyOptions {
+    /// Constructs [`Self`] that enables all normalizations.
+    pub const fn lenient() -> Self {
+        Self {
+            trim: true,
+            strip_separators: true,
+            scrub: false,
+        }
+    }
+
+    /// Normalizes the given code according to [`Self`].
+    ///
+    /// Allocation only happens if separators need to be stripped.
+    pub fn normalize<'c>(&self, code: &'c str) -> Cow<'c, str> {
+        let code = if self.trim { code.trim() } else { code };
+
+        if self.strip_separators && code.contains(SEPARATORS) {
+            Cow::Owned(code.replace(SEPARATORS, ""))
+        } else {
+            Cow::Borrowed(code)
+        }
+    }
+
+    /// Discards the code returned from [`normalize`], scrubbing it if [`scrub`] is set.
+    ///
+    /// Only allocated codes are scrubbed, since borrowed ones are owned by the caller.
+    ///
+    /// [`normalize`]: Self::normalize
+    /// [`scrub`]: Self::scrub
+    pub fn discard(&self, code: Cow<'_, str>) {
+        if let Cow::Owned(string) = code {
+            if self.scrub {
+                scrub_string(string);
+            };
+        };
+    }
+}
@@ -20,7 +20,8 @@ use crate::{
 };
 
 /// Represents either [`Hotp`] or [`Totp`] configuration.
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone)]
+#[cfg_attr(not(feature = "forbid-secret-eq"), derive(PartialEq, Eq, Hash))]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "snake_case"))]
 pub enum Otp<'o> {
@@ -71,6 +72,7 @@ impl Otp<'_> {
 #[derive(Debug, Error, Diagnostic)]
 #[error(transparent)]
 #[diagnostic(transparent)]
+#[non_exhaustive]
 pub enum ErrorSource {
     /// The HOTP configuration could not be extracted.
     Hotp(#[from] hotp::Error),
@@ -124,14 +126,14 @@ impl Otp<'_> {
     ///
     /// Returns [`struct@Error`] when the OTP configuration can not be extracted.
     pub fn extract_from(query: &mut Query<'_>, type_of: Type) -> Result<Self, Error> {
-        match type_of {
-            Type::Hotp => Hotp::extract_from(query)
+        if type_of.requires_counter() {
+            Hotp::extract_from(query)
                 .map(Self::Hotp)
-                .map_err(Error::hotp),
-
-            Type::Totp => Totp::extract_from(query)
+                .map_err(Error::hotp)
+        } else {
+            Totp::extract_from(query)
                 .map(Self::Totp)
-                .map_err(Error::totp),
+                .map_err(Error::totp)
         }
     }
 }
@@ -148,6 +150,20 @@ impl<'t> From<Totp<'t>> for Otp<'t> {
     }
 }
 
+impl Otp<'_> {
+    /// Checks whether [`Self`] and the other configuration have the same parameters,
+    /// comparing everything except the secrets.
+    ///
+    /// Configurations of different types never have the same parameters.
+    pub fn same_parameters(&self, other: &Otp<'_>) -> bool {
+        match (self, other) {
+            (Self::Hotp(hotp), Otp::Hotp(other)) => hotp.same_parameters(other),
+            (Self::Totp(totp), Otp::Totp(other)) => totp.same_parameters(other),
+            _ => false,
+        }
+    }
+}
+
 /// Represents owned [`Otp`].
 pub type Owned = Otp<'static>;
 
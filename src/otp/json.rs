@@ -0,0 +1,314 @@
+//! Partial JSON configurations.
+//!
+//! Configurations are often stored as hand-rolled JSON blobs containing only the `secret`
+//! and maybe the `digits`, relying on implicit defaults for everything else.
+//! [`Otp::from_partial_json`] fills missing fields from their defaults, reporting
+//! which of them were applied (see [`Report`]), which eases migrating such configurations.
+//!
+//! # Examples
+//!
+//! ```
+//! use otp_std::{otp::json::Field, Otp};
+//! use serde_json::json;
+//!
+//! let value = json!({ "secret": "JEQDYMZAN5YGK3RAONXXK4TDMU", "digits": 8 });
+//!
+//! let report = Otp::from_partial_json(value).unwrap();
+//!
+//! assert_eq!(report.otp.base().digits.get(), 8);
+//!
+//! assert_eq!(
+//!     report.defaults,
+//!     [Field::Type, Field::Algorithm, Field::Skew, Field::Period]
+//! );
+//! ```
+
+use std::fmt;
+
+use miette::Diagnostic;
+use serde_json::{Map, Value};
+use thiserror::Error;
+
+use crate::{
+    limit::{self, SkewLimit},
+    otp::{
+        core::{Otp, Owned},
+        type_of::{Type, HOTP},
+    },
+};
+
+/// The `type` field name.
+pub const TYPE: &str = "type";
+
+/// The `algorithm` field name.
+pub const ALGORITHM: &str = "algorithm";
+
+/// The `digits` field name.
+pub const DIGITS: &str = "digits";
+
+/// The `skew` field name.
+pub const SKEW: &str = "skew";
+
+/// The `period` field name.
+pub const PERIOD: &str = "period";
+
+/// The `counter` field name.
+pub const COUNTER: &str = "counter";
+
+/// The `look_ahead` field name.
+pub const LOOK_AHEAD: &str = "look_ahead";
+
+/// The type used when it is missing.
+pub const DEFAULT_TYPE: Type = Type::Totp;
+
+/// Represents fields that can be filled from their defaults.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Field {
+    /// The OTP type, defaulting to [`DEFAULT_TYPE`].
+    Type,
+    /// The algorithm (see [`Algorithm`](crate::algorithm::Algorithm)).
+    Algorithm,
+    /// The number of digits (see [`Digits`](crate::digits::Digits)).
+    Digits,
+    /// The TOTP skew (see [`Skew`](crate::skew::Skew)).
+    Skew,
+    /// The TOTP period (see [`Period`](crate::period::Period)).
+    Period,
+    /// The HOTP counter (see [`Counter`](crate::counter::Counter)).
+    Counter,
+    /// The HOTP look-ahead (see [`LookAhead`](crate::look_ahead::LookAhead)).
+    LookAhead,
+}
+
+impl Field {
+    /// Returns the static string representation of [`Self`], which is the JSON field name.
+    pub const fn static_str(self) -> &'static str {
+        match self {
+            Self::Type => TYPE,
+            Self::Algorithm => ALGORITHM,
+            Self::Digits => DIGITS,
+            Self::Skew => SKEW,
+            Self::Period => PERIOD,
+            Self::Counter => COUNTER,
+            Self::LookAhead => LOOK_AHEAD,
+        }
+    }
+
+    /// Returns the fields that are filled from their defaults for the given type,
+    /// excluding [`Self::Type`], in the order of [`Self`].
+    ///
+    /// The fields are derived from the capabilities of the type (see [`Type::uses_period`],
+    /// [`Type::accepts_skew`] and [`Type::requires_counter`]).
+    pub fn defaulted(type_of: Type) -> Vec<Self> {
+        let mut fields = vec![Self::Algorithm, Self::Digits];
+
+        if type_of.accepts_skew() {
+            fields.push(Self::Skew);
+        };
+
+        if type_of.uses_period() {
+            fields.push(Self::Period);
+        };
+
+        if type_of.requires_counter() {
+            fields.extend([Self::Counter, Self::LookAhead]);
+        };
+
+        fields
+    }
+}
+
+impl fmt::Display for Field {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.static_str().fmt(formatter)
+    }
+}
+
+/// Represents errors returned when the value is not a JSON object.
+#[derive(Debug, Error, Diagnostic)]
+#[error("expected JSON object")]
+#[diagnostic(
+    code(otp_std::otp::json::object),
+    help("make sure the configuration is a JSON object")
+)]
+pub struct ObjectError;
+
+/// Represents errors returned when configurations can not be deserialized.
+#[derive(Debug, Error, Diagnostic)]
+#[error("failed to deserialize configuration")]
+#[diagnostic(
+    code(otp_std::otp::json::deserialize),
+    help("make sure the configuration contains valid values")
+)]
+pub struct DeserializeError {
+    /// The source of this error.
+    #[source]
+    pub source: serde_json::Error,
+}
+
+impl DeserializeError {
+    /// Constructs [`Self`].
+    pub const fn new(source: serde_json::Error) -> Self {
+        Self { source }
+    }
+}
+
+/// Represents sources of errors that can occur when reading partial JSON configurations.
+#[derive(Debug, Error, Diagnostic)]
+#[error(transparent)]
+#[diagnostic(transparent)]
+#[non_exhaustive]
+pub enum ErrorSource {
+    /// The value is not a JSON object.
+    Object(#[from] ObjectError),
+    /// The configuration could not be deserialized.
+    Deserialize(#[from] DeserializeError),
+    /// The verification window exceeds the limit.
+    SkewLimit(#[from] limit::Error),
+}
+
+/// Represents errors that can occur when reading partial JSON configurations.
+#[derive(Debug, Error, Diagnostic)]
+#[error("failed to read partial JSON configuration")]
+#[diagnostic(code(otp_std::otp::json), help("see the report for more information"))]
+pub struct Error {
+    /// The source of this error.
+    #[source]
+    #[diagnostic_source]
+    pub source: ErrorSource,
+}
+
+impl Error {
+    /// Constructs [`Self`].
+    pub const fn new(source: ErrorSource) -> Self {
+        Self { source }
+    }
+
+    /// Constructs [`Self`] from [`ObjectError`].
+    pub fn object(error: ObjectError) -> Self {
+        Self::new(error.into())
+    }
+
+    /// Constructs [`Self`] from [`DeserializeError`].
+    pub fn deserialize(error: DeserializeError) -> Self {
+        Self::new(error.into())
+    }
+
+    /// Constructs [`Self`] from [`limit::Error`].
+    pub fn skew_limit(error: limit::Error) -> Self {
+        Self::new(error.into())
+    }
+
+    /// Constructs [`ObjectError`] and constructs [`Self`] from it.
+    pub fn new_object() -> Self {
+        Self::object(ObjectError)
+    }
+
+    /// Constructs [`DeserializeError`] and constructs [`Self`] from it.
+    pub fn new_deserialize(source: serde_json::Error) -> Self {
+        Self::deserialize(DeserializeError::new(source))
+    }
+}
+
+/// Represents OTP configurations read from partial JSON, along with the applied defaults.
+#[derive(Debug, Clone)]
+pub struct Report {
+    /// The OTP configuration.
+    pub otp: Owned,
+    /// The fields filled from their defaults, in the order of [`Field`].
+    pub defaults: Vec<Field>,
+}
+
+impl Report {
+    /// Constructs [`Self`].
+    pub const fn new(otp: Owned, defaults: Vec<Field>) -> Self {
+        Self { otp, defaults }
+    }
+
+    /// Checks whether no defaults were applied, meaning the configuration was complete.
+    pub fn is_complete(&self) -> bool {
+        self.defaults.is_empty()
+    }
+
+    /// Checks whether the given field was filled from its default.
+    pub fn is_defaulted(&self, field: Field) -> bool {
+        self.defaults.contains(&field)
+    }
+}
+
+fn type_of(object: &Map<String, Value>) -> Type {
+    match object.get(TYPE).and_then(Value::as_str) {
+        Some(HOTP) => Type::Hotp,
+        _ => Type::Totp,
+    }
+}
+
+impl Otp<'_> {
+    /// Reads OTP configurations from partial JSON values.
+    ///
+    /// Missing fields are filled from their defaults, which are reported along with
+    /// the configuration (see [`Report`]). If the `type` is missing, [`DEFAULT_TYPE`] is used.
+    ///
+    /// The verification window is checked against [`SkewLimit::DEFAULT`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`struct@Error`] if the value is not a JSON object, the configuration
+    /// can not be deserialized, for instance, if the `secret` is missing or invalid,
+    /// or the verification window exceeds the limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{otp::json::Field, Counter, Otp};
+    /// use serde_json::json;
+    ///
+    /// let value = json!({
+    ///     "type": "hotp",
+    ///     "secret": "JEQDYMZAN5YGK3RAONXXK4TDMU",
+    ///     "counter": 42,
+    /// });
+    ///
+    /// let report = Otp::from_partial_json(value).unwrap();
+    ///
+    /// let Otp::Hotp(hotp) = report.otp else {
+    ///     panic!("expected HOTP");
+    /// };
+    ///
+    /// assert_eq!(hotp.counter, Counter::new(42));
+    ///
+    /// assert_eq!(
+    ///     report.defaults,
+    ///     [Field::Algorithm, Field::Digits, Field::LookAhead]
+    /// );
+    /// ```
+    pub fn from_partial_json(value: Value) -> Result<Report, Error> {
+        let Value::Object(mut object) = value else {
+            return Err(Error::new_object());
+        };
+
+        let mut defaults = Vec::new();
+
+        if !object.contains_key(TYPE) {
+            object.insert(TYPE.to_owned(), DEFAULT_TYPE.static_str().into());
+
+            defaults.push(Field::Type);
+        }
+
+        let type_of = type_of(&object);
+
+        defaults.extend(
+            Field::defaulted(type_of)
+                .iter()
+                .filter(|field| !object.contains_key(field.static_str())),
+        );
+
+        let otp: Owned =
+            serde_json::from_value(Value::Object(object)).map_err(Error::new_deserialize)?;
+
+        otp.check_skew_limit(SkewLimit::DEFAULT)
+            .map_err(Error::skew_limit)?;
+
+        Ok(Report::new(otp, defaults))
+    }
+}
@@ -3,5 +3,11 @@
 pub mod core;
 pub mod type_of;
 
+#[cfg(feature = "auth")]
+pub mod url;
+
+#[cfg(feature = "json")]
+pub mod json;
+
 pub use core::{Otp, Owned};
 pub use type_of::Type;
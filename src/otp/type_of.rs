@@ -14,7 +14,14 @@ use thiserror::Error;
 use crate::macros::errors;
 
 #[cfg(feature = "auth")]
-use crate::auth::url::Url;
+use crate::{
+    algorithm::Algorithm,
+    auth::url::Url,
+    base::{ALGORITHM, DIGITS},
+    digits::Digits,
+    period::Period,
+    totp::PERIOD,
+};
 
 /// Represents OTP types: HOTP or TOTP.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -55,6 +62,58 @@ impl Type {
             Self::Totp => TOTP,
         }
     }
+
+    /// Checks whether this type requires the counter.
+    pub const fn requires_counter(&self) -> bool {
+        matches!(self, Self::Hotp)
+    }
+
+    /// Checks whether this type uses the period.
+    ///
+    /// Note that the period is optional in OTP URLs, defaulting to [`Period::DEFAULT`].
+    ///
+    /// [`Period::DEFAULT`]: crate::period::Period::DEFAULT
+    pub const fn uses_period(&self) -> bool {
+        matches!(self, Self::Totp)
+    }
+
+    /// Checks whether this type accepts the skew.
+    pub const fn accepts_skew(&self) -> bool {
+        matches!(self, Self::Totp)
+    }
+}
+
+/// Represents query parameters paired with their default values.
+#[cfg(feature = "auth")]
+pub type Params = Vec<(&'static str, String)>;
+
+#[cfg(feature = "auth")]
+impl Type {
+    /// Returns the optional query parameters of this type, paired with their default values.
+    ///
+    /// The secret (and the counter, for HOTP) is required, and therefore is not included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::Type;
+    ///
+    /// let params = Type::Totp.default_query_params();
+    ///
+    /// assert!(params.contains(&("period", "30".to_owned())));
+    /// ```
+    pub fn default_query_params(&self) -> Params {
+        let mut params = vec![
+            (ALGORITHM, Algorithm::default().static_str().to_owned()),
+            (DIGITS, Digits::DEFAULT.to_string()),
+        ];
+
+        if self.uses_period() {
+            params.push((PERIOD, Period::DEFAULT.to_string()));
+        };
+
+        params
+    }
 }
 
 impl fmt::Display for Type {
@@ -97,6 +156,7 @@ pub struct NotFoundError;
 #[derive(Debug, Error, Diagnostic)]
 #[error(transparent)]
 #[diagnostic(transparent)]
+#[non_exhaustive]
 pub enum ErrorSource {
     /// The type was not found.
     NotFound(#[from] NotFoundError),
@@ -157,10 +217,14 @@ impl Type {
     /// # Errors
     ///
     /// Returns [`struct@Error`] if the type can not be extracted from the given URL.
+    ///
+    /// Since hosts are case-insensitive, so is the type extracted.
     pub fn extract_from(url: &Url) -> Result<Self, Error> {
         let host = url.host_str().ok_or_else(|| not_found_error!())?;
 
-        host.parse().map_err(|error| parse_error!(error))
+        host.to_ascii_lowercase()
+            .parse()
+            .map_err(|error| parse_error!(error))
     }
 }
 
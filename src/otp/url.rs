@@ -0,0 +1,208 @@
+//! OTP URLs without labels.
+//!
+//! Servers commonly store labels separately (for instance, in dedicated database columns),
+//! keeping only the OTP parameters in URLs like `otpauth://totp?secret=...`.
+//!
+//! [`Otp`] implements [`FromStr`] parsing such URLs (labels are ignored if present),
+//! while [`Otp::build_url`] builds them back.
+
+use std::str::FromStr;
+
+#[cfg(not(feature = "forbid-secret-display"))]
+use std::fmt;
+
+use miette::Diagnostic;
+use thiserror::Error;
+
+use crate::{
+    auth::{
+        core::SCHEME,
+        query::Query,
+        redact::{redact_secrets, Input},
+        scheme,
+        url::{self, Url},
+    },
+    limit::{self, SkewLimit},
+    macros::errors,
+    otp::{
+        core::{self, Otp, Owned},
+        type_of::{self, Type},
+    },
+};
+
+/// The message indicating that OTP URLs without labels are always valid.
+pub const URL_ALWAYS_VALID: &str = "OTP URL without label is always valid";
+
+/// Represents sources of errors that can occur when parsing OTP URLs without labels.
+#[derive(Debug, Error, Diagnostic)]
+#[error(transparent)]
+#[diagnostic(transparent)]
+#[non_exhaustive]
+pub enum ErrorSource {
+    /// URL could not be parsed.
+    Url(#[from] url::Error),
+    /// Unexpected scheme found.
+    Scheme(#[from] scheme::Error),
+    /// OTP type extraction failed.
+    Type(#[from] type_of::Error),
+    /// OTP extraction failed.
+    Otp(#[from] core::Error),
+    /// Verification window exceeds the limit.
+    SkewLimit(#[from] limit::Error),
+}
+
+/// Represents errors that can occur when parsing OTP URLs without labels.
+#[derive(Debug, Error, Diagnostic)]
+#[error("failed to extract OTP from `{string}`")]
+#[diagnostic(code(otp_std::otp::url), help("see the report for more information"))]
+pub struct Error {
+    /// The source of this error.
+    #[source]
+    #[diagnostic_source]
+    pub source: ErrorSource,
+    /// The string that could not be parsed.
+    pub string: String,
+}
+
+impl Error {
+    /// Constructs [`Self`].
+    pub const fn new(source: ErrorSource, string: String) -> Self {
+        Self { source, string }
+    }
+
+    /// Constructs [`Self`] from [`url::Error`].
+    pub fn parse(error: url::Error, string: String) -> Self {
+        Self::new(error.into(), string)
+    }
+
+    /// Constructs [`Self`] from [`scheme::Error`].
+    pub fn scheme(error: scheme::Error, string: String) -> Self {
+        Self::new(error.into(), string)
+    }
+
+    /// Constructs [`Self`] from [`type_of::Error`].
+    pub fn type_of(error: type_of::Error, string: String) -> Self {
+        Self::new(error.into(), string)
+    }
+
+    /// Constructs [`Self`] from [`core::Error`].
+    pub fn otp(error: core::Error, string: String) -> Self {
+        Self::new(error.into(), string)
+    }
+
+    /// Constructs [`Self`] from [`limit::Error`].
+    pub fn skew_limit(error: limit::Error, string: String) -> Self {
+        Self::new(error.into(), string)
+    }
+}
+
+errors! {
+    Type = Error,
+    Hack = $,
+    parse_error => parse(error, string => redact),
+    scheme_error => scheme(error, string => redact),
+    type_of_error => type_of(error, string => redact),
+    otp_error => otp(error, string => redact),
+    skew_limit_error => skew_limit(error, string => redact),
+}
+
+/// Parses [`Otp`] from the given OTP URL, ignoring the label.
+///
+/// The verification window is checked against [`SkewLimit::DEFAULT`].
+///
+/// # Errors
+///
+/// Returns [`struct@Error`] if anything goes wrong.
+pub fn parse<S: AsRef<str>>(string: S) -> Result<Owned, Error> {
+    fn parse_inner(input: Input<'_>) -> Result<Owned, Error> {
+        let url = url::parse(input.get()).map_err(|error| parse_error!(error, input))?;
+
+        scheme::check_url(&url).map_err(|error| scheme_error!(error, input))?;
+
+        let type_of = Type::extract_from(&url).map_err(|error| type_of_error!(error, input))?;
+
+        let mut query: Query<'_> = url.query_pairs().collect();
+
+        let otp =
+            Otp::extract_from(&mut query, type_of).map_err(|error| otp_error!(error, input))?;
+
+        otp.check_skew_limit(SkewLimit::DEFAULT)
+            .map_err(|error| skew_limit_error!(error, input))?;
+
+        Ok(otp)
+    }
+
+    parse_inner(Input::new(string.as_ref(), redact_secrets))
+}
+
+/// Returns the OTP URL without label for the given type.
+///
+/// # Errors
+///
+/// Returns [`url::Error`] if the URL can not be parsed.
+pub fn try_base(type_of: Type) -> Result<Url, url::Error> {
+    url::parse(format!("{SCHEME}://{type_of}"))
+}
+
+impl Otp<'_> {
+    /// Similar to [`build_url`], except the error is returned instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`url::Error`] if the URL can not be parsed.
+    ///
+    /// [`build_url`]: Self::build_url
+    pub fn try_build_url(&self) -> Result<Url, url::Error> {
+        let mut url = try_base(self.type_of())?;
+
+        self.query_for(&mut url);
+
+        Ok(url)
+    }
+
+    /// Builds the OTP URL without label.
+    ///
+    /// # Panics
+    ///
+    /// The URL is always valid, so this method should never panic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{Base, Otp, Secret, Totp};
+    ///
+    /// let secret = Secret::decode("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+    ///
+    /// let base = Base::builder().secret(secret).build();
+    ///
+    /// let otp = Otp::Totp(Totp::builder().base(base).build());
+    ///
+    /// let url = otp.build_url();
+    ///
+    /// assert!(url.as_str().starts_with("otpauth://totp?secret=JEQDYMZAN5YGK3RAONXXK4TDMU"));
+    ///
+    /// assert!(url.as_str().parse::<Otp<'_>>().unwrap().same_parameters(&otp));
+    /// ```
+    pub fn build_url(&self) -> Url {
+        self.try_build_url().expect(URL_ALWAYS_VALID)
+    }
+}
+
+impl FromStr for Otp<'_> {
+    type Err = Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        parse(string)
+    }
+}
+
+/// Formats [`Otp`] as the OTP URL without label (see [`Otp::build_url`]).
+///
+/// This implementation is removed when the `forbid-secret-display` feature is enabled,
+/// since the URL contains the encoded secret.
+#[cfg(not(feature = "forbid-secret-display"))]
+impl fmt::Display for Otp<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.build_url().as_str().fmt(formatter)
+    }
+}
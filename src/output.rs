@@ -0,0 +1,248 @@
+//! Typed HMAC outputs.
+//!
+//! Dynamic truncation reads four bytes starting at the offset given by the last half byte,
+//! which requires HMAC values to be at least [`MIN_LENGTH`] bytes long.
+//!
+//! [`Output`] encodes the length in its type and checks it at compile time, so truncating
+//! it never panics. [`AnyOutput`] holds outputs of any [`Algorithm`], each with its fixed length.
+
+use std::fmt;
+
+use hmac::{
+    digest::{typenum::Unsigned, OutputSizeUser},
+    Mac,
+};
+
+use crate::{
+    algorithm::{new_hmac_sha1, Algorithm, SHA1_LENGTH},
+    base::{HALF_BYTE, MASK},
+    digits::Digits,
+    scrub::scrub,
+};
+
+#[cfg(feature = "sha2")]
+use crate::algorithm::{new_hmac_sha256, new_hmac_sha512, SHA256_LENGTH, SHA512_LENGTH};
+
+#[cfg(feature = "sha3")]
+use crate::algorithm::{new_hmac_sha3_256, new_hmac_sha3_512, SHA3_256_LENGTH, SHA3_512_LENGTH};
+
+#[cfg(feature = "blake2")]
+use crate::algorithm::{new_hmac_blake2b, new_hmac_blake2s, BLAKE2B_LENGTH, BLAKE2S_LENGTH};
+
+/// The minimum length of HMAC values that can be truncated.
+pub const MIN_LENGTH: usize = 20;
+
+/// Represents HMAC values of the given length.
+///
+/// Constructing outputs shorter than [`MIN_LENGTH`] results in compilation errors.
+///
+/// The value is scrubbed when dropped.
+///
+/// # Examples
+///
+/// ```compile_fail
+/// use otp_std::output::Output;
+///
+/// let output = Output::new([0; 16]);
+/// ```
+#[derive(Clone)]
+pub struct Output<const N: usize> {
+    bytes: [u8; N],
+}
+
+impl<const N: usize> fmt::Debug for Output<N> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("Output")
+            .field("length", &N)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<const N: usize> Output<N> {
+    /// Constructs [`Self`].
+    pub const fn new(bytes: [u8; N]) -> Self {
+        const { assert!(N >= MIN_LENGTH, "HMAC values are too short to truncate") };
+
+        Self { bytes }
+    }
+
+    /// Returns the bytes of [`Self`].
+    pub const fn as_bytes(&self) -> &[u8; N] {
+        &self.bytes
+    }
+
+    /// Dynamically truncates [`Self`], returning the code with the given digits.
+    ///
+    /// Unlike [`truncate`], this method never panics, since the offset is at most `15`
+    /// and four bytes are read starting from it, which fits into [`MIN_LENGTH`] bytes.
+    ///
+    /// [`truncate`]: crate::base::truncate
+    pub const fn truncate(&self, digits: Digits) -> u32 {
+        let bytes = &self.bytes;
+
+        let offset = (bytes[N - 1] & HALF_BYTE) as usize;
+
+        let value = u32::from_be_bytes([
+            bytes[offset],
+            bytes[offset + 1],
+            bytes[offset + 2],
+            bytes[offset + 3],
+        ]) & MASK;
+
+        // the remainder is less than the value itself, therefore it always fits into `u32`
+        (value as u64 % digits.power_u64()) as u32
+    }
+}
+
+impl<const N: usize> Drop for Output<N> {
+    fn drop(&mut self) {
+        scrub(&mut self.bytes);
+    }
+}
+
+/// Computes the HMAC of the given data, returning [`Output`] of the matching length.
+///
+/// Mismatched lengths result in compilation errors.
+pub fn compute<M: Mac, D: AsRef<[u8]>, const N: usize>(mut mac: M, data: D) -> Output<N> {
+    const { assert!(<M as OutputSizeUser>::OutputSize::USIZE == N) };
+
+    mac.update(data.as_ref());
+
+    let mut output = mac.finalize().into_bytes();
+
+    let mut bytes = [0; N];
+
+    bytes.copy_from_slice(&output);
+
+    scrub(&mut output);
+
+    Output::new(bytes)
+}
+
+/// Represents HMAC values of any algorithm.
+#[derive(Clone)]
+pub enum AnyOutput {
+    /// SHA-1 value.
+    Sha1(Output<SHA1_LENGTH>),
+    /// SHA-256 value.
+    #[cfg(feature = "sha2")]
+    Sha256(Output<SHA256_LENGTH>),
+    /// SHA-512 value.
+    #[cfg(feature = "sha2")]
+    Sha512(Output<SHA512_LENGTH>),
+    /// SHA3-256 value.
+    #[cfg(feature = "sha3")]
+    Sha3_256(Output<SHA3_256_LENGTH>),
+    /// SHA3-512 value.
+    #[cfg(feature = "sha3")]
+    Sha3_512(Output<SHA3_512_LENGTH>),
+    /// BLAKE2s value.
+    #[cfg(feature = "blake2")]
+    Blake2s(Output<BLAKE2S_LENGTH>),
+    /// BLAKE2b value.
+    #[cfg(feature = "blake2")]
+    Blake2b(Output<BLAKE2B_LENGTH>),
+}
+
+impl fmt::Debug for AnyOutput {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("AnyOutput")
+            .field("algorithm", &self.algorithm())
+            .finish_non_exhaustive()
+    }
+}
+
+impl AnyOutput {
+    /// Returns the algorithm of [`Self`].
+    pub const fn algorithm(&self) -> Algorithm {
+        match self {
+            Self::Sha1(_) => Algorithm::Sha1,
+            #[cfg(feature = "sha2")]
+            Self::Sha256(_) => Algorithm::Sha256,
+            #[cfg(feature = "sha2")]
+            Self::Sha512(_) => Algorithm::Sha512,
+            #[cfg(feature = "sha3")]
+            Self::Sha3_256(_) => Algorithm::Sha3_256,
+            #[cfg(feature = "sha3")]
+            Self::Sha3_512(_) => Algorithm::Sha3_512,
+            #[cfg(feature = "blake2")]
+            Self::Blake2s(_) => Algorithm::Blake2s,
+            #[cfg(feature = "blake2")]
+            Self::Blake2b(_) => Algorithm::Blake2b,
+        }
+    }
+
+    /// Returns the bytes of [`Self`].
+    pub const fn as_bytes(&self) -> &[u8] {
+        match self {
+            Self::Sha1(output) => output.as_bytes(),
+            #[cfg(feature = "sha2")]
+            Self::Sha256(output) => output.as_bytes(),
+            #[cfg(feature = "sha2")]
+            Self::Sha512(output) => output.as_bytes(),
+            #[cfg(feature = "sha3")]
+            Self::Sha3_256(output) => output.as_bytes(),
+            #[cfg(feature = "sha3")]
+            Self::Sha3_512(output) => output.as_bytes(),
+            #[cfg(feature = "blake2")]
+            Self::Blake2s(output) => output.as_bytes(),
+            #[cfg(feature = "blake2")]
+            Self::Blake2b(output) => output.as_bytes(),
+        }
+    }
+
+    /// Dynamically truncates [`Self`], returning the code with the given digits.
+    ///
+    /// This method never panics (see [`Output::truncate`]).
+    pub const fn truncate(&self, digits: Digits) -> u32 {
+        match self {
+            Self::Sha1(output) => output.truncate(digits),
+            #[cfg(feature = "sha2")]
+            Self::Sha256(output) => output.truncate(digits),
+            #[cfg(feature = "sha2")]
+            Self::Sha512(output) => output.truncate(digits),
+            #[cfg(feature = "sha3")]
+            Self::Sha3_256(output) => output.truncate(digits),
+            #[cfg(feature = "sha3")]
+            Self::Sha3_512(output) => output.truncate(digits),
+            #[cfg(feature = "blake2")]
+            Self::Blake2s(output) => output.truncate(digits),
+            #[cfg(feature = "blake2")]
+            Self::Blake2b(output) => output.truncate(digits),
+        }
+    }
+}
+
+impl Algorithm {
+    /// Computes HMAC using the [`Self`] algorithm, the key provided, and the given data,
+    /// returning [`AnyOutput`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::Algorithm;
+    ///
+    /// let output = Algorithm::Sha1.compute(b"key", b"data");
+    ///
+    /// assert_eq!(output.as_bytes(), Algorithm::Sha1.hmac(b"key", b"data"));
+    /// ```
+    pub fn compute<K: AsRef<[u8]>, D: AsRef<[u8]>>(self, key: K, data: D) -> AnyOutput {
+        match self {
+            Self::Sha1 => AnyOutput::Sha1(compute(new_hmac_sha1(key), data)),
+            #[cfg(feature = "sha2")]
+            Self::Sha256 => AnyOutput::Sha256(compute(new_hmac_sha256(key), data)),
+            #[cfg(feature = "sha2")]
+            Self::Sha512 => AnyOutput::Sha512(compute(new_hmac_sha512(key), data)),
+            #[cfg(feature = "sha3")]
+            Self::Sha3_256 => AnyOutput::Sha3_256(compute(new_hmac_sha3_256(key), data)),
+            #[cfg(feature = "sha3")]
+            Self::Sha3_512 => AnyOutput::Sha3_512(compute(new_hmac_sha3_512(key), data)),
+            #[cfg(feature = "blake2")]
+            Self::Blake2s => AnyOutput::Blake2s(compute(new_hmac_blake2s(key), data)),
+            #[cfg(feature = "blake2")]
+            Self::Blake2b => AnyOutput::Blake2b(compute(new_hmac_blake2b(key), data)),
+        }
+    }
+}
@@ -11,7 +11,11 @@ use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 use thiserror::Error;
 
-use crate::{int, macros::errors};
+use crate::{
+    int,
+    macros::{const_unwrap, errors},
+    skew::Skew,
+};
 
 /// The minimum period value.
 pub const MIN: u64 = 1;
@@ -22,7 +26,7 @@ pub const DEFAULT: u64 = 30;
 /// Represents errors that can occur during period creation.
 ///
 /// This error is returned when the given value is less than [`MIN`].
-#[derive(Debug, Error, Diagnostic)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error, Diagnostic)]
 #[error("expected period to be at least `{MIN}`, got `{value}`")]
 #[diagnostic(
     code(otp_std::period),
@@ -40,10 +44,33 @@ impl Error {
     }
 }
 
+/// Represents errors that can occur when converting durations to periods.
+///
+/// This error is returned when the given duration is not a whole number of seconds
+/// or is less than [`MIN`] seconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error, Diagnostic)]
+#[error("expected whole period of at least `{MIN}` seconds, got `{duration:?}`")]
+#[diagnostic(
+    code(otp_std::period::duration),
+    help("make sure the duration is a whole number of seconds, at least `{MIN}`")
+)]
+pub struct DurationError {
+    /// The invalid duration.
+    pub duration: Duration,
+}
+
+impl DurationError {
+    /// Constructs [`Self`].
+    pub const fn new(duration: Duration) -> Self {
+        Self { duration }
+    }
+}
+
 /// Represents sources of errors that can occur when parsing [`Period`] values.
-#[derive(Debug, Error, Diagnostic)]
+#[derive(Debug, Clone, PartialEq, Eq, Error, Diagnostic)]
 #[error(transparent)]
 #[diagnostic(transparent)]
+#[non_exhaustive]
 pub enum ParseErrorSource {
     /// Invalid period value.
     Period(#[from] Error),
@@ -100,7 +127,7 @@ impl Serialize for Period {
 #[cfg(feature = "serde")]
 impl<'de> Deserialize<'de> for Period {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        let value = u64::deserialize(deserializer)?;
+        let value = int::deserialize_u64(deserializer)?;
 
         Self::new(value).map_err(de::Error::custom)
     }
@@ -109,19 +136,14 @@ impl<'de> Deserialize<'de> for Period {
 errors! {
     Type = ParseError,
     Hack = $,
-    int_error => int(error, string => to_owned),
-    period_error => period(error, string => to_owned),
+    parse_error => new(source, string => to_owned),
 }
 
 impl FromStr for Period {
     type Err = ParseError;
 
     fn from_str(string: &str) -> Result<Self, Self::Err> {
-        let value = string
-            .parse()
-            .map_err(|error| int_error!(int::wrap(error), string))?;
-
-        Self::new(value).map_err(|error| period_error!(error, string))
+        Self::parse_str(string).map_err(|source| parse_error!(source, string))
     }
 }
 
@@ -139,6 +161,20 @@ impl TryFrom<u64> for Period {
     }
 }
 
+impl TryFrom<Duration> for Period {
+    type Error = DurationError;
+
+    fn try_from(duration: Duration) -> Result<Self, Self::Error> {
+        Self::try_from_duration(duration)
+    }
+}
+
+impl From<Period> for Duration {
+    fn from(period: Period) -> Self {
+        period.as_duration()
+    }
+}
+
 impl From<Period> for u64 {
     fn from(period: Period) -> Self {
         period.get()
@@ -179,6 +215,24 @@ impl Period {
         const_ok!(Self::new(value))
     }
 
+    /// Parses [`Self`] from the given string without allocating on errors.
+    ///
+    /// Unlike [`from_str`], the error returned does not contain the input string.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseErrorSource`] if the string is not a valid integer
+    /// or if the value is not valid for [`Self`].
+    ///
+    /// [`from_str`]: FromStr::from_str
+    pub fn parse_str(string: &str) -> Result<Self, ParseErrorSource> {
+        let value = string.parse().map_err(int::wrap)?;
+
+        let period = Self::new(value)?;
+
+        Ok(period)
+    }
+
     /// Checks if the given value is valid for [`Self`].
     ///
     /// # Errors
@@ -208,14 +262,67 @@ impl Period {
         self.value
     }
 
+    /// Checks whether [`Self`] is equal to [`DEFAULT`](Self::DEFAULT).
+    pub const fn is_default(&self) -> bool {
+        self.get() == DEFAULT
+    }
+
     /// Returns the period as [`Duration`].
     pub const fn as_duration(self) -> Duration {
         Duration::from_secs(self.get())
     }
 
+    /// Constructs [`Self`] from the given duration, if possible.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DurationError`] if the duration is not a whole number of seconds
+    /// or is less than [`MIN`] seconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use otp_std::Period;
+    ///
+    /// let period = Period::try_from_duration(Duration::from_secs(30)).unwrap();
+    ///
+    /// assert_eq!(period, Period::DEFAULT);
+    ///
+    /// assert!(Period::try_from_duration(Duration::from_millis(500)).is_err());
+    /// ```
+    pub const fn try_from_duration(duration: Duration) -> Result<Self, DurationError> {
+        const_early!(duration.subsec_nanos() != 0 => DurationError::new(duration));
+
+        match Self::new(duration.as_secs()) {
+            Ok(period) => Ok(period),
+            Err(_) => Err(DurationError::new(duration)),
+        }
+    }
+
+    /// Multiplies the period by the given skew, returning the maximum drift tolerated.
+    ///
+    /// The multiplication saturates on overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use otp_std::{Period, Skew};
+    ///
+    /// let drift = Period::DEFAULT.mul_skew(Skew::new(2));
+    ///
+    /// assert_eq!(drift, Duration::from_secs(60));
+    /// ```
+    pub const fn mul_skew(self, skew: Skew) -> Duration {
+        Duration::from_secs(self.get().saturating_mul(skew.get()))
+    }
+
     /// The minimum [`Self`] value.
-    pub const MIN: Self = Self::new_ok(MIN).unwrap();
+    pub const MIN: Self = const_unwrap!(Self::new_ok(MIN));
 
     /// The default [`Self`] value.
-    pub const DEFAULT: Self = Self::new_ok(DEFAULT).unwrap();
+    pub const DEFAULT: Self = const_unwrap!(Self::new_ok(DEFAULT));
 }
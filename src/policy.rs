@@ -0,0 +1,311 @@
+//! Verification policies.
+//!
+//! Policies are composed of checks that are applied by [`Verifier`] on each verification:
+//!
+//! - replay guard, rejecting codes for inputs at or before the last accepted one;
+//! - rate limit, bounding the number of attempts per interval;
+//! - skew limit, bounding the skew used during verification;
+//! - time window, rejecting verification outside of the given time range.
+//!
+//! [`Verifier`]: crate::verifier::Verifier
+
+use miette::Diagnostic;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use thiserror::Error;
+
+use crate::{builder::Unset, macros::setters, skew::Skew};
+
+/// Represents time windows during which verification is allowed.
+///
+/// Both bounds are inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Window {
+    /// The time before which verification is not allowed.
+    pub not_before: Option<u64>,
+    /// The time after which verification is not allowed.
+    pub not_after: Option<u64>,
+}
+
+/// Represents builders of [`Window`] (see [`Window::builder`]).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WindowBuilder {
+    not_before: Option<u64>,
+    not_after: Option<u64>,
+}
+
+impl Window {
+    /// Starts building [`Window`].
+    pub fn builder() -> WindowBuilder {
+        WindowBuilder::default()
+    }
+}
+
+impl WindowBuilder {
+    setters! {
+        not_before, maybe_not_before: u64;
+        not_after, maybe_not_after: u64;
+    }
+
+    /// Finishes building [`Window`].
+    pub fn build(self) -> Window {
+        Window {
+            not_before: self.not_before,
+            not_after: self.not_after,
+        }
+    }
+}
+
+impl Window {
+    /// Checks whether the given time is contained in [`Self`].
+    pub const fn contains(&self, time: u64) -> bool {
+        let after_start = match self.not_before {
+            Some(not_before) => time >= not_before,
+            None => true,
+        };
+
+        let before_end = match self.not_after {
+            Some(not_after) => time <= not_after,
+            None => true,
+        };
+
+        after_start && before_end
+    }
+}
+
+/// Represents rate limits, allowing some amount of attempts per interval.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RateLimit {
+    /// The maximum number of attempts per interval.
+    pub attempts: u64,
+    /// The interval, in seconds.
+    pub interval: u64,
+}
+
+/// Represents builders of [`RateLimit`] (see [`RateLimit::builder`]).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RateLimitBuilder<A = Unset, I = Unset> {
+    attempts: A,
+    interval: I,
+}
+
+impl RateLimit {
+    /// Starts building [`RateLimit`].
+    pub fn builder() -> RateLimitBuilder {
+        RateLimitBuilder::default()
+    }
+}
+
+impl<I> RateLimitBuilder<Unset, I> {
+    /// Sets the `attempts` member.
+    pub fn attempts(self, attempts: u64) -> RateLimitBuilder<u64, I> {
+        RateLimitBuilder {
+            attempts,
+            interval: self.interval,
+        }
+    }
+}
+
+impl<A> RateLimitBuilder<A, Unset> {
+    /// Sets the `interval` member.
+    pub fn interval(self, interval: u64) -> RateLimitBuilder<A, u64> {
+        RateLimitBuilder {
+            attempts: self.attempts,
+            interval,
+        }
+    }
+}
+
+impl RateLimitBuilder<u64, u64> {
+    /// Finishes building [`RateLimit`].
+    pub fn build(self) -> RateLimit {
+        RateLimit {
+            attempts: self.attempts,
+            interval: self.interval,
+        }
+    }
+}
+
+impl RateLimit {
+    /// Returns the index of the interval containing the given time.
+    pub const fn interval_at(&self, time: u64) -> u64 {
+        match self.interval {
+            0 => time,
+            interval => time / interval,
+        }
+    }
+}
+
+/// Represents verification policies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Policy {
+    /// Whether to reject codes for inputs at or before the last accepted one.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub replay_guard: bool,
+    /// The rate limit to apply.
+    pub rate_limit: Option<RateLimit>,
+    /// The maximum skew to use.
+    pub skew_limit: Option<Skew>,
+    /// The time window to allow verification in.
+    pub window: Option<Window>,
+}
+
+/// Represents builders of [`Policy`] (see [`Policy::builder`]).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PolicyBuilder {
+    replay_guard: Option<bool>,
+    rate_limit: Option<RateLimit>,
+    skew_limit: Option<Skew>,
+    window: Option<Window>,
+}
+
+impl Policy {
+    /// Starts building [`Policy`].
+    pub fn builder() -> PolicyBuilder {
+        PolicyBuilder::default()
+    }
+}
+
+impl PolicyBuilder {
+    setters! {
+        replay_guard, maybe_replay_guard: bool;
+        rate_limit, maybe_rate_limit: RateLimit;
+        skew_limit, maybe_skew_limit: Skew;
+        window, maybe_window: Window;
+    }
+
+    /// Finishes building [`Policy`].
+    pub fn build(self) -> Policy {
+        Policy {
+            replay_guard: self.replay_guard.unwrap_or_default(),
+            rate_limit: self.rate_limit,
+            skew_limit: self.skew_limit,
+            window: self.window,
+        }
+    }
+}
+
+impl Policy {
+    /// Returns the skew limited according to [`Self`].
+    pub fn limit_skew(&self, skew: Skew) -> Skew {
+        self.skew_limit.map_or(skew, |limit| skew.limit(limit))
+    }
+
+    /// Checks whether the given time is allowed by [`Self`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WindowError`] if the time is outside of the window.
+    pub fn check_window(&self, time: u64) -> Result<(), WindowError> {
+        match self.window {
+            Some(window) if !window.contains(time) => Err(WindowError::new(time)),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Represents errors returned when verifying outside of the allowed time window.
+#[derive(Debug, Error, Diagnostic)]
+#[error("verification is not allowed at `{time}`")]
+#[diagnostic(
+    code(otp_std::policy::window),
+    help("make sure the time is within the allowed window")
+)]
+pub struct WindowError {
+    /// The time of the verification.
+    pub time: u64,
+}
+
+impl WindowError {
+    /// Constructs [`Self`].
+    pub const fn new(time: u64) -> Self {
+        Self { time }
+    }
+}
+
+/// Represents errors returned when the rate limit is exceeded.
+#[derive(Debug, Error, Diagnostic)]
+#[error("rate limit of `{attempts}` attempts exceeded")]
+#[diagnostic(
+    code(otp_std::policy::rate_limit),
+    help("wait for the next interval before trying again")
+)]
+pub struct RateLimitError {
+    /// The maximum number of attempts.
+    pub attempts: u64,
+}
+
+impl RateLimitError {
+    /// Constructs [`Self`].
+    pub const fn new(attempts: u64) -> Self {
+        Self { attempts }
+    }
+}
+
+/// Represents errors returned when codes are replayed.
+#[derive(Debug, Error, Diagnostic)]
+#[error("code for input `{input}` was replayed")]
+#[diagnostic(code(otp_std::policy::replay), help("codes can only be used once"))]
+pub struct ReplayError {
+    /// The input matched by the replayed code.
+    pub input: u64,
+}
+
+impl ReplayError {
+    /// Constructs [`Self`].
+    pub const fn new(input: u64) -> Self {
+        Self { input }
+    }
+}
+
+/// Represents sources of errors that can occur when applying policies.
+#[derive(Debug, Error, Diagnostic)]
+#[error(transparent)]
+#[diagnostic(transparent)]
+#[non_exhaustive]
+pub enum ErrorSource {
+    /// Verification outside of the allowed time window.
+    Window(#[from] WindowError),
+    /// The rate limit was exceeded.
+    RateLimit(#[from] RateLimitError),
+    /// The code was replayed.
+    Replay(#[from] ReplayError),
+}
+
+/// Represents errors that can occur when applying policies.
+#[derive(Debug, Error, Diagnostic)]
+#[error("verification rejected by policy")]
+#[diagnostic(code(otp_std::policy), help("see the report for more information"))]
+pub struct Error {
+    /// The source of this error.
+    #[source]
+    #[diagnostic_source]
+    pub source: ErrorSource,
+}
+
+impl Error {
+    /// Constructs [`Self`].
+    pub const fn new(source: ErrorSource) -> Self {
+        Self { source }
+    }
+
+    /// Constructs [`Self`] from [`WindowError`].
+    pub fn window(error: WindowError) -> Self {
+        Self::new(error.into())
+    }
+
+    /// Constructs [`Self`] from [`RateLimitError`].
+    pub fn rate_limit(error: RateLimitError) -> Self {
+        Self::new(error.into())
+    }
+
+    /// Constructs [`Self`] from [`ReplayError`].
+    pub fn replay(error: ReplayError) -> Self {
+        Self::new(error.into())
+    }
+}
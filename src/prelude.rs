@@ -0,0 +1,73 @@
+//! Commonly used items.
+//!
+//! This module re-exports the items most applications need, allowing them to be imported at once:
+//!
+//! ```
+//! use otp_std::prelude::*;
+//!
+//! let secret = Secret::decode("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+//!
+//! let base = Base::builder().secret(secret).digits(Digits::DEFAULT).build();
+//! let totp = Totp::builder().base(base).skew(Skew::disabled()).build();
+//!
+//! let code = totp.generate_string_at(1_234_567_890);
+//!
+//! assert!(totp.verify_string_at(1_234_567_890, code));
+//! ```
+//!
+//! Traits are re-exported as well, so their methods are available on trait objects:
+//!
+//! ```
+//! use otp_std::prelude::*;
+//!
+//! let secret = Secret::decode("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+//!
+//! let base = Base::builder().secret(secret).build();
+//! let totp = Totp::builder().base(base).build();
+//!
+//! let code = totp.generate_string();
+//!
+//! let verifier: Box<dyn OtpVerify + Send + Sync> = Box::new(Verifier::new(totp));
+//!
+//! assert!(verifier.verify_now(&code).is_valid());
+//! ```
+//!
+//! Clocks can be injected via [`Clock`] implementations:
+//!
+//! ```
+//! use otp_std::prelude::*;
+//!
+//! let secret = Secret::decode("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+//!
+//! let base = Base::builder().secret(secret).build();
+//! let totp = Totp::builder().base(base).build();
+//!
+//! let code = totp.generate_with(&SystemClock);
+//!
+//! assert!(totp.verify_at(SystemClock.expect_now(), code));
+//! ```
+
+pub use crate::{
+    algorithm::Algorithm,
+    base::Base,
+    clock::{Clock, SystemClock},
+    config::Config,
+    counter::Counter,
+    digits::Digits,
+    hotp::Hotp,
+    limit::SkewLimit,
+    look_ahead::LookAhead,
+    options::VerifyOptions,
+    otp::{Otp, Type},
+    period::Period,
+    policy::Policy,
+    profile::Profile,
+    secret::{Length, Secret},
+    skew::Skew,
+    totp::Totp,
+    verifier::Verifier,
+    verify::{OtpVerify, VerifyOutcome},
+};
+
+#[cfg(feature = "auth")]
+pub use crate::auth::{Auth, Label, Part};
@@ -0,0 +1,178 @@
+//! Prepared HMAC keys.
+//!
+//! Computing HMAC values involves hashing the padded key before the actual data.
+//! [`Prepared`] keys perform this step once, so that verifying many codes
+//! against the same secret (see [`Totp::verify_batch`]) only hashes the inputs.
+//!
+//! Note that the prepared state is derived from the key, and is not scrubbed when dropped.
+//!
+//! [`Totp::verify_batch`]: crate::totp::Totp::verify_batch
+
+use std::fmt;
+
+use crate::{
+    algorithm::{hmac_into, new_hmac_sha1, Algorithm, HmacBuffer, HmacSha1},
+    base::truncate,
+    digits::Digits,
+    output::{compute, AnyOutput},
+};
+
+#[cfg(feature = "sha2")]
+use crate::algorithm::{new_hmac_sha256, new_hmac_sha512, HmacSha256, HmacSha512};
+
+#[cfg(feature = "sha3")]
+use crate::algorithm::{new_hmac_sha3_256, new_hmac_sha3_512, HmacSha3_256, HmacSha3_512};
+
+#[cfg(feature = "blake2")]
+use crate::algorithm::{new_hmac_blake2b, new_hmac_blake2s, HmacBlake2b, HmacBlake2s};
+
+/// Represents HMAC states keyed once and reused for multiple inputs.
+#[derive(Clone)]
+pub enum Prepared {
+    /// SHA-1 state.
+    Sha1(HmacSha1),
+    /// SHA-256 state.
+    #[cfg(feature = "sha2")]
+    Sha256(HmacSha256),
+    /// SHA-512 state.
+    #[cfg(feature = "sha2")]
+    Sha512(HmacSha512),
+    /// SHA3-256 state.
+    #[cfg(feature = "sha3")]
+    Sha3_256(HmacSha3_256),
+    /// SHA3-512 state.
+    #[cfg(feature = "sha3")]
+    Sha3_512(HmacSha3_512),
+    /// BLAKE2s state.
+    #[cfg(feature = "blake2")]
+    Blake2s(HmacBlake2s),
+    /// BLAKE2b state.
+    #[cfg(feature = "blake2")]
+    Blake2b(HmacBlake2b),
+}
+
+impl fmt::Debug for Prepared {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("Prepared")
+            .field("algorithm", &self.algorithm())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Prepared {
+    /// Prepares the given key for the given algorithm.
+    pub fn new<K: AsRef<[u8]>>(algorithm: Algorithm, key: K) -> Self {
+        match algorithm {
+            Algorithm::Sha1 => Self::Sha1(new_hmac_sha1(key)),
+            #[cfg(feature = "sha2")]
+            Algorithm::Sha256 => Self::Sha256(new_hmac_sha256(key)),
+            #[cfg(feature = "sha2")]
+            Algorithm::Sha512 => Self::Sha512(new_hmac_sha512(key)),
+            #[cfg(feature = "sha3")]
+            Algorithm::Sha3_256 => Self::Sha3_256(new_hmac_sha3_256(key)),
+            #[cfg(feature = "sha3")]
+            Algorithm::Sha3_512 => Self::Sha3_512(new_hmac_sha3_512(key)),
+            #[cfg(feature = "blake2")]
+            Algorithm::Blake2s => Self::Blake2s(new_hmac_blake2s(key)),
+            #[cfg(feature = "blake2")]
+            Algorithm::Blake2b => Self::Blake2b(new_hmac_blake2b(key)),
+        }
+    }
+
+    /// Returns the algorithm of [`Self`].
+    pub const fn algorithm(&self) -> Algorithm {
+        match self {
+            Self::Sha1(_) => Algorithm::Sha1,
+            #[cfg(feature = "sha2")]
+            Self::Sha256(_) => Algorithm::Sha256,
+            #[cfg(feature = "sha2")]
+            Self::Sha512(_) => Algorithm::Sha512,
+            #[cfg(feature = "sha3")]
+            Self::Sha3_256(_) => Algorithm::Sha3_256,
+            #[cfg(feature = "sha3")]
+            Self::Sha3_512(_) => Algorithm::Sha3_512,
+            #[cfg(feature = "blake2")]
+            Self::Blake2s(_) => Algorithm::Blake2s,
+            #[cfg(feature = "blake2")]
+            Self::Blake2b(_) => Algorithm::Blake2b,
+        }
+    }
+
+    /// Computes the HMAC of the given data, writing it into the given buffer.
+    ///
+    /// Returns the length of the HMAC value written.
+    pub fn hmac_into<D: AsRef<[u8]>>(&self, data: D, buffer: &mut HmacBuffer) -> usize {
+        match self {
+            Self::Sha1(mac) => hmac_into(mac.clone(), data, buffer),
+            #[cfg(feature = "sha2")]
+            Self::Sha256(mac) => hmac_into(mac.clone(), data, buffer),
+            #[cfg(feature = "sha2")]
+            Self::Sha512(mac) => hmac_into(mac.clone(), data, buffer),
+            #[cfg(feature = "sha3")]
+            Self::Sha3_256(mac) => hmac_into(mac.clone(), data, buffer),
+            #[cfg(feature = "sha3")]
+            Self::Sha3_512(mac) => hmac_into(mac.clone(), data, buffer),
+            #[cfg(feature = "blake2")]
+            Self::Blake2s(mac) => hmac_into(mac.clone(), data, buffer),
+            #[cfg(feature = "blake2")]
+            Self::Blake2b(mac) => hmac_into(mac.clone(), data, buffer),
+        }
+    }
+
+    /// Computes the HMAC of the given data, returning [`AnyOutput`].
+    pub fn compute<D: AsRef<[u8]>>(&self, data: D) -> AnyOutput {
+        match self {
+            Self::Sha1(mac) => AnyOutput::Sha1(compute(mac.clone(), data)),
+            #[cfg(feature = "sha2")]
+            Self::Sha256(mac) => AnyOutput::Sha256(compute(mac.clone(), data)),
+            #[cfg(feature = "sha2")]
+            Self::Sha512(mac) => AnyOutput::Sha512(compute(mac.clone(), data)),
+            #[cfg(feature = "sha3")]
+            Self::Sha3_256(mac) => AnyOutput::Sha3_256(compute(mac.clone(), data)),
+            #[cfg(feature = "sha3")]
+            Self::Sha3_512(mac) => AnyOutput::Sha3_512(compute(mac.clone(), data)),
+            #[cfg(feature = "blake2")]
+            Self::Blake2s(mac) => AnyOutput::Blake2s(compute(mac.clone(), data)),
+            #[cfg(feature = "blake2")]
+            Self::Blake2b(mac) => AnyOutput::Blake2b(compute(mac.clone(), data)),
+        }
+    }
+
+    /// Generates the code for the given input and digits.
+    ///
+    /// Unlike [`generate_into`], this method never panics (see [`AnyOutput::truncate`]).
+    ///
+    /// [`generate_into`]: Self::generate_into
+    pub fn generate(&self, input: u64, digits: Digits) -> u32 {
+        self.compute(input.to_be_bytes()).truncate(digits)
+    }
+
+    /// Generates the code for the given input and digits, using the given buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{base::generate, prepared::Prepared, Algorithm, Digits};
+    ///
+    /// let prepared = Prepared::new(Algorithm::Sha1, b"key");
+    ///
+    /// let mut buffer = [0; 64];
+    ///
+    /// let code = prepared.generate_into(13, Digits::default(), &mut buffer);
+    ///
+    /// assert_eq!(code, generate(b"key", 13, Digits::default(), Algorithm::Sha1));
+    /// ```
+    pub fn generate_into(&self, input: u64, digits: Digits, buffer: &mut HmacBuffer) -> u32 {
+        let length = self.hmac_into(input.to_be_bytes(), buffer);
+
+        truncate(&buffer[..length], digits)
+    }
+}
+
+impl Algorithm {
+    /// Prepares the given key for [`Self`] (see [`Prepared`]).
+    pub fn prepare<K: AsRef<[u8]>>(self, key: K) -> Prepared {
+        Prepared::new(self, key)
+    }
+}
@@ -0,0 +1,348 @@
+//! Printable enrollment sheets.
+//!
+//! Enrolling accounts on behalf of users (for instance, by IT departments) often involves
+//! handing out sheets containing the QR code, the secret grouped for manual entry
+//! and the recovery codes. [`Sheet`] renders such sheets as SVG documents, which can be
+//! printed as-is or converted to PDF.
+//!
+//! Note that sheets reveal secrets, so they should be handled accordingly.
+
+use std::fmt;
+
+use miette::Diagnostic;
+use qrcode::{types::QrError, Color, EcLevel, QrCode};
+use thiserror::Error;
+
+use crate::{
+    auth::{core::Auth, writer::WRITING_NEVER_FAILS},
+    otp::core::Otp,
+};
+
+/// The number of characters in secret groups.
+pub const GROUP_SIZE: usize = 4;
+
+/// The separator used between secret groups.
+pub const GROUP_SEPARATOR: char = ' ';
+
+/// The width of sheets, matching the width of A4 paper in points.
+pub const WIDTH: usize = 595;
+
+/// The margin of sheets.
+pub const MARGIN: usize = 48;
+
+/// The maximum size of QR codes.
+pub const QR_SIZE: usize = 240;
+
+/// The size of the quiet zone around QR codes, in modules.
+pub const QUIET_ZONE: usize = 4;
+
+/// The distance between recovery code columns.
+pub const COLUMN_GAP: usize = 180;
+
+/// The offset of titles.
+pub const TITLE_OFFSET: usize = 24;
+
+/// The offset of users, relative to titles.
+pub const USER_OFFSET: usize = 28;
+
+/// The height of lines.
+pub const LINE_HEIGHT: usize = 24;
+
+/// The gap between sections.
+pub const SECTION_GAP: usize = 32;
+
+/// The title used when the issuer is missing.
+pub const DEFAULT_TITLE: &str = "One-Time Password";
+
+/// Represents errors that can occur when QR codes can not be encoded.
+#[derive(Debug, Error, Diagnostic)]
+#[error("failed to encode QR code")]
+#[diagnostic(
+    code(otp_std::print),
+    help("make sure the OTP URL is short enough to fit into QR codes")
+)]
+pub struct Error {
+    /// The source of this error.
+    #[source]
+    pub source: QrError,
+}
+
+impl Error {
+    /// Constructs [`Self`].
+    pub const fn new(source: QrError) -> Self {
+        Self { source }
+    }
+}
+
+/// Groups the given string for manual entry, for instance, `JEQD YMZA N5YG K3RA ONXX K4TD MU`.
+pub fn group(string: &str) -> String {
+    let mut grouped = String::with_capacity(string.len() + string.len() / GROUP_SIZE);
+
+    for (index, character) in string.chars().enumerate() {
+        if index > 0 && index % GROUP_SIZE == 0 {
+            grouped.push(GROUP_SEPARATOR);
+        };
+
+        grouped.push(character);
+    }
+
+    grouped
+}
+
+/// Escapes the given string for inclusion in XML documents.
+pub fn escape(string: &str) -> String {
+    let mut escaped = String::with_capacity(string.len());
+
+    for character in string.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(character),
+        }
+    }
+
+    escaped
+}
+
+/// Represents printable enrollment sheets.
+///
+/// # Examples
+///
+/// ```
+/// use otp_std::{print::Sheet, Auth, Base, Label, Part, Secret, Totp};
+///
+/// let secret = Secret::decode("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+///
+/// let base = Base::builder().secret(secret).build();
+///
+/// let totp = Totp::builder().base(base).build();
+///
+/// let label = Label::builder()
+///     .issuer(Part::borrowed("MelodyKit").unwrap())
+///     .user(Part::borrowed("nekitdev").unwrap())
+///     .build();
+///
+/// let auth = Auth::builder().otp(totp).label(label).build();
+///
+/// let sheet = Sheet::new(&auth, vec!["1234-5678".to_owned()]);
+///
+/// let svg = sheet.render().unwrap();
+///
+/// assert!(svg.contains("JEQD YMZA N5YG K3RA ONXX K4TD MU"));
+/// assert!(svg.contains("1234-5678"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Sheet<'s> {
+    /// The authentication to enroll.
+    pub auth: &'s Auth<'s>,
+    /// The recovery codes to include.
+    pub recovery_codes: Vec<String>,
+}
+
+impl<'s> Sheet<'s> {
+    /// Constructs [`Self`].
+    pub const fn new(auth: &'s Auth<'s>, recovery_codes: Vec<String>) -> Self {
+        Self {
+            auth,
+            recovery_codes,
+        }
+    }
+}
+
+impl Sheet<'_> {
+    /// Returns the title of [`Self`], which is the issuer or [`DEFAULT_TITLE`] if it is missing.
+    pub fn title(&self) -> &str {
+        self.auth
+            .label()
+            .issuer
+            .as_ref()
+            .map_or(DEFAULT_TITLE, |issuer| issuer.as_str())
+    }
+
+    /// Returns the human-readable description of the OTP parameters.
+    pub fn parameters(&self) -> String {
+        let otp = self.auth.otp();
+
+        let base = otp.base();
+
+        let type_of = otp.type_of().static_str().to_ascii_uppercase();
+
+        let specific = match otp {
+            Otp::Hotp(hotp) => format!("counter {counter}", counter = hotp.counter),
+            Otp::Totp(totp) => format!("{period} seconds", period = totp.period),
+        };
+
+        format!(
+            "{type_of}, {algorithm}, {digits} digits, {specific}",
+            algorithm = base.algorithm,
+            digits = base.digits,
+        )
+    }
+
+    /// Writes [`Self`] as the SVG document into the given writer, using the QR code provided.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`fmt::Error`] if writing fails.
+    pub fn write_svg<W: fmt::Write>(&self, code: &QrCode, writer: &mut W) -> fmt::Result {
+        let width = code.width();
+
+        let modules = width + 2 * QUIET_ZONE;
+
+        let scale = (QR_SIZE / modules).max(1);
+
+        let qr_size = modules * scale;
+
+        let center = WIDTH / 2;
+
+        let height = self.height(qr_size);
+
+        write!(
+            writer,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{WIDTH}" height="{height}" viewBox="0 0 {WIDTH} {height}" font-family="sans-serif">"#
+        )?;
+
+        writer.write_str(r#"<rect width="100%" height="100%" fill="white"/>"#)?;
+
+        let mut y = MARGIN + TITLE_OFFSET;
+
+        write!(
+            writer,
+            r#"<text x="{center}" y="{y}" font-size="24" font-weight="bold" text-anchor="middle">{title}</text>"#,
+            title = escape(self.title()),
+        )?;
+
+        y += USER_OFFSET;
+
+        write!(
+            writer,
+            r#"<text x="{center}" y="{y}" font-size="16" text-anchor="middle">{user}</text>"#,
+            user = escape(self.auth.label().user.as_str()),
+        )?;
+
+        y += LINE_HEIGHT;
+
+        write!(
+            writer,
+            r#"<path transform="translate({x} {y}) scale({scale})" fill="black" d=""#,
+            x = center - qr_size / 2,
+        )?;
+
+        for (index, color) in code.to_colors().into_iter().enumerate() {
+            if color == Color::Dark {
+                let x = index % width + QUIET_ZONE;
+                let y = index / width + QUIET_ZONE;
+
+                write!(writer, "M{x} {y}h1v1h-1z")?;
+            };
+        }
+
+        writer.write_str(r#""/>"#)?;
+
+        y += qr_size + SECTION_GAP;
+
+        write!(
+            writer,
+            r#"<text x="{center}" y="{y}" font-size="12" text-anchor="middle">Manual entry</text>"#,
+        )?;
+
+        y += LINE_HEIGHT;
+
+        write!(
+            writer,
+            r#"<text x="{center}" y="{y}" font-size="16" font-family="monospace" text-anchor="middle">{secret}</text>"#,
+            secret = group(&self.auth.otp().base().secret.expose_encoded()),
+        )?;
+
+        y += LINE_HEIGHT;
+
+        write!(
+            writer,
+            r#"<text x="{center}" y="{y}" font-size="12" text-anchor="middle">{parameters}</text>"#,
+            parameters = escape(&self.parameters()),
+        )?;
+
+        if !self.recovery_codes.is_empty() {
+            y += 2 * LINE_HEIGHT;
+
+            write!(
+                writer,
+                r#"<text x="{center}" y="{y}" font-size="16" font-weight="bold" text-anchor="middle">Recovery codes</text>"#,
+            )?;
+
+            for (index, recovery_code) in self.recovery_codes.iter().enumerate() {
+                let x = if index % 2 == 0 {
+                    y += LINE_HEIGHT;
+
+                    center - COLUMN_GAP / 2
+                } else {
+                    center + COLUMN_GAP / 2
+                };
+
+                write!(
+                    writer,
+                    r#"<text x="{x}" y="{y}" font-size="14" font-family="monospace" text-anchor="middle">{code}</text>"#,
+                    code = escape(recovery_code),
+                )?;
+            }
+
+            y += SECTION_GAP;
+
+            write!(
+                writer,
+                r#"<text x="{center}" y="{y}" font-size="12" text-anchor="middle">Each recovery code can be used once.</text>"#,
+            )?;
+        };
+
+        writer.write_str("</svg>")
+    }
+
+    fn height(&self, qr_size: usize) -> usize {
+        let mut height = MARGIN + TITLE_OFFSET + USER_OFFSET + LINE_HEIGHT;
+
+        height += qr_size + SECTION_GAP + 2 * LINE_HEIGHT;
+
+        if !self.recovery_codes.is_empty() {
+            let rows = self.recovery_codes.len().div_ceil(2);
+
+            height += 2 * LINE_HEIGHT + rows * LINE_HEIGHT + SECTION_GAP;
+        };
+
+        height + MARGIN
+    }
+
+    /// Renders [`Self`] as the SVG document.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`struct@Error`] if the OTP URL does not fit into QR codes.
+    ///
+    /// # Panics
+    ///
+    /// This method can not panic because writing to strings never fails.
+    pub fn render(&self) -> Result<String, Error> {
+        let code = QrCode::with_error_correction_level(self.auth.to_qr_data(), EcLevel::M)
+            .map_err(Error::new)?;
+
+        let mut string = String::new();
+
+        self.write_svg(&code, &mut string)
+            .expect(WRITING_NEVER_FAILS);
+
+        Ok(string)
+    }
+}
+
+impl Auth<'_> {
+    /// Renders the enrollment sheet with the given recovery codes (see [`Sheet::render`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`struct@Error`] if the OTP URL does not fit into QR codes.
+    pub fn render_sheet(&self, recovery_codes: Vec<String>) -> Result<String, Error> {
+        Sheet::new(self, recovery_codes).render()
+    }
+}
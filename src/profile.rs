@@ -0,0 +1,246 @@
+//! Validation profiles.
+//!
+//! Profiles restrict configurations beyond what is needed for them to function.
+//! The [`Profile::StrictRfc`] profile, for instance, rejects everything outside the canonical
+//! parameters of [RFC 6238], which is useful for compliance-audited deployments.
+//!
+//! [RFC 6238]: https://datatracker.ietf.org/doc/html/rfc6238
+
+use std::fmt;
+
+use miette::Diagnostic;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use thiserror::Error;
+
+use crate::{algorithm::Algorithm, base::Base, period::Period, totp::Totp};
+
+/// The `permissive` literal.
+pub const PERMISSIVE: &str = "permissive";
+
+/// The `strict-rfc` literal.
+pub const STRICT_RFC: &str = "strict-rfc";
+
+/// The algorithm required by [`Profile::StrictRfc`].
+pub const STRICT_RFC_ALGORITHM: Algorithm = Algorithm::Sha1;
+
+/// The period required by [`Profile::StrictRfc`].
+pub const STRICT_RFC_PERIOD: Period = Period::DEFAULT;
+
+/// Represents validation profiles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+pub enum Profile {
+    /// Accepts any configuration that passes regular validation.
+    #[default]
+    Permissive,
+    /// Accepts only the canonical parameters of RFC 6238, that is, SHA-1 and 30-second periods.
+    StrictRfc,
+}
+
+/// Represents deviations of configurations from profiles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Violation {
+    /// The algorithm is not the one required.
+    Algorithm {
+        /// The algorithm used.
+        actual: Algorithm,
+        /// The algorithm required.
+        expected: Algorithm,
+    },
+    /// The period is not the one required.
+    Period {
+        /// The period used.
+        actual: Period,
+        /// The period required.
+        expected: Period,
+    },
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Algorithm { actual, expected } => {
+                write!(formatter, "algorithm `{actual}` (expected `{expected}`)")
+            }
+            Self::Period { actual, expected } => {
+                write!(formatter, "period `{actual}` (expected `{expected}`)")
+            }
+        }
+    }
+}
+
+/// Represents lists of violations.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Violations {
+    /// The violations.
+    pub items: Vec<Violation>,
+}
+
+impl Violations {
+    /// Constructs [`Self`].
+    pub const fn new(items: Vec<Violation>) -> Self {
+        Self { items }
+    }
+
+    /// Checks whether [`Self`] is empty.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+impl fmt::Display for Violations {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut iterator = self.items.iter();
+
+        if let Some(first) = iterator.next() {
+            first.fmt(formatter)?;
+
+            for violation in iterator {
+                write!(formatter, ", {violation}")?;
+            }
+        };
+
+        Ok(())
+    }
+}
+
+/// Represents errors returned when configurations violate profiles.
+#[derive(Debug, Error, Diagnostic)]
+#[error("configuration violates the `{profile}` profile: {violations}")]
+#[diagnostic(
+    code(otp_std::profile),
+    help("use the parameters required by the profile")
+)]
+pub struct Error {
+    /// The profile violated.
+    pub profile: Profile,
+    /// The violations found.
+    pub violations: Violations,
+}
+
+impl Error {
+    /// Constructs [`Self`].
+    pub const fn new(profile: Profile, violations: Violations) -> Self {
+        Self {
+            profile,
+            violations,
+        }
+    }
+}
+
+impl Profile {
+    /// Returns the static string representation of [`Self`].
+    pub const fn static_str(self) -> &'static str {
+        match self {
+            Self::Permissive => PERMISSIVE,
+            Self::StrictRfc => STRICT_RFC,
+        }
+    }
+
+    /// Checks whether [`Self`] is [`StrictRfc`].
+    ///
+    /// [`StrictRfc`]: Self::StrictRfc
+    pub const fn is_strict_rfc(self) -> bool {
+        matches!(self, Self::StrictRfc)
+    }
+
+    fn base_into(self, base: &Base<'_>, items: &mut Vec<Violation>) {
+        if self.is_strict_rfc() && base.algorithm != STRICT_RFC_ALGORITHM {
+            items.push(Violation::Algorithm {
+                actual: base.algorithm,
+                expected: STRICT_RFC_ALGORITHM,
+            });
+        };
+    }
+
+    fn totp_into(self, totp: &Totp<'_>, items: &mut Vec<Violation>) {
+        self.base_into(totp.base(), items);
+
+        if self.is_strict_rfc() && totp.period != STRICT_RFC_PERIOD {
+            items.push(Violation::Period {
+                actual: totp.period,
+                expected: STRICT_RFC_PERIOD,
+            });
+        };
+    }
+
+    /// Returns the violations of [`Self`] by the given base configuration.
+    pub fn base_violations(self, base: &Base<'_>) -> Violations {
+        let mut items = Vec::new();
+
+        self.base_into(base, &mut items);
+
+        Violations::new(items)
+    }
+
+    /// Returns the violations of [`Self`] by the given TOTP configuration.
+    pub fn totp_violations(self, totp: &Totp<'_>) -> Violations {
+        let mut items = Vec::new();
+
+        self.totp_into(totp, &mut items);
+
+        Violations::new(items)
+    }
+
+    /// Enforces [`Self`] on the given base configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`struct@Error`] listing each violation, if there are any.
+    pub fn enforce_base(self, base: &Base<'_>) -> Result<(), Error> {
+        self.check(self.base_violations(base))
+    }
+
+    /// Enforces [`Self`] on the given TOTP configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`struct@Error`] listing each violation, if there are any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{Algorithm, Base, Period, Profile, Secret, Totp};
+    ///
+    /// let secret = Secret::generate_for(Algorithm::Sha256);
+    ///
+    /// let base = Base::builder()
+    ///     .secret(secret)
+    ///     .algorithm(Algorithm::Sha256)
+    ///     .build();
+    ///
+    /// let period = Period::new(60).unwrap();
+    ///
+    /// let totp = Totp::builder().base(base).period(period).build();
+    ///
+    /// assert!(Profile::Permissive.enforce_totp(&totp).is_ok());
+    ///
+    /// let error = Profile::StrictRfc.enforce_totp(&totp).unwrap_err();
+    ///
+    /// assert_eq!(error.violations.items.len(), 2);
+    /// ```
+    pub fn enforce_totp(self, totp: &Totp<'_>) -> Result<(), Error> {
+        self.check(self.totp_violations(totp))
+    }
+
+    fn check(self, violations: Violations) -> Result<(), Error> {
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::new(self, violations))
+        }
+    }
+}
+
+impl fmt::Display for Profile {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.static_str().fmt(formatter)
+    }
+}
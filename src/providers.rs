@@ -0,0 +1,162 @@
+//! Issuer icon resolution.
+//!
+//! This module maps well-known issuers to their favicons and provides [`fetch_icon`],
+//! which fetches icons using the given [`Fetch`] implementation and stores them in the [`Cache`].
+//!
+//! The crate does not perform any networking on its own; the transport is up to the caller.
+
+use std::{collections::HashMap, future::Future, sync::Mutex};
+
+/// Represents well-known `(issuer, domain)` pairs.
+///
+/// Issuers are stored in lowercase, see [`domain_for`] for more information.
+pub const WELL_KNOWN: &[(&str, &str)] = &[
+    ("amazon", "amazon.com"),
+    ("apple", "apple.com"),
+    ("atlassian", "atlassian.com"),
+    ("binance", "binance.com"),
+    ("bitbucket", "bitbucket.org"),
+    ("cloudflare", "cloudflare.com"),
+    ("coinbase", "coinbase.com"),
+    ("digitalocean", "digitalocean.com"),
+    ("discord", "discord.com"),
+    ("dropbox", "dropbox.com"),
+    ("facebook", "facebook.com"),
+    ("github", "github.com"),
+    ("gitlab", "gitlab.com"),
+    ("google", "google.com"),
+    ("instagram", "instagram.com"),
+    ("linkedin", "linkedin.com"),
+    ("microsoft", "microsoft.com"),
+    ("npm", "npmjs.com"),
+    ("paypal", "paypal.com"),
+    ("reddit", "reddit.com"),
+    ("slack", "slack.com"),
+    ("twitch", "twitch.tv"),
+    ("twitter", "twitter.com"),
+];
+
+/// The path of favicons.
+pub const FAVICON: &str = "favicon.ico";
+
+/// Returns the domain of the given issuer, provided it is well-known.
+///
+/// The issuer is compared case-insensitively.
+pub fn domain_for<S: AsRef<str>>(issuer: S) -> Option<&'static str> {
+    fn domain_for_inner(issuer: &str) -> Option<&'static str> {
+        let lowercase = issuer.trim().to_lowercase();
+
+        WELL_KNOWN
+            .iter()
+            .find(|(name, _)| *name == lowercase)
+            .map(|(_, domain)| *domain)
+    }
+
+    domain_for_inner(issuer.as_ref())
+}
+
+/// Returns the favicon URL of the given domain.
+pub fn icon_url(domain: &str) -> String {
+    format!("https://{domain}/{FAVICON}")
+}
+
+/// Returns the favicon URL of the given issuer, provided it is well-known.
+pub fn icon_url_for<S: AsRef<str>>(issuer: S) -> Option<String> {
+    domain_for(issuer).map(icon_url)
+}
+
+/// Represents issuer icons.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Icon {
+    /// The URL the icon was fetched from.
+    pub url: String,
+    /// The icon data.
+    pub data: Vec<u8>,
+}
+
+impl Icon {
+    /// Constructs [`Self`].
+    pub const fn new(url: String, data: Vec<u8>) -> Self {
+        Self { url, data }
+    }
+}
+
+/// Represents icon transports.
+pub trait Fetch {
+    /// Fetches the data located at the given URL, returning [`None`] on failure.
+    fn fetch(&self, url: &str) -> impl Future<Output = Option<Vec<u8>>>;
+}
+
+/// Represents icon caches, keyed by domains (see [`domain_for`]).
+pub trait Cache {
+    /// Returns the cached icon for the given domain, if any.
+    fn get(&self, domain: &str) -> Option<Icon>;
+
+    /// Caches the icon for the given domain.
+    fn insert(&self, domain: &str, icon: Icon);
+}
+
+/// Represents caches that do not store anything.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct NoCache;
+
+impl Cache for NoCache {
+    fn get(&self, _domain: &str) -> Option<Icon> {
+        None
+    }
+
+    fn insert(&self, _domain: &str, _icon: Icon) {}
+}
+
+/// Represents in-memory caches.
+#[derive(Debug, Default)]
+pub struct MemoryCache {
+    icons: Mutex<HashMap<String, Icon>>,
+}
+
+impl MemoryCache {
+    /// Constructs [`Self`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// The message used for poisoned cache panics.
+const POISONED: &str = "icon cache is poisoned";
+
+impl Cache for MemoryCache {
+    fn get(&self, domain: &str) -> Option<Icon> {
+        self.icons.lock().expect(POISONED).get(domain).cloned()
+    }
+
+    fn insert(&self, domain: &str, icon: Icon) {
+        self.icons
+            .lock()
+            .expect(POISONED)
+            .insert(domain.to_owned(), icon);
+    }
+}
+
+/// Fetches the icon of the given issuer, consulting the cache first.
+///
+/// The cache is keyed by domains, so issuers resolving to the same domain
+/// (for instance, `GitHub` and ` github`) share their icons.
+///
+/// Returns [`None`] if the issuer is not well-known or if fetching fails.
+pub async fn fetch_icon<F: Fetch, C: Cache>(fetch: &F, cache: &C, issuer: &str) -> Option<Icon> {
+    let domain = domain_for(issuer)?;
+
+    if let Some(icon) = cache.get(domain) {
+        return Some(icon);
+    };
+
+    let url = icon_url(domain);
+
+    let data = fetch.fetch(url.as_str()).await?;
+
+    let icon = Icon::new(url, data);
+
+    cache.insert(domain, icon.clone());
+
+    Some(icon)
+}
@@ -0,0 +1,155 @@
+//! Verification receipts.
+//!
+//! Applications often need to record proofs of successful verifications, for instance,
+//! for auditing or replay prevention. [`VerifiedAt`] standardizes such proofs, recording
+//! the time step the code matched, the end of its period and the skew offset used.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    replay::UsedCodeStore,
+    time::{self, current, expect_current},
+    totp::{MatchedWindow, Totp},
+};
+
+/// Represents receipts of successful verifications.
+///
+/// See [`Totp::verify_receipt_at`] for more information.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VerifiedAt {
+    /// The time step (input) the code matched.
+    pub timestep: u64,
+    /// The time the period of the matched time step ends at.
+    pub period_end: u64,
+    /// The offset of the matched time step relative to the current one, in periods.
+    ///
+    /// Negative offsets mean that the client clock is behind, and positive ones
+    /// mean that it is ahead.
+    pub skew_offset: i64,
+}
+
+impl VerifiedAt {
+    /// Constructs [`Self`].
+    pub const fn new(timestep: u64, period_end: u64, skew_offset: i64) -> Self {
+        Self {
+            timestep,
+            period_end,
+            skew_offset,
+        }
+    }
+
+    /// Checks whether the code matched the current time step exactly.
+    pub const fn is_exact(&self) -> bool {
+        self.skew_offset == 0
+    }
+
+    /// Checks whether the period of the matched time step has ended at the given time.
+    pub const fn is_expired_at(&self, time: u64) -> bool {
+        time >= self.period_end
+    }
+
+    /// Checks whether the matched time step was already used according to the given store.
+    pub fn is_used_in<S: UsedCodeStore + ?Sized>(&self, store: &S) -> bool {
+        store.is_used(self.timestep)
+    }
+
+    /// Records the matched time step as accepted in the given store.
+    pub fn record_in<S: UsedCodeStore + ?Sized>(&self, store: &mut S) {
+        store.record(self.timestep);
+    }
+}
+
+impl Totp<'_> {
+    /// Returns the receipt for the given matched window.
+    pub fn receipt_for(&self, window: MatchedWindow) -> VerifiedAt {
+        let period_end = window
+            .input
+            .saturating_add(1)
+            .saturating_mul(self.period.get());
+
+        VerifiedAt::new(window.input, period_end, window.offset)
+    }
+
+    /// Verifies the given code for the given time, accounting for *skews*,
+    /// and returns the receipt on success.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{Base, Secret, Totp};
+    ///
+    /// let secret = Secret::decode("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+    ///
+    /// let base = Base::builder().secret(secret).build();
+    ///
+    /// let totp = Totp::builder().base(base).build();
+    ///
+    /// let time = 1_234_567_890;
+    ///
+    /// let code = totp.generate_at(time);
+    ///
+    /// let receipt = totp.verify_receipt_at(time, code).unwrap();
+    ///
+    /// assert_eq!(receipt.timestep, totp.input_at(time));
+    /// assert_eq!(receipt.period_end, 1_234_567_920);
+    /// assert!(receipt.is_exact());
+    /// ```
+    pub fn verify_receipt_at(&self, time: u64, code: u32) -> Option<VerifiedAt> {
+        self.verify_detailed(time, code)
+            .map(|window| self.receipt_for(window))
+    }
+
+    /// Similar to [`verify_receipt_at`], except the code is given as string.
+    ///
+    /// [`verify_receipt_at`]: Self::verify_receipt_at
+    pub fn verify_string_receipt_at<S: AsRef<str>>(
+        &self,
+        time: u64,
+        code: S,
+    ) -> Option<VerifiedAt> {
+        self.verify_string_detailed(time, code)
+            .map(|window| self.receipt_for(window))
+    }
+
+    /// Tries to verify the given code for the current time, returning the receipt on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`time::Error`] if the system time is before the epoch.
+    pub fn try_verify_receipt(&self, code: u32) -> Result<Option<VerifiedAt>, time::Error> {
+        current().map(|time| self.verify_receipt_at(time, code))
+    }
+
+    /// Verifies the given code for the current time, returning the receipt on success.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the system time is before the epoch.
+    pub fn verify_receipt(&self, code: u32) -> Option<VerifiedAt> {
+        self.verify_receipt_at(expect_current(), code)
+    }
+
+    /// Tries to verify the given string code for the current time,
+    /// returning the receipt on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`time::Error`] if the system time is before the epoch.
+    pub fn try_verify_string_receipt<S: AsRef<str>>(
+        &self,
+        code: S,
+    ) -> Result<Option<VerifiedAt>, time::Error> {
+        current().map(|time| self.verify_string_receipt_at(time, code))
+    }
+
+    /// Verifies the given string code for the current time, returning the receipt on success.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the system time is before the epoch.
+    pub fn verify_string_receipt<S: AsRef<str>>(&self, code: S) -> Option<VerifiedAt> {
+        self.verify_string_receipt_at(expect_current(), code)
+    }
+}
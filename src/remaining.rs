@@ -0,0 +1,116 @@
+//! Remaining time of TOTP codes.
+//!
+//! User interfaces commonly display countdowns and progress bars next to codes.
+//! [`RemainingTime`] wraps the time to live of codes along with their period,
+//! providing ready-made rendering helpers.
+
+use std::{fmt, time::Duration};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    period::Period,
+    time::{self, current, expect_current},
+    totp::Totp,
+};
+
+/// Represents remaining times of codes within their periods.
+///
+/// Formatting remaining times results in strings like `12s`.
+///
+/// # Examples
+///
+/// ```
+/// use otp_std::{Base, Secret, Totp};
+///
+/// let secret = Secret::decode("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+///
+/// let base = Base::builder().secret(secret).build();
+///
+/// let totp = Totp::builder().base(base).build();
+///
+/// let remaining = totp.remaining_at(1_234_567_890);
+///
+/// assert_eq!(remaining.to_string(), "30s");
+/// assert_eq!(remaining.as_fraction(), 1.0);
+///
+/// let remaining = totp.remaining_at(1_234_567_914);
+///
+/// assert_eq!(remaining.to_string(), "6s");
+/// assert!(remaining.is_expiring(10));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RemainingTime {
+    seconds: u64,
+    period: Period,
+}
+
+impl RemainingTime {
+    /// Constructs [`Self`].
+    pub const fn new(seconds: u64, period: Period) -> Self {
+        Self { seconds, period }
+    }
+
+    /// Returns the remaining seconds.
+    pub const fn seconds(self) -> u64 {
+        self.seconds
+    }
+
+    /// Returns the period of codes.
+    pub const fn period(self) -> Period {
+        self.period
+    }
+
+    /// Returns the remaining time as [`Duration`].
+    pub const fn as_duration(self) -> Duration {
+        Duration::from_secs(self.seconds)
+    }
+
+    /// Returns the fraction of the period remaining, in the `[0, 1]` range.
+    ///
+    /// This is useful for rendering progress bars.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn as_fraction(self) -> f64 {
+        (self.seconds as f64 / self.period.get() as f64).min(1.0)
+    }
+
+    /// Checks whether the remaining time is less than or equal to the given threshold, in seconds.
+    ///
+    /// This is useful for highlighting codes that are about to expire.
+    pub const fn is_expiring(self, threshold: u64) -> bool {
+        self.seconds <= threshold
+    }
+}
+
+impl fmt::Display for RemainingTime {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{seconds}s", seconds = self.seconds)
+    }
+}
+
+impl Totp<'_> {
+    /// Returns the remaining time of the code for the given time.
+    pub const fn remaining_at(&self, time: u64) -> RemainingTime {
+        RemainingTime::new(self.time_to_live_at(time), self.period)
+    }
+
+    /// Tries to return the remaining time of the code for the current time.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`time::Error`] if the system time is before the epoch.
+    pub fn try_remaining(&self) -> Result<RemainingTime, time::Error> {
+        current().map(|time| self.remaining_at(time))
+    }
+
+    /// Returns the remaining time of the code for the current time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the system time is before the epoch.
+    pub fn remaining(&self) -> RemainingTime {
+        self.remaining_at(expect_current())
+    }
+}
@@ -0,0 +1,136 @@
+//! Replay protection.
+//!
+//! Accepting the same TOTP code twice within its validity window allows replaying
+//! intercepted codes. As recommended by [RFC 6238, section 5.2], [`Totp::verify_once`]
+//! rejects codes matching inputs (time steps) at or before the last accepted one,
+//! tracking accepted inputs via [`UsedCodeStore`] implementations.
+//!
+//! [RFC 6238, section 5.2]: https://datatracker.ietf.org/doc/html/rfc6238#section-5.2
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{policy::ReplayError, totp::Totp};
+
+/// Represents stores of the last accepted inputs.
+pub trait UsedCodeStore {
+    /// Returns the last accepted input, if any.
+    fn last(&self) -> Option<u64>;
+
+    /// Records the given input as accepted.
+    fn record(&mut self, input: u64);
+
+    /// Checks whether the given input was already used, that is, whether it is
+    /// at or before the last accepted one.
+    fn is_used(&self, input: u64) -> bool {
+        self.last().is_some_and(|last| input <= last)
+    }
+}
+
+/// Represents in-memory stores of the last accepted inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MemoryStore {
+    /// The last accepted input, if any.
+    pub last: Option<u64>,
+}
+
+impl MemoryStore {
+    /// Constructs empty [`Self`].
+    pub const fn new() -> Self {
+        Self { last: None }
+    }
+}
+
+impl UsedCodeStore for MemoryStore {
+    fn last(&self) -> Option<u64> {
+        self.last
+    }
+
+    fn record(&mut self, input: u64) {
+        self.last = Some(input);
+    }
+}
+
+impl Totp<'_> {
+    fn verify_once_with<S: UsedCodeStore + ?Sized, F: Fn(u64) -> bool>(
+        &self,
+        store: &mut S,
+        time: u64,
+        verify: F,
+    ) -> Result<bool, ReplayError> {
+        let Some(input) = self
+            .skew
+            .apply(self.input_at(time))
+            .find(|&input| verify(input))
+        else {
+            return Ok(false);
+        };
+
+        if store.is_used(input) {
+            return Err(ReplayError::new(input));
+        };
+
+        store.record(input);
+
+        Ok(true)
+    }
+
+    /// Verifies the given code for the given time, accounting for *skews*,
+    /// and rejects codes matching inputs at or before the last accepted one.
+    ///
+    /// The matched input is recorded in the store on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReplayError`] if the code was already used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{replay::MemoryStore, Base, Secret, Totp};
+    ///
+    /// let secret = Secret::decode("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+    ///
+    /// let base = Base::builder().secret(secret).build();
+    ///
+    /// let totp = Totp::builder().base(base).build();
+    ///
+    /// let mut store = MemoryStore::new();
+    ///
+    /// let time = 1_234_567_890;
+    ///
+    /// let code = totp.generate_at(time);
+    ///
+    /// assert!(totp.verify_once(&mut store, time, code).unwrap());
+    ///
+    /// // the same code can not be used twice
+    /// assert!(totp.verify_once(&mut store, time, code).is_err());
+    /// ```
+    pub fn verify_once<S: UsedCodeStore + ?Sized>(
+        &self,
+        store: &mut S,
+        time: u64,
+        code: u32,
+    ) -> Result<bool, ReplayError> {
+        self.verify_once_with(store, time, |input| self.base.verify(input, code))
+    }
+
+    /// Similar to [`verify_once`], except the code is given as string.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReplayError`] if the code was already used.
+    ///
+    /// [`verify_once`]: Self::verify_once
+    pub fn verify_string_once<S: UsedCodeStore + ?Sized, C: AsRef<str>>(
+        &self,
+        store: &mut S,
+        time: u64,
+        code: C,
+    ) -> Result<bool, ReplayError> {
+        let code = code.as_ref();
+
+        self.verify_once_with(store, time, |input| self.base.verify_string(input, code))
+    }
+}
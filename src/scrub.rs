@@ -0,0 +1,32 @@
+//! Scrubbing of intermediate buffers.
+//!
+//! High-assurance deployments may want to avoid leaving plaintext codes and HMAC values
+//! in memory after verification. Setting [`scrub`] in [`VerifyOptions`] makes verification
+//! overwrite such buffers with zeros once they are no longer needed.
+//!
+//! [`scrub`]: crate::options::VerifyOptions::scrub
+//! [`VerifyOptions`]: crate::options::VerifyOptions
+
+use std::{
+    ptr,
+    sync::atomic::{compiler_fence, Ordering},
+};
+
+/// Overwrites the given bytes with zeros.
+///
+/// Volatile writes are used, so that the compiler does not optimize them away.
+pub fn scrub(bytes: &mut [u8]) {
+    for byte in bytes.iter_mut() {
+        // SAFETY: the pointer is derived from the mutable reference, so it is valid and aligned
+        unsafe { ptr::write_volatile(byte, 0) };
+    }
+
+    compiler_fence(Ordering::SeqCst);
+}
+
+/// Overwrites the bytes of the given string with zeros, consuming it.
+pub fn scrub_string(string: String) {
+    let mut bytes = string.into_bytes();
+
+    scrub(&mut bytes);
+}
@@ -1,11 +1,9 @@
 //! Core types and functions for working with secrets.
 
-use std::{
-    borrow::Cow,
-    fmt,
-    hash::{Hash, Hasher},
-    str::FromStr,
-};
+use std::{borrow::Cow, fmt, mem, str::FromStr};
+
+#[cfg(not(feature = "forbid-secret-eq"))]
+use std::hash::{Hash, Hasher};
 
 use constant_time_eq::constant_time_eq;
 use miette::Diagnostic;
@@ -15,20 +13,50 @@ use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 use thiserror::Error;
 
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
 use crate::secret::{
-    encoding,
-    length::{self, Length},
+    encoding::{self, Format},
+    length::{self, Length, Policy, PolicyError},
 };
 
+#[cfg(feature = "auth")]
+use crate::auth::{url, utf8};
+
 #[cfg(feature = "generate-secret")]
-use crate::secret::generate::generate;
+use crate::{algorithm::Algorithm, secret::generate::generate};
+
+/// The placeholder used in place of redacted secrets.
+pub const REDACTED: &str = "****";
+
+/// The character used in place of spaces in query values.
+#[cfg(feature = "auth")]
+const PLUS: char = '+';
+
+/// The space character.
+#[cfg(feature = "auth")]
+const SPACE: &str = " ";
 
 /// Represents secrets.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct Secret<'s> {
     value: Cow<'s, [u8]>,
 }
 
+/// Formats [`Secret`] redacting its value, for instance, `Secret(****, len=20)`.
+///
+/// Use [`Secret::expose_encoded`] or [`Secret::expose_bytes`] to explicitly obtain the value.
+impl fmt::Debug for Secret<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "Secret({REDACTED}, len={length})",
+            length = self.as_bytes().len()
+        )
+    }
+}
+
 #[cfg(feature = "serde")]
 impl Serialize for Secret<'_> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
@@ -39,26 +67,60 @@ impl Serialize for Secret<'_> {
 #[cfg(feature = "serde")]
 impl<'de> Deserialize<'de> for Secret<'_> {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        let string = <&str>::deserialize(deserializer)?;
+        use std::fmt;
+
+        struct Visitor;
+
+        impl de::Visitor<'_> for Visitor {
+            type Value = Owned;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("base32-encoded secret")
+            }
+
+            fn visit_str<E: de::Error>(self, string: &str) -> Result<Self::Value, E> {
+                Secret::decode(string).map_err(E::custom)
+            }
+        }
 
-        Self::decode(string).map_err(de::Error::custom)
+        deserializer.deserialize_str(Visitor)
     }
 }
 
+/// Formats [`Secret`] using its Base32 encoding.
+///
+/// When the `redact` feature is enabled, [`REDACTED`] is written instead.
+///
+/// This implementation is removed when the `forbid-secret-display` feature is enabled,
+/// leaving [`Secret::expose_encoded`] as the explicit way to obtain the encoded secret.
+#[cfg(not(feature = "forbid-secret-display"))]
 impl fmt::Display for Secret<'_> {
+    #[cfg(not(feature = "redact"))]
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.encode().fmt(formatter)
     }
+
+    #[cfg(feature = "redact")]
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        REDACTED.fmt(formatter)
+    }
 }
 
+/// Compares [`Secret`] values in constant time (see [`Secret::constant_time_eq`]).
+///
+/// This implementation (along with [`Eq`] and [`Hash`]) is removed when the `forbid-secret-eq`
+/// feature is enabled, leaving [`Secret::constant_time_eq`] as the only way to compare secrets.
+#[cfg(not(feature = "forbid-secret-eq"))]
 impl PartialEq for Secret<'_> {
     fn eq(&self, other: &Self) -> bool {
-        constant_time_eq(self.as_bytes(), other.as_bytes())
+        self.constant_time_eq(other)
     }
 }
 
+#[cfg(not(feature = "forbid-secret-eq"))]
 impl Eq for Secret<'_> {}
 
+#[cfg(not(feature = "forbid-secret-eq"))]
 impl Hash for Secret<'_> {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.as_bytes().hash(state);
@@ -75,11 +137,18 @@ impl AsRef<[u8]> for Secret<'_> {
 #[derive(Debug, Error, Diagnostic)]
 #[error(transparent)]
 #[diagnostic(transparent)]
+#[non_exhaustive]
 pub enum ErrorSource {
     /// Secret has an unsafe length.
     Length(#[from] length::Error),
     /// Secret could not be decoded.
     Encoding(#[from] encoding::Error),
+    /// Secret could not be decoded using the given format.
+    Format(#[from] encoding::FormatError),
+    /// Secret could not be decoded using any known format.
+    Any(#[from] encoding::AnyError),
+    /// Secret length is rejected by the policy.
+    Policy(#[from] length::PolicyError),
 }
 
 /// Represents errors that can occur when decoding secrets.
@@ -108,6 +177,80 @@ impl Error {
     pub fn encoding(error: encoding::Error) -> Self {
         Self::new(error.into())
     }
+
+    /// Constructs [`Self`] from [`encoding::FormatError`].
+    pub fn format(error: encoding::FormatError) -> Self {
+        Self::new(error.into())
+    }
+
+    /// Constructs [`Self`] from [`encoding::AnyError`].
+    pub fn any(error: encoding::AnyError) -> Self {
+        Self::new(error.into())
+    }
+
+    /// Constructs [`Self`] from [`length::PolicyError`].
+    pub fn policy(error: length::PolicyError) -> Self {
+        Self::new(error.into())
+    }
+
+    /// Replaces the encoded secret stored in the source of [`Self`] with [`REDACTED`].
+    #[must_use]
+    pub fn redacted(mut self) -> Self {
+        match &mut self.source {
+            ErrorSource::Encoding(error) => error.secret = REDACTED.to_owned(),
+            ErrorSource::Format(error) => error.secret = REDACTED.to_owned(),
+            ErrorSource::Any(error) => error.secret = REDACTED.to_owned(),
+            ErrorSource::Length(_) | ErrorSource::Policy(_) => {}
+        }
+
+        self
+    }
+}
+
+/// Represents sources of errors that can occur when decoding secrets from query values.
+#[cfg(feature = "auth")]
+#[derive(Debug, Error, Diagnostic)]
+#[error(transparent)]
+#[diagnostic(transparent)]
+#[non_exhaustive]
+pub enum QueryErrorSource {
+    /// The query value is not valid UTF-8 after percent-decoding.
+    Utf8(#[from] utf8::Error),
+    /// The secret could not be decoded.
+    Secret(#[from] Error),
+}
+
+/// Represents errors that can occur when decoding secrets from query values.
+#[cfg(feature = "auth")]
+#[derive(Debug, Error, Diagnostic)]
+#[error("failed to decode secret from query value")]
+#[diagnostic(
+    code(otp_std::secret::query),
+    help("make sure the value of the `secret` parameter is valid")
+)]
+pub struct QueryError {
+    /// The source of this error.
+    #[source]
+    #[diagnostic_source]
+    pub source: QueryErrorSource,
+}
+
+#[cfg(feature = "auth")]
+impl QueryError {
+    /// Constructs [`Self`].
+    pub const fn new(source: QueryErrorSource) -> Self {
+        Self { source }
+    }
+
+    /// Constructs [`Self`] from [`utf8::Error`].
+    pub fn utf8(error: utf8::Error) -> Self {
+        Self::new(error.into())
+    }
+
+    /// Constructs [`Self`] from [`struct@Error`].
+    pub fn secret(error: Error) -> Self {
+        Self::new(error.into())
+    }
 }
 
 impl<'s> Secret<'s> {
@@ -123,6 +266,19 @@ impl<'s> Secret<'s> {
         Ok(unsafe { Self::new_unchecked(value) })
     }
 
+    /// Constructs [`Self`], checking the secret length against the given policy
+    /// instead of the compile-time minimum.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`length::PolicyError`] if the secret length is rejected by the policy.
+    pub fn new_with_policy(value: Cow<'s, [u8]>, policy: Policy) -> Result<Self, PolicyError> {
+        policy.check(value.len())?;
+
+        // SAFETY: the caller explicitly opted into the length policy
+        Ok(unsafe { Self::new_unchecked(value) })
+    }
+
     /// Constructs [`Self`] without checking the secret length.
     ///
     /// # Safety
@@ -169,8 +325,12 @@ impl<'s> Secret<'s> {
     }
 
     /// Consumes [`Self`] and returns the contained secret value.
-    pub fn get(self) -> Cow<'s, [u8]> {
-        self.value
+    ///
+    /// Note that the returned value is not zeroized on drop, even if the `zeroize`
+    /// feature is enabled.
+    pub fn get(mut self) -> Cow<'s, [u8]> {
+        // taking the value out (instead of moving) is needed when `Drop` is implemented
+        mem::take(&mut self.value)
     }
 }
 
@@ -180,6 +340,24 @@ impl Secret<'_> {
         self.value.as_ref()
     }
 
+    /// Compares [`Self`] with the given secret in constant time.
+    ///
+    /// This is the only way to compare secrets when the `forbid-secret-eq` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::Secret;
+    ///
+    /// let secret = Secret::decode("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+    /// let other = Secret::decode("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+    ///
+    /// assert!(secret.constant_time_eq(&other));
+    /// ```
+    pub fn constant_time_eq(&self, other: &Secret<'_>) -> bool {
+        constant_time_eq(self.as_bytes(), other.as_bytes())
+    }
+
     /// Decodes [`Self`] from the given string.
     ///
     /// # Errors
@@ -194,10 +372,163 @@ impl Secret<'_> {
         Ok(secret)
     }
 
+    /// Decodes [`Self`] from the given string, checking the secret length
+    /// against the given policy (see [`new_with_policy`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`struct@Error`] if the secret could not be decoded
+    /// or the resulting length is rejected by the policy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::secret::{length::Policy, Secret};
+    ///
+    /// // the legacy secret is only 10 bytes long
+    /// let legacy = "JBSWY3DPEHPK3PXP";
+    ///
+    /// #[cfg(not(feature = "unsafe-length"))]
+    /// assert!(Secret::decode(legacy).is_err());
+    ///
+    /// let secret = Secret::decode_with_policy(legacy, Policy::permissive()).unwrap();
+    ///
+    /// assert_eq!(secret.as_bytes().len(), 10);
+    /// ```
+    ///
+    /// [`new_with_policy`]: Self::new_with_policy
+    pub fn decode_with_policy<S: AsRef<str>>(string: S, policy: Policy) -> Result<Self, Error> {
+        let owned = encoding::decode(string).map_err(Error::encoding)?;
+
+        let secret = Self::new_with_policy(owned.into(), policy).map_err(Error::policy)?;
+
+        Ok(secret)
+    }
+
+    /// Decodes [`Self`] from the given string using the given format.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`struct@Error`] if the secret could not be decoded using the format
+    /// or the resulting length is unsafe.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{secret::encoding::Format, Secret};
+    ///
+    /// let hex = "4920616d206f70656e20736f75726365";
+    ///
+    /// let secret = Secret::decode_with(Format::Hex, hex).unwrap();
+    ///
+    /// assert_eq!(secret.encode(), "JEQGC3JAN5YGK3RAONXXK4TDMU");
+    /// ```
+    pub fn decode_with<S: AsRef<str>>(format: Format, string: S) -> Result<Self, Error> {
+        let owned = encoding::decode_with(format, string).map_err(Error::format)?;
+
+        let secret = Self::owned(owned).map_err(Error::length)?;
+
+        Ok(secret)
+    }
+
+    /// Decodes [`Self`] from the given string, normalizing it and trying known formats
+    /// (see [`encoding::decode_any`] for more information).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`struct@Error`] if the secret could not be decoded using any known format
+    /// or the resulting length is unsafe.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::Secret;
+    ///
+    /// let secret = Secret::decode_any("jeqd ymza n5yg k3ra onxx k4td mu==").unwrap();
+    ///
+    /// assert!(secret.constant_time_eq(&Secret::decode("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap()));
+    /// ```
+    pub fn decode_any<S: AsRef<str>>(string: S) -> Result<Self, Error> {
+        let owned = encoding::decode_any(string).map_err(Error::any)?;
+
+        let secret = Self::owned(owned).map_err(Error::length)?;
+
+        Ok(secret)
+    }
+
+    /// Decodes [`Self`] from the given value of the `secret` query parameter.
+    ///
+    /// The value is percent-decoded (treating `+` as space), normalized and decoded
+    /// as case-insensitive Base32 (see [`encoding::decode_lenient`]), and finally
+    /// the resulting length is checked.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QueryError`] if the value does not percent-decode to valid UTF-8,
+    /// the secret could not be decoded or the resulting length is unsafe.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::Secret;
+    ///
+    /// let secret = Secret::from_query_value("jeqd%20ymza+n5yg%20k3ra+onxx%20k4td+mu%3D%3D").unwrap();
+    ///
+    /// assert_eq!(secret.expose_encoded(), "JEQDYMZAN5YGK3RAONXXK4TDMU");
+    /// ```
+    #[cfg(feature = "auth")]
+    pub fn from_query_value<S: AsRef<str>>(value: S) -> Result<Owned, QueryError> {
+        let value = value.as_ref().replace(PLUS, SPACE);
+
+        let decoded = url::decode(&value)
+            .map_err(utf8::wrap)
+            .map_err(QueryError::utf8)?;
+
+        let owned = encoding::decode_lenient(decoded)
+            .map_err(Error::encoding)
+            .map_err(QueryError::secret)?;
+
+        Owned::owned(owned)
+            .map_err(Error::length)
+            .map_err(QueryError::secret)
+    }
+
     /// Encodes [`Self`] into [`String`].
     pub fn encode(&self) -> String {
         encoding::encode(self.as_bytes())
     }
+
+    /// Explicitly exposes the Base32-encoded secret.
+    ///
+    /// This is equivalent to [`encode`], clearly marking places where the secret is revealed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::Secret;
+    ///
+    /// let encoded = "JEQDYMZAN5YGK3RAONXXK4TDMU";
+    ///
+    /// let secret = Secret::decode(encoded).unwrap();
+    ///
+    /// assert_eq!(secret.expose_encoded(), encoded);
+    ///
+    /// assert_eq!(format!("{secret:?}"), "Secret(****, len=16)");
+    /// ```
+    ///
+    /// [`encode`]: Self::encode
+    pub fn expose_encoded(&self) -> String {
+        self.encode()
+    }
+
+    /// Explicitly exposes the secret bytes.
+    ///
+    /// This is equivalent to [`as_bytes`], clearly marking places where the secret is revealed.
+    ///
+    /// [`as_bytes`]: Self::as_bytes
+    pub fn expose_bytes(&self) -> &[u8] {
+        self.as_bytes()
+    }
 }
 
 impl FromStr for Secret<'_> {
@@ -208,6 +539,29 @@ impl FromStr for Secret<'_> {
     }
 }
 
+/// Zeroizes owned secret values.
+///
+/// Borrowed values are not owned by [`Secret`], so they are replaced with empty ones instead.
+#[cfg(feature = "zeroize")]
+impl Zeroize for Secret<'_> {
+    fn zeroize(&mut self) {
+        match &mut self.value {
+            Cow::Owned(value) => value.zeroize(),
+            Cow::Borrowed(_) => self.value = Cow::Borrowed(&[]),
+        }
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Secret<'_> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl ZeroizeOnDrop for Secret<'_> {}
+
 #[cfg(feature = "generate-secret")]
 impl Secret<'_> {
     /// Generates secrets of the given length.
@@ -219,6 +573,50 @@ impl Secret<'_> {
     pub fn generate_default() -> Self {
         Self::generate(Length::default())
     }
+
+    /// Generates secrets of the length recommended for the given [`Algorithm`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{Algorithm, Length, Secret};
+    ///
+    /// let secret = Secret::generate_for(Algorithm::Sha512);
+    ///
+    /// assert_eq!(
+    ///     secret.as_bytes().len(),
+    ///     Length::recommended_for(Algorithm::Sha512).get(),
+    /// );
+    /// ```
+    pub fn generate_for(algorithm: Algorithm) -> Self {
+        Self::generate(Length::recommended_for(algorithm))
+    }
+
+    /// Generates secrets of the given length, returning them along with their encoded form.
+    pub fn generate_encoded(length: Length) -> (Self, String) {
+        let secret = Self::generate(length);
+
+        let encoded = secret.encode();
+
+        (secret, encoded)
+    }
+
+    /// Generates secrets of the given length, returning their encoded form only.
+    ///
+    /// This is useful when the encoded secret is immediately handed to the client.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{Length, Secret};
+    ///
+    /// let encoded = Secret::random_base32(Length::DEFAULT);
+    ///
+    /// assert_eq!(Secret::decode(encoded).unwrap().as_bytes().len(), Length::DEFAULT.get());
+    /// ```
+    pub fn random_base32(length: Length) -> String {
+        Self::generate(length).encode()
+    }
 }
 
 #[cfg(feature = "generate-secret")]
@@ -1,4 +1,10 @@
 //! Secret encoding and decoding.
+//!
+//! Secrets are encoded using unpadded Base32, as expected by authenticator apps.
+//! Since users tend to paste secrets in other formats, [`decode_with`] decodes secrets
+//! of the given [`Format`], while [`decode_any`] normalizes secrets and tries known formats.
+
+use std::fmt;
 
 use base32::Alphabet;
 use miette::Diagnostic;
@@ -25,6 +31,108 @@ impl Error {
 /// The alphabet used for encoding and decoding OTP secrets.
 pub const ALPHABET: Alphabet = Alphabet::Rfc4648 { padding: false };
 
+/// The alphabet used for decoding padded Base32 secrets.
+pub const PADDED_ALPHABET: Alphabet = Alphabet::Rfc4648 { padding: true };
+
+/// The `base32` literal.
+pub const BASE32: &str = "base32";
+
+/// The `base32-padded` literal.
+pub const BASE32_PADDED: &str = "base32-padded";
+
+/// The `hex` literal.
+pub const HEX: &str = "hex";
+
+/// The `base64` literal.
+pub const BASE64: &str = "base64";
+
+/// Represents secret encoding formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Format {
+    /// Unpadded RFC 4648 Base32, used by OTP URLs.
+    #[default]
+    Base32,
+    /// Padded RFC 4648 Base32.
+    Base32Padded,
+    /// Hexadecimal, case-insensitive.
+    Hex,
+    /// RFC 4648 Base64, either standard or URL-safe, with optional padding.
+    Base64,
+}
+
+impl Format {
+    /// The formats tried by [`decode_any`], in order.
+    ///
+    /// Padded Base32 is not included since padding is removed during normalization.
+    pub const ANY: [Self; 3] = [Self::Base32, Self::Hex, Self::Base64];
+
+    /// Returns the static string representation of [`Self`].
+    pub const fn static_str(self) -> &'static str {
+        match self {
+            Self::Base32 => BASE32,
+            Self::Base32Padded => BASE32_PADDED,
+            Self::Hex => HEX,
+            Self::Base64 => BASE64,
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.static_str().fmt(formatter)
+    }
+}
+
+/// Represents errors returned when secrets can not be decoded using the given format.
+#[derive(Debug, Error, Diagnostic)]
+#[error("failed to decode `{secret}` secret as `{format}`")]
+#[diagnostic(
+    code(otp_std::secret::encoding::format),
+    help("make sure the secret is encoded using the given format")
+)]
+pub struct FormatError {
+    /// The format used.
+    pub format: Format,
+    /// The encoded secret that could not be decoded.
+    pub secret: String,
+}
+
+impl FormatError {
+    /// Constructs [`Self`].
+    pub const fn new(format: Format, secret: String) -> Self {
+        Self { format, secret }
+    }
+}
+
+fn join(formats: &[Format]) -> String {
+    formats
+        .iter()
+        .map(|format| format!("`{format}`"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Represents errors returned when secrets can not be decoded using any known format.
+#[derive(Debug, Error, Diagnostic)]
+#[error("failed to decode `{secret}` secret, attempted {}", join(.attempted))]
+#[diagnostic(
+    code(otp_std::secret::encoding::any),
+    help("make sure the secret is encoded using Base32, hex or Base64")
+)]
+pub struct AnyError {
+    /// The formats attempted.
+    pub attempted: &'static [Format],
+    /// The encoded secret that could not be decoded.
+    pub secret: String,
+}
+
+impl AnyError {
+    /// Constructs [`Self`].
+    pub const fn new(attempted: &'static [Format], secret: String) -> Self {
+        Self { attempted, secret }
+    }
+}
+
 /// Encodes the given secret.
 pub fn encode<S: AsRef<[u8]>>(secret: S) -> String {
     base32::encode(ALPHABET, secret.as_ref())
@@ -36,6 +144,18 @@ errors! {
     error => new(secret => to_owned),
 }
 
+errors! {
+    Type = FormatError,
+    Hack = $,
+    format_error => new(format, secret => to_owned),
+}
+
+errors! {
+    Type = AnyError,
+    Hack = $,
+    any_error => new(attempted, secret => to_owned),
+}
+
 /// Decodes the given secret.
 ///
 /// # Errors
@@ -48,3 +168,231 @@ pub fn decode<S: AsRef<str>>(secret: S) -> Result<Vec<u8>, Error> {
 
     decode_inner(secret.as_ref())
 }
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// Encodes the given bytes using lowercase hexadecimal.
+pub fn encode_hex<B: AsRef<[u8]>>(bytes: B) -> String {
+    let bytes = bytes.as_ref();
+
+    let mut string = String::with_capacity(bytes.len() * 2);
+
+    for byte in bytes {
+        string.push(HEX_DIGITS[(byte >> 4) as usize].into());
+        string.push(HEX_DIGITS[(byte & 0xF) as usize].into());
+    }
+
+    string
+}
+
+const fn nibble(byte: u8) -> Option<u8> {
+    match byte {
+        b'0'..=b'9' => Some(byte - b'0'),
+        b'a'..=b'f' => Some(byte - b'a' + 10),
+        b'A'..=b'F' => Some(byte - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Decodes the given case-insensitive hexadecimal string.
+///
+/// Returns [`None`] if the string is not valid hexadecimal.
+pub fn decode_hex<S: AsRef<str>>(string: S) -> Option<Vec<u8>> {
+    let bytes = string.as_ref().as_bytes();
+
+    if bytes.len() % 2 == 1 {
+        return None;
+    };
+
+    bytes
+        .chunks(2)
+        .map(|pair| Some(nibble(pair[0])? << 4 | nibble(pair[1])?))
+        .collect()
+}
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+const PADDING: char = '=';
+
+const SEXTET: u32 = 0x3F;
+
+/// Encodes the given bytes using padded standard Base64.
+pub fn encode_base64<B: AsRef<[u8]>>(bytes: B) -> String {
+    let bytes = bytes.as_ref();
+
+    let mut string = String::with_capacity(bytes.len().div_ceil(3) * 4);
+
+    for chunk in bytes.chunks(3) {
+        let mut block = [0; 3];
+
+        block[..chunk.len()].copy_from_slice(chunk);
+
+        let value = u32::from_be_bytes([0, block[0], block[1], block[2]]);
+
+        for index in 0..4 {
+            if index <= chunk.len() {
+                let sextet = (value >> (18 - 6 * index)) & SEXTET;
+
+                string.push(BASE64_ALPHABET[sextet as usize].into());
+            } else {
+                string.push(PADDING);
+            }
+        }
+    }
+
+    string
+}
+
+const fn sextet(byte: u8) -> Option<u32> {
+    let value = match byte {
+        b'A'..=b'Z' => byte - b'A',
+        b'a'..=b'z' => byte - b'a' + 26,
+        b'0'..=b'9' => byte - b'0' + 52,
+        b'+' | b'-' => 62,
+        b'/' | b'_' => 63,
+        _ => return None,
+    };
+
+    Some(value as u32)
+}
+
+/// Decodes the given Base64 string, accepting both standard and URL-safe alphabets,
+/// with or without padding.
+///
+/// Returns [`None`] if the string is not valid Base64.
+pub fn decode_base64<S: AsRef<str>>(string: S) -> Option<Vec<u8>> {
+    let trimmed = string.as_ref().trim_end_matches(PADDING).as_bytes();
+
+    if trimmed.len() % 4 == 1 {
+        return None;
+    };
+
+    let mut bytes = Vec::with_capacity(trimmed.len() * 3 / 4);
+
+    for chunk in trimmed.chunks(4) {
+        let mut value = 0;
+
+        for (index, byte) in chunk.iter().enumerate() {
+            value |= sextet(*byte)? << (18 - 6 * index);
+        }
+
+        let [_, first, second, third] = value.to_be_bytes();
+
+        bytes.extend_from_slice(&[first, second, third][..chunk.len() - 1]);
+    }
+
+    Some(bytes)
+}
+
+fn decode_format(format: Format, string: &str) -> Option<Vec<u8>> {
+    match format {
+        Format::Base32 => base32::decode(ALPHABET, string),
+        Format::Base32Padded => base32::decode(PADDED_ALPHABET, string),
+        Format::Hex => decode_hex(string),
+        Format::Base64 => decode_base64(string),
+    }
+}
+
+/// Decodes the given secret using the given format.
+///
+/// # Errors
+///
+/// Returns [`FormatError`] if the secret could not be decoded.
+///
+/// # Examples
+///
+/// ```
+/// use otp_std::secret::encoding::{decode_with, Format};
+///
+/// assert_eq!(decode_with(Format::Hex, "48656c6c6f").unwrap(), b"Hello");
+/// assert_eq!(decode_with(Format::Base64, "SGVsbG8=").unwrap(), b"Hello");
+/// assert_eq!(decode_with(Format::Base32Padded, "JBSWY3DP").unwrap(), b"Hello");
+///
+/// assert!(decode_with(Format::Hex, "SGVsbG8=").is_err());
+/// ```
+pub fn decode_with<S: AsRef<str>>(format: Format, secret: S) -> Result<Vec<u8>, FormatError> {
+    fn decode_with_inner(format: Format, secret: &str) -> Result<Vec<u8>, FormatError> {
+        decode_format(format, secret).ok_or_else(|| format_error!(format, secret))
+    }
+
+    decode_with_inner(format, secret.as_ref())
+}
+
+/// Normalizes the given secret, removing whitespace and padding.
+pub fn normalize<S: AsRef<str>>(secret: S) -> String {
+    secret
+        .as_ref()
+        .chars()
+        .filter(|character| !character.is_whitespace())
+        .collect::<String>()
+        .trim_end_matches(PADDING)
+        .to_owned()
+}
+
+/// Normalizes the given secret (see [`normalize`]) and decodes it
+/// using the first matching format of [`Format::ANY`].
+///
+/// Base32 is tried case-insensitively first, since it is the format used by OTP URLs,
+/// followed by hex and Base64.
+///
+/// # Errors
+///
+/// Returns [`AnyError`] listing the attempted formats if none of them match.
+///
+/// # Examples
+///
+/// ```
+/// use otp_std::secret::encoding::decode_any;
+///
+/// assert_eq!(decode_any("jbsw y3dp").unwrap(), b"Hello");
+/// assert_eq!(decode_any("48656c6c6f").unwrap(), b"Hello");
+/// assert_eq!(decode_any("SGVsbG8=").unwrap(), b"Hello");
+///
+/// assert!(decode_any("not a secret!").is_err());
+/// ```
+pub fn decode_any<S: AsRef<str>>(secret: S) -> Result<Vec<u8>, AnyError> {
+    fn decode_any_inner(secret: &str) -> Result<Vec<u8>, AnyError> {
+        let normalized = normalize(secret);
+
+        let uppercase = normalized.to_ascii_uppercase();
+
+        Format::ANY
+            .into_iter()
+            .find_map(|format| match format {
+                Format::Base32 | Format::Base32Padded => decode_format(format, &uppercase),
+                Format::Hex | Format::Base64 => decode_format(format, &normalized),
+            })
+            .ok_or_else(|| any_error!(&Format::ANY, secret))
+    }
+
+    decode_any_inner(secret.as_ref())
+}
+
+/// Normalizes the given secret (see [`normalize`]) and decodes it as case-insensitive Base32.
+///
+/// Unlike [`decode_any`], only Base32 is accepted, since it is the format used by OTP URLs.
+///
+/// # Errors
+///
+/// Returns [`struct@Error`] if the secret could not be decoded.
+///
+/// # Examples
+///
+/// ```
+/// use otp_std::secret::encoding::decode_lenient;
+///
+/// assert_eq!(decode_lenient("jbsw y3dp").unwrap(), b"Hello");
+/// assert_eq!(decode_lenient("JBSWY3DP===").unwrap(), b"Hello");
+///
+/// assert!(decode_lenient("48656c6c6f").is_err());
+/// ```
+pub fn decode_lenient<S: AsRef<str>>(secret: S) -> Result<Vec<u8>, Error> {
+    fn decode_lenient_inner(secret: &str) -> Result<Vec<u8>, Error> {
+        let uppercase = normalize(secret).to_ascii_uppercase();
+
+        base32::decode(ALPHABET, &uppercase).ok_or_else(|| error!(secret))
+    }
+
+    decode_lenient_inner(secret.as_ref())
+}
@@ -0,0 +1,92 @@
+//! Secret fingerprints.
+//!
+//! Fingerprints allow comparing secrets (for instance, to detect reused ones)
+//! without revealing them directly.
+//!
+//! Fingerprints are computed as HMAC-SHA1 over secrets, keyed with [`LABEL`] by default.
+//! This separates them from digests of secrets, which for secrets longer than the block size
+//! are the effective HMAC keys themselves.
+//!
+//! Note that fingerprints are deterministic, meaning that short or weak secrets can be
+//! brute-forced from their default fingerprints. When fingerprints are stored or shown,
+//! prefer [`Fingerprint::compute_keyed`] with the application-specific key kept private.
+
+use std::fmt;
+
+use hmac::Mac;
+
+use crate::{algorithm::new_hmac_sha1, secret::core::Secret};
+
+/// The length of fingerprints, in bytes.
+pub const LENGTH: usize = 20;
+
+/// The key used to compute fingerprints by default.
+pub const LABEL: &[u8] = b"otp-std secret fingerprint v1";
+
+/// Represents secret fingerprints, computed as HMAC-SHA1 over secrets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Fingerprint {
+    bytes: [u8; LENGTH],
+}
+
+impl Fingerprint {
+    /// Computes the fingerprint of the given secret bytes, keyed with [`LABEL`].
+    pub fn compute<S: AsRef<[u8]>>(secret: S) -> Self {
+        Self::compute_keyed(LABEL, secret)
+    }
+
+    /// Computes the fingerprint of the given secret bytes, keyed with the given key.
+    ///
+    /// Fingerprints computed with different keys are unrelated, so keeping the key private
+    /// prevents brute-forcing secrets from their fingerprints.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::secret::fingerprint::Fingerprint;
+    ///
+    /// let secret = b"12345678901234567890";
+    ///
+    /// assert_eq!(Fingerprint::compute(secret), Fingerprint::compute(secret));
+    ///
+    /// assert_ne!(
+    ///     Fingerprint::compute(secret),
+    ///     Fingerprint::compute_keyed(b"key", secret),
+    /// );
+    /// ```
+    pub fn compute_keyed<K: AsRef<[u8]>, S: AsRef<[u8]>>(key: K, secret: S) -> Self {
+        let mut hmac = new_hmac_sha1(key);
+
+        hmac.update(secret.as_ref());
+
+        let bytes = hmac.finalize().into_bytes().into();
+
+        Self { bytes }
+    }
+
+    /// Returns the fingerprint bytes.
+    pub const fn as_bytes(&self) -> &[u8; LENGTH] {
+        &self.bytes
+    }
+}
+
+impl fmt::Display for Fingerprint {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.bytes
+            .iter()
+            .try_for_each(|byte| write!(formatter, "{byte:02x}"))
+    }
+}
+
+impl Secret<'_> {
+    /// Computes the fingerprint of [`Self`] (see [`Fingerprint::compute`]).
+    pub fn fingerprint(&self) -> Fingerprint {
+        Fingerprint::compute(self.as_bytes())
+    }
+
+    /// Computes the fingerprint of [`Self`], keyed with the given key
+    /// (see [`Fingerprint::compute_keyed`]).
+    pub fn fingerprint_keyed<K: AsRef<[u8]>>(&self, key: K) -> Fingerprint {
+        Fingerprint::compute_keyed(key, self.as_bytes())
+    }
+}
@@ -15,18 +15,24 @@ use thiserror::Error;
 #[cfg(not(feature = "unsafe-length"))]
 use crate::macros::errors;
 
-use crate::algorithm::Algorithm;
+use crate::{algorithm::Algorithm, macros::const_unwrap};
 
 /// The default (and recommended) secret length.
 pub const DEFAULT: usize = 20;
 
+/// The minimum safe secret length, as required by RFC 4226.
+pub const SAFE_MIN: usize = 16;
+
 /// The minimum allowed secret length.
 #[cfg(not(feature = "unsafe-length"))]
-pub const MIN: usize = 16;
+pub const MIN: usize = SAFE_MIN;
+
+/// The minimum secret length allowed by [`Policy::PERMISSIVE`].
+pub const PERMISSIVE_MIN: usize = 0;
 
 /// Represents errors returned when unsafe lengths are used.
 #[cfg(not(feature = "unsafe-length"))]
-#[derive(Debug, Error, Diagnostic)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error, Diagnostic)]
 #[error("expected length of at least `{MIN}`, got `{length}`")]
 #[diagnostic(
     code(otp_std::secret::length),
@@ -47,9 +53,96 @@ impl Error {
 
 /// Represents the absence of errors returned when the `unsafe-length` feature is enabled.
 #[cfg(feature = "unsafe-length")]
-#[derive(Debug, Error, Diagnostic)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error, Diagnostic)]
 pub enum Error {}
 
+/// Represents errors returned when secret lengths are rejected by policies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error, Diagnostic)]
+#[error("expected length of at least `{min}` required by policy, got `{length}`")]
+#[diagnostic(
+    code(otp_std::secret::length::policy),
+    help("make sure the secret length is at least `{min}` or use the permissive policy")
+)]
+pub struct PolicyError {
+    /// The rejected length.
+    pub length: usize,
+    /// The minimum length required by the policy.
+    pub min: usize,
+}
+
+impl PolicyError {
+    /// Constructs [`Self`].
+    pub const fn new(length: usize, min: usize) -> Self {
+        Self { length, min }
+    }
+}
+
+/// Represents runtime secret length policies.
+///
+/// Unlike the `unsafe-length` feature, which affects the whole build, policies are applied
+/// per call (see [`Secret::new_with_policy`]), allowing to accept specific legacy secrets
+/// without distributing differently-featured builds.
+///
+/// # Examples
+///
+/// ```
+/// use otp_std::secret::{length::Policy, Secret};
+///
+/// let legacy = [0; 10];
+///
+/// assert!(Secret::new_with_policy(legacy[..].into(), Policy::STRICT).is_err());
+/// assert!(Secret::new_with_policy(legacy[..].into(), Policy::permissive()).is_ok());
+/// ```
+///
+/// [`Secret::new_with_policy`]: crate::secret::core::Secret::new_with_policy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Policy {
+    /// The minimum allowed secret length.
+    pub min: usize,
+}
+
+impl Policy {
+    /// Constructs [`Self`].
+    pub const fn new(min: usize) -> Self {
+        Self { min }
+    }
+
+    /// Returns the strict [`Self`], requiring secrets to be at least [`SAFE_MIN`] bytes long.
+    pub const fn strict() -> Self {
+        Self::STRICT
+    }
+
+    /// Returns the permissive [`Self`], accepting secrets of any length.
+    pub const fn permissive() -> Self {
+        Self::PERMISSIVE
+    }
+
+    /// Checks whether the given length is allowed by [`Self`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PolicyError`] if the length is shorter than the minimum.
+    pub const fn check(self, length: usize) -> Result<(), PolicyError> {
+        if length < self.min {
+            Err(PolicyError::new(length, self.min))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// The strict [`Self`] value.
+    pub const STRICT: Self = Self::new(SAFE_MIN);
+
+    /// The permissive [`Self`] value.
+    pub const PERMISSIVE: Self = Self::new(PERMISSIVE_MIN);
+}
+
+impl Default for Policy {
+    fn default() -> Self {
+        Self::STRICT
+    }
+}
+
 /// Represents OTP secret lengths.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Length {
@@ -72,6 +165,8 @@ impl<'de> Deserialize<'de> for Length {
     }
 }
 
+// the error is uninhabited when the `unsafe-length` feature is enabled
+#[cfg_attr(feature = "unsafe-length", allow(clippy::infallible_try_from))]
 impl TryFrom<usize> for Length {
     type Error = Error;
 
@@ -162,8 +257,8 @@ impl Length {
 
     /// The minimum [`Self`] value.
     #[cfg(not(feature = "unsafe-length"))]
-    pub const MIN: Self = Self::new_ok(MIN).unwrap();
+    pub const MIN: Self = const_unwrap!(Self::new_ok(MIN));
 
     /// The default [`Self`] value.
-    pub const DEFAULT: Self = Self::new_ok(DEFAULT).unwrap();
+    pub const DEFAULT: Self = const_unwrap!(Self::new_ok(DEFAULT));
 }
@@ -0,0 +1,120 @@
+//! Compile-time secret literals.
+//!
+//! The functions in this module are `const`, which allows the [`secret!`] macro
+//! to decode and validate Base32-encoded secrets at compile time.
+//!
+//! [`secret!`]: crate::secret!
+
+use crate::secret::{core::Secret, length::Length};
+
+/// The number of bits per Base32 character.
+pub const BITS: usize = 5;
+
+/// The number of bits per byte.
+pub const BYTE: usize = 8;
+
+const fn value_of(character: u8) -> u8 {
+    match character {
+        b'A'..=b'Z' => character - b'A',
+        b'a'..=b'z' => character - b'a',
+        b'2'..=b'7' => character - b'2' + 26,
+        _ => panic!("invalid character in base32-encoded secret"),
+    }
+}
+
+/// Returns the length of the secret decoded from the given unpadded Base32 string.
+pub const fn length(string: &str) -> usize {
+    string.len() * BITS / BYTE
+}
+
+/// Decodes the given unpadded Base32 string into bytes.
+///
+/// # Panics
+///
+/// Panics if the string contains invalid characters or `N` does not match its [`length`].
+/// When evaluated in `const` contexts, this results in compilation errors.
+pub const fn decode<const N: usize>(string: &str) -> [u8; N] {
+    assert!(
+        N == length(string),
+        "mismatched base32-encoded secret length"
+    );
+
+    let bytes = string.as_bytes();
+
+    let mut output = [0; N];
+
+    let mut buffer: u16 = 0;
+    let mut bits = 0;
+
+    let mut index = 0;
+    let mut position = 0;
+
+    while index < bytes.len() {
+        buffer = (buffer << BITS) | value_of(bytes[index]) as u16;
+        bits += BITS;
+
+        if bits >= BYTE {
+            bits -= BYTE;
+
+            output[position] = (buffer >> bits) as u8;
+            position += 1;
+
+            buffer &= (1 << bits) - 1;
+        }
+
+        index += 1;
+    }
+
+    output
+}
+
+/// Constructs [`Secret`] from the given bytes.
+///
+/// # Panics
+///
+/// Panics if the secret has an unsafe length.
+/// When evaluated in `const` contexts, this results in compilation errors.
+pub const fn secret(bytes: &'static [u8]) -> Secret<'static> {
+    assert!(
+        Length::check(bytes.len()).is_ok(),
+        "secret has an unsafe length"
+    );
+
+    // SAFETY: the length was checked above
+    unsafe { Secret::borrowed_unchecked(bytes) }
+}
+
+/// Constructs [`Secret`] from Base32-encoded string literals, validated at compile time.
+///
+/// Invalid strings and secrets of unsafe lengths result in compilation errors.
+///
+/// # Examples
+///
+/// ```
+/// use otp_std::{secret, Secret};
+///
+/// let secret = secret!("JEQDYMZAN5YGK3RAONXXK4TDMU");
+///
+/// assert!(secret.constant_time_eq(&Secret::decode("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap()));
+/// ```
+///
+/// Invalid secrets are rejected at compile time:
+///
+/// ```compile_fail
+/// use otp_std::secret;
+///
+/// let secret = secret!("JEQDYMZAN5YGK3RAONXXK4TDM!");
+/// ```
+#[macro_export]
+macro_rules! secret {
+    ($string: expr $(,)?) => {{
+        const STRING: &str = $string;
+
+        const BYTES: [u8; $crate::secret::literal::length(STRING)] =
+            $crate::secret::literal::decode(STRING);
+
+        const SECRET: $crate::secret::Secret<'static> = $crate::secret::literal::secret(&BYTES);
+
+        SECRET
+    }};
+}
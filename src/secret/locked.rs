@@ -0,0 +1,163 @@
+//! Page-locked secrets.
+//!
+//! Long-running services holding many secrets may want to make sure the secrets never end up
+//! in swap or core dumps. [`Locked`] copies secrets into dedicated pages that are locked
+//! in memory, excluded from core dumps where supported, surrounded by guard pages
+//! and made read-only.
+//!
+//! Each secret occupies at least four pages, which counts against the memory lock limit
+//! of the process (`ulimit -l` on Unix). Locking pages is best-effort, meaning that
+//! failing to lock them does not result in errors.
+
+use std::{borrow::Cow, fmt, ptr::NonNull, slice};
+
+use memsec::Prot;
+use miette::Diagnostic;
+use thiserror::Error;
+
+use crate::{
+    scrub::scrub,
+    secret::core::{Owned, Secret, REDACTED},
+};
+
+/// The message used for secure memory allocation panics.
+pub const ALLOCATION_FAILED: &str = "failed to allocate secure memory";
+
+/// Represents errors returned when secure memory can not be allocated.
+#[derive(Debug, Error, Diagnostic)]
+#[error("failed to allocate secure memory")]
+#[diagnostic(
+    code(otp_std::secret::locked),
+    help("make sure the system has enough memory available")
+)]
+pub struct Error;
+
+/// Represents secrets stored in page-locked memory.
+///
+/// The memory is zeroed and unlocked when dropped.
+///
+/// # Examples
+///
+/// ```
+/// use otp_std::{Base, Secret, Totp};
+///
+/// let secret = Secret::decode("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+///
+/// let locked = secret.lock().unwrap();
+///
+/// let base = Base::builder().secret(locked.secret()).build();
+///
+/// let totp = Totp::builder().base(base).build();
+///
+/// let code = totp.generate_at(1_234_567_890);
+///
+/// assert!(totp.verify_at(1_234_567_890, code));
+/// ```
+pub struct Locked {
+    pointer: NonNull<[u8]>,
+}
+
+// SAFETY: `Locked` exclusively owns its memory, which is never mutated after construction
+unsafe impl Send for Locked {}
+
+// SAFETY: `Locked` only provides shared access to its memory
+unsafe impl Sync for Locked {}
+
+/// Formats [`Locked`] redacting its value, for instance, `Locked(****, len=20)`.
+impl fmt::Debug for Locked {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "Locked({REDACTED}, len={length})",
+            length = self.as_bytes().len()
+        )
+    }
+}
+
+impl Locked {
+    /// Copies the given secret into page-locked memory.
+    ///
+    /// Owned secrets are scrubbed after copying, while borrowed ones can not be.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`struct@Error`] if secure memory can not be allocated.
+    pub fn new(secret: Secret<'_>) -> Result<Self, Error> {
+        let value = secret.get();
+
+        let locked = Self::copy_from(&value);
+
+        if let Cow::Owned(mut bytes) = value {
+            scrub(&mut bytes);
+        };
+
+        locked
+    }
+
+    fn copy_from(bytes: &[u8]) -> Result<Self, Error> {
+        // SAFETY: the allocation is either of the requested size or missing
+        let mut pointer = unsafe { memsec::malloc_sized(bytes.len()) }.ok_or(Error)?;
+
+        // SAFETY: the memory was just allocated with the matching length and is writable
+        unsafe { pointer.as_mut() }.copy_from_slice(bytes);
+
+        // SAFETY: the pointer was returned by `malloc_sized`
+        unsafe { memsec::mprotect(pointer, Prot::ReadOnly) };
+
+        Ok(Self { pointer })
+    }
+
+    /// Returns the secret value as bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        // SAFETY: the memory is valid and readable for the lifetime of `self`
+        unsafe { slice::from_raw_parts(self.pointer.as_ptr().cast(), self.pointer.len()) }
+    }
+
+    /// Returns [`Secret`] borrowing from [`Self`], which never copies the value.
+    pub fn secret(&self) -> Secret<'_> {
+        // SAFETY: the value was copied from the valid secret
+        unsafe { Secret::borrowed_unchecked(self.as_bytes()) }
+    }
+
+    /// Copies the value of [`Self`] into regular memory, returning [`Owned`] secret.
+    pub fn to_secret(&self) -> Owned {
+        self.secret().into_owned()
+    }
+}
+
+impl Clone for Locked {
+    /// Copies the value into newly allocated page-locked memory.
+    ///
+    /// # Panics
+    ///
+    /// Panics if secure memory can not be allocated.
+    fn clone(&self) -> Self {
+        Self::copy_from(self.as_bytes()).expect(ALLOCATION_FAILED)
+    }
+}
+
+impl Drop for Locked {
+    fn drop(&mut self) {
+        // SAFETY: the pointer was returned by `malloc_sized`, and is freed exactly once
+        unsafe { memsec::free(self.pointer) };
+    }
+}
+
+impl Secret<'_> {
+    /// Copies [`Self`] into page-locked memory (see [`Locked`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`struct@Error`] if secure memory can not be allocated.
+    pub fn lock(self) -> Result<Locked, Error> {
+        Locked::new(self)
+    }
+}
+
+impl TryFrom<Secret<'_>> for Locked {
+    type Error = Error;
+
+    fn try_from(secret: Secret<'_>) -> Result<Self, Self::Error> {
+        Self::new(secret)
+    }
+}
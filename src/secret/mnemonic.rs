@@ -0,0 +1,118 @@
+//! Mnemonic secret backups.
+//!
+//! Secrets can be encoded into [BIP-39] English word lists, which contain checksums
+//! and are therefore suitable for paper backups.
+//!
+//! Note that BIP-39 only supports secrets of [`MIN`] to [`MAX`] bytes, in multiples of [`STEP`].
+//! This means that secrets recommended for SHA-512 can not be encoded.
+//!
+//! [BIP-39]: https://github.com/bitcoin/bips/blob/master/bip-0039.mediawiki
+
+use bip39::Mnemonic;
+use const_macros::const_early;
+use miette::Diagnostic;
+use thiserror::Error;
+
+use crate::{
+    macros::errors,
+    secret::core::{Owned, Secret},
+};
+
+/// The minimum length of secrets that can be encoded into mnemonics.
+pub const MIN: usize = 16;
+
+/// The maximum length of secrets that can be encoded into mnemonics.
+pub const MAX: usize = 32;
+
+/// The step of lengths of secrets that can be encoded into mnemonics.
+pub const STEP: usize = 4;
+
+/// Represents errors returned when secrets of unsupported lengths are encoded.
+#[derive(Debug, Error, Diagnostic)]
+#[error("can not encode secret of length `{length}` into mnemonic")]
+#[diagnostic(
+    code(otp_std::secret::mnemonic::length),
+    help("mnemonics support secrets of `16`, `20`, `24`, `28` or `32` bytes")
+)]
+pub struct LengthError {
+    /// The unsupported length.
+    pub length: usize,
+}
+
+impl LengthError {
+    /// Constructs [`Self`].
+    pub const fn new(length: usize) -> Self {
+        Self { length }
+    }
+}
+
+/// Wraps [`bip39::Error`] to provide diagnostics.
+#[derive(Debug, Error, Diagnostic)]
+#[error("failed to parse mnemonic")]
+#[diagnostic(
+    code(otp_std::secret::mnemonic::parse),
+    help("make sure the words and their order are correct")
+)]
+pub struct ParseError(#[from] pub bip39::Error);
+
+errors! {
+    Type = LengthError,
+    Hack = $,
+    length_error => new(length),
+}
+
+/// Checks whether secrets of the given length can be encoded into mnemonics.
+///
+/// # Errors
+///
+/// Returns [`LengthError`] if the length is not supported.
+pub const fn check(length: usize) -> Result<(), LengthError> {
+    const_early!(length < MIN || length > MAX || length % STEP != 0 => length_error!(length));
+
+    Ok(())
+}
+
+/// The message indicating that the length is checked before encoding.
+pub const LENGTH_CHECKED: &str = "secret length is checked before encoding";
+
+impl Secret<'_> {
+    /// Encodes [`Self`] into the mnemonic.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LengthError`] if the length of [`Self`] is not supported (see [`check`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::Secret;
+    ///
+    /// let secret = Secret::decode("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+    ///
+    /// let mnemonic = secret.to_mnemonic().unwrap();
+    ///
+    /// assert!(Secret::from_mnemonic(mnemonic).unwrap().constant_time_eq(&secret));
+    /// ```
+    pub fn to_mnemonic(&self) -> Result<String, LengthError> {
+        let bytes = self.as_bytes();
+
+        check(bytes.len())?;
+
+        let mnemonic = Mnemonic::from_entropy(bytes).expect(LENGTH_CHECKED);
+
+        Ok(mnemonic.to_string())
+    }
+
+    /// Decodes [`Owned`] secrets from the given mnemonic.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError`] if the mnemonic is invalid, for instance,
+    /// if it contains unknown words or its checksum does not match.
+    pub fn from_mnemonic<S: AsRef<str>>(string: S) -> Result<Owned, ParseError> {
+        let mnemonic = Mnemonic::parse(string.as_ref())?;
+
+        // SAFETY: mnemonics always contain at least `MIN` bytes, which is the minimum secret length
+        Ok(unsafe { Owned::owned_unchecked(mnemonic.to_entropy()) })
+    }
+}
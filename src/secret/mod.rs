@@ -1,13 +1,25 @@
 //! Secrets used in OTP generation.
 
 pub mod encoding;
+pub mod fingerprint;
 pub mod length;
+pub mod literal;
 
 #[cfg(feature = "generate-secret")]
 pub mod generate;
 
+#[cfg(feature = "mnemonic")]
+pub mod mnemonic;
+
+#[cfg(feature = "secure-memory")]
+pub mod locked;
+
 pub mod core;
 
+pub use fingerprint::Fingerprint;
 pub use length::Length;
 
+#[cfg(feature = "secure-memory")]
+pub use locked::Locked;
+
 pub use core::{Error, Owned, Secret};
@@ -1,14 +1,21 @@
 //! Time-based One-Time Password (TOTP) skews.
+//!
+//! Skews are symmetric by default, accepting the same number of values before and after
+//! the current one. Deployments often tolerate clients lagging behind more than running ahead,
+//! which is supported via [`Skew::asymmetric`], with separate backward and forward values.
 
 use std::{fmt, iter::once, str::FromStr};
 
 use miette::Diagnostic;
 
 #[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 use thiserror::Error;
 
+#[cfg(feature = "serde")]
+use crate::limit::SkewLimit;
+
 use crate::{
     int::{self, ParseError},
     macros::errors,
@@ -20,6 +27,12 @@ pub const DISABLED: u64 = 0;
 /// The default skew value.
 pub const DEFAULT: u64 = 1;
 
+/// The separator used in asymmetric skew strings, for instance, `2,0`.
+pub const SEPARATOR: char = ',';
+
+/// The message used when offsets do not form contiguous ranges containing zero.
+pub const INVALID_OFFSETS: &str = "expected offsets to form contiguous range containing zero";
+
 /// Represents errors that can occur when parsing skews.
 #[derive(Debug, Error, Diagnostic)]
 #[error("failed to parse `{string}` to skew")]
@@ -42,12 +55,78 @@ impl Error {
 
 /// Represents value skews (see [`apply`] for more information).
 ///
+/// Symmetric skews are formatted as single values, for instance, `1`, while asymmetric ones
+/// are formatted as backward and forward values joined by [`SEPARATOR`], for instance, `2,0`.
+///
+/// When serialized into human-readable formats, symmetric skews are represented as integers,
+/// and asymmetric ones as `{"back": 2, "forward": 0}` objects. Other formats always use the latter.
+///
+/// Human-readable formats additionally accept lists of offsets, like `[-2, -1, 0]`,
+/// which must form contiguous ranges containing zero (see [`from_offsets`]).
+///
 /// [`apply`]: Self::apply
+/// [`from_offsets`]: Self::from_offsets
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[cfg_attr(feature = "serde", serde(from = "u64", into = "u64"))]
 pub struct Skew {
-    value: u64,
+    back: u64,
+    forward: u64,
+}
+
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct Parts {
+    #[serde(deserialize_with = "int::deserialize_u64")]
+    back: u64,
+    #[serde(deserialize_with = "int::deserialize_u64")]
+    forward: u64,
+}
+
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Repr {
+    Symmetric(#[serde(deserialize_with = "int::deserialize_u64")] u64),
+    Asymmetric(Parts),
+    Offsets(Vec<i64>),
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Skew {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() && self.is_symmetric() {
+            return self.back.serialize(serializer);
+        };
+
+        Parts {
+            back: self.back,
+            forward: self.forward,
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Skew {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let skew = if deserializer.is_human_readable() {
+            match Repr::deserialize(deserializer)? {
+                Repr::Symmetric(value) => Self::new(value),
+                Repr::Asymmetric(parts) => Self::asymmetric(parts.back, parts.forward),
+                Repr::Offsets(offsets) => Self::from_offsets(&offsets)
+                    .ok_or_else(|| de::Error::custom(INVALID_OFFSETS))?,
+            }
+        } else {
+            let parts = Parts::deserialize(deserializer)?;
+
+            Self::asymmetric(parts.back, parts.forward)
+        };
+
+        SkewLimit::DEFAULT
+            .check_skew(skew)
+            .map_err(de::Error::custom)?;
+
+        Ok(skew)
+    }
 }
 
 errors! {
@@ -60,17 +139,22 @@ impl FromStr for Skew {
     type Err = Error;
 
     fn from_str(string: &str) -> Result<Self, Self::Err> {
-        let value = string
-            .parse()
-            .map_err(|error| error!(int::wrap(error), string))?;
-
-        Ok(Self::new(value))
+        Self::parse_str(string).map_err(|error| error!(error, string))
     }
 }
 
 impl fmt::Display for Skew {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.get().fmt(formatter)
+        if self.is_symmetric() {
+            self.back.fmt(formatter)
+        } else {
+            write!(
+                formatter,
+                "{back}{SEPARATOR}{forward}",
+                back = self.back,
+                forward = self.forward
+            )
+        }
     }
 }
 
@@ -80,9 +164,15 @@ impl From<u64> for Skew {
     }
 }
 
-impl From<Skew> for u64 {
+impl From<(u64, u64)> for Skew {
+    fn from((back, forward): (u64, u64)) -> Self {
+        Self::asymmetric(back, forward)
+    }
+}
+
+impl From<Skew> for (u64, u64) {
     fn from(skew: Skew) -> Self {
-        skew.get()
+        (skew.back(), skew.forward())
     }
 }
 
@@ -93,14 +183,105 @@ impl Default for Skew {
 }
 
 impl Skew {
-    /// Constructs [`Self`].
+    /// Constructs symmetric [`Self`], accepting `value` values in both directions.
     pub const fn new(value: u64) -> Self {
-        Self { value }
+        Self::asymmetric(value, value)
+    }
+
+    /// Constructs asymmetric [`Self`], accepting `back` past values and `forward` future ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::Skew;
+    ///
+    /// let skew = Skew::asymmetric(2, 0);
+    ///
+    /// let values: Vec<_> = skew.apply(13).collect();
+    ///
+    /// assert_eq!(values, [11, 12, 13]);
+    ///
+    /// assert_eq!(skew.to_string(), "2,0");
+    /// ```
+    pub const fn asymmetric(back: u64, forward: u64) -> Self {
+        Self { back, forward }
     }
 
-    /// Returns the value wrapped in [`Self`].
+    /// Constructs [`Self`] from the given offsets relative to the current value.
+    ///
+    /// Returns [`None`] unless the offsets are sorted and form a contiguous range containing zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::Skew;
+    ///
+    /// assert_eq!(Skew::from_offsets(&[-2, -1, 0, 1]), Some(Skew::asymmetric(2, 1)));
+    /// assert_eq!(Skew::from_offsets(&[0]), Some(Skew::disabled()));
+    ///
+    /// assert_eq!(Skew::from_offsets(&[-2, 0]), None);
+    /// assert_eq!(Skew::from_offsets(&[1, 2]), None);
+    /// ```
+    pub fn from_offsets(offsets: &[i64]) -> Option<Self> {
+        let (&first, &last) = (offsets.first()?, offsets.last()?);
+
+        let contiguous = offsets
+            .windows(2)
+            .all(|pair| pair[0].checked_add(1) == Some(pair[1]));
+
+        (contiguous && first <= 0 && last >= 0)
+            .then(|| Self::asymmetric(first.unsigned_abs(), last.unsigned_abs()))
+    }
+
+    /// Parses [`Self`] from the given string without allocating on errors.
+    ///
+    /// Both single values and backward and forward values joined by [`SEPARATOR`] are accepted.
+    ///
+    /// Unlike [`from_str`], the error returned does not contain the input string.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError`] if any of the values is not a valid integer.
+    ///
+    /// [`from_str`]: FromStr::from_str
+    pub fn parse_str(string: &str) -> Result<Self, ParseError> {
+        let parse = |value: &str| value.parse().map_err(int::wrap);
+
+        match string.split_once(SEPARATOR) {
+            Some((back, forward)) => Ok(Self::asymmetric(parse(back)?, parse(forward)?)),
+            None => parse(string).map(Self::new),
+        }
+    }
+
+    /// Returns the largest of the backward and forward values.
+    ///
+    /// For symmetric skews, this is the value [`Self`] was constructed with.
     pub const fn get(self) -> u64 {
-        self.value
+        if self.back > self.forward {
+            self.back
+        } else {
+            self.forward
+        }
+    }
+
+    /// Returns the number of past values accepted.
+    pub const fn back(self) -> u64 {
+        self.back
+    }
+
+    /// Returns the number of future values accepted.
+    pub const fn forward(self) -> u64 {
+        self.forward
+    }
+
+    /// Checks whether [`Self`] accepts the same number of values in both directions.
+    pub const fn is_symmetric(self) -> bool {
+        self.back == self.forward
+    }
+
+    /// Checks whether [`Self`] is equal to [`DEFAULT`](Self::DEFAULT).
+    pub const fn is_default(&self) -> bool {
+        self.back == DEFAULT && self.forward == DEFAULT
     }
 
     /// Returns the disabled [`Self`].
@@ -108,12 +289,59 @@ impl Skew {
         Self::DISABLED
     }
 
+    /// Limits [`Self`] by the given skew, taking the minimum in each direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::Skew;
+    ///
+    /// let skew = Skew::asymmetric(3, 0).limit(Skew::new(1));
+    ///
+    /// assert_eq!(skew, Skew::asymmetric(1, 0));
+    /// ```
+    pub const fn limit(self, limit: Self) -> Self {
+        let back = if self.back < limit.back {
+            self.back
+        } else {
+            limit.back
+        };
+
+        let forward = if self.forward < limit.forward {
+            self.forward
+        } else {
+            limit.forward
+        };
+
+        Self::asymmetric(back, forward)
+    }
+
+    /// Returns the number of values yielded by [`apply`] at most, that is, `b + f + 1`.
+    ///
+    /// The result saturates at [`u64::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::Skew;
+    ///
+    /// assert_eq!(Skew::disabled().window_size(), 1);
+    /// assert_eq!(Skew::new(2).window_size(), 5);
+    /// assert_eq!(Skew::asymmetric(2, 0).window_size(), 3);
+    /// ```
+    ///
+    /// [`apply`]: Self::apply
+    pub const fn window_size(self) -> u64 {
+        self.back.saturating_add(self.forward).saturating_add(1)
+    }
+
     /// Applies the skew to the given value.
     ///
-    /// Given some skew `s` and value `n`, this method returns an iterator that yields
+    /// Given some skew with backward value `b`, forward value `f` and value `n`,
+    /// this method returns an iterator that yields
     ///
     /// ```text
-    /// n - s, n - s + 1, ..., n - 1, n, n + 1, ..., n + s - 1, n + s
+    /// n - b, n - b + 1, ..., n - 1, n, n + 1, ..., n + f - 1, n + f
     /// ```
     ///
     /// # Note
@@ -166,9 +394,9 @@ impl Skew {
     /// assert_eq!(values.next(), None);
     /// ```
     pub fn apply(self, value: u64) -> impl Iterator<Item = u64> {
-        let sub = (1..=self.get()).filter_map(move |offset| value.checked_sub(offset));
+        let sub = (1..=self.back).filter_map(move |offset| value.checked_sub(offset));
 
-        let add = (1..=self.get()).filter_map(move |offset| value.checked_add(offset));
+        let add = (1..=self.forward).filter_map(move |offset| value.checked_add(offset));
 
         sub.rev().chain(once(value)).chain(add)
     }
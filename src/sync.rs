@@ -0,0 +1,158 @@
+//! Counter synchronization between devices.
+//!
+//! When HOTP counters are synced between devices, each device can advance its counter
+//! independently. The [`SyncState`] type tracks the last counter observed per device,
+//! similar to vector clocks, so that merging states never regresses counters.
+//!
+//! # Examples
+//!
+//! ```
+//! use otp_std::{sync::{Relation, SyncState}, Counter};
+//!
+//! let mut phone = SyncState::new();
+//! let mut laptop = SyncState::new();
+//!
+//! phone.observe("phone", Counter::new(3));
+//! laptop.observe("laptop", Counter::new(5));
+//!
+//! assert_eq!(phone.compare(&laptop), Relation::Concurrent);
+//!
+//! phone.merge(&laptop);
+//!
+//! assert_eq!(phone.counter(), Counter::new(5));
+//! assert_eq!(phone.compare(&laptop), Relation::After);
+//! ```
+
+use std::{
+    cmp::Ordering,
+    collections::{btree_map, BTreeMap},
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::counter::Counter;
+
+/// Represents relations between sync states.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Relation {
+    /// Both states are equal.
+    Equal,
+    /// The state is strictly behind the other one.
+    Before,
+    /// The state is strictly ahead of the other one.
+    After,
+    /// Both states have observed counters the other one has not.
+    Concurrent,
+}
+
+impl Relation {
+    /// Combines [`Self`] with the ordering of counters for some device.
+    pub const fn combine(self, ordering: Ordering) -> Self {
+        match (self, ordering) {
+            (relation, Ordering::Equal) => relation,
+            (Self::Equal | Self::Before, Ordering::Less) => Self::Before,
+            (Self::Equal | Self::After, Ordering::Greater) => Self::After,
+            _ => Self::Concurrent,
+        }
+    }
+}
+
+/// Represents counters observed per device.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct SyncState {
+    devices: BTreeMap<String, Counter>,
+}
+
+impl SyncState {
+    /// Constructs empty [`Self`].
+    pub const fn new() -> Self {
+        Self {
+            devices: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the counter observed for the given device, if any.
+    pub fn get(&self, device: &str) -> Option<Counter> {
+        self.devices.get(device).copied()
+    }
+
+    /// Returns the iterator over devices and counters observed for them.
+    pub fn iter(&self) -> btree_map::Iter<'_, String, Counter> {
+        self.devices.iter()
+    }
+
+    /// Returns the effective counter, that is, the greatest counter observed.
+    ///
+    /// If no counters were observed, [`Counter::DEFAULT`] is returned.
+    pub fn counter(&self) -> Counter {
+        self.devices
+            .values()
+            .copied()
+            .fold(Counter::DEFAULT, Counter::merge)
+    }
+
+    /// Observes the given counter for the given device.
+    ///
+    /// Counters smaller than the ones already observed are ignored.
+    ///
+    /// Returns [`bool`] indicating whether the observed counter has advanced.
+    pub fn observe<D: Into<String>>(&mut self, device: D, counter: Counter) -> bool {
+        match self.devices.entry(device.into()) {
+            btree_map::Entry::Vacant(entry) => {
+                entry.insert(counter);
+
+                true
+            }
+            btree_map::Entry::Occupied(mut entry) => {
+                let observed = entry.get_mut();
+
+                let advanced = counter > *observed;
+
+                *observed = Counter::merge(counter, *observed);
+
+                advanced
+            }
+        }
+    }
+
+    /// Merges the remote state into [`Self`], taking the greatest counter for each device.
+    pub fn merge(&mut self, remote: &Self) {
+        for (device, &counter) in remote.iter() {
+            self.observe(device.as_str(), counter);
+        }
+    }
+
+    /// Compares [`Self`] with the other state.
+    ///
+    /// Devices missing from either state are treated as having observed no counters.
+    pub fn compare(&self, other: &Self) -> Relation {
+        let mut relation = Relation::Equal;
+
+        for (device, &counter) in self.iter() {
+            relation = match other.get(device) {
+                Some(other) => relation.combine(counter.cmp(&other)),
+                None => relation.combine(Ordering::Greater),
+            };
+        }
+
+        for device in other.devices.keys() {
+            if !self.devices.contains_key(device) {
+                relation = relation.combine(Ordering::Less);
+            };
+        }
+
+        relation
+    }
+}
+
+impl<'s> IntoIterator for &'s SyncState {
+    type Item = (&'s String, &'s Counter);
+    type IntoIter = btree_map::Iter<'s, String, Counter>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
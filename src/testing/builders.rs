@@ -0,0 +1,166 @@
+//! Builders of configurations used in compliance suites.
+//!
+//! The configurations built use the test secrets (see [`secret_for`]), so that wrappers
+//! around this crate can be checked against the known-answer vectors.
+//!
+//! This module also provides the fixtures shared by the test suites of this crate,
+//! built around the [`ENCODED`] secret and the [`ISSUER`] and [`USER`] label parts.
+//!
+//! # Examples
+//!
+//! ```
+//! use otp_std::{
+//!     testing::{
+//!         builders,
+//!         compliance::{self, RFC4226_VECTORS},
+//!     },
+//!     Algorithm,
+//! };
+//!
+//! let hotp = builders::rfc4226_hotp();
+//!
+//! compliance::assert_rfc4226(|_, counter| hotp.base.generate(counter));
+//!
+//! let totp = builders::rfc6238_totp(Algorithm::Sha1);
+//!
+//! compliance::assert_rfc6238_verify_for(Algorithm::Sha1, |_, time, code| {
+//!     totp.verify_at(time, code)
+//! });
+//! ```
+
+#[cfg(feature = "auth")]
+use crate::{
+    auth::{Auth, Label, Owned as OwnedAuth, OwnedLabel, Part},
+    otp::Otp,
+};
+
+use crate::{
+    algorithm::Algorithm,
+    base::{Base, Owned as OwnedBase},
+    counter::Counter,
+    digits::Digits,
+    hotp::{Hotp, Owned as OwnedHotp},
+    period::Period,
+    secret::{core::Secret, Owned as OwnedSecret},
+    skew::Skew,
+    testing::compliance::{secret_for, RFC4226_DIGITS, RFC6238_DIGITS, RFC6238_PERIOD},
+    totp::{Owned as OwnedTotp, Totp},
+};
+
+/// The message used for panics when test secrets are rejected.
+///
+/// The test secrets have the recommended lengths, so they are never rejected.
+pub const SECRET_ALWAYS_VALID: &str = "test secrets are always valid";
+
+/// Builds the test secret for the given algorithm (see [`secret_for`]).
+///
+/// # Panics
+///
+/// The test secrets are always valid, so this function should never panic.
+pub fn build_secret_for(algorithm: Algorithm) -> OwnedSecret {
+    Secret::owned(secret_for(algorithm)).expect(SECRET_ALWAYS_VALID)
+}
+
+/// Builds the base configuration using the test secret for the given algorithm.
+pub fn build_base_for(algorithm: Algorithm, digits: Digits) -> OwnedBase {
+    Base::builder()
+        .secret(build_secret_for(algorithm))
+        .algorithm(algorithm)
+        .digits(digits)
+        .build()
+}
+
+/// Builds the HOTP configuration using the test secret for the given algorithm.
+pub fn build_hotp_for(algorithm: Algorithm, digits: Digits) -> OwnedHotp {
+    Hotp::builder()
+        .base(build_base_for(algorithm, digits))
+        .build()
+}
+
+/// Builds the TOTP configuration using the test secret for the given algorithm.
+pub fn build_totp_for(
+    algorithm: Algorithm,
+    digits: Digits,
+    skew: Skew,
+    period: Period,
+) -> OwnedTotp {
+    Totp::builder()
+        .base(build_base_for(algorithm, digits))
+        .skew(skew)
+        .period(period)
+        .build()
+}
+
+/// Builds the HOTP configuration used in RFC 4226 test vectors.
+pub fn rfc4226_hotp() -> OwnedHotp {
+    build_hotp_for(Algorithm::Sha1, RFC4226_DIGITS)
+}
+
+/// Builds the TOTP configuration used in RFC 6238 test vectors for the given algorithm.
+///
+/// The skew is disabled, so that only the exact codes are accepted.
+pub fn rfc6238_totp(algorithm: Algorithm) -> OwnedTotp {
+    build_totp_for(algorithm, RFC6238_DIGITS, Skew::disabled(), RFC6238_PERIOD)
+}
+
+/// The encoded secret used in fixtures.
+pub const ENCODED: &str = "JEQDYMZAN5YGK3RAONXXK4TDMU";
+
+/// The issuer used in fixtures.
+#[cfg(feature = "auth")]
+pub const ISSUER: &str = "MelodyKit";
+
+/// The user used in fixtures.
+#[cfg(feature = "auth")]
+pub const USER: &str = "nekitdev";
+
+/// The message used for panics when label parts given to [`build_label`] are rejected.
+#[cfg(feature = "auth")]
+pub const EXPECTED_VALID_PART: &str = "expected valid label part";
+
+/// Decodes the [`ENCODED`] secret.
+///
+/// # Panics
+///
+/// The fixture secret is always valid, so this function should never panic.
+pub fn build_secret() -> OwnedSecret {
+    Secret::decode(ENCODED).expect(SECRET_ALWAYS_VALID)
+}
+
+/// Builds the default base configuration using the [`ENCODED`] secret.
+pub fn build_base() -> OwnedBase {
+    Base::builder().secret(build_secret()).build()
+}
+
+/// Builds the default HOTP configuration using the [`ENCODED`] secret and the given counter.
+pub fn build_hotp(counter: Counter) -> OwnedHotp {
+    Hotp::builder().base(build_base()).counter(counter).build()
+}
+
+/// Builds the default TOTP configuration using the [`ENCODED`] secret.
+pub fn build_totp() -> OwnedTotp {
+    Totp::builder().base(build_base()).build()
+}
+
+/// Builds the label from the given optional issuer and user.
+///
+/// # Panics
+///
+/// Panics if either of the parts is invalid.
+#[cfg(feature = "auth")]
+pub fn build_label(issuer: Option<&'static str>, user: &'static str) -> OwnedLabel {
+    Label::builder()
+        .maybe_issuer(issuer.map(|issuer| Part::borrowed(issuer).expect(EXPECTED_VALID_PART)))
+        .user(Part::borrowed(user).expect(EXPECTED_VALID_PART))
+        .build()
+}
+
+/// Builds the authentication configuration from the given OTP configuration,
+/// labeled with [`ISSUER`] and [`USER`].
+#[cfg(feature = "auth")]
+pub fn build_auth<O: Into<Otp<'static>>>(otp: O) -> OwnedAuth {
+    Auth::builder()
+        .otp(otp)
+        .label(build_label(Some(ISSUER), USER))
+        .build()
+}
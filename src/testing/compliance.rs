@@ -0,0 +1,262 @@
+//! RFC 4226 (HOTP) and RFC 6238 (TOTP) compliance suites.
+//!
+//! The suites are parameterized by functions that compute codes given the algorithm,
+//! the raw secret and the input (counter or time), so that any implementation can be checked.
+//!
+//! # Examples
+//!
+//! ```
+//! use otp_std::{
+//!     compute,
+//!     testing::compliance::{self, RFC4226_DIGITS, RFC6238_DIGITS, RFC6238_PERIOD},
+//!     Algorithm,
+//! };
+//!
+//! compliance::assert_rfc4226(|secret, counter| {
+//!     compute::hotp(secret, counter, RFC4226_DIGITS, Algorithm::Sha1)
+//! });
+//!
+//! compliance::assert_rfc6238(|algorithm, secret, time| {
+//!     compute::totp(secret, time, RFC6238_PERIOD, RFC6238_DIGITS, algorithm)
+//! });
+//! ```
+
+use crate::{algorithm::Algorithm, digits::Digits, macros::const_unwrap, period::Period};
+
+/// Represents test vectors, that is, inputs paired with expected codes.
+pub type Vector = (u64, u32);
+
+/// The characters the test secrets are composed of.
+pub const SECRET_CHARS: &str = "1234567890";
+
+/// The bytes the test secrets are composed of.
+pub const SECRET_BYTES: &[u8] = SECRET_CHARS.as_bytes();
+
+/// Returns the test secret for the given algorithm.
+///
+/// The secret is [`SECRET_BYTES`] repeated up to the recommended length of the algorithm.
+pub fn secret_for(algorithm: Algorithm) -> Vec<u8> {
+    SECRET_BYTES
+        .iter()
+        .copied()
+        .cycle()
+        .take(algorithm.recommended_length())
+        .collect()
+}
+
+/// The number of digits used in RFC 4226 test vectors.
+pub const RFC4226_DIGITS: Digits = const_unwrap!(Digits::new_ok(6));
+
+/// The RFC 4226 test vectors (counters paired with codes), using SHA-1.
+pub const RFC4226_VECTORS: [Vector; 10] = [
+    (0, 755224),
+    (1, 287082),
+    (2, 359152),
+    (3, 969429),
+    (4, 338314),
+    (5, 254676),
+    (6, 287922),
+    (7, 162583),
+    (8, 399871),
+    (9, 520489),
+];
+
+/// The number of digits used in RFC 6238 test vectors.
+pub const RFC6238_DIGITS: Digits = const_unwrap!(Digits::new_ok(8));
+
+/// The period used in RFC 6238 test vectors.
+pub const RFC6238_PERIOD: Period = const_unwrap!(Period::new_ok(30));
+
+/// The RFC 6238 test vectors (times paired with codes), using SHA-1.
+pub const RFC6238_SHA1_VECTORS: [Vector; 6] = [
+    (59, 94287082),
+    (1111111109, 7081804),
+    (1111111111, 14050471),
+    (1234567890, 89005924),
+    (2000000000, 69279037),
+    (20000000000, 65353130),
+];
+
+/// The RFC 6238 test vectors (times paired with codes), using SHA-256.
+#[cfg(feature = "sha2")]
+pub const RFC6238_SHA256_VECTORS: [Vector; 6] = [
+    (59, 46119246),
+    (1111111109, 68084774),
+    (1111111111, 67062674),
+    (1234567890, 91819424),
+    (2000000000, 90698825),
+    (20000000000, 77737706),
+];
+
+/// The RFC 6238 test vectors (times paired with codes), using SHA-512.
+#[cfg(feature = "sha2")]
+pub const RFC6238_SHA512_VECTORS: [Vector; 6] = [
+    (59, 90693936),
+    (1111111109, 25091201),
+    (1111111111, 99943326),
+    (1234567890, 93441116),
+    (2000000000, 38618901),
+    (20000000000, 47863826),
+];
+
+/// The message used when algorithms have no RFC 6238 test vectors.
+pub const NO_RFC6238_VECTORS: &str = "algorithm has no RFC 6238 test vectors";
+
+/// Returns the RFC 6238 test vectors for the given algorithm.
+///
+/// RFC 6238 only covers SHA-1, SHA-256 and SHA-512, so [`None`] is returned for
+/// other algorithms (SHA-3 and BLAKE2).
+pub const fn rfc6238_vectors(algorithm: Algorithm) -> Option<&'static [Vector]> {
+    match algorithm {
+        Algorithm::Sha1 => Some(&RFC6238_SHA1_VECTORS),
+        #[cfg(feature = "sha2")]
+        Algorithm::Sha256 => Some(&RFC6238_SHA256_VECTORS),
+        #[cfg(feature = "sha2")]
+        Algorithm::Sha512 => Some(&RFC6238_SHA512_VECTORS),
+        #[cfg(feature = "sha3")]
+        Algorithm::Sha3_256 | Algorithm::Sha3_512 => None,
+        #[cfg(feature = "blake2")]
+        Algorithm::Blake2s | Algorithm::Blake2b => None,
+    }
+}
+
+/// Checks whether the given algorithm has RFC 6238 test vectors (see [`rfc6238_vectors`]).
+pub const fn has_rfc6238_vectors(algorithm: Algorithm) -> bool {
+    rfc6238_vectors(algorithm).is_some()
+}
+
+/// Returns the algorithms available that have RFC 6238 test vectors.
+pub fn rfc6238_algorithms() -> Vec<Algorithm> {
+    Algorithm::ARRAY
+        .into_iter()
+        .filter(|&algorithm| has_rfc6238_vectors(algorithm))
+        .collect()
+}
+
+/// Similar to [`rfc6238_vectors`], but panics if the algorithm has no test vectors.
+///
+/// # Panics
+///
+/// Panics if the algorithm has no RFC 6238 test vectors.
+pub fn expect_rfc6238_vectors(algorithm: Algorithm) -> &'static [Vector] {
+    rfc6238_vectors(algorithm).expect(NO_RFC6238_VECTORS)
+}
+
+/// Asserts that the given function computes RFC 4226 compliant codes.
+///
+/// The function is called with the secret and the counter, and should return
+/// the code using [`RFC4226_DIGITS`] and SHA-1.
+///
+/// # Panics
+///
+/// Panics if any of the codes computed do not match the expected ones.
+pub fn assert_rfc4226<F: FnMut(&[u8], u64) -> u32>(mut generate: F) {
+    let secret = secret_for(Algorithm::Sha1);
+
+    for (counter, expected) in RFC4226_VECTORS {
+        let code = generate(&secret, counter);
+
+        assert_eq!(
+            code, expected,
+            "RFC 4226 code mismatch for counter `{counter}`"
+        );
+    }
+}
+
+/// Asserts that the given function computes RFC 6238 compliant codes for the given algorithm.
+///
+/// The function is called with the secret and the time, and should return the code
+/// using [`RFC6238_DIGITS`], [`RFC6238_PERIOD`] and the given algorithm.
+///
+/// Only algorithms covered by RFC 6238 (SHA-1, SHA-256 and SHA-512) can be checked.
+///
+/// # Panics
+///
+/// Panics if the algorithm has no RFC 6238 test vectors (see [`rfc6238_vectors`])
+/// or if any of the codes computed do not match the expected ones.
+pub fn assert_rfc6238_for<F: FnMut(&[u8], u64) -> u32>(algorithm: Algorithm, mut generate: F) {
+    let secret = secret_for(algorithm);
+
+    for &(time, expected) in expect_rfc6238_vectors(algorithm) {
+        let code = generate(&secret, time);
+
+        assert_eq!(
+            code, expected,
+            "RFC 6238 code mismatch for algorithm `{algorithm}` and time `{time}`"
+        );
+    }
+}
+
+/// Asserts that the given function computes RFC 6238 compliant codes for all algorithms
+/// available that have RFC 6238 test vectors (see [`rfc6238_algorithms`]).
+///
+/// See [`assert_rfc6238_for`] for more information.
+///
+/// # Panics
+///
+/// Panics if any of the codes computed do not match the expected ones.
+pub fn assert_rfc6238<F: FnMut(Algorithm, &[u8], u64) -> u32>(mut generate: F) {
+    for algorithm in rfc6238_algorithms() {
+        assert_rfc6238_for(algorithm, |secret, time| generate(algorithm, secret, time));
+    }
+}
+
+/// Asserts that the given function accepts RFC 4226 compliant codes.
+///
+/// The function is called with the secret, the counter and the expected code, and should
+/// verify the code using [`RFC4226_DIGITS`] and SHA-1.
+///
+/// # Panics
+///
+/// Panics if any of the expected codes are rejected.
+pub fn assert_rfc4226_verify<F: FnMut(&[u8], u64, u32) -> bool>(mut verify: F) {
+    let secret = secret_for(Algorithm::Sha1);
+
+    for (counter, code) in RFC4226_VECTORS {
+        assert!(
+            verify(&secret, counter, code),
+            "RFC 4226 code rejected for counter `{counter}`"
+        );
+    }
+}
+
+/// Asserts that the given function accepts RFC 6238 compliant codes for the given algorithm.
+///
+/// The function is called with the secret, the time and the expected code, and should
+/// verify the code using [`RFC6238_DIGITS`], [`RFC6238_PERIOD`] and the given algorithm.
+///
+/// Only algorithms covered by RFC 6238 (SHA-1, SHA-256 and SHA-512) can be checked.
+///
+/// # Panics
+///
+/// Panics if the algorithm has no RFC 6238 test vectors (see [`rfc6238_vectors`])
+/// or if any of the expected codes are rejected.
+pub fn assert_rfc6238_verify_for<F: FnMut(&[u8], u64, u32) -> bool>(
+    algorithm: Algorithm,
+    mut verify: F,
+) {
+    let secret = secret_for(algorithm);
+
+    for &(time, code) in expect_rfc6238_vectors(algorithm) {
+        assert!(
+            verify(&secret, time, code),
+            "RFC 6238 code rejected for algorithm `{algorithm}` and time `{time}`"
+        );
+    }
+}
+
+/// Asserts that the given function accepts RFC 6238 compliant codes for all algorithms
+/// available that have RFC 6238 test vectors (see [`rfc6238_algorithms`]).
+///
+/// See [`assert_rfc6238_verify_for`] for more information.
+///
+/// # Panics
+///
+/// Panics if any of the expected codes are rejected.
+pub fn assert_rfc6238_verify<F: FnMut(Algorithm, &[u8], u64, u32) -> bool>(mut verify: F) {
+    for algorithm in rfc6238_algorithms() {
+        assert_rfc6238_verify_for(algorithm, |secret, time, code| {
+            verify(algorithm, secret, time, code)
+        });
+    }
+}
@@ -0,0 +1,9 @@
+//! Testing utilities.
+//!
+//! This module is available with the `testing` feature (also known as `test-utils`)
+//! and provides suites that alternative implementations (for instance, custom signers)
+//! and wrappers can use to check their compliance, along with builders of the configurations
+//! used in the suites.
+
+pub mod builders;
+pub mod compliance;
@@ -2,8 +2,30 @@
 //!
 //! This module provides the [`now`] function to fetch the current time as seconds since the epoch.
 //! Note that [`now`] can return [`struct@Error`] in case the current time is before the epoch.
+//!
+//! Millisecond resolution is available via [`now_millis`].
+//!
+//! With the `coarse-clock` feature enabled, [`now_coarse`] returns the cached time,
+//! which is updated at most once per second by the background thread. This avoids
+//! fetching the system time on every call, which matters for servers verifying thousands
+//! of codes per second. Convenience methods using the current time (like [`Totp::verify`],
+//! [`Verifier::verify`] or [`OtpVerify::verify_now`]) use the cached time once [`enable_coarse`]
+//! is called, so codes, countdowns and verification results are always based on the same clock.
+//!
+//! [`Totp::verify`]: crate::totp::Totp::verify
+//! [`Verifier::verify`]: crate::verifier::Verifier::verify
+//! [`OtpVerify::verify_now`]: crate::verify::OtpVerify::verify_now
 
-use std::time::{SystemTime, SystemTimeError, UNIX_EPOCH};
+#[cfg(feature = "coarse-clock")]
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Once,
+    },
+    thread,
+};
+
+use std::time::{Duration, SystemTime, SystemTimeError, UNIX_EPOCH};
 
 use miette::Diagnostic;
 use thiserror::Error;
@@ -13,22 +35,103 @@ pub const CURRENT_TIME_BEFORE_EPOCH: &str = "current time is before the epoch";
 
 /// Wraps [`SystemTimeError`] to provide diagnostics.
 #[derive(Debug, Error, Diagnostic)]
-#[error("system time is before epoch")]
-#[diagnostic(code(otp_std::time), help("see the report for more information"))]
+#[error("system time is before epoch by `{:?}`", .0.duration())]
+#[diagnostic(
+    code(otp_std::time),
+    help("make sure the system clock is set correctly (for instance, check the RTC battery)")
+)]
 pub struct Error(#[from] pub SystemTimeError);
 
+impl Error {
+    /// Returns how far the system time is before the epoch.
+    pub fn offset(&self) -> Duration {
+        self.0.duration()
+    }
+}
+
+/// Represents the current time clamped to the epoch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Clamped {
+    /// The time as seconds since the epoch, or `0` if clamped.
+    pub time: u64,
+    /// Whether the time was clamped, meaning the system time is before the epoch.
+    pub clamped: bool,
+}
+
+/// The number of milliseconds per second, as [`u64`].
+pub const MILLISECONDS_PER_SECOND: u64 = 1000;
+
+/// The number of milliseconds per second.
+#[cfg(all(feature = "wasm", target_arch = "wasm32", target_os = "unknown"))]
+pub const MILLISECONDS: f64 = 1000.0;
+
+#[cfg(not(all(feature = "wasm", target_arch = "wasm32", target_os = "unknown")))]
+fn system_now() -> SystemTime {
+    SystemTime::now()
+}
+
+// `SystemTime::now` panics on `wasm32-unknown-unknown`, so JavaScript `Date` is used instead
+#[cfg(all(feature = "wasm", target_arch = "wasm32", target_os = "unknown"))]
+fn system_now() -> SystemTime {
+    let seconds = js_sys::Date::now() / MILLISECONDS;
+
+    let offset = Duration::from_secs_f64(seconds.abs());
+
+    if seconds < 0.0 {
+        UNIX_EPOCH - offset
+    } else {
+        UNIX_EPOCH + offset
+    }
+}
+
 /// Returns the current time as seconds since the epoch.
 ///
+/// With the `wasm` feature enabled, the time is fetched via JavaScript `Date`
+/// on `wasm32-unknown-unknown`.
+///
 /// # Errors
 ///
 /// Returns [`struct@Error`] if the system time is before the epoch.
 pub fn now() -> Result<u64, Error> {
-    SystemTime::now()
+    system_now()
         .duration_since(UNIX_EPOCH)
         .map(|duration| duration.as_secs())
         .map_err(Error)
 }
 
+/// Returns the current time as milliseconds since the epoch.
+///
+/// The result saturates at [`u64::MAX`], which is only reached in about 584 million years.
+///
+/// # Errors
+///
+/// Returns [`struct@Error`] if the system time is before the epoch.
+pub fn now_millis() -> Result<u64, Error> {
+    system_now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| u64::try_from(duration.as_millis()).unwrap_or(u64::MAX))
+        .map_err(Error)
+}
+
+/// Similar to [`now`], but clamps the time to `0` if the system time is before the epoch.
+///
+/// The [`clamped`] flag indicates whether that happened, allowing callers to warn about
+/// misconfigured clocks instead of panicking via [`expect_now`].
+///
+/// [`clamped`]: Clamped::clamped
+pub fn now_clamped() -> Clamped {
+    match now() {
+        Ok(time) => Clamped {
+            time,
+            clamped: false,
+        },
+        Err(_) => Clamped {
+            time: 0,
+            clamped: true,
+        },
+    }
+}
+
 /// Similar to [`now`], but panics if the current time is before the epoch.
 ///
 /// # Panics
@@ -37,3 +140,144 @@ pub fn now() -> Result<u64, Error> {
 pub fn expect_now() -> u64 {
     now().expect(CURRENT_TIME_BEFORE_EPOCH)
 }
+
+/// Similar to [`now_millis`], but panics if the current time is before the epoch.
+///
+/// # Panics
+///
+/// Panics if the current time is before the epoch.
+pub fn expect_now_millis() -> u64 {
+    now_millis().expect(CURRENT_TIME_BEFORE_EPOCH)
+}
+
+/// The name of the thread updating the cached time.
+#[cfg(feature = "coarse-clock")]
+pub const COARSE_THREAD_NAME: &str = "otp-std-coarse-clock";
+
+// `0` means the cached time is unavailable, in which case the system time is used instead
+#[cfg(feature = "coarse-clock")]
+const UNSET: u64 = 0;
+
+#[cfg(feature = "coarse-clock")]
+static COARSE: AtomicU64 = AtomicU64::new(UNSET);
+
+#[cfg(feature = "coarse-clock")]
+static COARSE_START: Once = Once::new();
+
+#[cfg(feature = "coarse-clock")]
+static COARSE_ENABLED: AtomicBool = AtomicBool::new(false);
+
+// updates the cached time, returning the number of milliseconds until the next second
+#[cfg(feature = "coarse-clock")]
+fn refresh() -> u64 {
+    let Ok(millis) = now_millis() else {
+        COARSE.store(UNSET, Ordering::Relaxed);
+
+        return MILLISECONDS_PER_SECOND;
+    };
+
+    COARSE.store(millis / MILLISECONDS_PER_SECOND, Ordering::Relaxed);
+
+    MILLISECONDS_PER_SECOND - millis % MILLISECONDS_PER_SECOND
+}
+
+#[cfg(feature = "coarse-clock")]
+fn run() {
+    loop {
+        let millis = refresh();
+
+        thread::sleep(Duration::from_millis(millis));
+    }
+}
+
+#[cfg(feature = "coarse-clock")]
+fn start() {
+    let spawned = thread::Builder::new()
+        .name(COARSE_THREAD_NAME.to_owned())
+        .spawn(run);
+
+    // if the thread can not be spawned (for instance, on `wasm32-unknown-unknown`),
+    // the cached time remains unset, so the system time is always used
+    if spawned.is_ok() {
+        refresh();
+    }
+}
+
+/// Returns the cached current time as seconds since the epoch.
+///
+/// The first call spawns the background thread that updates the cached time
+/// at the start of every second. If the thread can not be spawned, or the system time
+/// is before the epoch, this function falls back to [`now`].
+///
+/// # Errors
+///
+/// Returns [`struct@Error`] if the system time is before the epoch.
+///
+/// # Examples
+///
+/// ```
+/// use otp_std::time::{now, now_coarse};
+///
+/// let coarse = now_coarse().unwrap();
+///
+/// assert!(coarse.abs_diff(now().unwrap()) <= 1);
+/// ```
+#[cfg(feature = "coarse-clock")]
+pub fn now_coarse() -> Result<u64, Error> {
+    COARSE_START.call_once(start);
+
+    match COARSE.load(Ordering::Relaxed) {
+        UNSET => now(),
+        time => Ok(time),
+    }
+}
+
+/// Similar to [`now_coarse`], but panics if the current time is before the epoch.
+///
+/// # Panics
+///
+/// Panics if the current time is before the epoch.
+#[cfg(feature = "coarse-clock")]
+pub fn expect_now_coarse() -> u64 {
+    now_coarse().expect(CURRENT_TIME_BEFORE_EPOCH)
+}
+
+/// Makes convenience methods using the current time use the cached time (see [`now_coarse`]).
+///
+/// This affects the entire process, so it should be called once, when the server starts.
+#[cfg(feature = "coarse-clock")]
+pub fn enable_coarse() {
+    COARSE_ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Makes convenience methods using the current time use the system time (see [`now`]) again.
+#[cfg(feature = "coarse-clock")]
+pub fn disable_coarse() {
+    COARSE_ENABLED.store(false, Ordering::Relaxed);
+}
+
+/// Checks whether convenience methods using the current time use the cached time.
+#[cfg(feature = "coarse-clock")]
+pub fn is_coarse_enabled() -> bool {
+    COARSE_ENABLED.load(Ordering::Relaxed)
+}
+
+/// Returns the current time used by convenience methods.
+#[cfg(feature = "coarse-clock")]
+pub(crate) fn current() -> Result<u64, Error> {
+    if is_coarse_enabled() {
+        now_coarse()
+    } else {
+        now()
+    }
+}
+
+#[cfg(not(feature = "coarse-clock"))]
+pub(crate) fn current() -> Result<u64, Error> {
+    now()
+}
+
+/// Similar to [`current`], but panics if the current time is before the epoch.
+pub(crate) fn expect_current() -> u64 {
+    current().expect(CURRENT_TIME_BEFORE_EPOCH)
+}
@@ -1,7 +1,5 @@
 //! Time-based One-Time Password (TOTP) functionality.
 
-use bon::Builder;
-
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -15,32 +13,147 @@ use thiserror::Error;
 use crate::auth::url::Url;
 
 use crate::{
-    base::Base,
+    algorithm::Algorithm,
+    base::{Base, ValidationError},
+    builder::Unset,
+    digits::Digits,
+    macros::setters,
+    options::VerifyOptions,
     period::Period,
+    profile::Profile,
+    secret::core::Secret,
     skew::Skew,
-    time::{self, expect_now, now},
+    time::{self, current, expect_current},
 };
 
 #[cfg(feature = "auth")]
-use crate::{auth::query::Query, base, period};
+use crate::{
+    auth::query::{extract_parse, Query},
+    base::{self, Parameter},
+    period,
+};
 
 /// Represents TOTP configurations.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Builder)]
+#[derive(Debug, Clone)]
+#[cfg_attr(not(feature = "forbid-secret-eq"), derive(PartialEq, Eq, Hash))]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Totp<'t> {
     /// The base configuration.
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub base: Base<'t>,
     /// The skew to apply.
-    #[builder(default)]
     #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(
+        feature = "compact-serde",
+        serde(skip_serializing_if = "Skew::is_default")
+    )]
     pub skew: Skew,
     /// The period to use.
-    #[builder(default)]
     #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(
+        feature = "compact-serde",
+        serde(skip_serializing_if = "Period::is_default")
+    )]
     pub period: Period,
 }
 
+/// Represents builders of [`Totp`] (see [`Totp::builder`]).
+#[derive(Debug, Clone, Default)]
+pub struct TotpBuilder<B = Unset> {
+    base: B,
+    skew: Option<Skew>,
+    period: Option<Period>,
+}
+
+impl Totp<'_> {
+    /// Starts building [`Totp`].
+    pub fn builder() -> TotpBuilder {
+        TotpBuilder::default()
+    }
+}
+
+impl TotpBuilder {
+    /// Sets the `base` member.
+    pub fn base(self, base: Base<'_>) -> TotpBuilder<Base<'_>> {
+        TotpBuilder {
+            base,
+            skew: self.skew,
+            period: self.period,
+        }
+    }
+}
+
+impl<B> TotpBuilder<B> {
+    setters! {
+        skew, maybe_skew: Skew;
+        period, maybe_period: Period;
+    }
+
+    /// Sets the `skew` member to the asymmetric one (see [`Skew::asymmetric`]).
+    pub fn asymmetric_skew(self, back: u64, forward: u64) -> Self {
+        self.skew(Skew::asymmetric(back, forward))
+    }
+}
+
+impl<'t> TotpBuilder<Base<'t>> {
+    /// Finishes building [`Totp`].
+    pub fn build(self) -> Totp<'t> {
+        Totp {
+            base: self.base,
+            skew: self.skew.unwrap_or_default(),
+            period: self.period.unwrap_or_default(),
+        }
+    }
+}
+
+/// Represents worst-case verification costs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Cost {
+    /// The number of HMAC computations.
+    pub hmac_calls: u64,
+}
+
+impl Cost {
+    /// Constructs [`Self`].
+    pub const fn new(hmac_calls: u64) -> Self {
+        Self { hmac_calls }
+    }
+}
+
+/// Represents windows matched during verification.
+///
+/// See [`Totp::verify_detailed`] for more information.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MatchedWindow {
+    /// The input the code matched for.
+    pub input: u64,
+    /// The offset of the matched input relative to the current one, in periods.
+    ///
+    /// Negative offsets mean that the client clock is behind, and positive ones
+    /// mean that it is ahead.
+    pub offset: i64,
+    /// The drift of the client clock, in seconds.
+    ///
+    /// This is the offset multiplied by the period.
+    pub drift: i64,
+}
+
+impl MatchedWindow {
+    /// Constructs [`Self`].
+    pub const fn new(input: u64, offset: i64, drift: i64) -> Self {
+        Self {
+            input,
+            offset,
+            drift,
+        }
+    }
+
+    /// Checks whether the code matched the current input exactly.
+    pub const fn is_exact(&self) -> bool {
+        self.offset == 0
+    }
+}
+
 impl<'t> Totp<'t> {
     /// Returns the base configuration.
     pub const fn base(&self) -> &Base<'t> {
@@ -59,6 +172,78 @@ impl<'t> Totp<'t> {
 }
 
 impl Totp<'_> {
+    /// Returns [`Self`] with the given skew.
+    #[must_use]
+    pub const fn with_skew(mut self, skew: Skew) -> Self {
+        self.skew = skew;
+
+        self
+    }
+
+    /// Returns [`Self`] with the given period.
+    #[must_use]
+    pub const fn with_period(mut self, period: Period) -> Self {
+        self.period = period;
+
+        self
+    }
+
+    /// Returns [`Self`] with the given algorithm (see [`Base::with_algorithm`]).
+    #[must_use]
+    pub const fn with_algorithm(mut self, algorithm: Algorithm) -> Self {
+        self.base.algorithm = algorithm;
+
+        self
+    }
+
+    /// Returns [`Self`] with the given number of digits (see [`Base::with_digits`]).
+    #[must_use]
+    pub const fn with_digits(mut self, digits: Digits) -> Self {
+        self.base.digits = digits;
+
+        self
+    }
+
+    /// Returns [`Self`] with the given secret (see [`Base::with_secret`]).
+    #[must_use]
+    pub fn with_secret<'s>(self, secret: Secret<'s>) -> Totp<'s> {
+        Totp {
+            base: self.base.with_secret(secret),
+            skew: self.skew,
+            period: self.period,
+        }
+    }
+}
+
+impl Totp<'_> {
+    /// Validates [`Self`] (see [`Base::validate`]), additionally enforcing the given profile.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValidationError`] if regular validation fails or the profile is violated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{Algorithm, Base, Period, Profile, Secret, Totp};
+    ///
+    /// let secret = Secret::generate_for(Algorithm::Sha1);
+    ///
+    /// let base = Base::builder().secret(secret).build();
+    ///
+    /// let period = Period::new(60).unwrap();
+    ///
+    /// let totp = Totp::builder().base(base).period(period).build();
+    ///
+    /// assert!(totp.validate_with(Profile::Permissive).is_ok());
+    /// assert!(totp.validate_with(Profile::StrictRfc).is_err());
+    /// ```
+    pub fn validate_with(&self, profile: Profile) -> Result<(), ValidationError> {
+        self.base.validate()?;
+
+        profile.enforce_totp(self).map_err(ValidationError::profile)
+    }
+
     /// Returns the input value corresponding to the given time.
     pub const fn input_at(&self, time: u64) -> u64 {
         time / self.period.get()
@@ -77,7 +262,7 @@ impl Totp<'_> {
     ///
     /// Returns [`time::Error`] if the system time is before the epoch.
     pub fn try_next_period(&self) -> Result<u64, time::Error> {
-        now().map(|time| self.next_period_at(time))
+        current().map(|time| self.next_period_at(time))
     }
 
     /// Returns the time corresponding to the next period from the current time.
@@ -86,7 +271,7 @@ impl Totp<'_> {
     ///
     /// Panics if the system time is before the epoch.
     pub fn next_period(&self) -> u64 {
-        self.next_period_at(expect_now())
+        self.next_period_at(expect_current())
     }
 
     /// Returns the time to live of the code for the given time.
@@ -102,7 +287,7 @@ impl Totp<'_> {
     ///
     /// Returns [`time::Error`] if the system time is before the epoch.
     pub fn try_time_to_live(&self) -> Result<u64, time::Error> {
-        now().map(|time| self.time_to_live_at(time))
+        current().map(|time| self.time_to_live_at(time))
     }
 
     /// Returns the time to live of the code for the current time.
@@ -111,7 +296,7 @@ impl Totp<'_> {
     ///
     /// Panics if the system time is before the epoch.
     pub fn time_to_live(&self) -> u64 {
-        self.time_to_live_at(expect_now())
+        self.time_to_live_at(expect_current())
     }
 
     /// Generates the code for the given time.
@@ -130,7 +315,7 @@ impl Totp<'_> {
     ///
     /// Returns [`time::Error`] if the system time is before the epoch.
     pub fn try_generate(&self) -> Result<u32, time::Error> {
-        now().map(|time| self.generate_at(time))
+        current().map(|time| self.generate_at(time))
     }
 
     /// Generates the code for the current time.
@@ -139,7 +324,7 @@ impl Totp<'_> {
     ///
     /// Panics if the system time is before the epoch.
     pub fn generate(&self) -> u32 {
-        self.generate_at(expect_now())
+        self.generate_at(expect_current())
     }
 
     /// Tries to generate the string code for the current time.
@@ -148,7 +333,7 @@ impl Totp<'_> {
     ///
     /// Returns [`time::Error`] if the system time is before the epoch.
     pub fn try_generate_string(&self) -> Result<String, time::Error> {
-        now().map(|time| self.generate_string_at(time))
+        current().map(|time| self.generate_string_at(time))
     }
 
     /// Generates the string code for the current time.
@@ -157,7 +342,7 @@ impl Totp<'_> {
     ///
     /// Panics if the system time is before the epoch.
     pub fn generate_string(&self) -> String {
-        self.generate_string_at(expect_now())
+        self.generate_string_at(expect_current())
     }
 
     /// Verifies the given code for the given time.
@@ -170,13 +355,27 @@ impl Totp<'_> {
         self.base.verify_string(self.input_at(time), code)
     }
 
+    /// Similar to [`verify_string_exact_at`], except the code is normalized
+    /// according to the options.
+    ///
+    /// [`verify_string_exact_at`]: Self::verify_string_exact_at
+    pub fn verify_string_exact_at_with<S: AsRef<str>>(
+        &self,
+        time: u64,
+        code: S,
+        options: VerifyOptions,
+    ) -> bool {
+        self.base
+            .verify_string_with(self.input_at(time), code, options)
+    }
+
     /// Tries to verify the given code for the current time *exactly*.
     ///
     /// # Errors
     ///
     /// Returns [`time::Error`] if the system time is before the epoch.
     pub fn try_verify_exact(&self, code: u32) -> Result<bool, time::Error> {
-        now().map(|time| self.verify_exact_at(time, code))
+        current().map(|time| self.verify_exact_at(time, code))
     }
 
     /// Verifies the given code for the current time *exactly*.
@@ -185,7 +384,7 @@ impl Totp<'_> {
     ///
     /// Panics if the system time is before the epoch.
     pub fn verify_exact(&self, code: u32) -> bool {
-        self.verify_exact_at(expect_now(), code)
+        self.verify_exact_at(expect_current(), code)
     }
 
     /// Tries to verify the given string code for the current time *exactly*.
@@ -194,7 +393,7 @@ impl Totp<'_> {
     ///
     /// Returns [`time::Error`] if the system time is before the epoch.
     pub fn try_verify_string_exact<S: AsRef<str>>(&self, code: S) -> Result<bool, time::Error> {
-        now().map(|time| self.verify_string_exact_at(time, code))
+        current().map(|time| self.verify_string_exact_at(time, code))
     }
 
     /// Verifies the given string code for the current time *exactly*.
@@ -203,7 +402,26 @@ impl Totp<'_> {
     ///
     /// Panics if the system time is before the epoch.
     pub fn verify_string_exact<S: AsRef<str>>(&self, code: S) -> bool {
-        self.verify_string_exact_at(expect_now(), code)
+        self.verify_string_exact_at(expect_current(), code)
+    }
+
+    /// Returns the worst-case cost of verifying codes, accounting for *skews*.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{Base, Secret, Skew, Totp};
+    ///
+    /// let secret = Secret::decode("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+    ///
+    /// let base = Base::builder().secret(secret).build();
+    ///
+    /// let totp = Totp::builder().base(base).skew(Skew::new(2)).build();
+    ///
+    /// assert_eq!(totp.verification_cost().hmac_calls, 5);
+    /// ```
+    pub const fn verification_cost(&self) -> Cost {
+        Cost::new(self.skew.window_size())
     }
 
     /// Verifies the given code for the given time, accounting for *skews*.
@@ -213,6 +431,93 @@ impl Totp<'_> {
             .any(|input| self.base.verify(input, code))
     }
 
+    fn matched_window(&self, input: u64, distance: u64, ahead: bool) -> Option<MatchedWindow> {
+        let matched = if ahead {
+            input.checked_add(distance)?
+        } else {
+            input.checked_sub(distance)?
+        };
+
+        let distance = i64::try_from(distance).unwrap_or(i64::MAX);
+
+        let offset = if ahead { distance } else { -distance };
+
+        let period = i64::try_from(self.period.get()).unwrap_or(i64::MAX);
+
+        Some(MatchedWindow::new(
+            matched,
+            offset,
+            offset.saturating_mul(period),
+        ))
+    }
+
+    fn verify_detailed_with<F: Fn(u64) -> bool>(
+        &self,
+        time: u64,
+        verify: F,
+    ) -> Option<MatchedWindow> {
+        let input = self.input_at(time);
+
+        let skew = self.skew;
+
+        (0..=skew.get())
+            .flat_map(|distance| {
+                let behind = (distance <= skew.back())
+                    .then(|| self.matched_window(input, distance, false))
+                    .flatten();
+
+                let ahead = (distance > 0 && distance <= skew.forward())
+                    .then(|| self.matched_window(input, distance, true))
+                    .flatten();
+
+                behind.into_iter().chain(ahead)
+            })
+            .find(|window| verify(window.input))
+    }
+
+    /// Verifies the given code for the given time, accounting for *skews*,
+    /// and returns the matched window, if any.
+    ///
+    /// Inputs closer to the current one are checked first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otp_std::{Base, Secret, Totp};
+    ///
+    /// let secret = Secret::decode("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+    ///
+    /// let base = Base::builder().secret(secret).build();
+    ///
+    /// let totp = Totp::builder().base(base).build();
+    ///
+    /// let time = 1_234_567_890;
+    ///
+    /// // the client clock is one period behind
+    /// let code = totp.generate_at(time - 30);
+    ///
+    /// let matched = totp.verify_detailed(time, code).unwrap();
+    ///
+    /// assert_eq!(matched.offset, -1);
+    /// assert_eq!(matched.drift, -30);
+    /// ```
+    pub fn verify_detailed(&self, time: u64, code: u32) -> Option<MatchedWindow> {
+        self.verify_detailed_with(time, |input| self.base.verify(input, code))
+    }
+
+    /// Similar to [`verify_detailed`], except the code is given as string.
+    ///
+    /// [`verify_detailed`]: Self::verify_detailed
+    pub fn verify_string_detailed<S: AsRef<str>>(
+        &self,
+        time: u64,
+        code: S,
+    ) -> Option<MatchedWindow> {
+        let code = code.as_ref();
+
+        self.verify_detailed_with(time, |input| self.base.verify_string(input, code))
+    }
+
     fn verify_str_at(&self, time: u64, code: &str) -> bool {
         self.skew
             .apply(self.input_at(time))
@@ -224,13 +529,34 @@ impl Totp<'_> {
         self.verify_str_at(time, code.as_ref())
     }
 
+    /// Similar to [`verify_string_at`], except the code is normalized according to the options.
+    ///
+    /// [`verify_string_at`]: Self::verify_string_at
+    pub fn verify_string_at_with<S: AsRef<str>>(
+        &self,
+        time: u64,
+        code: S,
+        options: VerifyOptions,
+    ) -> bool {
+        let code = options.normalize(code.as_ref());
+
+        let valid = self
+            .skew
+            .apply(self.input_at(time))
+            .any(|input| self.base.verify_normalized(input, &code, options));
+
+        options.discard(code);
+
+        valid
+    }
+
     /// Tries to verify the given code for the current time, accounting for *skews*.
     ///
     /// # Errors
     ///
     /// Returns [`time::Error`] if the system time is before the epoch.
     pub fn try_verify(&self, code: u32) -> Result<bool, time::Error> {
-        now().map(|time| self.verify_at(time, code))
+        current().map(|time| self.verify_at(time, code))
     }
 
     /// Verifies the given code for the current time, accounting for *skews*.
@@ -239,7 +565,7 @@ impl Totp<'_> {
     ///
     /// Panics if the system time is before the epoch.
     pub fn verify(&self, code: u32) -> bool {
-        self.verify_at(expect_now(), code)
+        self.verify_at(expect_current(), code)
     }
 
     /// Tries to verify the given string code for the current time, accounting for *skews*.
@@ -248,7 +574,7 @@ impl Totp<'_> {
     ///
     /// Returns [`time::Error`] if the system time is before the epoch.
     pub fn try_verify_string<S: AsRef<str>>(&self, code: S) -> Result<bool, time::Error> {
-        now().map(|time| self.verify_string_at(time, code))
+        current().map(|time| self.verify_string_at(time, code))
     }
 
     /// Verifies the given string code for the current time, accounting for *skews*.
@@ -257,7 +583,33 @@ impl Totp<'_> {
     ///
     /// Panics if the system time is before the epoch.
     pub fn verify_string<S: AsRef<str>>(&self, code: S) -> bool {
-        self.verify_string_at(expect_now(), code)
+        self.verify_string_at(expect_current(), code)
+    }
+
+    /// Similar to [`try_verify_string`], except the code is normalized according to the options.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`time::Error`] if the system time is before the epoch.
+    ///
+    /// [`try_verify_string`]: Self::try_verify_string
+    pub fn try_verify_string_with<S: AsRef<str>>(
+        &self,
+        code: S,
+        options: VerifyOptions,
+    ) -> Result<bool, time::Error> {
+        current().map(|time| self.verify_string_at_with(time, code, options))
+    }
+
+    /// Similar to [`verify_string`], except the code is normalized according to the options.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the system time is before the epoch.
+    ///
+    /// [`verify_string`]: Self::verify_string
+    pub fn verify_string_with<S: AsRef<str>>(&self, code: S, options: VerifyOptions) -> bool {
+        self.verify_string_at_with(expect_current(), code, options)
     }
 }
 
@@ -271,6 +623,7 @@ pub const PERIOD: &str = "period";
 #[derive(Debug, Error, Diagnostic)]
 #[error(transparent)]
 #[diagnostic(transparent)]
+#[non_exhaustive]
 pub enum ErrorSource {
     /// The base configuration could not be extracted.
     Base(#[from] base::Error),
@@ -278,10 +631,21 @@ pub enum ErrorSource {
     Period(#[from] period::ParseError),
 }
 
+#[cfg(feature = "auth")]
+impl ErrorSource {
+    /// Returns the parameter that caused this error.
+    pub fn parameter(&self) -> Parameter<'_> {
+        match self {
+            Self::Base(error) => error.parameter(),
+            Self::Period(error) => Parameter::with_value(PERIOD, &error.string),
+        }
+    }
+}
+
 /// Represents errors that can occur when extracting TOTP configurations from OTP URLs.
 #[cfg(feature = "auth")]
 #[derive(Debug, Error, Diagnostic)]
-#[error("failed to extract TOTP from OTP URL")]
+#[error("failed to extract TOTP from OTP URL (parameter {})", .source.parameter())]
 #[diagnostic(code(otp_std::totp), help("see the report for more information"))]
 pub struct Error {
     /// The source of this error.
@@ -306,6 +670,11 @@ impl Error {
     pub fn period(error: period::ParseError) -> Self {
         Self::new(error.into())
     }
+
+    /// Returns the parameter that caused this error.
+    pub fn parameter(&self) -> Parameter<'_> {
+        self.source.parameter()
+    }
 }
 
 #[cfg(feature = "auth")]
@@ -329,11 +698,7 @@ impl Totp<'_> {
     pub fn extract_from(query: &mut Query<'_>) -> Result<Self, Error> {
         let base = Base::extract_from(query).map_err(Error::base)?;
 
-        let maybe_period = query
-            .remove(PERIOD)
-            .map(|string| string.parse())
-            .transpose()
-            .map_err(Error::period)?;
+        let maybe_period = extract_parse(query, PERIOD).map_err(Error::period)?;
 
         let totp = Self::builder()
             .base(base)
@@ -344,6 +709,16 @@ impl Totp<'_> {
     }
 }
 
+impl Totp<'_> {
+    /// Checks whether [`Self`] and the other configuration have the same parameters,
+    /// comparing everything except the secrets (see [`Base::same_parameters`]).
+    pub fn same_parameters(&self, other: &Totp<'_>) -> bool {
+        self.base.same_parameters(&other.base)
+            && self.skew == other.skew
+            && self.period == other.period
+    }
+}
+
 /// Represents owned [`Totp`].
 pub type Owned = Totp<'static>;
 
@@ -357,3 +732,69 @@ impl Totp<'_> {
             .build()
     }
 }
+
+/// Constructs [`Totp`] from literals, validated at compile time.
+///
+/// The secret is given as Base32-encoded string (see [`secret!`]), followed by
+/// optional `algorithm`, `digits`, `skew` and `period`, in this order.
+///
+/// Invalid secrets, digits and periods result in compilation errors.
+///
+/// # Examples
+///
+/// ```
+/// use otp_std::{totp, Algorithm};
+///
+/// let totp = totp!(
+///     "JEQDYMZAN5YGK3RAONXXK4TDMU",
+///     algorithm = Algorithm::Sha1,
+///     digits = 8,
+///     period = 60,
+/// );
+///
+/// assert_eq!(totp.base.digits.get(), 8);
+/// assert_eq!(totp.period.get(), 60);
+/// ```
+///
+/// Invalid periods are rejected at compile time:
+///
+/// ```compile_fail
+/// use otp_std::totp;
+///
+/// let totp = totp!("JEQDYMZAN5YGK3RAONXXK4TDMU", period = 0);
+/// ```
+///
+/// [`secret!`]: crate::secret!
+#[macro_export]
+macro_rules! totp {
+    (
+        $secret: expr
+        $(, algorithm = $algorithm: expr)?
+        $(, digits = $digits: expr)?
+        $(, skew = $skew: expr)?
+        $(, period = $period: expr)?
+        $(,)?
+    ) => {
+        $crate::totp::Totp::builder()
+            .base(
+                $crate::base::Base::builder()
+                    .secret($crate::secret!($secret))
+                    $(.algorithm($algorithm))?
+                    $(.digits({
+                        const DIGITS: $crate::digits::Digits =
+                            $crate::digits::Digits::new_ok($digits).expect("invalid digits");
+
+                        DIGITS
+                    }))?
+                    .build(),
+            )
+            $(.skew($crate::skew::Skew::new($skew)))?
+            $(.period({
+                const PERIOD: $crate::period::Period =
+                    $crate::period::Period::new_ok($period).expect("invalid period");
+
+                PERIOD
+            }))?
+            .build()
+    };
+}
@@ -0,0 +1,228 @@
+//! Policy-enforcing TOTP verification.
+
+use std::sync::Mutex;
+
+use crate::{
+    options::VerifyOptions,
+    policy::{self, Policy, RateLimitError, ReplayError},
+    time::expect_current,
+    totp::Totp,
+};
+
+/// Represents the mutable state of [`Verifier`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct State {
+    /// The last accepted input, if any.
+    pub last: Option<u64>,
+    /// The current rate limit interval.
+    pub interval: u64,
+    /// The number of attempts made during the current interval.
+    pub attempts: u64,
+}
+
+/// The message used for poisoned state panics.
+pub const POISONED: &str = "verifier state is poisoned";
+
+/// Represents TOTP verifiers that enforce [`Policy`].
+#[derive(Debug)]
+pub struct Verifier<'v> {
+    totp: Totp<'v>,
+    policy: Policy,
+    state: Mutex<State>,
+}
+
+impl<'v> Verifier<'v> {
+    /// Constructs [`Self`] with the default (empty) policy.
+    pub fn new(totp: Totp<'v>) -> Self {
+        Self::with_policy(totp, Policy::default())
+    }
+
+    /// Constructs [`Self`] with the given policy.
+    pub fn with_policy(totp: Totp<'v>, policy: Policy) -> Self {
+        Self {
+            totp,
+            policy,
+            state: Mutex::default(),
+        }
+    }
+
+    /// Returns the TOTP configuration.
+    pub const fn totp(&self) -> &Totp<'v> {
+        &self.totp
+    }
+
+    /// Returns the policy.
+    pub const fn policy(&self) -> &Policy {
+        &self.policy
+    }
+
+    /// Returns the copy of the current state.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the state is poisoned.
+    pub fn state(&self) -> State {
+        *self.state.lock().expect(POISONED)
+    }
+
+    /// Consumes [`Self`], returning the TOTP configuration and the policy.
+    pub fn into_parts(self) -> (Totp<'v>, Policy) {
+        (self.totp, self.policy)
+    }
+}
+
+impl Verifier<'_> {
+    fn verify_with<F: Fn(u64) -> bool>(&self, time: u64, verify: F) -> Result<bool, policy::Error> {
+        let policy = self.policy;
+
+        policy.check_window(time).map_err(policy::Error::window)?;
+
+        let mut state = self.state.lock().expect(POISONED);
+
+        if let Some(rate_limit) = policy.rate_limit {
+            let interval = rate_limit.interval_at(time);
+
+            if state.interval != interval {
+                state.interval = interval;
+                state.attempts = 0;
+            };
+
+            if state.attempts >= rate_limit.attempts {
+                return Err(policy::Error::rate_limit(RateLimitError::new(
+                    rate_limit.attempts,
+                )));
+            };
+
+            state.attempts += 1;
+        };
+
+        let skew = policy.limit_skew(self.totp.skew);
+
+        let Some(input) = skew
+            .apply(self.totp.input_at(time))
+            .find(|&input| verify(input))
+        else {
+            return Ok(false);
+        };
+
+        if policy.replay_guard {
+            if let Some(last) = state.last {
+                if input <= last {
+                    return Err(policy::Error::replay(ReplayError::new(input)));
+                };
+            };
+
+            state.last = Some(input);
+        };
+
+        Ok(true)
+    }
+
+    /// Verifies the given code for the given time, enforcing the policy.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`policy::Error`] if the verification is rejected by the policy.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the state is poisoned.
+    pub fn verify_at(&self, time: u64, code: u32) -> Result<bool, policy::Error> {
+        self.verify_with(time, |input| self.totp.base.verify(input, code))
+    }
+
+    /// Verifies the given string code for the given time, enforcing the policy.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`policy::Error`] if the verification is rejected by the policy.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the state is poisoned.
+    pub fn verify_string_at<S: AsRef<str>>(
+        &self,
+        time: u64,
+        code: S,
+    ) -> Result<bool, policy::Error> {
+        let code = code.as_ref();
+
+        self.verify_with(time, |input| self.totp.base.verify_string(input, code))
+    }
+
+    /// Verifies the given code for the current time, enforcing the policy.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`policy::Error`] if the verification is rejected by the policy.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the system time is before the epoch or if the state is poisoned.
+    pub fn verify(&self, code: u32) -> Result<bool, policy::Error> {
+        self.verify_at(expect_current(), code)
+    }
+
+    /// Verifies the given string code for the current time, enforcing the policy.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`policy::Error`] if the verification is rejected by the policy.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the system time is before the epoch or if the state is poisoned.
+    pub fn verify_string<S: AsRef<str>>(&self, code: S) -> Result<bool, policy::Error> {
+        self.verify_string_at(expect_current(), code)
+    }
+
+    /// Similar to [`verify_string_at`], except the code is normalized according to the options.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`policy::Error`] if the verification is rejected by the policy.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the state is poisoned.
+    ///
+    /// [`verify_string_at`]: Self::verify_string_at
+    pub fn verify_string_at_with<S: AsRef<str>>(
+        &self,
+        time: u64,
+        code: S,
+        options: VerifyOptions,
+    ) -> Result<bool, policy::Error> {
+        let code = options.normalize(code.as_ref());
+
+        let result = self.verify_with(time, |input| {
+            self.totp.base.verify_normalized(input, &code, options)
+        });
+
+        options.discard(code);
+
+        result
+    }
+
+    /// Similar to [`verify_string`], except the code is normalized according to the options.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`policy::Error`] if the verification is rejected by the policy.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the system time is before the epoch or if the state is poisoned.
+    ///
+    /// [`verify_string`]: Self::verify_string
+    pub fn verify_string_with<S: AsRef<str>>(
+        &self,
+        code: S,
+        options: VerifyOptions,
+    ) -> Result<bool, policy::Error> {
+        self.verify_string_at_with(expect_current(), code, options)
+    }
+}
+
+/// Represents owned [`Verifier`].
+pub type Owned = Verifier<'static>;
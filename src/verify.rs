@@ -0,0 +1,120 @@
+//! Object-safe verification.
+//!
+//! The [`OtpVerify`] trait allows holding verifiers as `Box<dyn OtpVerify + Send + Sync>`,
+//! which is useful for frameworks and middleware.
+//!
+//! # Examples
+//!
+//! ```
+//! use otp_std::{verify::OtpVerify, Base, Secret, Totp, Verifier};
+//!
+//! let secret = Secret::decode("JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+//!
+//! let base = Base::builder().secret(secret).build();
+//! let totp = Totp::builder().base(base).build();
+//!
+//! let verifiers: Vec<Box<dyn OtpVerify + Send + Sync>> = vec![
+//!     Box::new(totp.clone()),
+//!     Box::new(Verifier::new(totp.clone())),
+//! ];
+//!
+//! let code = totp.generate_string();
+//!
+//! for verifier in verifiers {
+//!     assert!(verifier.verify_now(&code).is_valid());
+//! }
+//! ```
+
+#[cfg(feature = "auth")]
+use crate::auth::rotation::{Matched, Rotation};
+
+use crate::{
+    policy,
+    time::{self, current},
+    totp::Totp,
+    verifier::Verifier,
+};
+
+/// Represents outcomes of verification.
+#[derive(Debug)]
+pub enum VerifyOutcome {
+    /// The code is valid.
+    Valid,
+    /// The code is valid for the old secret (see [`Rotation`]).
+    ///
+    /// [`Rotation`]: crate::auth::rotation::Rotation
+    ValidOld,
+    /// The code is invalid.
+    Invalid,
+    /// The verification was rejected by the policy.
+    Rejected(policy::Error),
+    /// The system time is before the epoch.
+    Time(time::Error),
+}
+
+impl VerifyOutcome {
+    /// Constructs [`Self`] from the verification result.
+    pub const fn from_bool(valid: bool) -> Self {
+        if valid {
+            Self::Valid
+        } else {
+            Self::Invalid
+        }
+    }
+
+    /// Checks whether the code is valid, including [`ValidOld`].
+    ///
+    /// [`ValidOld`]: Self::ValidOld
+    pub const fn is_valid(&self) -> bool {
+        matches!(self, Self::Valid | Self::ValidOld)
+    }
+}
+
+impl From<bool> for VerifyOutcome {
+    fn from(valid: bool) -> Self {
+        Self::from_bool(valid)
+    }
+}
+
+/// Represents types that can verify codes for the current time.
+///
+/// This trait is object-safe.
+pub trait OtpVerify {
+    /// Verifies the given string code for the current time.
+    fn verify_now(&self, code: &str) -> VerifyOutcome;
+}
+
+impl OtpVerify for Totp<'_> {
+    fn verify_now(&self, code: &str) -> VerifyOutcome {
+        match current() {
+            Ok(time) => self.verify_string_at(time, code).into(),
+            Err(error) => VerifyOutcome::Time(error),
+        }
+    }
+}
+
+impl OtpVerify for Verifier<'_> {
+    fn verify_now(&self, code: &str) -> VerifyOutcome {
+        match current() {
+            Ok(time) => match self.verify_string_at(time, code) {
+                Ok(valid) => valid.into(),
+                Err(error) => VerifyOutcome::Rejected(error),
+            },
+            Err(error) => VerifyOutcome::Time(error),
+        }
+    }
+}
+
+#[cfg(feature = "auth")]
+impl OtpVerify for Rotation<'_> {
+    fn verify_now(&self, code: &str) -> VerifyOutcome {
+        match current() {
+            Ok(time) => match self.verify_string_at(time, code) {
+                Some(Matched::New) => VerifyOutcome::Valid,
+                Some(Matched::Old) => VerifyOutcome::ValidOld,
+                None => VerifyOutcome::Invalid,
+            },
+            Err(error) => VerifyOutcome::Time(error),
+        }
+    }
+}
@@ -0,0 +1,254 @@
+//! JavaScript bindings.
+//!
+//! This module exports [`Secret`], [`Totp`] and [`Auth`] wrappers to JavaScript
+//! via [`wasm_bindgen`](mod@wasm_bindgen), allowing browser authenticators to reuse this crate.
+//!
+//! Since the system time is not available on all WebAssembly targets, the time
+//! has to be provided explicitly, as seconds since the epoch, unless the `wasm` feature
+//! is enabled (see [`now`]).
+//!
+//! [`now`]: crate::time::now
+//!
+//! [`Auth`]: crate::auth::core::Auth
+
+use wasm_bindgen::prelude::{wasm_bindgen, JsError};
+
+#[cfg(feature = "auth")]
+use crate::{
+    auth::core::{Auth, Owned as OwnedAuth},
+    otp::core::Otp,
+};
+
+use crate::{
+    algorithm::Algorithm,
+    base::Base,
+    digits::Digits,
+    period::Period,
+    secret::core::{Owned as OwnedSecret, Secret},
+    skew::Skew,
+    totp::{Owned as OwnedTotp, Totp},
+};
+
+/// Represents secrets exported to JavaScript.
+#[wasm_bindgen(js_name = Secret)]
+#[derive(Debug, Clone)]
+pub struct JsSecret {
+    inner: OwnedSecret,
+}
+
+#[wasm_bindgen(js_class = Secret)]
+impl JsSecret {
+    /// Constructs [`Self`] from the given bytes (`Uint8Array` in JavaScript).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JsError`] if the length of the secret is unsafe.
+    #[wasm_bindgen(constructor)]
+    pub fn new(bytes: &[u8]) -> Result<Self, JsError> {
+        let inner = Secret::owned(bytes.to_vec())?;
+
+        Ok(Self { inner })
+    }
+
+    /// Decodes [`Self`] from the given Base32 string.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JsError`] if the secret could not be decoded.
+    pub fn decode(string: &str) -> Result<Self, JsError> {
+        let inner = Secret::decode(string)?;
+
+        Ok(Self { inner })
+    }
+
+    /// Generates [`Self`] of the length recommended for the given algorithm.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JsError`] if the algorithm is unknown.
+    #[cfg(feature = "generate-secret")]
+    pub fn generate(algorithm: Option<String>) -> Result<Self, JsError> {
+        let algorithm = parse_algorithm(algorithm)?;
+
+        let inner = Secret::generate_for(algorithm);
+
+        Ok(Self { inner })
+    }
+
+    /// Encodes [`Self`] into the Base32 string.
+    pub fn encode(&self) -> String {
+        self.inner.encode()
+    }
+
+    /// Returns the bytes of [`Self`] (`Uint8Array` in JavaScript).
+    pub fn bytes(&self) -> Vec<u8> {
+        self.inner.as_bytes().to_vec()
+    }
+}
+
+fn parse_algorithm(algorithm: Option<String>) -> Result<Algorithm, JsError> {
+    let algorithm = algorithm.map(|string| string.parse()).transpose()?;
+
+    Ok(algorithm.unwrap_or_default())
+}
+
+/// Represents TOTP configurations exported to JavaScript.
+#[wasm_bindgen(js_name = Totp)]
+#[derive(Debug, Clone)]
+pub struct JsTotp {
+    inner: OwnedTotp,
+}
+
+#[wasm_bindgen(js_class = Totp)]
+impl JsTotp {
+    /// Constructs [`Self`], using defaults for the parameters not provided.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JsError`] if any of the parameters are invalid.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        secret: &JsSecret,
+        algorithm: Option<String>,
+        digits: Option<u8>,
+        period: Option<u64>,
+        skew: Option<u64>,
+    ) -> Result<Self, JsError> {
+        let algorithm = parse_algorithm(algorithm)?;
+
+        let digits = digits.map(Digits::new).transpose()?;
+
+        let period = period.map(Period::new).transpose()?;
+
+        let base = Base::builder()
+            .secret(secret.inner.clone())
+            .algorithm(algorithm)
+            .maybe_digits(digits)
+            .build();
+
+        let inner = Totp::builder()
+            .base(base)
+            .maybe_period(period)
+            .maybe_skew(skew.map(Skew::new))
+            .build();
+
+        Ok(Self { inner })
+    }
+
+    /// Generates the string code for the given time.
+    #[wasm_bindgen(js_name = generateAt)]
+    pub fn generate_at(&self, time: u64) -> String {
+        self.inner.generate_string_at(time)
+    }
+
+    /// Verifies the given string code for the given time, accounting for *skews*.
+    #[wasm_bindgen(js_name = verifyAt)]
+    pub fn verify_at(&self, time: u64, code: &str) -> bool {
+        self.inner.verify_string_at(time, code)
+    }
+
+    /// Returns the time to live of the code generated for the given time.
+    #[wasm_bindgen(js_name = timeToLiveAt)]
+    pub fn time_to_live_at(&self, time: u64) -> u64 {
+        self.inner.time_to_live_at(time)
+    }
+
+    /// Returns the secret used.
+    pub fn secret(&self) -> JsSecret {
+        JsSecret {
+            inner: self.inner.base.secret.clone(),
+        }
+    }
+
+    /// Generates the string code for the current time.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JsError`] if the current time is before the epoch.
+    #[cfg(feature = "wasm")]
+    pub fn generate(&self) -> Result<String, JsError> {
+        let code = self.inner.try_generate_string()?;
+
+        Ok(code)
+    }
+
+    /// Verifies the given string code for the current time, accounting for *skews*.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JsError`] if the current time is before the epoch.
+    #[cfg(feature = "wasm")]
+    pub fn verify(&self, code: &str) -> Result<bool, JsError> {
+        let valid = self.inner.try_verify_string(code)?;
+
+        Ok(valid)
+    }
+}
+
+impl JsTotp {
+    /// Returns the wrapped TOTP configuration.
+    pub const fn get(&self) -> &OwnedTotp {
+        &self.inner
+    }
+}
+
+/// Represents OTP authentication exported to JavaScript.
+#[cfg(feature = "auth")]
+#[wasm_bindgen(js_name = Auth)]
+#[derive(Debug, Clone)]
+pub struct JsAuth {
+    inner: OwnedAuth,
+}
+
+#[cfg(feature = "auth")]
+#[wasm_bindgen(js_class = Auth)]
+impl JsAuth {
+    /// Parses [`Self`] from the given OTP URL.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JsError`] if the OTP URL could not be parsed.
+    pub fn parse(string: &str) -> Result<Self, JsError> {
+        let inner = Auth::parse_url(string)?;
+
+        Ok(Self { inner })
+    }
+
+    /// Builds the OTP URL.
+    #[wasm_bindgen(js_name = toUrl)]
+    pub fn to_url(&self) -> String {
+        self.inner.build_url().into()
+    }
+
+    /// Returns the issuer, if any.
+    pub fn issuer(&self) -> Option<String> {
+        self.inner
+            .label()
+            .issuer
+            .as_ref()
+            .map(|issuer| issuer.as_str().to_owned())
+    }
+
+    /// Returns the user.
+    pub fn user(&self) -> String {
+        self.inner.label().user.as_str().to_owned()
+    }
+
+    /// Returns the TOTP configuration, if the OTP URL is for TOTP.
+    pub fn totp(&self) -> Option<JsTotp> {
+        match self.inner.otp() {
+            Otp::Totp(totp) => Some(JsTotp {
+                inner: totp.clone().into_owned(),
+            }),
+            Otp::Hotp(_) => None,
+        }
+    }
+}
+
+#[cfg(feature = "auth")]
+impl JsAuth {
+    /// Returns the wrapped authentication.
+    pub const fn get(&self) -> &OwnedAuth {
+        &self.inner
+    }
+}
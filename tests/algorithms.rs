@@ -0,0 +1,98 @@
+use otp_std::{
+    algorithm::{HmacBuffer, HMAC_BUFFER_SIZE},
+    testing::builders::{build_label, USER},
+    Algorithm, Auth, Base, Length, Secret, Totp,
+};
+
+const NEW: [(Algorithm, &str, usize); 4] = [
+    (Algorithm::Sha3_256, "SHA3-256", 32),
+    (Algorithm::Sha3_512, "SHA3-512", 64),
+    (Algorithm::Blake2s, "BLAKE2S", 32),
+    (Algorithm::Blake2b, "BLAKE2B", 64),
+];
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+const KEY: &[u8] = b"key";
+const DATA: &[u8] = b"The quick brown fox jumps over the lazy dog";
+
+#[test]
+fn vectors() {
+    let expected = [
+        (Algorithm::Sha3_256, "8c6e0683409427f8931711b10ca92a506eb1fafa48fadd66d76126f47ac2c333"),
+        (Algorithm::Sha3_512, "237a35049c40b3ef5ddd960b3dc893d8284953b9a4756611b1b61bffcf53edd979f93547db714b06ef0a692062c609b70208ab8d4a280ceee40ed8100f293063"),
+        (Algorithm::Blake2s, "f93215bb90d4af4c3061cd932fb169fb8bb8a91d0b4022baea1271e1323cd9a0"),
+        (Algorithm::Blake2b, "92294f92c0dfb9b00ec9ae8bd94d7e7d8a036b885a499f149dfe2fd2199394aaaf6b8894a1730cccb2cd050f9bcf5062a38b51b0dab33207f8ef35ae2c9df51b"),
+    ];
+
+    for (algorithm, hmac) in expected {
+        assert_eq!(hex(&algorithm.hmac(KEY, DATA)), hmac);
+    }
+}
+
+#[test]
+fn names() {
+    for (algorithm, name, _) in NEW {
+        assert_eq!(algorithm.static_str(), name);
+        assert_eq!(name.parse::<Algorithm>().unwrap(), algorithm);
+    }
+}
+
+#[test]
+fn lengths() {
+    for (algorithm, _, length) in NEW {
+        assert_eq!(algorithm.hash_length(), length);
+        assert_eq!(algorithm.recommended_length(), length);
+        assert_eq!(Length::recommended_for(algorithm).get(), length);
+
+        assert_eq!(algorithm.hmac(b"key", b"data").len(), length);
+    }
+}
+
+#[test]
+fn hmac_into() {
+    for (algorithm, _, _) in NEW {
+        let mut buffer: HmacBuffer = [0; HMAC_BUFFER_SIZE];
+
+        let length = algorithm.hmac_into(b"key", b"data", &mut buffer);
+
+        assert_eq!(&buffer[..length], algorithm.hmac(b"key", b"data"));
+    }
+}
+
+#[test]
+fn distinct() {
+    let codes: Vec<Vec<u8>> = Algorithm::ARRAY
+        .iter()
+        .map(|algorithm| algorithm.hmac(b"key", b"data"))
+        .collect();
+
+    for (index, code) in codes.iter().enumerate() {
+        assert!(!codes[index + 1..].contains(code));
+    }
+
+    assert_eq!(Algorithm::COUNT, 7);
+}
+
+#[test]
+fn url_round_trip() {
+    for (algorithm, name, _) in NEW {
+        let secret = Secret::generate_for(algorithm);
+
+        let base = Base::builder().secret(secret).algorithm(algorithm).build();
+        let totp = Totp::builder().base(base).build();
+
+        let auth = Auth::builder()
+            .otp(totp)
+            .label(build_label(None, USER))
+            .build();
+
+        let url = auth.build_url();
+
+        assert!(url.as_str().contains(&format!("algorithm={name}")));
+
+        assert!(Auth::parse_url(url).unwrap().same_parameters(&auth));
+    }
+}
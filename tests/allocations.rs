@@ -0,0 +1,107 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+use otp_std::{
+    algorithm::{HmacBuffer, HMAC_BUFFER_SIZE},
+    base::generate_into,
+    Algorithm, Base, Secret, Skew, Totp,
+};
+
+struct Counting;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|allocations| allocations.set(allocations.get() + 1));
+
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        System.dealloc(pointer, layout);
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+fn allocations<F: FnOnce()>(function: F) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+
+    function();
+
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+const TIME: u64 = 1_234_567_890;
+
+fn totp(algorithm: Algorithm) -> Totp<'static> {
+    let secret = Secret::generate_for(algorithm);
+
+    let base = Base::builder().secret(secret).algorithm(algorithm).build();
+
+    Totp::builder().base(base).skew(Skew::new(2)).build()
+}
+
+#[test]
+fn generate_does_not_allocate() {
+    for algorithm in Algorithm::ARRAY {
+        let totp = totp(algorithm);
+
+        assert_eq!(
+            allocations(|| {
+                totp.base.generate(TIME);
+            }),
+            0
+        );
+    }
+}
+
+#[test]
+fn verify_does_not_allocate() {
+    for algorithm in Algorithm::ARRAY {
+        let totp = totp(algorithm);
+
+        let code = totp.generate_at(TIME);
+        let string = totp.generate_string_at(TIME);
+
+        assert_eq!(
+            allocations(|| {
+                assert!(totp.verify_at(TIME, code));
+                assert!(totp.verify_string_at(TIME, &string));
+                assert!(!totp.verify_at(TIME + 3600, code));
+            }),
+            0
+        );
+    }
+}
+
+#[test]
+fn generate_into_reuses_buffer() {
+    let mut buffer: HmacBuffer = [0; HMAC_BUFFER_SIZE];
+
+    let totp = totp(Algorithm::Sha1);
+
+    let base = &totp.base;
+
+    let count = allocations(|| {
+        for input in 0..16 {
+            let code = generate_into(
+                base.secret.as_ref(),
+                input,
+                base.digits,
+                base.algorithm,
+                &mut buffer,
+            );
+
+            assert_eq!(code, base.generate(input));
+        }
+    });
+
+    assert_eq!(count, 0);
+}
@@ -0,0 +1,37 @@
+use insta::assert_snapshot;
+use public_api::Builder as ApiBuilder;
+use rustdoc_json::Builder as JsonBuilder;
+
+const FEATURES: [&str; 16] = [
+    "serde",
+    "compact-serde",
+    "json",
+    "sha2",
+    "sha3",
+    "blake2",
+    "icons",
+    "testing",
+    "mnemonic",
+    "secure-memory",
+    "wasm-bindgen",
+    "zeroize",
+    "rayon",
+    "coarse-clock",
+    "print",
+    "qr-decode",
+];
+
+#[test]
+#[ignore = "requires the nightly toolchain"]
+fn public_api() {
+    let path = JsonBuilder::default()
+        .toolchain("nightly")
+        .manifest_path(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml"))
+        .features(FEATURES)
+        .build()
+        .unwrap();
+
+    let api = ApiBuilder::from_rustdoc_json(path).build().unwrap();
+
+    assert_snapshot!(api);
+}
@@ -0,0 +1,116 @@
+use otp_std::{
+    artifact::{ErrorSource, Seal},
+    auth::{
+        migration::google,
+        store::{self, Store},
+    },
+    Algorithm, Auth,
+};
+
+const URLS: [&str; 2] = [
+    "otpauth://totp/MelodyKit:nekitdev?secret=JEQDYMZAN5YGK3RAONXXK4TDMU&issuer=MelodyKit",
+    "otpauth://hotp/Other:nekit?secret=GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ&counter=13",
+];
+
+const KEY: &[u8] = b"vault key";
+
+fn same_parameters(left: &[Auth<'_>], right: &[Auth<'_>]) -> bool {
+    left.len() == right.len()
+        && left
+            .iter()
+            .zip(right)
+            .all(|(left, right)| left.same_parameters(right))
+}
+
+fn build_store() -> Store<'static> {
+    URLS.iter()
+        .map(|url| Auth::parse_url(url).unwrap())
+        .collect()
+}
+
+fn artifact_source(error: store::Error) -> ErrorSource {
+    match error.source {
+        store::ErrorSource::Artifact(error) => error.source,
+        _ => panic!("expected artifact error"),
+    }
+}
+
+#[test]
+fn round_trip() {
+    let store = build_store();
+
+    let seal = Seal::builder()
+        .key(KEY)
+        .algorithm(Algorithm::Sha256)
+        .build();
+
+    let sealed = store.seal(&seal);
+
+    assert_eq!(sealed[0], store::ARTIFACT_VERSION);
+    assert!(same_parameters(
+        Store::open(&seal, &sealed).unwrap().entries(),
+        store.entries()
+    ));
+}
+
+#[test]
+fn tampered() {
+    let seal = Seal::new(KEY);
+
+    let mut sealed = build_store().seal(&seal);
+
+    sealed[10] ^= 1;
+
+    let error = Store::open(&seal, &sealed).unwrap_err();
+
+    assert!(matches!(artifact_source(error), ErrorSource::Tampered(_)));
+
+    let sealed = build_store().seal(&seal);
+
+    let error = Store::open(&Seal::new(b"other key"), &sealed).unwrap_err();
+
+    assert!(matches!(artifact_source(error), ErrorSource::Tampered(_)));
+}
+
+#[test]
+fn wrong_version() {
+    let seal = Seal::new(KEY);
+
+    let sealed = seal.seal(store::ARTIFACT_VERSION + 1, URLS[0]);
+
+    let error = Store::open(&seal, &sealed).unwrap_err();
+
+    let ErrorSource::WrongVersion(error) = artifact_source(error) else {
+        panic!("expected wrong version");
+    };
+
+    assert_eq!(error.expected, store::ARTIFACT_VERSION);
+    assert_eq!(error.actual, store::ARTIFACT_VERSION + 1);
+}
+
+#[test]
+fn truncated() {
+    let seal = Seal::new(KEY);
+
+    let error = Store::open(&seal, b"short").unwrap_err();
+
+    assert!(matches!(artifact_source(error), ErrorSource::Truncated(_)));
+}
+
+#[test]
+fn migration() {
+    let store = build_store();
+
+    let seal = Seal::new(KEY);
+
+    let sealed = google::seal(&seal, &store).unwrap();
+
+    assert!(same_parameters(
+        &google::open(&seal, &sealed).unwrap(),
+        store.entries()
+    ));
+
+    let error = google::open(&Seal::new(b"other key"), &sealed).unwrap_err();
+
+    assert!(matches!(error.source, google::ErrorSource::Artifact(_)));
+}
@@ -0,0 +1,111 @@
+use otp_std::{
+    batch::{verify_many, verify_many_into},
+    Algorithm, Base, Period, Secret, Skew, Totp,
+};
+
+#[cfg(feature = "rayon")]
+use otp_std::batch::par_verify_many;
+
+const TIME: u64 = 1_234_567_890;
+
+fn totps() -> Vec<Totp<'static>> {
+    Algorithm::ARRAY
+        .into_iter()
+        .enumerate()
+        .map(|(index, algorithm)| {
+            let secret = Secret::generate_for(algorithm);
+
+            let base = Base::builder().secret(secret).algorithm(algorithm).build();
+
+            let period = Period::new(30 + index as u64).unwrap();
+
+            Totp::builder()
+                .base(base)
+                .skew(Skew::new(index as u64 % 3))
+                .period(period)
+                .build()
+        })
+        .collect()
+}
+
+fn items<'t>(totps: &'t [Totp<'static>]) -> Vec<(&'t Totp<'static>, u64, u32)> {
+    totps
+        .iter()
+        .flat_map(|totp| {
+            let code = totp.generate_at(TIME);
+            let behind = totp.generate_at(TIME - totp.period.get());
+
+            [
+                (totp, TIME, code),
+                (totp, TIME, behind),
+                (totp, TIME + 3600, code),
+                (totp, TIME, code ^ 1),
+            ]
+        })
+        .collect()
+}
+
+#[test]
+fn matches_verify_at() {
+    let totps = totps();
+    let items = items(&totps);
+
+    let expected: Vec<bool> = items
+        .iter()
+        .map(|&(totp, time, code)| totp.verify_at(time, code))
+        .collect();
+
+    assert!(expected.contains(&true));
+    assert!(expected.contains(&false));
+
+    assert_eq!(verify_many(items.iter().copied()), expected);
+}
+
+#[test]
+fn appends_results() {
+    let totps = totps();
+    let items = items(&totps);
+
+    let mut results = vec![false];
+
+    verify_many_into(items.iter().copied(), &mut results);
+
+    assert_eq!(results.len(), items.len() + 1);
+    assert_eq!(&results[1..], verify_many(items.iter().copied()));
+}
+
+#[test]
+fn empty() {
+    assert!(verify_many([]).is_empty());
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn parallel_matches_sequential() {
+    let totps = totps();
+    let items = items(&totps);
+
+    assert_eq!(par_verify_many(&items), verify_many(items.iter().copied()));
+}
+
+#[test]
+fn verify_batch() {
+    let totps = totps();
+
+    for totp in &totps {
+        let items: Vec<(u64, u32)> = (0..16)
+            .map(|index| {
+                let time = TIME + index * 15;
+
+                (time, totp.generate_at(TIME + (index % 3) * 30))
+            })
+            .collect();
+
+        let expected: Vec<bool> = items
+            .iter()
+            .map(|&(time, code)| totp.verify_at(time, code))
+            .collect();
+
+        assert_eq!(totp.verify_batch(&items), expected);
+    }
+}
@@ -0,0 +1,27 @@
+use otp_std::testing::builders::build_base;
+
+const BINDING: &[u8] = b"transfer 100 EUR to nekitdev";
+
+#[test]
+fn round_trip() {
+    let base = build_base();
+
+    let code = base.generate_bound_string(13, BINDING);
+
+    assert!(base.verify_bound_string(13, BINDING, &code));
+
+    assert!(!base.verify_bound_string(14, BINDING, &code));
+    assert!(!base.verify_bound_string(13, b"transfer 999 EUR to attacker", &code));
+}
+
+#[test]
+fn differs_from_standard() {
+    let base = build_base();
+
+    let codes: Vec<_> = (0..16).map(|input| base.generate(input)).collect();
+    let bound: Vec<_> = (0..16)
+        .map(|input| base.generate_bound(input, []))
+        .collect();
+
+    assert_ne!(codes, bound);
+}
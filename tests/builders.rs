@@ -0,0 +1,119 @@
+use otp_std::{
+    testing::builders::{build_base, build_secret, build_totp, ENCODED},
+    Algorithm, Auth, Base, Config, Counter, Digits, Hotp, Label, Length, LookAhead, Part, Period,
+    Secret, Skew, Totp,
+};
+
+const ROTATED: &str = "KRUGKIDROVUWG2ZAMJZG653OEBTG66BA";
+
+#[test]
+fn maybe_setters() {
+    let algorithm: Option<Algorithm> = None;
+    let digits: Option<Digits> = Some(Digits::MAX);
+    let period: Option<Period> = None;
+    let skew: Option<Skew> = Some(Skew::disabled());
+    let counter: Option<Counter> = None;
+    let issuer: Option<Part<'_>> = None;
+
+    let secret = build_secret();
+
+    let base = Base::builder()
+        .secret(secret)
+        .maybe_algorithm(algorithm)
+        .maybe_digits(digits)
+        .build();
+
+    assert_eq!(base.algorithm, Algorithm::default());
+    assert_eq!(base.digits, Digits::MAX);
+
+    let hotp = Hotp::builder()
+        .base(base.clone())
+        .maybe_counter(counter)
+        .build();
+
+    assert_eq!(hotp.counter, Counter::default());
+
+    let totp = Totp::builder()
+        .base(base)
+        .maybe_period(period)
+        .maybe_skew(skew)
+        .build();
+
+    assert_eq!(totp.period, Period::default());
+    assert_eq!(totp.skew, Skew::disabled());
+
+    let label = Label::builder()
+        .maybe_issuer(issuer)
+        .user(Part::borrowed("nekitdev").unwrap())
+        .build();
+
+    let auth = Auth::builder().otp(totp).label(label).build();
+
+    assert!(auth.label.issuer.is_none());
+
+    let config = Config::builder()
+        .maybe_algorithm(algorithm)
+        .maybe_digits(digits)
+        .maybe_period(period)
+        .maybe_skew(skew)
+        .build();
+
+    assert_eq!(config.digits, Digits::MAX);
+}
+
+#[test]
+fn generate_with_recommended_length() {
+    let config = Config::builder().algorithm(Algorithm::Sha512).build();
+
+    let hotp = config.generate_hotp();
+
+    assert_eq!(hotp.base.algorithm, Algorithm::Sha512);
+
+    assert_eq!(
+        hotp.base.secret.as_bytes().len(),
+        Length::recommended_for(Algorithm::Sha512).get()
+    );
+}
+
+#[test]
+fn with_methods_totp() {
+    let totp = build_totp();
+
+    let period = Period::new(60).unwrap();
+
+    let adjusted = totp
+        .clone()
+        .with_algorithm(Algorithm::Sha1)
+        .with_digits(Digits::MAX)
+        .with_period(period)
+        .with_skew(Skew::disabled())
+        .with_secret(Secret::decode(ROTATED).unwrap());
+
+    assert_eq!(adjusted.base.digits, Digits::MAX);
+    assert_eq!(adjusted.period, period);
+    assert_eq!(adjusted.skew, Skew::disabled());
+    assert_eq!(adjusted.base.secret.expose_encoded(), ROTATED);
+
+    assert_eq!(totp.base.digits, Digits::default());
+    assert_eq!(totp.period, Period::default());
+    assert_eq!(totp.base.secret.expose_encoded(), ENCODED);
+}
+
+#[test]
+fn with_methods_hotp() {
+    let base = build_base();
+
+    let hotp = Hotp::builder().base(base).build();
+
+    let adjusted = hotp
+        .with_counter(Counter::new(13))
+        .with_look_ahead(LookAhead::disabled())
+        .with_digits(Digits::MAX)
+        .with_secret(Secret::decode(ROTATED).unwrap());
+
+    assert_eq!(adjusted.counter, Counter::new(13));
+    assert_eq!(adjusted.look_ahead, LookAhead::disabled());
+    assert_eq!(adjusted.base.digits, Digits::MAX);
+    assert_eq!(adjusted.base.algorithm, Algorithm::default());
+    assert_eq!(adjusted.base.secret.expose_encoded(), ROTATED);
+}
@@ -0,0 +1,108 @@
+use otp_std::{
+    clock::{Clock, FixedClock, OffsetClock, SystemClock},
+    now, now_millis,
+    testing::builders::build_totp,
+    time::MILLISECONDS_PER_SECOND,
+};
+
+#[cfg(feature = "coarse-clock")]
+use otp_std::{clock::CoarseClock, time::now_coarse};
+
+const TIME: u64 = 1_234_567_890;
+
+#[test]
+fn fixed() {
+    let totp = build_totp();
+
+    let mut clock = FixedClock::new(TIME);
+
+    let code = totp.generate_with(&clock);
+
+    assert_eq!(code, totp.generate_at(TIME));
+    assert_eq!(totp.try_generate_with(&clock).unwrap(), code);
+
+    assert!(totp.verify_with(&clock, code));
+    assert!(totp.try_verify_with(&clock, code).unwrap());
+
+    clock.time += 3600;
+
+    assert!(!totp.verify_with(&clock, code));
+}
+
+#[test]
+fn offset() {
+    let clock = OffsetClock::new(FixedClock::new(TIME), -60);
+
+    assert_eq!(clock.now().unwrap(), TIME - 60);
+
+    let totp = build_totp();
+
+    assert_eq!(totp.generate_with(&clock), totp.generate_at(TIME - 60));
+}
+
+#[test]
+fn offset_saturates() {
+    let clock = OffsetClock::new(FixedClock::new(10), -60);
+
+    assert_eq!(clock.now().unwrap(), 0);
+
+    let clock = OffsetClock::new(FixedClock::new(u64::MAX), 60);
+
+    assert_eq!(clock.now().unwrap(), u64::MAX);
+}
+
+#[test]
+fn system() {
+    let before = now().unwrap();
+
+    let time = SystemClock.now().unwrap();
+
+    assert!(time >= before);
+
+    let shifted = OffsetClock::system(3600).now().unwrap();
+
+    assert!(shifted >= time + 3600);
+}
+
+#[test]
+fn dynamic() {
+    let clocks: [&dyn Clock; 2] = [
+        &FixedClock::new(TIME),
+        &OffsetClock::new(FixedClock::new(TIME), 0),
+    ];
+
+    let totp = build_totp();
+
+    for clock in clocks {
+        assert_eq!(totp.generate_with(clock), totp.generate_at(TIME));
+    }
+}
+
+#[test]
+fn millis() {
+    let before = now().unwrap();
+
+    let millis = now_millis().unwrap();
+
+    let after = now().unwrap();
+
+    assert!(millis / MILLISECONDS_PER_SECOND >= before);
+    assert!(millis / MILLISECONDS_PER_SECOND <= after);
+}
+
+#[cfg(feature = "coarse-clock")]
+#[test]
+fn coarse() {
+    let before = now().unwrap();
+
+    let coarse = now_coarse().unwrap();
+
+    let after = now().unwrap();
+
+    assert!(coarse + 1 >= before);
+    assert!(coarse <= after);
+
+    let time = CoarseClock.now().unwrap();
+
+    assert!(time >= coarse);
+}
@@ -0,0 +1,43 @@
+#![cfg(feature = "coarse-clock")]
+
+use otp_std::{
+    testing::builders::build_totp,
+    time::{disable_coarse, enable_coarse, is_coarse_enabled},
+    OtpVerify, Verifier, VerifyOutcome,
+};
+
+// coarse time is global, so it is only enabled within this test
+#[test]
+fn toggle() {
+    let totp = build_totp();
+
+    assert!(!is_coarse_enabled());
+
+    enable_coarse();
+
+    assert!(is_coarse_enabled());
+
+    assert!(totp.verify(totp.generate()));
+    assert!(totp.time_to_live() <= totp.period.get());
+
+    assert!(totp.remaining().seconds() <= totp.period.get());
+
+    let (current, next) = totp.current_and_next();
+
+    assert_eq!(current.expiry, next.start);
+    assert!(totp.verify_code(current.code));
+
+    assert!(totp.verify_receipt(totp.generate()).is_some());
+
+    let code = totp.generate_code().to_string();
+
+    assert!(matches!(totp.verify_now(&code), VerifyOutcome::Valid));
+
+    let verifier = Verifier::new(totp.clone());
+
+    assert!(verifier.verify(totp.generate()).unwrap());
+
+    disable_coarse();
+
+    assert!(!is_coarse_enabled());
+}
@@ -0,0 +1,95 @@
+use otp_std::{
+    code::Code,
+    testing::builders::{build_base, build_hotp, build_totp},
+    Counter, Digits,
+};
+
+const TIME: u64 = 1_234_567_890;
+
+#[test]
+fn padding() {
+    let code = Code::new(7, Digits::new(8).unwrap()).unwrap();
+
+    assert_eq!(code.to_string(), "00000007");
+}
+
+#[test]
+fn bounds() {
+    assert!(Code::new(999_999, Digits::DEFAULT).is_ok());
+    assert!(Code::new(1_000_000, Digits::DEFAULT).is_err());
+}
+
+#[test]
+fn parse() {
+    let code: Code = "0123456".parse().unwrap();
+
+    assert_eq!(code.value(), 123_456);
+    assert_eq!(code.digits(), Digits::new(7).unwrap());
+
+    assert_eq!(code.to_string(), "0123456");
+}
+
+#[test]
+fn parse_invalid() {
+    for string in ["", "12345", "123456789", "12a456", "+12345", " 123456"] {
+        assert!(string.parse::<Code>().is_err(), "{string:?}");
+    }
+}
+
+#[test]
+fn base_round_trip() {
+    let base = build_base();
+
+    let code = base.generate_code(42);
+
+    assert_eq!(code.value(), base.generate(42));
+    assert_eq!(code.digits(), base.digits);
+
+    assert!(base.verify_code(42, code));
+    assert!(!base.verify_code(43, code));
+}
+
+#[test]
+fn digits_mismatch() {
+    let base = build_base();
+
+    let code = base.generate_code(42);
+
+    let other = Code::new(code.value(), Digits::new(8).unwrap()).unwrap();
+
+    assert!(!base.verify_code(42, other));
+}
+
+#[test]
+fn hotp() {
+    let hotp = build_hotp(Counter::new(13));
+
+    let code = hotp.peek_code();
+
+    assert_eq!(code.to_string(), hotp.peek_string());
+    assert!(hotp.verify_code(code));
+}
+
+#[test]
+fn totp() {
+    let totp = build_totp();
+
+    let code = totp.generate_code_at(TIME);
+
+    assert_eq!(code.to_string(), totp.generate_string_at(TIME));
+
+    assert!(totp.verify_code_at(TIME, code));
+    assert!(!totp.verify_code_at(TIME + 10 * totp.period.get(), code));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    let code = Code::new(42, Digits::DEFAULT).unwrap();
+
+    let string = serde_json::to_string(&code).unwrap();
+
+    assert_eq!(string, r#""000042""#);
+
+    assert_eq!(serde_json::from_str::<Code>(&string).unwrap(), code);
+}
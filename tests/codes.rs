@@ -0,0 +1,58 @@
+use otp_std::{
+    codes::Window,
+    testing::builders::{build_base, build_totp},
+    Period, Totp,
+};
+
+const TIME: u64 = 1_234_567_890;
+
+#[test]
+fn windows() {
+    let totp = build_totp();
+
+    let period = totp.period.get();
+
+    let codes: Vec<Window> = totp.codes_from(TIME).take(3).collect();
+
+    let start = TIME / period * period;
+
+    for (index, code) in (0..).zip(&codes) {
+        let window = start + index * period;
+
+        assert_eq!(code.start, window);
+        assert_eq!(code.expiry, window + period);
+        assert_eq!(code.code.to_string(), totp.generate_string_at(window));
+    }
+
+    assert!(codes[0].is_valid_at(TIME));
+    assert!(!codes[1].is_valid_at(TIME));
+
+    assert_eq!(codes[0].time_to_live_at(TIME), totp.time_to_live_at(TIME));
+}
+
+#[test]
+fn current_and_next() {
+    let totp = build_totp();
+
+    let (current, next) = totp.current_and_next_at(TIME);
+
+    let mut codes = totp.codes_from(TIME);
+
+    assert_eq!(Some(current), codes.next());
+    assert_eq!(Some(next), codes.next());
+}
+
+#[test]
+fn end() {
+    let base = build_base();
+
+    let totp = Totp::builder()
+        .base(base)
+        .period(Period::new(1).unwrap())
+        .build();
+
+    let codes: Vec<Window> = totp.codes_from(u64::MAX - 1).collect();
+
+    assert_eq!(codes.len(), 2);
+    assert_eq!(codes[1].expiry, u64::MAX);
+}
@@ -0,0 +1,33 @@
+#![cfg(feature = "compact-serde")]
+
+use serde_json::{json, to_value};
+
+use otp_std::{
+    testing::builders::{build_base, build_secret, ENCODED},
+    Base, Digits, Otp, Totp,
+};
+
+#[test]
+fn defaults_are_skipped() {
+    let base = build_base();
+    let totp = Totp::builder().base(base).build();
+
+    let value = to_value(Otp::Totp(totp)).unwrap();
+
+    assert_eq!(value, json!({ "type": "totp", "secret": ENCODED }));
+}
+
+#[test]
+fn non_defaults_are_kept() {
+    let secret = build_secret();
+
+    let base = Base::builder().secret(secret).digits(Digits::MAX).build();
+    let totp = Totp::builder().base(base).build();
+
+    let value = to_value(Otp::Totp(totp)).unwrap();
+
+    assert_eq!(
+        value,
+        json!({ "type": "totp", "secret": ENCODED, "digits": 8 })
+    );
+}
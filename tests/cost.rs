@@ -0,0 +1,13 @@
+use otp_std::{testing::builders::build_totp, Skew};
+
+#[test]
+fn window_size_saturates() {
+    assert_eq!(Skew::new(u64::MAX).window_size(), u64::MAX);
+}
+
+#[test]
+fn default_cost() {
+    let totp = build_totp();
+
+    assert_eq!(totp.verification_cost().hmac_calls, 3);
+}
@@ -0,0 +1,35 @@
+use otp_std::{
+    deps::{base32, Hmac, Mac, Sha1},
+    secret::encoding::ALPHABET,
+    testing::builders::{build_secret, ENCODED},
+    Algorithm,
+};
+
+#[test]
+fn alphabet() {
+    let secret = build_secret();
+
+    assert_eq!(base32::encode(ALPHABET, &secret.get()), ENCODED);
+}
+
+#[test]
+fn hmac() {
+    let mut mac = Hmac::<Sha1>::new_from_slice(b"key").unwrap();
+
+    mac.update(b"data");
+
+    assert_eq!(
+        mac.finalize().into_bytes().as_slice(),
+        Algorithm::Sha1.hmac(b"key", b"data")
+    );
+}
+
+#[cfg(feature = "auth")]
+#[test]
+fn url() {
+    use otp_std::{deps::Url, Auth};
+
+    let url = Url::parse("otpauth://totp/nekitdev?secret=JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap();
+
+    assert!(Auth::parse_url(url).is_ok());
+}
@@ -0,0 +1,61 @@
+use otp_std::{base::truncate, digits::Buffer, Digits};
+
+#[test]
+fn power_u64_matches_power() {
+    for value in Digits::MIN.get()..=Digits::MAX.get() {
+        let digits = Digits::new(value).unwrap();
+
+        assert_eq!(digits.power_u64(), u64::from(digits.power()));
+    }
+}
+
+#[test]
+fn truncate_stays_in_range() {
+    let hmac = [0xFF; 20];
+
+    for value in Digits::MIN.get()..=Digits::MAX.get() {
+        let digits = Digits::new(value).unwrap();
+
+        let code = truncate(hmac, digits);
+
+        assert_eq!(u64::from(code), 0x7FFF_FFFF % digits.power_u64());
+    }
+}
+
+#[test]
+fn format_fixed_matches_format_into() {
+    for value in Digits::MIN.get()..=Digits::MAX.get() {
+        let digits = Digits::new(value).unwrap();
+
+        for code in [0, 1, 42, 123_456, digits.power() - 1] {
+            let mut expected = Buffer::default();
+            let mut actual = Buffer::default();
+
+            assert_eq!(
+                digits.format_fixed(code, &mut actual),
+                digits.format_into(code, &mut expected)
+            );
+
+            assert_eq!(
+                digits.string(code),
+                format!("{code:0count$}", count = digits.count())
+            );
+        }
+    }
+}
+
+#[test]
+fn format_fixed_truncates() {
+    let mut buffer = Buffer::default();
+
+    assert_eq!(
+        Digits::DEFAULT.format_fixed(u32::MAX, &mut buffer),
+        b"967295"
+    );
+}
+
+#[test]
+fn string_does_not_truncate() {
+    assert_eq!(Digits::DEFAULT.string(42), "000042");
+    assert_eq!(Digits::DEFAULT.string(1_234_567), "1234567");
+}
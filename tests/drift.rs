@@ -0,0 +1,67 @@
+use otp_std::{testing::builders::build_base, Period, Skew, Totp};
+
+const TIME: u64 = 1_234_567_890;
+
+fn build_totp() -> Totp<'static> {
+    Totp::builder()
+        .base(build_base())
+        .skew(Skew::new(2))
+        .period(Period::new(60).unwrap())
+        .build()
+}
+
+#[test]
+fn exact() {
+    let totp = build_totp();
+
+    let matched = totp
+        .verify_string_detailed(TIME, totp.generate_string_at(TIME))
+        .unwrap();
+
+    assert!(matched.is_exact());
+    assert_eq!(matched.input, totp.input_at(TIME));
+    assert_eq!(matched.drift, 0);
+}
+
+#[test]
+fn ahead() {
+    let totp = build_totp();
+
+    let matched = totp
+        .verify_detailed(TIME, totp.generate_at(TIME + 120))
+        .unwrap();
+
+    assert_eq!(matched.input, totp.input_at(TIME) + 2);
+    assert_eq!(matched.offset, 2);
+    assert_eq!(matched.drift, 120);
+}
+
+#[test]
+fn behind() {
+    let totp = build_totp();
+
+    let matched = totp
+        .verify_detailed(TIME, totp.generate_at(TIME - 60))
+        .unwrap();
+
+    assert_eq!(matched.offset, -1);
+    assert_eq!(matched.drift, -60);
+}
+
+#[test]
+fn outside() {
+    let totp = build_totp();
+
+    assert!(totp
+        .verify_detailed(TIME, totp.generate_at(TIME + 180))
+        .is_none());
+}
+
+#[test]
+fn near_epoch() {
+    let totp = build_totp();
+
+    let matched = totp.verify_detailed(0, totp.generate_at(0)).unwrap();
+
+    assert!(matched.is_exact());
+}
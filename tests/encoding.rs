@@ -0,0 +1,179 @@
+use otp_std::{
+    secret::{
+        core::{ErrorSource, QueryErrorSource},
+        encoding::{
+            decode_any, decode_base64, decode_hex, decode_lenient, decode_with, encode_base64,
+            encode_hex, normalize, Format,
+        },
+    },
+    testing::builders::{build_secret, ENCODED},
+    Secret,
+};
+
+const HEX: &str = "49203c33206f70656e20736f75726365";
+
+const BASE64: &str = "SSA8MyBvcGVuIHNvdXJjZQ==";
+
+const PADDED: &str = "JEQDYMZAN5YGK3RAONXXK4TDMU======";
+
+#[test]
+fn formats() {
+    assert_eq!(
+        Secret::decode_with(Format::Base32, ENCODED)
+            .unwrap()
+            .as_bytes(),
+        build_secret().as_bytes()
+    );
+    assert_eq!(
+        Secret::decode_with(Format::Base32Padded, PADDED)
+            .unwrap()
+            .as_bytes(),
+        build_secret().as_bytes()
+    );
+    assert_eq!(
+        Secret::decode_with(Format::Hex, HEX).unwrap().as_bytes(),
+        build_secret().as_bytes()
+    );
+    assert_eq!(
+        Secret::decode_with(Format::Hex, HEX.to_uppercase())
+            .unwrap()
+            .as_bytes(),
+        build_secret().as_bytes()
+    );
+    assert_eq!(
+        Secret::decode_with(Format::Base64, BASE64)
+            .unwrap()
+            .as_bytes(),
+        build_secret().as_bytes()
+    );
+}
+
+#[test]
+fn wrong_format() {
+    let error = decode_with(Format::Hex, BASE64).unwrap_err();
+
+    assert_eq!(error.format, Format::Hex);
+    assert_eq!(error.secret, BASE64);
+
+    assert!(decode_with(Format::Base32, HEX).is_err());
+    assert!(decode_with(Format::Hex, "abc").is_err());
+    assert!(decode_with(Format::Base64, "SSA8M").is_err());
+}
+
+#[test]
+fn any_normalizes() {
+    let inputs = [
+        ENCODED.to_owned(),
+        ENCODED.to_lowercase(),
+        PADDED.to_owned(),
+        "JEQD YMZA N5YG K3RA ONXX K4TD MU".to_owned(),
+        "jeqd\tymza\nn5yg k3ra onxx k4td mu==".to_owned(),
+        HEX.to_owned(),
+        BASE64.to_owned(),
+    ];
+
+    for input in inputs {
+        assert_eq!(
+            Secret::decode_any(&input).unwrap().as_bytes(),
+            build_secret().as_bytes(),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn any_lists_attempted() {
+    let error = decode_any("not a secret!").unwrap_err();
+
+    assert_eq!(error.attempted, Format::ANY);
+
+    assert_eq!(
+        error.to_string(),
+        "failed to decode `not a secret!` secret, attempted `base32`, `hex`, `base64`"
+    );
+}
+
+#[test]
+fn secret_errors() {
+    let error = Secret::decode_any("!").unwrap_err();
+
+    assert!(matches!(error.source, ErrorSource::Any(_)));
+
+    let error = Secret::decode_with(Format::Hex, "!").unwrap_err();
+
+    assert!(matches!(error.source, ErrorSource::Format(_)));
+}
+
+#[cfg(not(feature = "unsafe-length"))]
+#[test]
+fn short_secret() {
+    let error = Secret::decode_with(Format::Hex, "00").unwrap_err();
+
+    assert!(matches!(error.source, ErrorSource::Length(_)));
+}
+
+#[test]
+fn lenient_accepts_base32_only() {
+    assert_eq!(decode_lenient(PADDED).unwrap(), build_secret().as_bytes());
+    assert_eq!(
+        decode_lenient("jeqd ymza n5yg k3ra onxx k4td mu").unwrap(),
+        build_secret().as_bytes()
+    );
+
+    let error = decode_lenient(BASE64).unwrap_err();
+
+    assert_eq!(error.secret, BASE64);
+}
+
+#[test]
+fn query_values() {
+    let values = [
+        ENCODED,
+        "jeqdymzan5ygk3raonxxk4tdmu",
+        "JEQDYMZAN5YGK3RAONXXK4TDMU%3D%3D%3D%3D%3D%3D",
+        "JEQD+YMZA+N5YG+K3RA+ONXX+K4TD+MU",
+        "JEQD%20YMZA%20N5YG%20K3RA%20ONXX%20K4TD%20MU",
+    ];
+
+    for value in values {
+        assert_eq!(
+            Secret::from_query_value(value).unwrap().as_bytes(),
+            build_secret().as_bytes(),
+            "{value}"
+        );
+    }
+}
+
+#[test]
+fn query_value_errors() {
+    let error = Secret::from_query_value("%FF").unwrap_err();
+
+    assert!(matches!(error.source, QueryErrorSource::Utf8(_)));
+
+    let error = Secret::from_query_value(HEX).unwrap_err();
+
+    assert!(matches!(
+        error.source,
+        QueryErrorSource::Secret(ref secret) if matches!(secret.source, ErrorSource::Encoding(_))
+    ));
+}
+
+#[test]
+fn normalize_removes_whitespace_and_padding() {
+    assert_eq!(normalize(" ab cd\n== "), "abcd");
+}
+
+#[test]
+fn round_trips() {
+    let bytes = build_secret().as_bytes().to_vec();
+
+    for length in 0..bytes.len() {
+        let slice = &bytes[..length];
+
+        assert_eq!(decode_hex(encode_hex(slice)).unwrap(), slice);
+        assert_eq!(decode_base64(encode_base64(slice)).unwrap(), slice);
+    }
+
+    assert_eq!(encode_hex(&bytes), HEX);
+    assert_eq!(encode_base64(&bytes), BASE64);
+}
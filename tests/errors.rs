@@ -0,0 +1,214 @@
+#![cfg(not(feature = "unsafe-length"))]
+
+use std::{
+    error::Error as _,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use insta::assert_snapshot;
+use miette::Diagnostic;
+use qrcode::types::QrError;
+
+use otp_std::{
+    artifact::{self, Seal},
+    auth::{
+        extensions::Extensions,
+        interop,
+        label::{self, MismatchError},
+        lenient,
+        migration::google,
+        options::{IssuerError, StrictError},
+        part::{self, SeparatorError},
+        qr, rotation,
+        scheme::{self, SchemePolicy},
+        store, url, utf8,
+    },
+    base, code,
+    deps::{
+        image::error::{ImageError, LimitError, LimitErrorKind},
+        rqrr::DeQRError,
+    },
+    errors, hotp, int, otp,
+    policy::{self, RateLimitError, ReplayError, WindowError},
+    print,
+    profile::{self, Profile, Violation, Violations},
+    secret::{encoding, length, locked, mnemonic},
+    time, totp, Algorithm, Auth, Counter, Digits, Label, Length, LookAhead, Part, Period, Secret,
+    Skew, SkewLimit, Type,
+};
+
+fn diagnostics() -> Vec<Box<dyn Diagnostic>> {
+    vec![
+        Box::new("MD5".parse::<Algorithm>().unwrap_err()),
+        Box::new(Seal::new(b"key").open(1, &[]).unwrap_err()),
+        Box::new(artifact::TamperedError),
+        Box::new(artifact::TruncatedError::new(0)),
+        Box::new(artifact::WrongVersionError::new(1, 2)),
+        Box::new(base::ValidationError::new_weak_secret(
+            16,
+            20,
+            Algorithm::Sha1,
+        )),
+        Box::new(base::WeakSecretError::new(16, 20, Algorithm::Sha1)),
+        Box::new(code::Code::new(1_000_000, Digits::DEFAULT).unwrap_err()),
+        Box::new("12a456".parse::<code::Code>().unwrap_err()),
+        Box::new("x".parse::<Counter>().unwrap_err()),
+        Box::new(Digits::new(0).unwrap_err()),
+        Box::new("x".parse::<Digits>().unwrap_err()),
+        Box::new(int::wrap("x".parse::<u8>().unwrap_err())),
+        Box::new("x".parse::<Type>().unwrap_err()),
+        Box::new(Period::new(0).unwrap_err()),
+        Box::new("0".parse::<Period>().unwrap_err()),
+        Box::new(Period::try_from_duration(Duration::ZERO).unwrap_err()),
+        Box::new(policy::Error::replay(ReplayError::new(0))),
+        Box::new(RateLimitError::new(0)),
+        Box::new(ReplayError::new(0)),
+        Box::new(WindowError::new(0)),
+        Box::new(profile::Error::new(
+            Profile::StrictRfc,
+            Violations::new(vec![Violation::Period {
+                actual: Period::new(60).unwrap(),
+                expected: Period::DEFAULT,
+            }]),
+        )),
+        Box::new(Secret::decode("0").unwrap_err()),
+        Box::new(Secret::from_query_value("0").unwrap_err()),
+        Box::new(encoding::decode("0").unwrap_err()),
+        Box::new(encoding::decode_any("0").unwrap_err()),
+        Box::new(encoding::decode_with(encoding::Format::Hex, "0").unwrap_err()),
+        Box::new(length::PolicyError::new(10, 16)),
+        Box::new(locked::Error),
+        Box::new(print::Error::new(QrError::DataTooLong)),
+        Box::new(qr::Error::new_not_found()),
+        Box::new(qr::NotFoundError),
+        Box::new(qr::DecodeError::new(DeQRError::InvalidVersion)),
+        Box::new(qr::ImageError::new(ImageError::Limits(
+            LimitError::from_kind(LimitErrorKind::DimensionError),
+        ))),
+        Box::new(otp::Otp::from_partial_json(serde_json::json!([])).unwrap_err()),
+        Box::new(otp::json::ObjectError),
+        Box::new(otp::json::DeserializeError::new(
+            serde_json::from_str::<u8>("x").unwrap_err(),
+        )),
+        Box::new(mnemonic::check(0).unwrap_err()),
+        Box::new(Secret::from_mnemonic("x").unwrap_err()),
+        Box::new(SkewLimit::DEFAULT.check_skew(Skew::new(11)).unwrap_err()),
+        Box::new("x".parse::<LookAhead>().unwrap_err()),
+        Box::new("x".parse::<Skew>().unwrap_err()),
+        Box::new(time::Error(
+            UNIX_EPOCH.duration_since(SystemTime::now()).unwrap_err(),
+        )),
+        Box::new(Length::new(0).unwrap_err()),
+        Box::new(Auth::parse_url("x").unwrap_err()),
+        Box::new(Extensions::new().insert("secret", "").unwrap_err()),
+        Box::new(label::Error::mismatch(MismatchError::new(
+            "A".to_owned(),
+            "B".to_owned(),
+        ))),
+        Box::new(Label::decode("").unwrap_err()),
+        Box::new(label::EmptyError),
+        Box::new(MismatchError::new("A".to_owned(), "B".to_owned())),
+        Box::new("".parse::<Label>().unwrap_err()),
+        Box::new("".parse::<Part>().unwrap_err()),
+        Box::new(Part::decode("").unwrap_err()),
+        Box::new(lenient::parse_totp("otpauth://hotp/?secret=x").unwrap_err()),
+        Box::new(lenient::HotpError),
+        Box::new(interop::Error::digits(Digits::new(0).unwrap_err())),
+        Box::new(google::parse_url("otpauth://totp/").unwrap_err()),
+        Box::new(google::Base64Error),
+        Box::new(google::FormatError),
+        Box::new(google::PayloadError),
+        Box::new(google::UnrepresentableError::new("period", "60".to_owned())),
+        Box::new(google::UnsupportedError::new("algorithm", 4)),
+        Box::new(IssuerError::new("A".to_owned(), None)),
+        Box::new(StrictError::new(vec!["image".to_owned()])),
+        Box::new(part::EmptyError),
+        Box::new(SeparatorError::new(":".to_owned())),
+        Box::new(rotation::Error),
+        Box::new(scheme::check("https").unwrap_err()),
+        Box::new(SchemePolicy::new("0").unwrap_err()),
+        Box::new(SchemePolicy::DEFAULT.check("https").unwrap_err()),
+        Box::new(store::Store::open(&Seal::new(b"key"), &[]).unwrap_err()),
+        Box::new(url::parse("x").unwrap_err()),
+        Box::new(utf8::wrap(String::from_utf8(vec![0xFF]).unwrap_err())),
+        Box::new(base::Error::new_secret_not_found()),
+        Box::new(base::SecretNotFoundError),
+        Box::new(hotp::CounterNotFoundError),
+        Box::new(hotp::Error::new_counter_not_found()),
+        Box::new(otp::core::Error::hotp(hotp::Error::new_counter_not_found())),
+        Box::new(otp::type_of::Error::new_not_found()),
+        Box::new(otp::type_of::NotFoundError),
+        Box::new("otpauth://totp".parse::<otp::Otp<'_>>().unwrap_err()),
+        Box::new(totp::Error::period("0".parse::<Period>().unwrap_err())),
+    ]
+}
+
+#[test]
+fn codes_are_exhaustive() {
+    let mut actual: Vec<String> = diagnostics()
+        .iter()
+        .map(|diagnostic| diagnostic.code().unwrap().to_string())
+        .collect();
+
+    actual.sort_unstable();
+
+    let expected: Vec<String> = errors::all_codes()
+        .iter()
+        .map(|entry| entry.code.to_owned())
+        .collect();
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn codes_are_stable() {
+    let lines: Vec<String> = errors::all_codes()
+        .iter()
+        .map(|entry| format!("{} {}", entry.code, entry.type_name))
+        .collect();
+
+    assert_snapshot!(lines.join("\n"));
+}
+
+#[test]
+fn source_chain() {
+    let error = Auth::parse_url("otpauth://totp/nekitdev?secret=JEQDYMZA").unwrap_err();
+
+    let mut messages = vec![error.to_string()];
+
+    let mut source = error.source();
+
+    while let Some(error) = source {
+        messages.push(error.to_string());
+
+        source = error.source();
+    }
+
+    assert_snapshot!(messages.join("\n"));
+}
+
+fn parse(string: &str) -> Result<Auth<'_>, otp_std::Error> {
+    let auth = Auth::parse_url(string)?;
+
+    Ok(auth)
+}
+
+#[test]
+fn unified() {
+    let error = parse("otpauth://totp/nekitdev?secret=JEQDYMZA").unwrap_err();
+
+    assert!(matches!(error, otp_std::Error::Auth(_)));
+
+    let expected = Auth::parse_url("otpauth://totp/nekitdev?secret=JEQDYMZA").unwrap_err();
+
+    assert_eq!(error.to_string(), expected.to_string());
+
+    assert_eq!(
+        error.code().unwrap().to_string(),
+        expected.code().unwrap().to_string()
+    );
+
+    let error = otp_std::Error::from("?".parse::<Period>().unwrap_err());
+
+    assert_eq!(error.code().unwrap().to_string(), "otp_std::period::parse");
+}
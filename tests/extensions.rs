@@ -0,0 +1,184 @@
+use otp_std::{
+    auth::{
+        core::ErrorSource,
+        extensions::{is_reserved, Color, Extensions},
+        options::ParseOptions,
+        writer::to_uri,
+    },
+    Auth, Digits, Otp,
+};
+
+const URL: &str = "otpauth://totp/MelodyKit:nekitdev?secret=JEQDYMZAN5YGK3RAONXXK4TDMU\
+    &issuer=MelodyKit&image=https%3A%2F%2Fexample.com%2Ficon.png&color=FF8000&vendor=value";
+
+#[test]
+fn typed_accessors() {
+    let auth = Auth::parse_url(URL).unwrap();
+
+    let extensions = auth.extensions();
+
+    assert_eq!(extensions.len(), 3);
+
+    assert_eq!(
+        extensions.image_url().unwrap().as_str(),
+        "https://example.com/icon.png"
+    );
+
+    assert_eq!(extensions.color(), Some(Color::new(0xFF, 0x80, 0x00)));
+
+    assert_eq!(extensions.get("vendor"), Some("value"));
+}
+
+#[test]
+fn emit() {
+    let auth = Auth::parse_url(URL).unwrap();
+
+    assert!(auth.emit_extensions);
+
+    let url = auth.build_url();
+
+    let names: Vec<_> = url
+        .query_pairs()
+        .map(|(name, _)| name.into_owned())
+        .collect();
+
+    assert!(names.ends_with(&["image".to_owned(), "color".to_owned(), "vendor".to_owned()]));
+
+    assert_eq!(
+        Auth::parse_url(url).unwrap().extensions(),
+        auth.extensions()
+    );
+}
+
+#[test]
+fn suppress() {
+    let mut auth = Auth::parse_url(URL).unwrap();
+
+    auth.emit_extensions = false;
+
+    assert!(!auth.build_url().as_str().contains("vendor"));
+}
+
+#[test]
+fn round_trip() {
+    let auth = Auth::parse_url(URL).unwrap();
+
+    let parsed = Auth::parse_url(auth.build_url()).unwrap();
+
+    assert!(parsed.same_parameters(&auth));
+}
+
+#[test]
+fn byte_round_trip() {
+    let auth = Auth::parse_url(URL).unwrap();
+
+    let url = auth.build_url();
+
+    let parsed = Auth::parse_url(&url).unwrap();
+
+    assert_eq!(parsed.build_url().as_str(), url.as_str());
+    assert_eq!(to_uri(&parsed), url.as_str());
+}
+
+#[test]
+fn repeated() {
+    let string = "otpauth://totp/nekitdev?secret=JEQDYMZAN5YGK3RAONXXK4TDMU\
+        &tag=a&vendor=value&tag=b";
+
+    let auth = Auth::parse_url(string).unwrap();
+
+    let extensions = auth.extensions();
+
+    assert_eq!(extensions.len(), 3);
+    assert_eq!(extensions.names(), ["tag", "vendor"]);
+
+    let url = auth.build_url();
+
+    assert!(url.as_str().ends_with("&tag=a&vendor=value&tag=b"));
+    assert!(Auth::parse_url(url).unwrap().same_parameters(&auth));
+}
+
+#[test]
+fn strict() {
+    let options = ParseOptions::builder().strict(true).build();
+
+    let error = Auth::parse_url_with_options(URL, &options).unwrap_err();
+
+    let ErrorSource::Strict(strict) = error.source else {
+        panic!("expected strict error");
+    };
+
+    assert_eq!(strict.names, ["image", "color", "vendor"]);
+
+    let auth = Auth::parse_url(URL).unwrap();
+
+    let string = Auth::builder()
+        .otp(auth.otp().clone())
+        .label(auth.label().clone())
+        .build()
+        .build_url();
+
+    assert!(Auth::parse_url_with_options(string, &options).is_ok());
+}
+
+#[test]
+fn reserved() {
+    assert!(is_reserved("secret"));
+    assert!(is_reserved("digits"));
+    assert!(!is_reserved("image"));
+
+    let mut extensions = Extensions::new();
+
+    let error = extensions.insert("secret", "KRUGKIDROVUWG2ZAMJZG653OEBTG66BA");
+
+    assert_eq!(error.unwrap_err().name, "secret");
+
+    assert!(extensions.append("digits", "8").is_err());
+
+    assert!(extensions.is_empty());
+
+    let collected: Extensions = [("digits", "8"), ("vendor", "value")].into_iter().collect();
+
+    assert_eq!(collected.names(), ["vendor"]);
+}
+
+#[test]
+fn colliding_round_trip() {
+    // `counter` is reserved, even though TOTP does not use it
+    let string = "otpauth://totp/nekitdev?secret=JEQDYMZAN5YGK3RAONXXK4TDMU\
+        &digits=6&counter=5&vendor=value";
+
+    let auth = Auth::parse_url(string).unwrap();
+
+    assert_eq!(auth.extensions().names(), ["vendor"]);
+
+    let mut other = auth.clone();
+
+    other.extensions.insert("vendor", "other").unwrap();
+
+    assert!(other.extensions.insert("digits", "8").is_err());
+
+    let parsed = Auth::parse_url(other.build_url()).unwrap();
+
+    assert_eq!(parsed.otp().base().digits, Digits::DEFAULT);
+    assert_eq!(parsed.extensions().get("vendor"), Some("other"));
+
+    assert!(matches!(parsed.otp(), Otp::Totp(_)));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn reserved_deserialize() {
+    let result: Result<Extensions, _> = serde_json::from_str(
+        r#"[["vendor", "value"], ["secret", "KRUGKIDROVUWG2ZAMJZG653OEBTG66BA"]]"#,
+    );
+
+    assert!(result.is_err());
+
+    let extensions: Extensions = serde_json::from_str(r#"[["vendor", "value"]]"#).unwrap();
+
+    assert_eq!(
+        serde_json::to_string(&extensions).unwrap(),
+        r#"[["vendor","value"]]"#
+    );
+}
@@ -0,0 +1,117 @@
+use serde::Deserialize;
+
+use otp_std::{
+    testing::builders::{build_secret, ENCODED},
+    Algorithm, Base, Counter, Digits, Hotp, Period, Skew, Totp,
+};
+
+#[derive(Deserialize)]
+struct Config {
+    totp: Totp<'static>,
+}
+
+fn build_base() -> Base<'static> {
+    Base::builder()
+        .secret(build_secret())
+        .algorithm(Algorithm::Sha256)
+        .digits(Digits::new(8).unwrap())
+        .build()
+}
+
+fn build_totp() -> Totp<'static> {
+    Totp::builder()
+        .base(build_base())
+        .skew(Skew::new(2))
+        .period(Period::new(60).unwrap())
+        .build()
+}
+
+fn build_hotp() -> Hotp<'static> {
+    Hotp::builder()
+        .base(build_base())
+        .counter(Counter::new(13))
+        .build()
+}
+
+#[test]
+fn json_totp() {
+    let totp = build_totp();
+    let string = serde_json::to_string(&totp).unwrap();
+    assert!(serde_json::from_str::<Totp<'_>>(&string)
+        .unwrap()
+        .same_parameters(&totp));
+}
+
+#[test]
+fn json_hotp() {
+    let hotp = build_hotp();
+    let string = serde_json::to_string(&hotp).unwrap();
+    assert!(serde_json::from_str::<Hotp<'_>>(&string)
+        .unwrap()
+        .same_parameters(&hotp));
+}
+
+#[test]
+fn toml_totp() {
+    let totp = build_totp();
+    let string = toml::to_string(&totp).unwrap();
+    assert!(toml::from_str::<Totp<'_>>(&string)
+        .unwrap()
+        .same_parameters(&totp));
+}
+
+#[test]
+fn toml_hotp() {
+    let hotp = build_hotp();
+    let string = toml::to_string(&hotp).unwrap();
+    assert!(toml::from_str::<Hotp<'_>>(&string)
+        .unwrap()
+        .same_parameters(&hotp));
+}
+
+#[test]
+fn yaml_totp() {
+    let totp = build_totp();
+    let string = serde_yaml::to_string(&totp).unwrap();
+    assert!(serde_yaml::from_str::<Totp<'_>>(&string)
+        .unwrap()
+        .same_parameters(&totp));
+}
+
+#[test]
+fn yaml_hotp() {
+    let hotp = build_hotp();
+    let string = serde_yaml::to_string(&hotp).unwrap();
+    assert!(serde_yaml::from_str::<Hotp<'_>>(&string)
+        .unwrap()
+        .same_parameters(&hotp));
+}
+
+#[test]
+fn toml_table() {
+    let string = format!(
+        "[totp]\nsecret = \"{ENCODED}\"\nalgorithm = \"SHA256\"\ndigits = 8\nskew = 2\nperiod = 60\n"
+    );
+
+    let config: Config = toml::from_str(&string).unwrap();
+
+    assert!(config.totp.same_parameters(&build_totp()));
+}
+
+#[test]
+fn urlencoded_totp() {
+    let totp = build_totp();
+    let string = serde_urlencoded::to_string(&totp).unwrap();
+    assert!(serde_urlencoded::from_str::<Totp<'_>>(&string)
+        .unwrap()
+        .same_parameters(&totp));
+}
+
+#[test]
+fn urlencoded_hotp() {
+    let hotp = build_hotp();
+    let string = serde_urlencoded::to_string(&hotp).unwrap();
+    assert!(serde_urlencoded::from_str::<Hotp<'_>>(&string)
+        .unwrap()
+        .same_parameters(&hotp));
+}
@@ -0,0 +1,70 @@
+use std::{collections::HashSet, sync::Arc, thread};
+
+use otp_std::{
+    hotp::{AtomicCounter, CounterStore, Generator},
+    testing::builders::build_hotp,
+    Counter,
+};
+
+#[test]
+fn sequential() {
+    let mut hotp = build_hotp(Counter::new(13));
+
+    let generator = Generator::from_hotp(hotp.clone());
+
+    for _ in 0..3 {
+        let generated = generator.next_code();
+
+        assert_eq!(generated.code, hotp.next());
+        assert_eq!(generated.counter, hotp.counter);
+    }
+
+    assert_eq!(generator.store().load(), Counter::new(16));
+}
+
+#[test]
+fn concurrent() {
+    const THREADS: u64 = 8;
+    const CODES: u64 = 32;
+
+    let generator = Arc::new(Generator::from_hotp(build_hotp(Counter::new(0))));
+
+    let handles: Vec<_> = (0..THREADS)
+        .map(|_| {
+            let generator = Arc::clone(&generator);
+
+            thread::spawn(move || {
+                (0..CODES)
+                    .map(|_| generator.next_code().counter.get())
+                    .collect::<Vec<_>>()
+            })
+        })
+        .collect();
+
+    let counters: HashSet<u64> = handles
+        .into_iter()
+        .flat_map(|handle| handle.join().unwrap())
+        .collect();
+
+    assert_eq!(counters, (1..=THREADS * CODES).collect());
+}
+
+#[test]
+fn overflow() {
+    let generator = Generator::from_hotp(build_hotp(Counter::new(u64::MAX)));
+
+    assert_eq!(generator.try_next_code(), None);
+
+    assert_eq!(generator.store().consume(), None);
+}
+
+#[test]
+fn custom_store() {
+    let store = AtomicCounter::new(Counter::new(42));
+
+    let hotp = build_hotp(Counter::new(42));
+
+    let generator = Generator::new(hotp.base.clone(), store);
+
+    assert_eq!(generator.next_code().code, hotp.peek());
+}
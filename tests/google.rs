@@ -0,0 +1,125 @@
+use otp_std::{
+    auth::migration::google::{self, ErrorSource},
+    testing::builders::build_label,
+    Algorithm, Auth, Base, Counter, Digits, Hotp, Label, Otp, Part, Period, Secret, Totp,
+};
+
+const EXPORTED: &str = "otpauth-migration://offline?data=Cj8KFDEyMzQ1Njc4OTAxMjM0NTY3ODkwEhhFeGF\
+    tcGxlOmFsaWNlQGdvb2dsZS5jb20aB0V4YW1wbGUgASgBMAIKNgogMTIzNDU2Nzg5MDEyMzQ1Njc4OTAxMjM0NTY3ODk\
+    wMTISA2JvYhoFT3RoZXIgASgCMAE4KhABGAEgACj%2F%2F%2F%2F%2F%2F%2F%2F%2F%2F%2F8B";
+
+#[cfg(not(feature = "unsafe-length"))]
+const SHORT_SECRET: &str = "otpauth-migration://offline?data=CjEKCkhlbGxvId6tvu8SGEV4YW1wbGU6YW\
+    xpY2VAZ29vZ2xlLmNvbRoHRXhhbXBsZSABKAEwAhABGAEgACjr4JKK%2FP%2F%2F%2F%2F8B";
+
+fn same_parameters(left: &[Auth<'_>], right: &[Auth<'_>]) -> bool {
+    left.len() == right.len()
+        && left
+            .iter()
+            .zip(right)
+            .all(|(left, right)| left.same_parameters(right))
+}
+
+#[test]
+fn import() {
+    let auths = google::parse_url(EXPORTED).unwrap();
+
+    assert_eq!(auths.len(), 2);
+
+    let totp = &auths[0];
+
+    assert_eq!(totp.label, build_label(Some("Example"), "alice@google.com"));
+    assert_eq!(totp.otp.base().secret.as_bytes(), b"12345678901234567890");
+    assert_eq!(totp.otp.base().algorithm, Algorithm::Sha1);
+    assert_eq!(totp.otp.base().digits, Digits::DEFAULT);
+
+    assert!(matches!(totp.otp, Otp::Totp(_)));
+
+    let hotp = &auths[1];
+
+    assert_eq!(hotp.label, build_label(Some("Other"), "bob"));
+    assert_eq!(hotp.otp.base().digits, Digits::MAX);
+
+    let Otp::Hotp(hotp) = &hotp.otp else {
+        panic!("expected HOTP");
+    };
+
+    assert_eq!(hotp.counter, Counter::new(42));
+}
+
+#[test]
+fn round_trip() {
+    let secret = Secret::generate_default();
+
+    let base = Base::builder().secret(secret).build();
+
+    let totp = Totp::builder().base(base.clone()).build();
+    let hotp = Hotp::builder().base(base).counter(Counter::new(13)).build();
+
+    let auths = [
+        Auth::builder()
+            .otp(totp)
+            .label(build_label(Some("MelodyKit"), "nekitdev"))
+            .build(),
+        Auth::builder()
+            .otp(hotp)
+            .label(
+                Label::builder()
+                    .user(Part::borrowed("nekit/dev").unwrap())
+                    .build(),
+            )
+            .build(),
+    ];
+
+    let url = google::build_url(&auths).unwrap();
+
+    assert!(same_parameters(
+        &google::parse_url(url.as_str()).unwrap(),
+        &auths
+    ));
+}
+
+#[test]
+fn unrepresentable_period() {
+    let secret = Secret::generate_default();
+
+    let base = Base::builder().secret(secret).build();
+
+    let totp = Totp::builder()
+        .base(base)
+        .period(Period::new(60).unwrap())
+        .build();
+
+    let auth = Auth::builder()
+        .otp(totp)
+        .label(build_label(Some("MelodyKit"), "nekitdev"))
+        .build();
+
+    let error = google::build_url([&auth]).unwrap_err();
+
+    assert!(matches!(error.source, ErrorSource::Unrepresentable(_)));
+}
+
+#[test]
+#[cfg(not(feature = "unsafe-length"))]
+fn short_secret() {
+    let error = google::parse_url(SHORT_SECRET).unwrap_err();
+
+    assert!(matches!(error.source, ErrorSource::Secret(_)));
+}
+
+#[test]
+fn format() {
+    let error =
+        google::parse_url("otpauth://totp/nekitdev?secret=JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap_err();
+
+    assert!(matches!(error.source, ErrorSource::Format(_)));
+
+    let error = google::parse_url("otpauth-migration://offline?data=%21").unwrap_err();
+
+    assert!(matches!(error.source, ErrorSource::Base64(_)));
+
+    let error = google::parse_url("otpauth-migration://offline?data=CgE").unwrap_err();
+
+    assert!(matches!(error.source, ErrorSource::Payload(_)));
+}
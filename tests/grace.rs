@@ -0,0 +1,125 @@
+use otp_std::{
+    hotp::Grace,
+    testing::builders::{build_base, build_hotp},
+    Counter, Hotp, LookAhead, VerifyOptions,
+};
+
+#[test]
+fn previous_once() {
+    let mut hotp = build_hotp(Counter::new(0));
+    let mut grace = Grace::new();
+
+    let code = hotp.peek();
+
+    assert!(hotp.verify_increment_grace(code, &mut grace));
+    assert_eq!(grace.used, None);
+
+    assert!(hotp.verify_increment_grace(code, &mut grace));
+    assert_eq!(grace.used, Some(0));
+
+    assert!(!hotp.verify_increment_grace(code, &mut grace));
+
+    assert_eq!(hotp.counter(), 1);
+}
+
+#[test]
+fn rejected_after_newer_increment() {
+    let mut hotp = build_hotp(Counter::new(0));
+    let mut grace = Grace::new();
+
+    let first = hotp.peek();
+
+    assert!(hotp.verify_increment_grace(first, &mut grace));
+
+    let second = hotp.peek();
+
+    assert!(hotp.verify_increment_grace(second, &mut grace));
+    assert_eq!(hotp.counter(), 2);
+
+    // only the code for the immediately previous counter value is accepted
+    assert!(!hotp.verify_increment_grace(first, &mut grace));
+    assert_eq!(grace.used, None);
+
+    assert!(hotp.verify_increment_grace(second, &mut grace));
+    assert_eq!(grace.used, Some(1));
+
+    assert_eq!(hotp.counter(), 2);
+}
+
+#[test]
+fn no_previous() {
+    let mut hotp = build_hotp(Counter::new(0));
+    let mut grace = Grace::new();
+
+    let code = hotp.base.generate(1);
+
+    assert!(!hotp.verify_increment_grace(code, &mut grace));
+
+    assert_eq!(hotp.counter(), 0);
+    assert_eq!(grace, Grace::default());
+}
+
+#[test]
+fn look_ahead() {
+    let mut hotp = Hotp::builder()
+        .base(build_base())
+        .counter(Counter::new(10))
+        .look_ahead(LookAhead::new(3))
+        .build();
+
+    let mut grace = Grace::new();
+
+    let code = hotp.base.generate(12);
+
+    // grace does not resynchronize, even within the look-ahead window
+    assert!(!hotp.verify_increment_grace(code, &mut grace));
+    assert_eq!(hotp.counter(), 10);
+
+    assert!(hotp.verify_resync(code));
+    assert_eq!(hotp.counter(), 13);
+
+    // after resynchronizing, the matched code becomes the previous one
+    assert!(hotp.verify_increment_grace(code, &mut grace));
+    assert!(!hotp.verify_increment_grace(code, &mut grace));
+
+    // skipped codes are not accepted
+    assert!(!hotp.verify_increment_grace(hotp.base.generate(11), &mut grace));
+
+    assert_eq!(hotp.counter(), 13);
+}
+
+#[test]
+fn string() {
+    let mut hotp = build_hotp(Counter::new(0));
+    let mut grace = Grace::new();
+
+    let string = hotp.peek_string();
+
+    assert!(hotp.verify_string_increment_grace(&string, &mut grace));
+    assert!(hotp.verify_string_increment_grace(&string, &mut grace));
+    assert!(!hotp.verify_string_increment_grace(&string, &mut grace));
+
+    assert_eq!(hotp.counter(), 1);
+}
+
+#[test]
+fn string_with_options() {
+    let mut hotp = build_hotp(Counter::new(0));
+    let mut grace = Grace::new();
+
+    let string = hotp.peek_string();
+
+    let (left, right) = string.split_at(string.len() / 2);
+
+    let code = format!("{left} {right}");
+
+    let options = VerifyOptions::lenient();
+
+    assert!(!hotp.verify_string_increment_grace(&code, &mut grace));
+
+    assert!(hotp.verify_string_increment_grace_with(&code, &mut grace, options));
+    assert!(hotp.verify_string_increment_grace_with(&code, &mut grace, options));
+    assert!(!hotp.verify_string_increment_grace_with(&code, &mut grace, options));
+
+    assert_eq!(hotp.counter(), 1);
+}
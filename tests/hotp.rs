@@ -0,0 +1,33 @@
+use otp_std::{testing::builders::build_hotp, Counter};
+
+#[test]
+fn peek_does_not_advance() {
+    let hotp = build_hotp(Counter::new(0));
+
+    assert_eq!(hotp.peek(), hotp.peek());
+    assert_eq!(hotp.peek_string(), hotp.peek_string());
+
+    assert_eq!(hotp.counter(), 0);
+}
+
+#[test]
+fn next_advances() {
+    let mut hotp = build_hotp(Counter::new(0));
+
+    let peeked = hotp.peek_string();
+
+    assert_eq!(hotp.next_string(), peeked);
+    assert_eq!(hotp.counter(), 1);
+
+    assert_ne!(hotp.peek_string(), peeked);
+}
+
+#[test]
+fn try_next_overflow() {
+    let mut hotp = build_hotp(Counter::new(u64::MAX));
+
+    assert_eq!(hotp.try_next(), None);
+    assert_eq!(hotp.try_next_string(), None);
+
+    assert_eq!(hotp.counter(), u64::MAX);
+}
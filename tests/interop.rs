@@ -0,0 +1,178 @@
+#![cfg(feature = "serde")]
+
+use serde_json::{from_value, json, to_value};
+
+use otp_std::{
+    auth::interop::{aegis::Vault, andotp, ErrorSource},
+    testing::builders::{build_auth, build_hotp, build_label, build_secret, ENCODED},
+    Algorithm, Auth, Base, Counter, Digits, Period, Totp,
+};
+
+fn same_parameters(left: &[Auth<'_>], right: &[Auth<'_>]) -> bool {
+    left.len() == right.len()
+        && left
+            .iter()
+            .zip(right)
+            .all(|(left, right)| left.same_parameters(right))
+}
+
+fn totp_auth() -> Auth<'static> {
+    let secret = build_secret();
+
+    let base = Base::builder()
+        .secret(secret)
+        .algorithm(Algorithm::Sha256)
+        .digits(Digits::MAX)
+        .build();
+
+    let totp = Totp::builder()
+        .base(base)
+        .period(Period::new(60).unwrap())
+        .build();
+
+    build_auth(totp)
+}
+
+fn hotp_auth() -> Auth<'static> {
+    let hotp = build_hotp(Counter::new(13));
+
+    Auth::builder()
+        .otp(hotp)
+        .label(build_label(None, "nekit"))
+        .build()
+}
+
+#[test]
+fn aegis_import() {
+    let value = json!({
+        "version": 1,
+        "header": { "slots": null, "params": null },
+        "db": {
+            "version": 2,
+            "entries": [
+                {
+                    "type": "totp",
+                    "uuid": "01234567-89ab-cdef-0123-456789abcdef",
+                    "name": "nekitdev",
+                    "issuer": "MelodyKit",
+                    "note": "",
+                    "favorite": false,
+                    "icon": null,
+                    "groups": [],
+                    "info": { "secret": ENCODED, "algo": "SHA256", "digits": 8, "period": 60 }
+                },
+                {
+                    "type": "hotp",
+                    "name": "nekit",
+                    "issuer": "",
+                    "info": { "secret": ENCODED, "algo": "SHA1", "digits": 6, "counter": 13 }
+                }
+            ]
+        }
+    });
+
+    let vault: Vault = from_value(value).unwrap();
+
+    assert!(same_parameters(
+        &vault.to_auths().unwrap(),
+        &[totp_auth(), hotp_auth()]
+    ));
+}
+
+#[test]
+fn aegis_encrypted() {
+    let value = json!({
+        "version": 1,
+        "header": { "slots": [{ "type": 1 }], "params": { "nonce": "00" } },
+        "db": "ZW5jcnlwdGVk"
+    });
+
+    assert!(from_value::<Vault>(value).is_err());
+}
+
+#[test]
+fn aegis_round_trip() {
+    let auths = [totp_auth(), hotp_auth()];
+
+    let vault = Vault::from_auths(&auths);
+
+    let value = to_value(&vault).unwrap();
+
+    assert_eq!(value["header"], json!({ "slots": null, "params": null }));
+    assert_eq!(value["db"]["entries"][1]["type"], "hotp");
+    assert_eq!(value["db"]["entries"][1]["info"]["counter"], 13);
+
+    let parsed: Vault = from_value(value).unwrap();
+
+    assert!(same_parameters(&parsed.to_auths().unwrap(), &auths));
+}
+
+#[test]
+fn andotp_import() {
+    let value = json!([
+        {
+            "secret": ENCODED,
+            "issuer": "MelodyKit",
+            "label": "nekitdev",
+            "digits": 8,
+            "type": "TOTP",
+            "algorithm": "SHA256",
+            "thumbnail": "Default",
+            "last_used": 1_700_000_000_000u64,
+            "used_frequency": 3,
+            "period": 60,
+            "tags": ["work"]
+        },
+        {
+            "secret": ENCODED,
+            "label": "nekit",
+            "digits": 6,
+            "type": "HOTP",
+            "algorithm": "SHA1",
+            "counter": 13
+        }
+    ]);
+
+    let entries: Vec<andotp::Entry> = from_value(value).unwrap();
+
+    assert_eq!(entries[1].thumbnail, andotp::DEFAULT_THUMBNAIL);
+
+    assert!(same_parameters(
+        &andotp::to_auths(&entries).unwrap(),
+        &[totp_auth(), hotp_auth()]
+    ));
+}
+
+#[test]
+fn andotp_round_trip() {
+    let auths = [totp_auth(), hotp_auth()];
+
+    let entries = andotp::from_auths(&auths);
+
+    let value = to_value(&entries).unwrap();
+
+    assert_eq!(value[0]["type"], "TOTP");
+    assert_eq!(value[1]["type"], "HOTP");
+
+    let parsed: Vec<andotp::Entry> = from_value(value).unwrap();
+
+    assert!(same_parameters(&andotp::to_auths(&parsed).unwrap(), &auths));
+}
+
+#[test]
+fn unsupported() {
+    let mut entry = andotp::Entry::from_auth(&totp_auth());
+
+    entry.type_of = "STEAM".to_owned();
+
+    let error = entry.to_auth().unwrap_err();
+
+    assert!(matches!(error.source, ErrorSource::Type(_)));
+
+    entry.type_of = "TOTP".to_owned();
+    entry.algorithm = "MD5".to_owned();
+
+    let error = entry.to_auth().unwrap_err();
+
+    assert!(matches!(error.source, ErrorSource::Algorithm(_)));
+}
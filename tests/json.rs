@@ -0,0 +1,164 @@
+use otp_std::{
+    otp::json::{ErrorSource, Field},
+    testing::builders::ENCODED,
+    Algorithm, Counter, Digits, LookAhead, Otp, Period, Skew, Type,
+};
+use serde_json::json;
+
+#[test]
+fn secret_only() {
+    let report = Otp::from_partial_json(json!({ "secret": ENCODED })).unwrap();
+
+    assert_eq!(
+        report.defaults,
+        [
+            Field::Type,
+            Field::Algorithm,
+            Field::Digits,
+            Field::Skew,
+            Field::Period
+        ]
+    );
+
+    assert!(!report.is_complete());
+    assert!(report.is_defaulted(Field::Type));
+
+    let Otp::Totp(totp) = report.otp else {
+        panic!("expected TOTP");
+    };
+
+    assert_eq!(totp.base.secret.expose_encoded(), ENCODED);
+    assert_eq!(totp.base.algorithm, Algorithm::default());
+    assert_eq!(totp.base.digits, Digits::DEFAULT);
+    assert_eq!(totp.skew, Skew::DEFAULT);
+    assert_eq!(totp.period, Period::DEFAULT);
+}
+
+#[test]
+fn digits() {
+    let report = Otp::from_partial_json(json!({ "secret": ENCODED, "digits": 8 })).unwrap();
+
+    assert!(!report.is_defaulted(Field::Digits));
+
+    assert_eq!(report.otp.base().digits, Digits::new(8).unwrap());
+}
+
+#[test]
+fn hotp() {
+    let value = json!({
+        "type": "hotp",
+        "secret": ENCODED,
+        "look_ahead": 5,
+    });
+
+    let report = Otp::from_partial_json(value).unwrap();
+
+    assert_eq!(
+        report.defaults,
+        [Field::Algorithm, Field::Digits, Field::Counter]
+    );
+
+    let Otp::Hotp(hotp) = report.otp else {
+        panic!("expected HOTP");
+    };
+
+    assert_eq!(hotp.counter, Counter::DEFAULT);
+    assert_eq!(hotp.look_ahead, LookAhead::new(5));
+}
+
+#[test]
+fn complete() {
+    let value = json!({
+        "type": "totp",
+        "secret": ENCODED,
+        "algorithm": "SHA1",
+        "digits": 6,
+        "skew": 1,
+        "period": 30,
+    });
+
+    let report = Otp::from_partial_json(value).unwrap();
+
+    assert!(report.is_complete());
+}
+
+#[test]
+fn round_trip() {
+    let report = Otp::from_partial_json(json!({ "secret": ENCODED })).unwrap();
+
+    let value = serde_json::to_value(&report.otp).unwrap();
+
+    let round = Otp::from_partial_json(value).unwrap();
+
+    // compact serialization omits fields equal to their defaults, which are then filled back
+    if cfg!(feature = "compact-serde") {
+        assert_eq!(round.defaults, Field::defaulted(Type::Totp));
+    } else {
+        assert!(round.is_complete());
+    }
+
+    assert!(round.otp.same_parameters(&report.otp));
+}
+
+#[test]
+fn not_object() {
+    let error = Otp::from_partial_json(json!(ENCODED)).unwrap_err();
+
+    assert!(matches!(error.source, ErrorSource::Object(_)));
+}
+
+#[test]
+fn missing_secret() {
+    let error = Otp::from_partial_json(json!({ "digits": 8 })).unwrap_err();
+
+    assert!(matches!(error.source, ErrorSource::Deserialize(_)));
+}
+
+#[test]
+fn invalid_type() {
+    let value = json!({ "type": "motp", "secret": ENCODED });
+
+    let error = Otp::from_partial_json(value).unwrap_err();
+
+    assert!(matches!(error.source, ErrorSource::Deserialize(_)));
+}
+
+#[test]
+fn skew_limit() {
+    let value = json!({ "secret": ENCODED, "skew": 1_000_000_000 });
+
+    let error = Otp::from_partial_json(value).unwrap_err();
+
+    let ErrorSource::Deserialize(error) = error.source else {
+        panic!("expected deserialize error");
+    };
+
+    assert!(error.source.to_string().contains("exceeds the limit"));
+
+    let value = json!({ "type": "hotp", "secret": ENCODED, "look_ahead": 11 });
+
+    assert!(Otp::from_partial_json(value).is_err());
+}
+
+#[test]
+fn defaulted() {
+    assert_eq!(
+        Field::defaulted(Type::Hotp),
+        [
+            Field::Algorithm,
+            Field::Digits,
+            Field::Counter,
+            Field::LookAhead
+        ]
+    );
+
+    assert_eq!(
+        Field::defaulted(Type::Totp),
+        [Field::Algorithm, Field::Digits, Field::Skew, Field::Period]
+    );
+}
+
+#[test]
+fn field() {
+    assert_eq!(Field::LookAhead.to_string(), "look_ahead");
+}
@@ -0,0 +1,93 @@
+use otp_std::{
+    testing::builders::{build_label, build_totp, ISSUER},
+    Auth, Label, Part,
+};
+
+fn auth_with(user: &'static str) -> Auth<'static> {
+    Auth::builder()
+        .otp(build_totp())
+        .label(build_label(Some(ISSUER), user))
+        .build()
+}
+
+#[test]
+fn slash_is_encoded() {
+    let auth = auth_with("cn=nekit/ou=dev");
+
+    let url = auth.build_url();
+
+    assert_eq!(url.path(), "/MelodyKit:cn%3Dnekit%2Fou%3Ddev");
+}
+
+#[test]
+fn slash_round_trip() {
+    let auth = auth_with("cn=nekit/ou=dev");
+
+    let parsed = Auth::parse_url(auth.build_url()).unwrap();
+
+    assert_eq!(parsed.label.user.as_str(), "cn=nekit/ou=dev");
+    assert!(parsed.same_parameters(&auth));
+}
+
+#[test]
+fn reserved_round_trip() {
+    let auth = auth_with("what?#100%");
+
+    let parsed = Auth::parse_url(auth.build_url()).unwrap();
+
+    assert_eq!(parsed.label.user.as_str(), "what?#100%");
+}
+
+fn label(issuer: Option<&'static str>, user: &'static str) -> Label<'static> {
+    Label::builder()
+        .maybe_issuer(issuer.map(|issuer| Part::borrowed(issuer).unwrap()))
+        .user(Part::borrowed(user).unwrap())
+        .build()
+}
+
+#[test]
+fn normalize() {
+    let expected = label(Some("example"), "alice");
+
+    for (issuer, user) in [
+        (Some("Example"), "Alice"),
+        (Some(" EXAMPLE "), "alice"),
+        (Some("Example"), "%41lice"),
+        (None, "Example%3Aalice"),
+        (None, "Example%3A%20Alice"),
+    ] {
+        assert_eq!(label(issuer, user).normalize(), expected);
+    }
+}
+
+#[test]
+fn normalize_invalid() {
+    let original = label(Some("Example"), "alice%3Abob");
+
+    assert_eq!(original.normalize(), original);
+
+    let blank = label(None, "%20");
+
+    assert_eq!(blank.normalize(), blank);
+}
+
+#[test]
+fn matches() {
+    assert!(label(Some("Example"), "alice").matches(&label(None, "example%3AALICE")));
+
+    assert!(!label(Some("Example"), "alice").matches(&label(None, "alice")));
+    assert!(!label(Some("Example"), "alice").matches(&label(Some("Example"), "bob")));
+}
+
+#[test]
+fn auth_matches() {
+    let auth = auth_with("nekitdev");
+
+    let other = Auth::parse_url(
+        "otpauth://totp/NekitDev?secret=JEQDYMZAN5YGK3RAONXXK4TDMU&issuer=melodykit",
+    )
+    .unwrap();
+
+    assert!(auth.matches(&other));
+    assert!(!auth.matches(&auth_with("alice")));
+}
@@ -0,0 +1,54 @@
+use otp_std::secret::{
+    core::ErrorSource,
+    length::{Policy, PERMISSIVE_MIN, SAFE_MIN},
+    Secret,
+};
+
+const LEGACY: &str = "JBSWY3DPEHPK3PXP";
+
+#[test]
+fn policies() {
+    assert_eq!(Policy::default(), Policy::strict());
+    assert_eq!(Policy::strict().min, SAFE_MIN);
+    assert_eq!(Policy::permissive().min, PERMISSIVE_MIN);
+}
+
+#[test]
+fn check() {
+    assert!(Policy::STRICT.check(SAFE_MIN).is_ok());
+
+    let error = Policy::STRICT.check(10).unwrap_err();
+
+    assert_eq!(error.length, 10);
+    assert_eq!(error.min, SAFE_MIN);
+
+    assert!(Policy::PERMISSIVE.check(0).is_ok());
+    assert!(Policy::new(12).check(10).is_err());
+}
+
+#[test]
+fn legacy_secret() {
+    let error = Secret::decode_with_policy(LEGACY, Policy::STRICT).unwrap_err();
+
+    assert!(matches!(error.source, ErrorSource::Policy(_)));
+
+    let secret = Secret::decode_with_policy(LEGACY, Policy::PERMISSIVE).unwrap();
+
+    assert_eq!(secret.encode(), LEGACY);
+}
+
+#[test]
+fn new_with_policy() {
+    let bytes = [0; 20];
+
+    assert!(Secret::new_with_policy(bytes[..].into(), Policy::STRICT).is_ok());
+    assert!(Secret::new_with_policy(bytes[..10].into(), Policy::STRICT).is_err());
+    assert!(Secret::new_with_policy(bytes[..10].into(), Policy::PERMISSIVE).is_ok());
+}
+
+#[test]
+fn invalid_encoding() {
+    let error = Secret::decode_with_policy("0", Policy::PERMISSIVE).unwrap_err();
+
+    assert!(matches!(error.source, ErrorSource::Encoding(_)));
+}
@@ -0,0 +1,35 @@
+use otp_std::{
+    auth::lenient::{parse_totp, Warning},
+    testing::builders::ENCODED,
+};
+
+#[test]
+fn secret_only() {
+    let lenient = parse_totp(format!("{ENCODED}\n")).unwrap();
+
+    assert_eq!(lenient.totp.base.secret.encode(), ENCODED);
+    assert!(lenient.label.is_none());
+    assert_eq!(lenient.warnings, [Warning::SecretOnly]);
+}
+
+#[test]
+fn missing_label() {
+    let lenient = parse_totp(format!("otpauth://totp/?secret={ENCODED}&period=60")).unwrap();
+
+    assert_eq!(lenient.totp.period.get(), 60);
+    assert!(lenient.label.is_none());
+    assert_eq!(lenient.warnings, [Warning::MissingLabel]);
+}
+
+#[test]
+fn with_label() {
+    let lenient = parse_totp(format!("otpauth://totp/nekitdev?secret={ENCODED}")).unwrap();
+
+    assert_eq!(lenient.label.unwrap().user.as_ref(), "nekitdev");
+    assert!(lenient.warnings.is_empty());
+}
+
+#[test]
+fn hotp() {
+    assert!(parse_totp(format!("otpauth://hotp/?secret={ENCODED}&counter=0")).is_err());
+}
@@ -0,0 +1,151 @@
+use otp_std::{
+    auth::{core::ErrorSource, options::ParseOptions},
+    limit::{Kind, SkewLimit},
+    otp::url::ErrorSource as UrlErrorSource,
+    testing::builders::{build_base, ENCODED},
+    Auth, Hotp, LookAhead, Otp, Skew, Totp,
+};
+
+fn hotp_url(window: u64) -> String {
+    format!("otpauth://hotp/nekitdev?secret={ENCODED}&counter=0&window={window}")
+}
+
+#[test]
+fn skew() {
+    let limit = SkewLimit::new(2);
+
+    assert!(limit.check_skew(Skew::new(2)).is_ok());
+    assert!(limit.check_skew(Skew::asymmetric(2, 0)).is_ok());
+
+    let error = limit.check_skew(Skew::asymmetric(0, 3)).unwrap_err();
+
+    assert_eq!(error.kind, Kind::Skew);
+    assert_eq!(error.value, 3);
+    assert_eq!(error.max, 2);
+
+    assert_eq!(
+        error.to_string(),
+        "skew of 3 steps exceeds the limit of 2 steps"
+    );
+}
+
+#[test]
+fn look_ahead() {
+    let limit = SkewLimit::DEFAULT;
+
+    assert!(limit.check_look_ahead(LookAhead::new(10)).is_ok());
+
+    let error = limit.check_look_ahead(LookAhead::new(11)).unwrap_err();
+
+    assert_eq!(error.kind, Kind::LookAhead);
+}
+
+#[test]
+fn unlimited() {
+    let limit = SkewLimit::UNLIMITED;
+
+    assert!(limit.check_skew(Skew::new(u64::MAX)).is_ok());
+    assert!(limit.check_look_ahead(LookAhead::new(u64::MAX)).is_ok());
+}
+
+#[test]
+fn configurations() {
+    let totp = Totp::builder()
+        .base(build_base())
+        .skew(Skew::new(100))
+        .build();
+
+    assert!(totp.check_skew_limit(SkewLimit::DEFAULT).is_err());
+    assert!(totp.check_skew_limit(SkewLimit::new(100)).is_ok());
+
+    let hotp = Hotp::builder()
+        .base(build_base())
+        .look_ahead(LookAhead::new(100))
+        .build();
+
+    assert!(hotp.check_skew_limit(SkewLimit::DEFAULT).is_err());
+
+    let otp = Otp::Hotp(hotp);
+
+    assert!(otp.check_skew_limit(SkewLimit::DEFAULT).is_err());
+    assert!(otp.check_skew_limit(SkewLimit::new(100)).is_ok());
+}
+
+#[test]
+fn parse_url() {
+    assert!(Auth::parse_url(hotp_url(10)).is_ok());
+
+    let error = Auth::parse_url(hotp_url(1_000_000)).unwrap_err();
+
+    assert!(matches!(error.source, ErrorSource::SkewLimit(_)));
+}
+
+#[test]
+fn parse_url_with_options() {
+    let string = hotp_url(1_000_000);
+
+    let error = Auth::parse_url_with_options(&string, &ParseOptions::default()).unwrap_err();
+
+    assert!(matches!(error.source, ErrorSource::SkewLimit(_)));
+
+    let options = ParseOptions::builder()
+        .skew_limit(SkewLimit::UNLIMITED)
+        .build();
+
+    let auth = Auth::parse_url_with_options(&string, &options).unwrap();
+
+    let Otp::Hotp(hotp) = auth.otp() else {
+        panic!("expected HOTP");
+    };
+
+    assert_eq!(hotp.look_ahead, LookAhead::new(1_000_000));
+}
+
+#[test]
+fn parse_otp_url() {
+    let string = format!("otpauth://hotp?secret={ENCODED}&counter=0&window=11");
+
+    let error = string.parse::<Otp<'_>>().unwrap_err();
+
+    assert!(matches!(error.source, UrlErrorSource::SkewLimit(_)));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    let limit: SkewLimit = serde_json::from_str("5").unwrap();
+
+    assert_eq!(limit, SkewLimit::new(5));
+
+    assert_eq!(serde_json::to_string(&limit).unwrap(), "5");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn deserialize_skew() {
+    assert!(serde_json::from_str::<Skew>("10").is_ok());
+    assert!(serde_json::from_str::<Skew>("1000000000").is_err());
+    assert!(serde_json::from_str::<Skew>(r#"{"back": 0, "forward": 11}"#).is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn deserialize_totp() {
+    let string = format!(r#"{{"secret": "{ENCODED}", "skew": 1000000000}}"#);
+
+    let error = serde_json::from_str::<Totp<'_>>(&string).unwrap_err();
+
+    assert!(error.to_string().contains("exceeds the limit"));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn deserialize_hotp() {
+    let string = format!(r#"{{"secret": "{ENCODED}", "counter": 0, "look_ahead": 11}}"#);
+
+    assert!(serde_json::from_str::<Hotp<'_>>(&string).is_err());
+
+    let string = format!(r#"{{"type": "hotp", "secret": "{ENCODED}", "look_ahead": 11}}"#);
+
+    assert!(serde_json::from_str::<Otp<'_>>(&string).is_err());
+}
@@ -0,0 +1,65 @@
+use otp_std::{
+    hotp, secret,
+    secret::literal::{decode, length},
+    testing::builders::{build_hotp, build_secret, build_totp, ENCODED},
+    totp, Algorithm, Counter,
+};
+
+#[test]
+fn literal() {
+    let secret = secret!("JEQDYMZAN5YGK3RAONXXK4TDMU");
+
+    assert_eq!(secret.as_bytes(), build_secret().as_bytes());
+}
+
+#[test]
+fn lowercase() {
+    let secret = secret!("jeqdymzan5ygk3raonxxk4tdmu");
+
+    assert_eq!(secret.as_bytes(), b"I <3 open source");
+}
+
+#[test]
+fn const_decode() {
+    const BYTES: [u8; length(ENCODED)] = decode(ENCODED);
+
+    assert_eq!(BYTES.as_slice(), b"I <3 open source");
+}
+
+#[test]
+#[should_panic = "invalid character"]
+fn invalid() {
+    decode::<16>("JEQDYMZAN5YGK3RAONXXK4TDM!");
+}
+
+#[test]
+fn totp_literal() {
+    let totp = totp!("JEQDYMZAN5YGK3RAONXXK4TDMU");
+
+    assert!(totp.same_parameters(&build_totp()));
+}
+
+#[test]
+fn totp_options() {
+    let totp = totp!(
+        "JEQDYMZAN5YGK3RAONXXK4TDMU",
+        algorithm = Algorithm::Sha256,
+        digits = 8,
+        skew = 0,
+        period = 60,
+    );
+
+    assert_eq!(totp.base.algorithm, Algorithm::Sha256);
+    assert_eq!(totp.base.digits.get(), 8);
+    assert_eq!(totp.skew.get(), 0);
+    assert_eq!(totp.period.get(), 60);
+}
+
+#[test]
+fn hotp_literal() {
+    let hotp = hotp!("JEQDYMZAN5YGK3RAONXXK4TDMU", counter = 13);
+
+    let expected = build_hotp(Counter::new(13));
+
+    assert!(hotp.same_parameters(&expected));
+}
@@ -0,0 +1,49 @@
+#![cfg(feature = "secure-memory")]
+
+use otp_std::{secret::Locked, testing::builders::build_secret, Base, Totp};
+
+const TIME: u64 = 1_234_567_890;
+
+#[test]
+fn round_trip() {
+    let secret = build_secret();
+
+    let locked = secret.clone().lock().unwrap();
+
+    assert_eq!(locked.as_bytes(), secret.as_bytes());
+    assert_eq!(locked.secret().as_bytes(), secret.as_bytes());
+    assert_eq!(locked.to_secret().as_bytes(), secret.as_bytes());
+}
+
+#[test]
+fn try_from() {
+    let secret = build_secret();
+
+    let locked = Locked::try_from(secret.clone()).unwrap();
+
+    assert_eq!(locked.clone().secret().as_bytes(), secret.as_bytes());
+}
+
+#[test]
+fn generate() {
+    let secret = build_secret();
+
+    let locked = secret.clone().lock().unwrap();
+
+    let expected = Totp::builder()
+        .base(Base::builder().secret(secret).build())
+        .build();
+
+    let totp = Totp::builder()
+        .base(Base::builder().secret(locked.secret()).build())
+        .build();
+
+    assert_eq!(totp.generate_at(TIME), expected.generate_at(TIME));
+}
+
+#[test]
+fn redacted() {
+    let locked = build_secret().lock().unwrap();
+
+    assert_eq!(format!("{locked:?}"), "Locked(****, len=16)");
+}
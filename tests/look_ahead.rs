@@ -0,0 +1,112 @@
+use otp_std::{testing::builders::build_base, Counter, Hotp, LookAhead};
+
+#[cfg(feature = "auth")]
+use otp_std::{auth::writer::to_uri, testing::builders::build_auth, Auth, Otp};
+
+fn build_hotp(counter: u64, look_ahead: u64) -> Hotp<'static> {
+    Hotp::builder()
+        .base(build_base())
+        .counter(Counter::new(counter))
+        .look_ahead(LookAhead::new(look_ahead))
+        .build()
+}
+
+#[test]
+fn default_is_disabled() {
+    let hotp = build_hotp(0, 0);
+
+    assert_eq!(hotp.look_ahead, LookAhead::default());
+    assert!(hotp.look_ahead.is_default());
+
+    let code = hotp.base.generate(1);
+
+    assert_eq!(hotp.verify_ahead(code), None);
+}
+
+#[test]
+fn verify_ahead() {
+    let hotp = build_hotp(10, 3);
+
+    let code = hotp.base.generate(12);
+
+    assert_eq!(hotp.verify_ahead(code), Some(Counter::new(12)));
+    assert_eq!(
+        hotp.verify_string_ahead(hotp.base.generate_string(13)),
+        Some(Counter::new(13))
+    );
+
+    assert_eq!(hotp.verify_ahead(hotp.base.generate(14)), None);
+    assert_eq!(hotp.verify_ahead(hotp.base.generate(9)), None);
+
+    assert_eq!(hotp.counter(), 10);
+}
+
+#[test]
+fn verify_resync() {
+    let mut hotp = build_hotp(10, 3);
+
+    let code = hotp.base.generate(12);
+
+    assert!(hotp.verify_resync(code));
+    assert_eq!(hotp.counter(), 13);
+
+    assert!(!hotp.verify_resync(code));
+    assert_eq!(hotp.counter(), 13);
+
+    let string = hotp.base.generate_string(13);
+
+    assert!(hotp.verify_string_resync(string));
+    assert_eq!(hotp.counter(), 14);
+}
+
+#[test]
+fn resync_overflow() {
+    let mut hotp = build_hotp(u64::MAX - 1, 3);
+
+    let code = hotp.base.generate(u64::MAX);
+
+    assert_eq!(hotp.verify_ahead(code), Some(Counter::new(u64::MAX)));
+
+    assert!(!hotp.verify_resync(code));
+    assert_eq!(hotp.counter(), u64::MAX - 1);
+}
+
+#[cfg(feature = "auth")]
+#[test]
+fn url_round_trip() {
+    let auth = build_auth(build_hotp(13, 5));
+
+    let url = auth.build_url();
+
+    assert!(url.as_str().contains("&counter=13&window=5"));
+    assert_eq!(to_uri(&auth), url.as_str());
+
+    let parsed = Auth::parse_url(url).unwrap();
+
+    assert!(parsed.same_parameters(&auth));
+    assert!(parsed.extensions().is_empty());
+}
+
+#[cfg(feature = "auth")]
+#[test]
+fn url_omits_disabled() {
+    let auth = build_auth(build_hotp(13, 0));
+
+    let url = auth.build_url();
+
+    assert!(!url.as_str().contains("window"));
+    assert_eq!(to_uri(&auth), url.as_str());
+}
+
+#[cfg(feature = "auth")]
+#[test]
+fn url_invalid() {
+    let string = "otpauth://hotp?secret=JEQDYMZAN5YGK3RAONXXK4TDMU&counter=0&window=x";
+
+    let error = string.parse::<Otp<'_>>().unwrap_err();
+
+    assert_eq!(
+        error.string,
+        "otpauth://hotp?secret=****&counter=0&window=x"
+    );
+}
@@ -0,0 +1,58 @@
+use otp_std::{
+    auth::migration::{Compatibility, IGNORING_ALGORITHM},
+    testing::builders::{build_label, ISSUER, USER},
+    Algorithm, Auth, Base, Secret, Totp,
+};
+
+fn build_auth() -> Auth<'static> {
+    let secret = Secret::generate_for(Algorithm::Sha1);
+
+    let base = Base::builder().secret(secret).build();
+    let totp = Totp::builder().base(base).build();
+
+    Auth::builder()
+        .otp(totp)
+        .label(build_label(Some(ISSUER), USER))
+        .build()
+}
+
+#[test]
+fn keep_secret() {
+    let auth = build_auth();
+
+    let migration = auth.with_algorithm(Algorithm::Sha1, None).unwrap();
+
+    assert!(migration.auth.same_parameters(&auth));
+    assert_eq!(migration.url, auth.build_url());
+    assert!(migration.compatibility.is_universal());
+}
+
+#[test]
+fn weak_secret() {
+    let auth = build_auth();
+
+    assert!(auth.with_algorithm(Algorithm::Sha512, None).is_err());
+}
+
+#[test]
+fn generated() {
+    let auth = build_auth();
+
+    let migration = auth.with_algorithm_generated(Algorithm::Sha512);
+
+    let base = migration.auth.otp.base();
+
+    assert_eq!(base.algorithm, Algorithm::Sha512);
+    assert_ne!(base.secret.as_bytes(), auth.otp.base().secret.as_bytes());
+
+    assert_eq!(migration.compatibility, Compatibility::Limited);
+    assert_eq!(
+        migration.compatibility.incompatible_apps(),
+        IGNORING_ALGORITHM
+    );
+
+    let parsed = Auth::parse_url(&migration.url).unwrap();
+
+    assert!(parsed.same_parameters(&migration.auth));
+    assert_eq!(parsed.label, auth.label);
+}
@@ -0,0 +1,60 @@
+use otp_std::{
+    secret::mnemonic::{check, MAX, MIN},
+    testing::builders::build_secret,
+    Algorithm, Length, Secret,
+};
+
+#[test]
+fn round_trip() {
+    let secret = build_secret();
+
+    let mnemonic = secret.to_mnemonic().unwrap();
+
+    assert_eq!(mnemonic.split_whitespace().count(), 12);
+
+    assert_eq!(
+        Secret::from_mnemonic(&mnemonic).unwrap().as_bytes(),
+        secret.as_bytes()
+    );
+}
+
+#[test]
+fn recommended_lengths() {
+    for algorithm in [Algorithm::Sha1, Algorithm::Sha256] {
+        let secret = Secret::generate_for(algorithm);
+
+        let mnemonic = secret.to_mnemonic().unwrap();
+
+        assert_eq!(
+            Secret::from_mnemonic(mnemonic).unwrap().as_bytes(),
+            secret.as_bytes()
+        );
+    }
+}
+
+#[test]
+fn unsupported_length() {
+    let secret = Secret::generate(Length::recommended_for(Algorithm::Sha512));
+
+    assert_eq!(secret.to_mnemonic().unwrap_err().length, 64);
+}
+
+#[test]
+fn check_lengths() {
+    let supported: Vec<_> = (0..=64).filter(|&length| check(length).is_ok()).collect();
+
+    assert_eq!(supported, [MIN, 20, 24, 28, MAX]);
+}
+
+#[test]
+fn checksum() {
+    let secret = build_secret();
+
+    let mnemonic = secret.to_mnemonic().unwrap();
+
+    let mut words: Vec<_> = mnemonic.split_whitespace().collect();
+
+    words.swap(0, 1);
+
+    assert!(Secret::from_mnemonic(words.join(" ")).is_err());
+}
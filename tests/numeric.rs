@@ -0,0 +1,41 @@
+use serde_json::{from_str, from_value, json};
+
+use otp_std::{testing::builders::ENCODED, Counter, Hotp, Otp, Period};
+
+#[test]
+fn counter_number_or_string() {
+    assert_eq!(from_value::<Counter>(json!(5)).unwrap(), Counter::new(5));
+    assert_eq!(from_value::<Counter>(json!("5")).unwrap(), Counter::new(5));
+
+    assert!(from_value::<Counter>(json!("five")).is_err());
+    assert!(from_value::<Counter>(json!(-5)).is_err());
+}
+
+#[test]
+fn period_number_or_string() {
+    let period = Period::new(60).unwrap();
+
+    assert_eq!(from_value::<Period>(json!(60)).unwrap(), period);
+    assert_eq!(from_value::<Period>(json!("60")).unwrap(), period);
+
+    assert!(from_value::<Period>(json!("0")).is_err());
+}
+
+#[test]
+fn flattened() {
+    let string = json!({ "secret": ENCODED, "counter": "13" }).to_string();
+
+    let hotp: Hotp<'_> = from_str(&string).unwrap();
+
+    assert_eq!(hotp.counter(), 13);
+
+    let string = json!({ "type": "totp", "secret": ENCODED, "period": "60" }).to_string();
+
+    let otp: Otp<'_> = from_str(&string).unwrap();
+
+    let Otp::Totp(totp) = otp else {
+        panic!("expected TOTP");
+    };
+
+    assert_eq!(totp.period.get(), 60);
+}
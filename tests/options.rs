@@ -0,0 +1,104 @@
+use otp_std::{
+    scrub::scrub,
+    testing::builders::{build_base, build_totp},
+    Hotp, VerifyOptions,
+};
+
+const TIME: u64 = 1_234_567_890;
+
+#[test]
+fn normalize_default() {
+    let options = VerifyOptions::default();
+
+    assert_eq!(options.normalize(" 123 456\n"), " 123 456\n");
+}
+
+#[test]
+fn normalize_trim() {
+    let options = VerifyOptions::builder().trim(true).build();
+
+    assert_eq!(options.normalize(" 123 456\n"), "123 456");
+}
+
+#[test]
+fn normalize_strip_separators() {
+    let options = VerifyOptions::builder().strip_separators(true).build();
+
+    assert_eq!(options.normalize("123-456\n"), "123456\n");
+}
+
+#[test]
+fn totp_trailing_newline() {
+    let totp = build_totp();
+
+    let code = format!("{}\n", totp.generate_string_at(TIME));
+
+    assert!(!totp.verify_string_at(TIME, &code));
+    assert!(totp.verify_string_at_with(TIME, &code, VerifyOptions::lenient()));
+}
+
+#[test]
+fn hotp_separators() {
+    let mut hotp = Hotp::builder().base(build_base()).build();
+
+    let string = hotp.peek_string();
+
+    let (left, right) = string.split_at(string.len() / 2);
+
+    let code = format!("{left} {right}");
+
+    assert!(!hotp.verify_string(&code));
+    assert!(hotp.verify_string_increment_with(&code, VerifyOptions::lenient()));
+
+    assert_eq!(hotp.counter(), 1);
+}
+
+fn scrubbing() -> VerifyOptions {
+    VerifyOptions {
+        scrub: true,
+        ..VerifyOptions::lenient()
+    }
+}
+
+#[test]
+fn scrub_bytes() {
+    let mut bytes = *b"123456";
+
+    scrub(&mut bytes);
+
+    assert_eq!(bytes, [0; 6]);
+}
+
+#[test]
+fn totp_scrub() {
+    let totp = build_totp();
+
+    let code = format!(" {}\n", totp.generate_string_at(TIME));
+
+    assert!(totp.verify_string_at_with(TIME, &code, scrubbing()));
+    assert!(totp.verify_string_exact_at_with(TIME, &code, scrubbing()));
+
+    assert!(!totp.verify_string_at_with(TIME + 120, &code, scrubbing()));
+}
+
+#[test]
+fn base_scrub() {
+    let base = build_base();
+
+    let code = base.generate_string(13);
+
+    assert!(base.verify_string_scrubbed(13, &code));
+    assert!(!base.verify_string_scrubbed(14, &code));
+}
+
+#[test]
+fn hotp_scrub() {
+    let mut hotp = Hotp::builder().base(build_base()).build();
+
+    let code = hotp.peek_string();
+
+    assert!(hotp.verify_string_with(&code, scrubbing()));
+    assert!(hotp.verify_string_increment_with(&code, scrubbing()));
+
+    assert_eq!(hotp.counter(), 1);
+}
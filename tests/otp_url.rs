@@ -0,0 +1,89 @@
+#![cfg(feature = "auth")]
+
+use otp_std::{
+    otp::url::{parse, ErrorSource},
+    testing::builders::build_secret,
+    Base, Counter, Digits, Hotp, Otp, Period, Totp,
+};
+
+fn base() -> Base<'static> {
+    Base::builder()
+        .secret(build_secret())
+        .digits(Digits::MAX)
+        .build()
+}
+
+#[test]
+fn totp() {
+    let totp = Totp::builder()
+        .base(base())
+        .period(Period::new(60).unwrap())
+        .build();
+
+    let otp = Otp::Totp(totp);
+
+    let url = otp.build_url();
+
+    assert!(url.as_str().starts_with("otpauth://totp?"));
+
+    assert!(url
+        .as_str()
+        .parse::<Otp<'_>>()
+        .unwrap()
+        .same_parameters(&otp));
+}
+
+#[test]
+fn hotp() {
+    let hotp = Hotp::builder()
+        .base(base())
+        .counter(Counter::new(13))
+        .build();
+
+    let otp = Otp::Hotp(hotp);
+
+    assert!(parse(otp.build_url()).unwrap().same_parameters(&otp));
+}
+
+#[cfg(not(feature = "forbid-secret-display"))]
+#[test]
+fn display() {
+    let otp = Otp::Totp(Totp::builder().base(base()).build());
+
+    assert_eq!(otp.to_string(), otp.build_url().as_str());
+
+    assert!(otp
+        .to_string()
+        .parse::<Otp<'_>>()
+        .unwrap()
+        .same_parameters(&otp));
+}
+
+#[test]
+fn label_ignored() {
+    let otp: Otp<'_> = "otpauth://totp/MelodyKit:nekitdev?secret=JEQDYMZAN5YGK3RAONXXK4TDMU\
+        &issuer=MelodyKit"
+        .parse()
+        .unwrap();
+
+    assert_eq!(otp.base().secret.as_bytes(), build_secret().as_bytes());
+}
+
+#[test]
+fn errors() {
+    let error = "https://totp?secret=JEQDYMZAN5YGK3RAONXXK4TDMU"
+        .parse::<Otp<'_>>()
+        .unwrap_err();
+
+    assert!(matches!(error.source, ErrorSource::Scheme(_)));
+
+    let error = "otpauth://motp?secret=JEQDYMZAN5YGK3RAONXXK4TDMU"
+        .parse::<Otp<'_>>()
+        .unwrap_err();
+
+    assert!(matches!(error.source, ErrorSource::Type(_)));
+
+    let error = "otpauth://totp".parse::<Otp<'_>>().unwrap_err();
+
+    assert!(matches!(error.source, ErrorSource::Otp(_)));
+}
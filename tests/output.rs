@@ -0,0 +1,68 @@
+use otp_std::{
+    base::{generate, truncate},
+    output::{Output, MIN_LENGTH},
+    Algorithm, Digits,
+};
+
+const KEY: &[u8] = b"12345678901234567890";
+
+const ALGORITHMS: [Algorithm; 7] = [
+    Algorithm::Sha1,
+    Algorithm::Sha256,
+    Algorithm::Sha512,
+    Algorithm::Sha3_256,
+    Algorithm::Sha3_512,
+    Algorithm::Blake2s,
+    Algorithm::Blake2b,
+];
+
+// the HMAC value for the counter of `0` from RFC 4226
+const HMAC: [u8; MIN_LENGTH] = [
+    0xCC, 0x93, 0xCF, 0x18, 0x50, 0x8D, 0x94, 0x93, 0x4C, 0x64, 0xB6, 0x5D, 0x8B, 0xA7, 0x66, 0x7F,
+    0xB7, 0xCD, 0xE4, 0xB0,
+];
+
+#[test]
+fn rfc() {
+    let output = Output::new(HMAC);
+
+    assert_eq!(output.truncate(Digits::DEFAULT), 755_224);
+    assert_eq!(
+        output.truncate(Digits::DEFAULT),
+        truncate(HMAC, Digits::DEFAULT)
+    );
+}
+
+#[test]
+fn compute() {
+    let output = Algorithm::Sha1.compute(KEY, 0u64.to_be_bytes());
+
+    assert_eq!(output.algorithm(), Algorithm::Sha1);
+    assert_eq!(output.as_bytes(), HMAC);
+}
+
+#[test]
+fn matches_slices() {
+    for algorithm in ALGORITHMS {
+        for input in 0..16u64 {
+            let output = algorithm.compute(KEY, input.to_be_bytes());
+
+            let expected = truncate(algorithm.hmac(KEY, input.to_be_bytes()), Digits::DEFAULT);
+
+            assert_eq!(output.truncate(Digits::DEFAULT), expected);
+            assert_eq!(generate(KEY, input, Digits::DEFAULT, algorithm), expected);
+        }
+    }
+}
+
+#[test]
+fn prepared() {
+    for algorithm in ALGORITHMS {
+        let prepared = algorithm.prepare(KEY);
+
+        assert_eq!(
+            prepared.generate(13, Digits::DEFAULT),
+            generate(KEY, 13, Digits::DEFAULT, algorithm)
+        );
+    }
+}
@@ -0,0 +1,94 @@
+use otp_std::{
+    testing::builders::{build_hotp, build_label, build_secret, ENCODED, ISSUER, USER},
+    Auth, Base, Counter, Digits, Period, Secret, Totp,
+};
+
+const ROTATED: &str = "KRUGKIDROVUWG2ZAMJZG653OEBTG66BA";
+
+fn build_totp(secret: &str, period: Period) -> Totp<'static> {
+    let base = Base::builder()
+        .secret(Secret::decode(secret).unwrap().into_owned())
+        .build();
+
+    Totp::builder().base(base).period(period).build()
+}
+
+fn build(secret: &str, user: &'static str) -> Auth<'static> {
+    Auth::builder()
+        .otp(build_totp(secret, Period::DEFAULT))
+        .label(build_label(Some(ISSUER), user))
+        .build()
+}
+
+#[test]
+fn rotated_secret() {
+    let auth = build(ENCODED, "nekitdev");
+    let rotated = build(ROTATED, "nekitdev");
+
+    assert!(auth.same_identity(&rotated));
+    assert!(auth.same_parameters(&rotated));
+}
+
+#[test]
+fn different_label() {
+    let auth = build(ENCODED, "nekitdev");
+    let other = build(ENCODED, "alice");
+
+    assert!(!auth.same_identity(&other));
+    assert!(!auth.same_parameters(&other));
+}
+
+#[test]
+fn different_parameters() {
+    let auth = build(ENCODED, "nekitdev");
+
+    let other = Auth::builder()
+        .otp(build_totp(ROTATED, Period::new(60).unwrap()))
+        .label(build_label(Some(ISSUER), USER))
+        .build();
+
+    assert!(auth.same_identity(&other));
+    assert!(!auth.same_parameters(&other));
+
+    let base = Base::builder()
+        .secret(build_secret())
+        .digits(Digits::MAX)
+        .build();
+
+    let other = Auth::builder()
+        .otp(Totp::builder().base(base).build())
+        .label(build_label(Some(ISSUER), USER))
+        .build();
+
+    assert!(!auth.same_parameters(&other));
+}
+
+#[test]
+fn different_types() {
+    let auth = build(ENCODED, "nekitdev");
+
+    let hotp = build_hotp(Counter::new(13));
+
+    let other = Auth::builder()
+        .otp(hotp)
+        .label(build_label(Some(ISSUER), USER))
+        .build();
+
+    assert!(auth.same_identity(&other));
+    assert!(!auth.same_parameters(&other));
+}
+
+#[test]
+fn extensions() {
+    let auth = build(ENCODED, "nekitdev");
+
+    let mut other = build(ROTATED, "nekitdev");
+
+    other
+        .extensions
+        .append("image", "https://example.com/image.png")
+        .unwrap();
+
+    assert!(auth.same_identity(&other));
+    assert!(!auth.same_parameters(&other));
+}
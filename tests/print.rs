@@ -0,0 +1,79 @@
+use otp_std::{
+    print::{escape, group, Sheet, DEFAULT_TITLE},
+    testing::builders::{build_hotp, build_label, build_totp, ENCODED, ISSUER, USER},
+    Auth, Counter,
+};
+
+fn build_auth(issuer: Option<&'static str>) -> Auth<'static> {
+    let totp = build_totp();
+
+    Auth::builder()
+        .otp(totp)
+        .label(build_label(issuer, USER))
+        .build()
+}
+
+#[test]
+fn grouping() {
+    assert_eq!(group(ENCODED), "JEQD YMZA N5YG K3RA ONXX K4TD MU");
+    assert_eq!(group("JEQD"), "JEQD");
+    assert_eq!(group(""), "");
+}
+
+#[test]
+fn escaping() {
+    assert_eq!(escape("<A & B>"), "&lt;A &amp; B&gt;");
+}
+
+#[test]
+fn render() {
+    let auth = build_auth(Some("Melody<Kit>"));
+
+    let codes = vec!["1234-5678".to_owned(), "8765-4321".to_owned()];
+
+    let svg = auth.render_sheet(codes).unwrap();
+
+    assert!(svg.starts_with("<svg "));
+    assert!(svg.ends_with("</svg>"));
+
+    assert!(svg.contains("Melody&lt;Kit&gt;"));
+    assert!(svg.contains("nekitdev"));
+    assert!(svg.contains("JEQD YMZA N5YG K3RA ONXX K4TD MU"));
+    assert!(svg.contains("TOTP, SHA1, 6 digits, 30 seconds"));
+    assert!(svg.contains("Recovery codes"));
+    assert!(svg.contains("1234-5678"));
+    assert!(svg.contains("8765-4321"));
+}
+
+#[test]
+fn render_without_codes() {
+    let auth = build_auth(None);
+
+    let svg = Sheet::new(&auth, Vec::new()).render().unwrap();
+
+    assert!(svg.contains(DEFAULT_TITLE));
+    assert!(!svg.contains("Recovery codes"));
+}
+
+#[test]
+fn parameters_hotp() {
+    let hotp = build_hotp(Counter::new(13));
+
+    let auth = Auth::builder()
+        .otp(hotp)
+        .label(build_label(Some(ISSUER), USER))
+        .build();
+
+    let sheet = Sheet::new(&auth, Vec::new());
+
+    assert_eq!(sheet.parameters(), "HOTP, SHA1, 6 digits, counter 13");
+}
+
+#[test]
+fn too_long() {
+    let mut auth = build_auth(Some("MelodyKit"));
+
+    auth.extensions.append("image", "x".repeat(8192)).unwrap();
+
+    assert!(auth.render_sheet(Vec::new()).is_err());
+}
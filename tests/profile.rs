@@ -0,0 +1,98 @@
+use otp_std::{
+    base::ValidationErrorSource,
+    profile::{Violation, Violations},
+    testing::builders::build_totp,
+    Algorithm, Base, Period, Profile, Secret, Totp,
+};
+
+fn totp(algorithm: Algorithm, period: u64) -> Totp<'static> {
+    let secret = Secret::generate_for(algorithm);
+
+    let base = Base::builder().secret(secret).algorithm(algorithm).build();
+
+    let period = Period::new(period).unwrap();
+
+    Totp::builder().base(base).period(period).build()
+}
+
+#[test]
+fn default_is_permissive() {
+    assert_eq!(Profile::default(), Profile::Permissive);
+}
+
+#[test]
+fn permissive_accepts_anything() {
+    let totp = totp(Algorithm::Sha512, 60);
+
+    assert!(Profile::Permissive.totp_violations(&totp).is_empty());
+    assert!(totp.validate_with(Profile::Permissive).is_ok());
+}
+
+#[test]
+fn strict_rfc_accepts_canonical() {
+    let totp = totp(Algorithm::Sha1, 30);
+
+    assert!(totp.validate_with(Profile::StrictRfc).is_ok());
+    assert!(totp.base.validate_with(Profile::StrictRfc).is_ok());
+}
+
+#[test]
+fn strict_rfc_lists_each_violation() {
+    let totp = totp(Algorithm::Sha256, 60);
+
+    let error = Profile::StrictRfc.enforce_totp(&totp).unwrap_err();
+
+    let expected = Violations::new(vec![
+        Violation::Algorithm {
+            actual: Algorithm::Sha256,
+            expected: Algorithm::Sha1,
+        },
+        Violation::Period {
+            actual: Period::new(60).unwrap(),
+            expected: Period::DEFAULT,
+        },
+    ]);
+
+    assert_eq!(error.profile, Profile::StrictRfc);
+    assert_eq!(error.violations, expected);
+
+    assert_eq!(
+        error.to_string(),
+        "configuration violates the `strict-rfc` profile: \
+        algorithm `SHA256` (expected `SHA1`), period `60` (expected `30`)"
+    );
+}
+
+#[test]
+fn strict_rfc_base_checks_algorithm_only() {
+    let totp = totp(Algorithm::Sha1, 60);
+
+    assert!(totp.base.validate_with(Profile::StrictRfc).is_ok());
+
+    let error = totp.validate_with(Profile::StrictRfc).unwrap_err();
+
+    assert!(matches!(error.source, ValidationErrorSource::Profile(_)));
+}
+
+#[test]
+fn regular_validation_runs_first() {
+    let totp = build_totp();
+
+    let error = totp.validate_with(Profile::StrictRfc).unwrap_err();
+
+    assert!(matches!(error.source, ValidationErrorSource::WeakSecret(_)));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_names() {
+    assert_eq!(
+        serde_json::to_string(&Profile::StrictRfc).unwrap(),
+        "\"strict-rfc\""
+    );
+
+    assert_eq!(
+        serde_json::from_str::<Profile>("\"permissive\"").unwrap(),
+        Profile::Permissive
+    );
+}
@@ -0,0 +1,143 @@
+#![cfg(feature = "icons")]
+
+use std::{
+    future::Future,
+    pin::pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Wake, Waker},
+};
+
+use otp_std::providers::{
+    domain_for, fetch_icon, icon_url_for, Cache, Fetch, Icon, MemoryCache, NoCache,
+};
+
+const DATA: &[u8] = b"icon";
+
+struct Noop;
+
+impl Wake for Noop {
+    fn wake(self: Arc<Self>) {}
+}
+
+// the stub transport is always ready, so polling once is enough
+fn block_on<F: Future>(future: F) -> F::Output {
+    let waker = Waker::from(Arc::new(Noop));
+
+    let mut context = Context::from_waker(&waker);
+
+    match pin!(future).poll(&mut context) {
+        Poll::Ready(output) => output,
+        Poll::Pending => panic!("expected the future to be ready"),
+    }
+}
+
+#[derive(Default)]
+struct Stub {
+    urls: Mutex<Vec<String>>,
+}
+
+impl Stub {
+    fn urls(&self) -> Vec<String> {
+        self.urls.lock().unwrap().clone()
+    }
+}
+
+impl Fetch for Stub {
+    async fn fetch(&self, url: &str) -> Option<Vec<u8>> {
+        self.urls.lock().unwrap().push(url.to_owned());
+
+        Some(DATA.to_vec())
+    }
+}
+
+struct Failing;
+
+impl Fetch for Failing {
+    async fn fetch(&self, _url: &str) -> Option<Vec<u8>> {
+        None
+    }
+}
+
+#[test]
+fn domains() {
+    assert_eq!(domain_for("GitHub"), Some("github.com"));
+    assert_eq!(domain_for(" github "), Some("github.com"));
+    assert_eq!(domain_for("MelodyKit"), None);
+
+    assert_eq!(
+        icon_url_for("GitHub").as_deref(),
+        Some("https://github.com/favicon.ico")
+    );
+}
+
+#[test]
+fn miss() {
+    let stub = Stub::default();
+    let cache = MemoryCache::new();
+
+    let icon = block_on(fetch_icon(&stub, &cache, "GitHub")).unwrap();
+
+    assert_eq!(
+        icon,
+        Icon::new("https://github.com/favicon.ico".to_owned(), DATA.to_vec())
+    );
+
+    assert_eq!(stub.urls(), ["https://github.com/favicon.ico"]);
+
+    assert_eq!(cache.get("github.com"), Some(icon));
+}
+
+#[test]
+fn hit() {
+    let stub = Stub::default();
+    let cache = MemoryCache::new();
+
+    let icon = Icon::new("https://example.com/icon.png".to_owned(), DATA.to_vec());
+
+    cache.insert("github.com", icon.clone());
+
+    assert_eq!(block_on(fetch_icon(&stub, &cache, "GitHub")), Some(icon));
+
+    assert!(stub.urls().is_empty());
+}
+
+#[test]
+fn normalization() {
+    let stub = Stub::default();
+    let cache = MemoryCache::new();
+
+    let icon = block_on(fetch_icon(&stub, &cache, "GitHub")).unwrap();
+
+    assert_eq!(block_on(fetch_icon(&stub, &cache, " github")), Some(icon));
+
+    assert_eq!(stub.urls().len(), 1);
+}
+
+#[test]
+fn no_cache() {
+    let stub = Stub::default();
+
+    block_on(fetch_icon(&stub, &NoCache, "GitHub")).unwrap();
+    block_on(fetch_icon(&stub, &NoCache, "GitHub")).unwrap();
+
+    assert_eq!(stub.urls().len(), 2);
+}
+
+#[test]
+fn unknown() {
+    let stub = Stub::default();
+    let cache = MemoryCache::new();
+
+    assert_eq!(block_on(fetch_icon(&stub, &cache, "MelodyKit")), None);
+
+    assert!(stub.urls().is_empty());
+}
+
+#[test]
+fn failure() {
+    let cache = MemoryCache::new();
+
+    assert_eq!(block_on(fetch_icon(&Failing, &cache, "GitHub")), None);
+
+    assert_eq!(cache.get("github.com"), None);
+}
@@ -0,0 +1,125 @@
+use otp_std::{
+    auth::profile::{Profile, Warning},
+    testing::builders::{build_label, build_secret, ISSUER, USER},
+    Algorithm, Auth, Base, Counter, Digits, Hotp, Period, Totp,
+};
+
+fn base(algorithm: Algorithm, digits: Digits) -> Base<'static> {
+    Base::builder()
+        .secret(build_secret())
+        .algorithm(algorithm)
+        .digits(digits)
+        .build()
+}
+
+fn totp_auth(base: Base<'static>, period: Period, issuer: Option<&'static str>) -> Auth<'static> {
+    let totp = Totp::builder().base(base).period(period).build();
+
+    Auth::builder()
+        .otp(totp)
+        .label(build_label(issuer, USER))
+        .build()
+}
+
+#[test]
+fn defaults_are_clean() {
+    let auth = totp_auth(
+        base(Algorithm::Sha1, Digits::DEFAULT),
+        Period::DEFAULT,
+        Some("MelodyKit"),
+    );
+
+    for profile in [
+        Profile::GoogleAuthenticator,
+        Profile::MicrosoftAuthenticator,
+        Profile::YubicoAuthenticator,
+    ] {
+        let report = auth.build_url_for(profile);
+
+        assert!(report.is_clean(), "{profile}");
+        assert_eq!(report.url, auth.build_url());
+    }
+}
+
+#[test]
+fn google_ignored() {
+    let auth = totp_auth(
+        base(Algorithm::Sha256, Digits::MAX),
+        Period::new(60).unwrap(),
+        None,
+    );
+
+    let report = Profile::GoogleAuthenticator.adjust(&auth);
+
+    assert_eq!(
+        report.warnings,
+        [
+            Warning::Algorithm(Algorithm::Sha256),
+            Warning::Digits(Digits::MAX),
+            Warning::Period(Period::new(60).unwrap()),
+            Warning::MissingIssuer,
+        ]
+    );
+
+    let report = Profile::YubicoAuthenticator.adjust(&auth);
+
+    assert!(report.is_clean());
+}
+
+#[test]
+fn yubico_unsupported() {
+    let auth = totp_auth(
+        base(Algorithm::Sha3_256, Digits::DEFAULT),
+        Period::new(45).unwrap(),
+        None,
+    );
+
+    let report = Profile::YubicoAuthenticator.adjust(&auth);
+
+    assert_eq!(
+        report.warnings,
+        [
+            Warning::Algorithm(Algorithm::Sha3_256),
+            Warning::Period(Period::new(45).unwrap()),
+        ]
+    );
+}
+
+#[test]
+fn microsoft_hotp() {
+    let hotp = Hotp::builder()
+        .base(base(Algorithm::Sha1, Digits::DEFAULT))
+        .counter(Counter::new(13))
+        .build();
+
+    let auth = Auth::builder()
+        .otp(hotp)
+        .label(build_label(Some(ISSUER), USER))
+        .build();
+
+    let report = Profile::MicrosoftAuthenticator.adjust(&auth);
+
+    assert_eq!(report.warnings.len(), 1);
+    assert_eq!(
+        report.warnings[0].to_string(),
+        "type `hotp` is not supported"
+    );
+
+    assert!(Profile::GoogleAuthenticator.adjust(&auth).is_clean());
+}
+
+#[test]
+fn extensions_omitted() {
+    let string = "otpauth://totp/MelodyKit:nekitdev?secret=JEQDYMZAN5YGK3RAONXXK4TDMU\
+        &issuer=MelodyKit&image=x";
+
+    let auth = Auth::parse_url(string).unwrap();
+
+    let report = Profile::GoogleAuthenticator.adjust(&auth);
+
+    assert_eq!(report.warnings, [Warning::Extension("image".to_owned())]);
+
+    assert!(!report.url.as_str().contains("image"));
+    assert!(report.url.as_str().contains("issuer=MelodyKit"));
+    assert!(report.url.path().ends_with("MelodyKit:nekitdev"));
+}
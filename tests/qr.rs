@@ -0,0 +1,64 @@
+use otp_std::{
+    testing::builders::{build_label, build_secret},
+    Algorithm, Auth, Base, Digits, Period, Totp,
+};
+use qrcode::{bits::encode_auto, EcLevel, QrCode};
+
+fn build_auth(issuer: &'static str, user: &'static str) -> Auth<'static> {
+    let base = Base::builder()
+        .secret(build_secret())
+        .algorithm(Algorithm::Sha256)
+        .digits(Digits::new(8).unwrap())
+        .build();
+
+    let totp = Totp::builder()
+        .base(base)
+        .period(Period::new(60).unwrap())
+        .build();
+
+    Auth::builder()
+        .otp(totp)
+        .label(build_label(Some(issuer), user))
+        .build()
+}
+
+fn bits(data: &str) -> usize {
+    encode_auto(data.as_bytes(), EcLevel::M).unwrap().len()
+}
+
+fn modules(data: &str) -> usize {
+    let width = QrCode::new(data).unwrap().width();
+
+    width * width
+}
+
+#[test]
+fn round_trip() {
+    let auth = build_auth("MelodyKit", "nekitdev");
+
+    assert!(Auth::parse_url(auth.to_qr_data())
+        .unwrap()
+        .same_parameters(&auth));
+}
+
+#[test]
+fn fewer_modules() {
+    let auth = build_auth("MELODYKIT ACCOUNTS", "NEKITDEV");
+
+    let naive = auth.build_url();
+    let optimized = auth.to_qr_data();
+
+    assert!(bits(&optimized) < bits(naive.as_str()));
+    assert!(modules(&optimized) < modules(naive.as_str()));
+}
+
+#[test]
+fn never_larger() {
+    let auth = build_auth("MelodyKit", "nekitdev");
+
+    let naive = auth.build_url();
+    let optimized = auth.to_qr_data();
+
+    assert!(bits(&optimized) <= bits(naive.as_str()));
+    assert!(modules(&optimized) <= modules(naive.as_str()));
+}
@@ -0,0 +1,104 @@
+use std::io::Cursor;
+
+use otp_std::{
+    auth::qr::{self, ErrorSource},
+    deps::image::{GrayImage, ImageFormat, Luma},
+    testing::builders::{build_auth, build_secret},
+    Algorithm, Auth, Base, Digits, Period, Totp,
+};
+use qrcode::{Color, QrCode};
+
+const SCALE: u32 = 4;
+const QUIET_ZONE: u32 = 4;
+
+fn build_sha256_auth() -> Auth<'static> {
+    let base = Base::builder()
+        .secret(build_secret())
+        .algorithm(Algorithm::Sha256)
+        .digits(Digits::new(8).unwrap())
+        .build();
+
+    let totp = Totp::builder()
+        .base(base)
+        .period(Period::new(60).unwrap())
+        .build();
+
+    build_auth(totp)
+}
+
+fn encode_png(image: &GrayImage) -> Vec<u8> {
+    let mut bytes = Vec::new();
+
+    image
+        .write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png)
+        .unwrap();
+
+    bytes
+}
+
+fn render(data: &str) -> Vec<u8> {
+    let code = QrCode::new(data).unwrap();
+
+    let width = u32::try_from(code.width()).unwrap();
+
+    let size = (width + 2 * QUIET_ZONE) * SCALE;
+
+    let colors = code.to_colors();
+
+    let image = GrayImage::from_fn(size, size, |x, y| {
+        let (x, y) = (x / SCALE, y / SCALE);
+
+        let inside = (QUIET_ZONE..QUIET_ZONE + width).contains(&x)
+            && (QUIET_ZONE..QUIET_ZONE + width).contains(&y);
+
+        let dark = inside && {
+            let index = (y - QUIET_ZONE) * width + (x - QUIET_ZONE);
+
+            colors[usize::try_from(index).unwrap()] == Color::Dark
+        };
+
+        Luma([if dark { 0 } else { 255 }])
+    });
+
+    encode_png(&image)
+}
+
+#[test]
+fn round_trip() {
+    let auth = build_sha256_auth();
+
+    let image = render(auth.build_url().as_str());
+
+    assert!(Auth::from_qr_image(&image).unwrap().same_parameters(&auth));
+
+    let image = render(&auth.to_qr_data());
+
+    assert!(Auth::from_qr_image(&image).unwrap().same_parameters(&auth));
+}
+
+#[test]
+fn read() {
+    let image = render("Hello, world!");
+
+    assert_eq!(qr::read(&image).unwrap(), "Hello, world!");
+
+    let error = Auth::from_qr_image(&image).unwrap_err();
+
+    assert!(matches!(error.source, ErrorSource::Auth(_)));
+}
+
+#[test]
+fn not_found() {
+    let image = encode_png(&GrayImage::from_pixel(64, 64, Luma([255])));
+
+    let error = qr::read(&image).unwrap_err();
+
+    assert!(matches!(error.source, ErrorSource::NotFound(_)));
+}
+
+#[test]
+fn invalid_image() {
+    let error = qr::read(b"not an image").unwrap_err();
+
+    assert!(matches!(error.source, ErrorSource::Image(_)));
+}
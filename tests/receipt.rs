@@ -0,0 +1,85 @@
+use otp_std::{
+    receipt::VerifiedAt, replay::MemoryStore, testing::builders::build_base, Period, Skew, Totp,
+};
+
+const TIME: u64 = 1_234_567_890;
+
+fn totp() -> Totp<'static> {
+    Totp::builder()
+        .base(build_base())
+        .skew(Skew::new(1))
+        .period(Period::new(60).unwrap())
+        .build()
+}
+
+#[test]
+fn exact() {
+    let totp = totp();
+
+    let code = totp.generate_at(TIME);
+
+    let receipt = totp.verify_receipt_at(TIME, code).unwrap();
+
+    let timestep = totp.input_at(TIME);
+
+    assert_eq!(receipt, VerifiedAt::new(timestep, (timestep + 1) * 60, 0));
+    assert!(receipt.is_exact());
+
+    assert!(!receipt.is_expired_at(TIME));
+    assert!(receipt.is_expired_at(receipt.period_end));
+}
+
+#[test]
+fn skewed() {
+    let totp = totp();
+
+    let code = totp.generate_string_at(TIME - 60);
+
+    let receipt = totp.verify_string_receipt_at(TIME, code).unwrap();
+
+    assert_eq!(receipt.timestep, totp.input_at(TIME) - 1);
+    assert_eq!(receipt.skew_offset, -1);
+    assert!(!receipt.is_exact());
+
+    assert!(receipt.is_expired_at(TIME));
+}
+
+#[test]
+fn invalid() {
+    let totp = totp();
+
+    let code = totp.generate_at(TIME + 120);
+
+    assert_eq!(totp.verify_receipt_at(TIME, code), None);
+}
+
+#[test]
+fn replay() {
+    let totp = totp();
+
+    let mut store = MemoryStore::new();
+
+    let code = totp.generate_at(TIME);
+
+    let receipt = totp.verify_receipt_at(TIME, code).unwrap();
+
+    assert!(!receipt.is_used_in(&store));
+
+    receipt.record_in(&mut store);
+
+    assert!(receipt.is_used_in(&store));
+
+    assert!(totp.verify_once(&mut store, TIME, code).is_err());
+}
+
+#[test]
+fn current() {
+    let totp = totp();
+
+    let code = totp.generate();
+
+    assert!(totp.verify_receipt(code).is_some());
+    assert!(totp
+        .try_verify_string_receipt(totp.generate_string())
+        .is_ok());
+}
@@ -0,0 +1,174 @@
+use std::error::Error;
+
+use otp_std::{
+    auth::{
+        options::ParseOptions,
+        redact::{keep, redact_secrets},
+    },
+    secret::core::REDACTED,
+    testing::builders::{build_base, build_secret, ENCODED},
+    Auth, Otp,
+};
+
+const STRING: &str = "otpauth://totp/nekitdev?secret=JEQDYMZAN5YGK3RAONXXK4TDMU&digits=x";
+
+#[test]
+fn debug() {
+    let secret = build_secret();
+
+    let debug = format!("{secret:?}");
+
+    assert_eq!(debug, "Secret(****, len=16)");
+    assert!(!debug.contains(ENCODED));
+}
+
+#[test]
+fn debug_nested() {
+    let base = build_base();
+
+    let debug = format!("{base:?}");
+
+    assert!(debug.contains(REDACTED));
+    assert!(!debug.contains(ENCODED));
+}
+
+#[test]
+fn expose() {
+    let secret = build_secret();
+
+    assert_eq!(secret.expose_encoded(), ENCODED);
+    assert_eq!(secret.expose_bytes(), secret.as_bytes());
+}
+
+#[cfg(not(feature = "forbid-secret-display"))]
+#[test]
+fn display() {
+    let secret = build_secret();
+
+    let expected = if cfg!(feature = "redact") {
+        REDACTED
+    } else {
+        ENCODED
+    };
+
+    assert_eq!(secret.to_string(), expected);
+}
+
+#[test]
+fn redact_secrets_query() {
+    assert_eq!(
+        redact_secrets("otpauth://totp/nekitdev?issuer=MelodyKit&secret=JEQDYMZA&period=15"),
+        "otpauth://totp/nekitdev?issuer=MelodyKit&secret=****&period=15",
+    );
+}
+
+#[test]
+fn redact_secrets_case_insensitive() {
+    assert_eq!(
+        redact_secrets("otpauth://totp/nekitdev?SECRET=JEQDYMZA&Secret=ONXXK4TD"),
+        "otpauth://totp/nekitdev?SECRET=****&Secret=****",
+    );
+}
+
+#[test]
+fn redact_secrets_encoded_name() {
+    assert_eq!(
+        redact_secrets("otpauth://totp/nekitdev?%73ecret=JEQDYMZA&%53ECRET=ONXXK4TD&s%65cret=MU"),
+        "otpauth://totp/nekitdev?%73ecret=****&%53ECRET=****&s%65cret=****",
+    );
+}
+
+#[test]
+fn redact_secrets_fragment() {
+    assert_eq!(
+        redact_secrets("otpauth://totp/nekitdev?secret=JEQDYMZA#secret=ONXXK4TD"),
+        "otpauth://totp/nekitdev?secret=****#secret=ONXXK4TD",
+    );
+}
+
+#[test]
+fn redact_secrets_untouched() {
+    let strings = [
+        "otpauth://totp/nekitdev",
+        "otpauth://totp/secret=JEQDYMZA",
+        "otpauth://totp/nekitdev?secret",
+        "otpauth://totp/nekitdev?secrets=JEQDYMZA",
+        "otpauth://totp/nekitdev?",
+    ];
+
+    for string in strings {
+        assert_eq!(redact_secrets(string), string);
+    }
+}
+
+fn redact_everything(_: &str) -> String {
+    "[redacted]".to_owned()
+}
+
+#[test]
+fn parse_errors() {
+    let error = Auth::parse_url(STRING).unwrap_err();
+
+    assert_eq!(error.string, "otpauth://totp/nekitdev?secret=****&digits=x");
+
+    let error = STRING.parse::<Otp<'_>>().unwrap_err();
+
+    assert_eq!(error.string, "otpauth://totp/nekitdev?secret=****&digits=x");
+
+    let error = Auth::parse_url("otpauth://totp/nekitdev?%73ecret=JEQDYMZA&digits=x").unwrap_err();
+
+    assert_eq!(
+        error.string,
+        "otpauth://totp/nekitdev?%73ecret=****&digits=x"
+    );
+}
+
+#[test]
+fn stripped_name() {
+    let string = "otpauth://totp/x?sec\tret=JEQDYMZAN5YGK3RAONXXK4TDMU&digits=x";
+
+    let error = Auth::parse_url(string).unwrap_err();
+
+    assert_eq!(error.string, "otpauth://totp/x?sec\tret=****&digits=x");
+
+    assert_eq!(
+        redact_secrets("otpauth://totp/x?se\r\ncret=JEQDYMZA"),
+        "otpauth://totp/x?se\r\ncret=****",
+    );
+}
+
+#[test]
+fn invalid_secret() {
+    let string = "otpauth://totp/nekitdev?secret=JEQDYMZAN5YGK3RAONXXK4TDM1";
+
+    let error = Auth::parse_url(string).unwrap_err();
+
+    assert_eq!(error.string, "otpauth://totp/nekitdev?secret=****");
+
+    let mut source: Option<&dyn Error> = Some(&error);
+
+    while let Some(error) = source {
+        assert!(!error.to_string().contains("JEQDYMZAN5YGK3RAONXXK4TDM1"));
+
+        source = error.source();
+    }
+}
+
+#[test]
+fn hooks() {
+    let options = ParseOptions::builder().redact(redact_everything).build();
+
+    let error = Auth::parse_url_with_options(STRING, &options).unwrap_err();
+
+    assert_eq!(error.string, "[redacted]");
+
+    let options = ParseOptions::builder().redact(keep).build();
+
+    let error = Auth::parse_url_with_options(STRING, &options).unwrap_err();
+
+    assert_eq!(error.string, STRING);
+
+    let error = Auth::parse_url_with_options(STRING, &ParseOptions::default()).unwrap_err();
+
+    assert_eq!(error.string, "otpauth://totp/nekitdev?secret=****&digits=x");
+}
@@ -0,0 +1,43 @@
+use otp_std::{remaining::RemainingTime, testing::builders::build_totp, Period};
+
+#[test]
+fn remaining_at() {
+    let totp = build_totp();
+
+    let remaining = totp.remaining_at(1_234_567_902);
+
+    assert_eq!(remaining.seconds(), totp.time_to_live_at(1_234_567_902));
+    assert_eq!(remaining.period(), totp.period);
+    assert_eq!(remaining.seconds(), 18);
+}
+
+#[test]
+fn display() {
+    let remaining = RemainingTime::new(12, Period::DEFAULT);
+
+    assert_eq!(remaining.to_string(), "12s");
+}
+
+#[test]
+fn as_fraction() {
+    let period = Period::DEFAULT;
+
+    assert_eq!(RemainingTime::new(15, period).as_fraction(), 0.5);
+    assert_eq!(RemainingTime::new(30, period).as_fraction(), 1.0);
+    assert_eq!(RemainingTime::new(60, period).as_fraction(), 1.0);
+}
+
+#[test]
+fn is_expiring() {
+    let remaining = RemainingTime::new(5, Period::DEFAULT);
+
+    assert!(remaining.is_expiring(5));
+    assert!(!remaining.is_expiring(4));
+}
+
+#[test]
+fn as_duration() {
+    let remaining = RemainingTime::new(7, Period::DEFAULT);
+
+    assert_eq!(remaining.as_duration().as_secs(), 7);
+}
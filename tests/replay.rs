@@ -0,0 +1,113 @@
+use otp_std::{
+    replay::{MemoryStore, UsedCodeStore},
+    testing::builders::build_base,
+    Skew, Totp,
+};
+
+const TIME: u64 = 1_234_567_890;
+
+fn totp() -> Totp<'static> {
+    Totp::builder()
+        .base(build_base())
+        .skew(Skew::new(1))
+        .build()
+}
+
+#[test]
+fn rejects_reused_code() {
+    let totp = totp();
+
+    let mut store = MemoryStore::new();
+
+    let code = totp.generate_at(TIME);
+
+    assert!(totp.verify_once(&mut store, TIME, code).unwrap());
+
+    assert_eq!(store.last, Some(totp.input_at(TIME)));
+
+    let error = totp.verify_once(&mut store, TIME, code).unwrap_err();
+
+    assert_eq!(error.input, totp.input_at(TIME));
+}
+
+#[test]
+fn rejects_older_code() {
+    let totp = totp();
+
+    let mut store = MemoryStore::new();
+
+    let current = totp.generate_at(TIME);
+    let previous = totp.generate_at(TIME - 30);
+
+    assert!(totp.verify_once(&mut store, TIME, current).unwrap());
+
+    // the previous code is still within the skew, but it is before the accepted one
+    assert!(totp.verify_once(&mut store, TIME, previous).is_err());
+}
+
+#[test]
+fn accepts_next_code() {
+    let totp = totp();
+
+    let mut store = MemoryStore::new();
+
+    let code = totp.generate_at(TIME);
+    let next = totp.generate_at(TIME + 30);
+
+    assert!(totp.verify_once(&mut store, TIME, code).unwrap());
+    assert!(totp.verify_once(&mut store, TIME + 30, next).unwrap());
+}
+
+#[test]
+fn invalid_code_is_not_recorded() {
+    let totp = totp();
+
+    let mut store = MemoryStore::new();
+
+    let code = totp.generate_at(TIME);
+
+    assert!(!totp.verify_once(&mut store, TIME + 3600, code).unwrap());
+
+    assert_eq!(store.last(), None);
+}
+
+#[test]
+fn string_codes() {
+    let totp = totp();
+
+    let mut store = MemoryStore::new();
+
+    let code = totp.generate_string_at(TIME);
+
+    assert!(totp.verify_string_once(&mut store, TIME, &code).unwrap());
+    assert!(totp.verify_string_once(&mut store, TIME, &code).is_err());
+}
+
+#[test]
+fn custom_store() {
+    #[derive(Default)]
+    struct Log {
+        inputs: Vec<u64>,
+    }
+
+    impl UsedCodeStore for Log {
+        fn last(&self) -> Option<u64> {
+            self.inputs.last().copied()
+        }
+
+        fn record(&mut self, input: u64) {
+            self.inputs.push(input);
+        }
+    }
+
+    let totp = totp();
+
+    let mut log = Log::default();
+
+    let code = totp.generate_at(TIME);
+
+    assert!(totp.verify_once(&mut log, TIME, code).unwrap());
+    assert!(totp.verify_once(&mut log, TIME, code).is_err());
+
+    assert_eq!(log.inputs, [totp.input_at(TIME)]);
+}
@@ -0,0 +1,166 @@
+use otp_std::{testing::builders::build_base, Policy, Skew, Totp};
+
+const TIME: u64 = 1_234_567_890;
+
+fn build_totp(skew: Skew) -> Totp<'static> {
+    Totp::builder().base(build_base()).skew(skew).build()
+}
+
+#[test]
+fn symmetric() {
+    let skew = Skew::new(2);
+
+    assert!(skew.is_symmetric());
+    assert_eq!(skew, Skew::asymmetric(2, 2));
+    assert_eq!((skew.back(), skew.forward()), (2, 2));
+    assert_eq!(skew.get(), 2);
+}
+
+#[test]
+fn asymmetric() {
+    let skew = Skew::asymmetric(2, 0);
+
+    assert!(!skew.is_symmetric());
+    assert!(!skew.is_default());
+    assert_eq!(skew.get(), 2);
+    assert_eq!(skew.window_size(), 3);
+
+    let values: Vec<_> = Skew::asymmetric(0, 2).apply(1).collect();
+
+    assert_eq!(values, [1, 2, 3]);
+
+    let values: Vec<_> = Skew::asymmetric(3, 1).apply(1).collect();
+
+    assert_eq!(values, [0, 1, 2]);
+}
+
+#[test]
+fn parse_and_display() {
+    assert_eq!("3".parse::<Skew>().unwrap(), Skew::new(3));
+    assert_eq!("2,0".parse::<Skew>().unwrap(), Skew::asymmetric(2, 0));
+
+    assert_eq!(Skew::new(3).to_string(), "3");
+    assert_eq!(Skew::asymmetric(2, 0).to_string(), "2,0");
+
+    for string in ["", "x", "1,", ",1", "1,2,3"] {
+        assert!(string.parse::<Skew>().is_err(), "{string:?}");
+    }
+}
+
+#[test]
+fn verify() {
+    let totp = build_totp(Skew::asymmetric(2, 0));
+
+    let period = totp.period.get();
+
+    assert!(totp.verify_at(TIME, totp.generate_at(TIME - 2 * period)));
+    assert!(!totp.verify_at(TIME, totp.generate_at(TIME - 3 * period)));
+    assert!(!totp.verify_at(TIME, totp.generate_at(TIME + period)));
+
+    assert_eq!(totp.verification_cost().hmac_calls, 3);
+}
+
+#[test]
+fn verify_detailed() {
+    let totp = build_totp(Skew::asymmetric(0, 2));
+
+    let period = totp.period.get();
+
+    let matched = totp
+        .verify_detailed(TIME, totp.generate_at(TIME + 2 * period))
+        .unwrap();
+
+    assert_eq!(matched.offset, 2);
+
+    assert!(totp
+        .verify_detailed(TIME, totp.generate_at(TIME - period))
+        .is_none());
+}
+
+#[test]
+fn policy_limit() {
+    let policy = Policy::builder().skew_limit(Skew::new(1)).build();
+
+    assert_eq!(
+        policy.limit_skew(Skew::asymmetric(3, 0)),
+        Skew::asymmetric(1, 0)
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    let string = serde_json::to_string(&Skew::new(2)).unwrap();
+
+    assert_eq!(string, "2");
+
+    let string = serde_json::to_string(&Skew::asymmetric(2, 0)).unwrap();
+
+    assert_eq!(string, r#"{"back":2,"forward":0}"#);
+
+    assert_eq!(
+        serde_json::from_str::<Skew>(&string).unwrap(),
+        Skew::asymmetric(2, 0)
+    );
+
+    assert_eq!(
+        serde_json::from_str::<Skew>(r#""3""#).unwrap(),
+        Skew::new(3)
+    );
+}
+
+#[test]
+fn builder() {
+    let base = build_base();
+
+    let totp = Totp::builder().base(base).asymmetric_skew(2, 1).build();
+
+    assert_eq!(totp.skew, Skew::asymmetric(2, 1));
+}
+
+#[test]
+fn from_offsets() {
+    assert_eq!(Skew::from_offsets(&[-1, 0, 1]), Some(Skew::new(1)));
+    assert_eq!(Skew::from_offsets(&[0, 1, 2]), Some(Skew::asymmetric(0, 2)));
+
+    assert_eq!(Skew::from_offsets(&[]), None);
+    assert_eq!(Skew::from_offsets(&[1, 0]), None);
+    assert_eq!(Skew::from_offsets(&[-1, -1, 0]), None);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_offsets() {
+    assert_eq!(
+        serde_json::from_str::<Skew>("[-2, -1, 0, 1]").unwrap(),
+        Skew::asymmetric(2, 1)
+    );
+
+    let error = serde_json::from_str::<Skew>("[-2, 0]").unwrap_err();
+
+    assert!(error.to_string().contains("contiguous"));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_totp() {
+    let totp = build_totp(Skew::asymmetric(2, 1));
+
+    let value = serde_json::to_value(&totp).unwrap();
+
+    assert_eq!(value["skew"], serde_json::json!({"back": 2, "forward": 1}));
+
+    let parsed: Totp<'_> = serde_json::from_value(value).unwrap();
+
+    assert!(parsed.same_parameters(&totp));
+
+    let mut value = serde_json::to_value(build_totp(Skew::new(3))).unwrap();
+
+    assert_eq!(value["skew"], 3);
+
+    value["skew"] = serde_json::json!([-1, 0]);
+
+    let parsed: Totp<'_> = serde_json::from_value(value).unwrap();
+
+    assert_eq!(parsed.skew, Skew::asymmetric(1, 0));
+}
@@ -0,0 +1,191 @@
+use insta::assert_snapshot;
+use miette::{Diagnostic, NarratableReportHandler};
+
+use otp_std::{
+    auth::options::ParseOptions,
+    testing::builders::{
+        build_auth, build_hotp, build_label, build_secret, build_totp, ISSUER, USER,
+    },
+    Algorithm, Auth, Base, Counter, Digits, Label, Part, Period, Skew, Totp, Type,
+};
+
+#[cfg(not(feature = "unsafe-length"))]
+use otp_std::Secret;
+
+fn render(diagnostic: &dyn Diagnostic) -> String {
+    let mut string = String::new();
+
+    NarratableReportHandler::new()
+        .render_report(&mut string, diagnostic)
+        .unwrap();
+
+    string
+}
+
+#[test]
+fn url_totp() {
+    assert_snapshot!(build_auth(build_totp()).build_url());
+}
+
+#[test]
+fn url_hotp() {
+    assert_snapshot!(build_auth(build_hotp(Counter::new(13))).build_url());
+}
+
+#[test]
+fn url_sha256_without_issuer() {
+    let base = Base::builder()
+        .secret(build_secret())
+        .algorithm(Algorithm::Sha256)
+        .digits(Digits::MAX)
+        .build();
+
+    let totp = Totp::builder()
+        .base(base)
+        .period(Period::new(60).unwrap())
+        .build();
+
+    let user = Part::borrowed(USER).unwrap().into_owned();
+
+    let label = Label::builder().user(user).build();
+
+    let auth = Auth::builder().otp(totp).label(label).build();
+
+    assert_snapshot!(auth.build_url());
+}
+
+#[test]
+fn display() {
+    let lines = [
+        build_secret().expose_encoded(),
+        Algorithm::Sha1.to_string(),
+        Algorithm::Sha256.to_string(),
+        Algorithm::Sha512.to_string(),
+        Digits::DEFAULT.to_string(),
+        Period::DEFAULT.to_string(),
+        Skew::DEFAULT.to_string(),
+        Counter::DEFAULT.to_string(),
+        Type::Hotp.to_string(),
+        Type::Totp.to_string(),
+        build_label(Some(ISSUER), USER).to_string(),
+    ];
+
+    assert_snapshot!(lines.join("\n"));
+}
+
+#[cfg(not(feature = "compact-serde"))]
+mod json {
+    use insta::assert_json_snapshot;
+
+    use super::*;
+
+    #[test]
+    fn json_base() {
+        assert_json_snapshot!(build_base());
+    }
+
+    #[test]
+    fn json_hotp() {
+        assert_json_snapshot!(build_hotp(Counter::new(13)));
+    }
+
+    #[test]
+    fn json_totp() {
+        assert_json_snapshot!(build_totp());
+    }
+
+    #[test]
+    fn json_otp() {
+        assert_json_snapshot!([
+            Otp::from(build_hotp(Counter::new(13))),
+            Otp::from(build_totp())
+        ]);
+    }
+
+    #[test]
+    fn json_auth() {
+        assert_json_snapshot!(build_auth(build_totp()));
+    }
+}
+
+#[test]
+fn error_digits() {
+    assert_snapshot!(render(&"13".parse::<Digits>().unwrap_err()));
+}
+
+#[test]
+fn error_period() {
+    assert_snapshot!(render(&"0".parse::<Period>().unwrap_err()));
+}
+
+#[test]
+fn error_algorithm() {
+    assert_snapshot!(render(&"MD5".parse::<Algorithm>().unwrap_err()));
+}
+
+#[test]
+#[cfg(not(feature = "unsafe-length"))]
+fn error_secret() {
+    assert_snapshot!(render(&Secret::decode("JEQDYMZA").unwrap_err()));
+}
+
+#[test]
+fn error_scheme() {
+    assert_snapshot!(render(
+        &Auth::parse_url("https://totp/nekitdev?secret=JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap_err()
+    ));
+}
+
+#[test]
+fn error_counter_not_found() {
+    assert_snapshot!(render(
+        &Auth::parse_url("otpauth://hotp/nekitdev?secret=JEQDYMZAN5YGK3RAONXXK4TDMU").unwrap_err()
+    ));
+}
+
+#[test]
+fn error_period_parameter() {
+    assert_snapshot!(render(
+        &Auth::parse_url("otpauth://totp/nekitdev?secret=JEQDYMZAN5YGK3RAONXXK4TDMU&period=0")
+            .unwrap_err()
+    ));
+}
+
+#[test]
+fn error_digits_parameter() {
+    assert_snapshot!(render(
+        &Auth::parse_url("otpauth://totp/nekitdev?secret=JEQDYMZAN5YGK3RAONXXK4TDMU&digits=x")
+            .unwrap_err()
+    ));
+}
+
+#[test]
+fn error_algorithm_parameter() {
+    assert_snapshot!(render(
+        &Auth::parse_url("otpauth://totp/nekitdev?secret=JEQDYMZAN5YGK3RAONXXK4TDMU&algorithm=MD5")
+            .unwrap_err()
+    ));
+}
+
+#[test]
+fn error_counter_parameter() {
+    assert_snapshot!(render(
+        &Auth::parse_url("otpauth://hotp/nekitdev?secret=JEQDYMZAN5YGK3RAONXXK4TDMU&counter=x")
+            .unwrap_err()
+    ));
+}
+
+#[test]
+fn error_issuer() {
+    let options = ParseOptions::builder()
+        .expected_issuer(Part::borrowed(ISSUER).unwrap())
+        .build();
+
+    assert_snapshot!(render(
+        &Auth::parse_url_with_options(
+            "otpauth://totp/nekitdev?secret=JEQDYMZAN5YGK3RAONXXK4TDMU",
+            &options
+        )
+        .unwrap_err()
+    ));
+}
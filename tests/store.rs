@@ -0,0 +1,106 @@
+use std::ptr;
+
+use otp_std::{
+    algorithm::hmac_sha1,
+    auth::store::Store,
+    secret::fingerprint::{Fingerprint, LABEL},
+    testing::builders::build_label,
+    Auth, Base, Secret, Totp,
+};
+
+fn build(issuer: Option<&'static str>, user: &'static str) -> Auth<'static> {
+    let base = Base::builder().secret(Secret::generate_default()).build();
+
+    let totp = Totp::builder().base(base).build();
+
+    Auth::builder()
+        .otp(totp)
+        .label(build_label(issuer, user))
+        .build()
+}
+
+fn build_store() -> Store<'static> {
+    Store::new(vec![
+        build(Some("MelodyKit"), "nekitdev"),
+        build(None, "nekitdev"),
+        build(Some("Example"), "alice"),
+        build(Some("MelodyKit"), "bob"),
+        build(Some("Example"), "nekitdev"),
+    ])
+}
+
+#[test]
+fn issuers() {
+    assert_eq!(build_store().issuers(), ["Example", "MelodyKit"]);
+}
+
+#[test]
+fn entries_for_issuer() {
+    let store = build_store();
+
+    let users: Vec<_> = store
+        .entries_for_issuer("MelodyKit")
+        .map(|entry| entry.label().user.as_str())
+        .collect();
+
+    assert_eq!(users, ["nekitdev", "bob"]);
+
+    assert_eq!(store.entries_for_issuer("Unknown").count(), 0);
+}
+
+#[test]
+fn page() {
+    let store = build_store();
+
+    assert!(ptr::eq(store.page(0, 2), &store.entries()[0..2]));
+    assert!(ptr::eq(store.page(2, 2), &store.entries()[4..]));
+
+    assert!(store.page(3, 2).is_empty());
+    assert!(store.page(usize::MAX, usize::MAX).is_empty());
+}
+
+#[test]
+fn pages() {
+    let store = build_store();
+
+    let sizes: Vec<_> = store.pages(2).map(<[_]>::len).collect();
+
+    assert_eq!(sizes, [2, 2, 1]);
+}
+
+#[test]
+fn dedup() {
+    let mut store = build_store();
+
+    store.push(build(Some("melodykit"), "NekitDev"));
+    store.push(build(None, "Example%3Aalice"));
+    store.push(build(None, " NEKITDEV "));
+
+    store.dedup();
+
+    let labels: Vec<_> = store.iter().map(Auth::label).collect();
+    let expected = build_store();
+    let expected: Vec<_> = expected.iter().map(Auth::label).collect();
+
+    assert_eq!(labels, expected);
+}
+
+#[test]
+fn fingerprints() {
+    // longer than the SHA-1 block size, so HMAC hashes the secret to get the effective key
+    let secret = Secret::owned(vec![0x42; 100]).unwrap();
+
+    let fingerprint = secret.fingerprint();
+
+    assert_eq!(fingerprint, Fingerprint::compute(secret.as_bytes()));
+    assert_eq!(
+        fingerprint.as_bytes().as_slice(),
+        hmac_sha1(LABEL, secret.as_bytes())
+    );
+
+    assert_ne!(fingerprint, secret.fingerprint_keyed(b"key"));
+    assert_eq!(
+        secret.fingerprint_keyed(b"key"),
+        Fingerprint::compute_keyed(b"key", secret.as_bytes())
+    );
+}
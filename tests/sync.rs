@@ -0,0 +1,71 @@
+use otp_std::{
+    sync::{Relation, SyncState},
+    Counter,
+};
+
+#[test]
+fn merge_counters() {
+    let small = Counter::new(1);
+    let large = Counter::new(2);
+
+    assert_eq!(Counter::merge(small, large), large);
+    assert_eq!(Counter::merge(large, small), large);
+}
+
+#[test]
+fn observe_never_regresses() {
+    let mut state = SyncState::new();
+
+    assert!(state.observe("phone", Counter::new(5)));
+    assert!(!state.observe("phone", Counter::new(3)));
+    assert!(!state.observe("phone", Counter::new(5)));
+
+    assert_eq!(state.get("phone"), Some(Counter::new(5)));
+}
+
+#[test]
+fn empty_counter() {
+    assert_eq!(SyncState::new().counter(), Counter::DEFAULT);
+}
+
+#[test]
+fn merge_states() {
+    let mut local = SyncState::new();
+
+    local.observe("phone", Counter::new(5));
+    local.observe("laptop", Counter::new(1));
+
+    let mut remote = SyncState::new();
+
+    remote.observe("phone", Counter::new(2));
+    remote.observe("laptop", Counter::new(4));
+    remote.observe("tablet", Counter::new(3));
+
+    local.merge(&remote);
+
+    assert_eq!(local.get("phone"), Some(Counter::new(5)));
+    assert_eq!(local.get("laptop"), Some(Counter::new(4)));
+    assert_eq!(local.get("tablet"), Some(Counter::new(3)));
+
+    assert_eq!(local.counter(), Counter::new(5));
+}
+
+#[test]
+fn compare() {
+    let mut before = SyncState::new();
+
+    before.observe("phone", Counter::new(1));
+
+    let mut after = before.clone();
+
+    assert_eq!(before.compare(&after), Relation::Equal);
+
+    after.observe("laptop", Counter::new(1));
+
+    assert_eq!(before.compare(&after), Relation::Before);
+    assert_eq!(after.compare(&before), Relation::After);
+
+    before.observe("phone", Counter::new(2));
+
+    assert_eq!(before.compare(&after), Relation::Concurrent);
+}
@@ -0,0 +1,103 @@
+use otp_std::{
+    auth::{scheme::SchemePolicy, url},
+    testing::builders::{build_base, build_label, build_totp, ENCODED, ISSUER},
+    Auth, Hotp, Label, Type,
+};
+
+fn label() -> Label<'static> {
+    build_label(Some(ISSUER), "nekit@melodykit.app")
+}
+
+fn totp_auth() -> Auth<'static> {
+    Auth::builder().otp(build_totp()).label(label()).build()
+}
+
+fn hotp_auth() -> Auth<'static> {
+    let hotp = Hotp::builder().base(build_base()).build();
+
+    Auth::builder().otp(hotp).label(label()).build()
+}
+
+#[test]
+fn try_matches_panicking() {
+    for auth in [totp_auth(), hotp_auth()] {
+        assert_eq!(auth.try_base_url().unwrap(), auth.base_url());
+        assert_eq!(auth.try_build_url().unwrap(), auth.build_url());
+    }
+}
+
+#[test]
+fn try_with_policy() {
+    let policy = SchemePolicy::new("otpauth-test").unwrap();
+
+    let auth = totp_auth();
+
+    let url = auth.try_build_url_with(&policy).unwrap();
+
+    assert_eq!(url.scheme(), "otpauth-test");
+    assert_eq!(url, auth.build_url_with(&policy));
+    assert_eq!(
+        auth.try_base_url_with(&policy).unwrap(),
+        auth.base_url_with(&policy)
+    );
+}
+
+#[test]
+fn try_base_functions() {
+    let label = label();
+
+    assert_eq!(
+        url::try_base(Type::Totp, &label).unwrap(),
+        url::base(Type::Totp, &label)
+    );
+}
+
+#[test]
+fn capabilities() {
+    assert!(Type::Hotp.requires_counter());
+    assert!(!Type::Hotp.uses_period());
+    assert!(!Type::Hotp.accepts_skew());
+
+    assert!(!Type::Totp.requires_counter());
+    assert!(Type::Totp.uses_period());
+    assert!(Type::Totp.accepts_skew());
+}
+
+#[test]
+fn default_query_params() {
+    let names = |type_of: Type| -> Vec<&str> {
+        type_of
+            .default_query_params()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect()
+    };
+
+    assert_eq!(names(Type::Hotp), ["algorithm", "digits"]);
+    assert_eq!(names(Type::Totp), ["algorithm", "digits", "period"]);
+
+    assert_eq!(
+        Type::Totp.default_query_params(),
+        [
+            ("algorithm", "SHA1".to_owned()),
+            ("digits", "6".to_owned()),
+            ("period", "30".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn extract_by_capabilities() {
+    let url = format!("otpauth://hotp/nekitdev?secret={ENCODED}&counter=13");
+
+    let auth = Auth::parse_url(url).unwrap();
+
+    assert!(auth.otp().type_of().requires_counter());
+
+    let url = format!("otpauth://totp/nekitdev?secret={ENCODED}&counter=13");
+
+    let auth = Auth::parse_url(url).unwrap();
+
+    assert!(auth.otp().type_of().uses_period());
+    assert_eq!(auth.extensions().get("counter"), None);
+}
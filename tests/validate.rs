@@ -0,0 +1,59 @@
+use otp_std::{
+    base::{ValidationErrorSource, WeakSecretError},
+    testing::builders::build_secret,
+    Algorithm, Base, Digits, Length, Secret,
+};
+
+#[test]
+fn weak_secret() {
+    let secret = Secret::generate(Length::recommended_for(Algorithm::Sha256));
+
+    let error = Base::builder()
+        .secret(secret)
+        .algorithm(Algorithm::Sha512)
+        .build()
+        .validate()
+        .unwrap_err();
+
+    let ValidationErrorSource::WeakSecret(WeakSecretError {
+        length,
+        recommended,
+        algorithm,
+    }) = error.source
+    else {
+        panic!("expected weak secret error");
+    };
+
+    assert_eq!(length, 32);
+    assert_eq!(recommended, 64);
+    assert_eq!(algorithm, Algorithm::Sha512);
+}
+
+#[test]
+fn recommended_secret() {
+    for algorithm in [Algorithm::Sha1, Algorithm::Sha256, Algorithm::Sha512] {
+        let base = Base::builder()
+            .secret(Secret::generate_for(algorithm))
+            .algorithm(algorithm)
+            .digits(Digits::MAX)
+            .build();
+
+        assert!(base.validate().is_ok());
+    }
+}
+
+#[test]
+fn builder_checked() {
+    let result = Base::builder_checked().secret(build_secret()).build();
+
+    assert!(result.is_err());
+
+    let base = Base::builder_checked()
+        .secret(Secret::generate_for(Algorithm::Sha256))
+        .algorithm(Algorithm::Sha256)
+        .maybe_digits(None)
+        .build()
+        .unwrap();
+
+    assert_eq!(base.digits, Digits::DEFAULT);
+}
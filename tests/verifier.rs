@@ -0,0 +1,76 @@
+use otp_std::{
+    policy::{ErrorSource, RateLimit, Window},
+    testing::builders::build_totp,
+    Policy, Skew, Verifier,
+};
+
+const TIME: u64 = 1_234_567_890;
+const PERIOD: u64 = 30;
+
+#[test]
+fn replay_guard() {
+    let totp = build_totp();
+
+    let code = totp.generate_at(TIME);
+    let next = totp.generate_at(TIME + PERIOD);
+
+    let policy = Policy::builder().replay_guard(true).build();
+
+    let verifier = Verifier::with_policy(totp, policy);
+
+    assert!(verifier.verify_at(TIME, code).unwrap());
+
+    let error = verifier.verify_at(TIME, code).unwrap_err();
+
+    assert!(matches!(error.source, ErrorSource::Replay(_)));
+
+    assert!(verifier.verify_at(TIME + PERIOD, next).unwrap());
+}
+
+#[test]
+fn rate_limit() {
+    let rate_limit = RateLimit::builder().attempts(2).interval(PERIOD).build();
+
+    let policy = Policy::builder().rate_limit(rate_limit).build();
+
+    let verifier = Verifier::with_policy(build_totp(), policy);
+
+    assert!(!verifier.verify_at(TIME, 0).unwrap());
+    assert!(!verifier.verify_at(TIME, 0).unwrap());
+
+    let error = verifier.verify_at(TIME, 0).unwrap_err();
+
+    assert!(matches!(error.source, ErrorSource::RateLimit(_)));
+
+    assert!(!verifier.verify_at(TIME + PERIOD, 0).unwrap());
+}
+
+#[test]
+fn skew_limit() {
+    let totp = build_totp();
+
+    let previous = totp.generate_at(TIME - PERIOD);
+
+    let policy = Policy::builder().skew_limit(Skew::disabled()).build();
+
+    let verifier = Verifier::with_policy(totp, policy);
+
+    assert!(!verifier.verify_at(TIME, previous).unwrap());
+}
+
+#[test]
+fn window() {
+    let totp = build_totp();
+
+    let code = totp.generate_at(TIME);
+
+    let window = Window::builder().not_after(TIME - 1).build();
+
+    let policy = Policy::builder().window(window).build();
+
+    let verifier = Verifier::with_policy(totp, policy);
+
+    let error = verifier.verify_at(TIME, code).unwrap_err();
+
+    assert!(matches!(error.source, ErrorSource::Window(_)));
+}
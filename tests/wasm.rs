@@ -0,0 +1,50 @@
+use otp_std::{
+    testing::builders::ENCODED,
+    wasm::{JsAuth, JsSecret, JsTotp},
+    Auth,
+};
+
+const URL: &str = "otpauth://totp/MelodyKit:nekitdev?secret=JEQDYMZAN5YGK3RAONXXK4TDMU\
+    &issuer=MelodyKit&period=60";
+
+const TIME: u64 = 1_234_567_890;
+
+#[test]
+fn secret() {
+    let secret = JsSecret::decode(ENCODED).unwrap();
+
+    assert_eq!(secret.encode(), ENCODED);
+
+    let bytes = JsSecret::new(&secret.bytes()).unwrap();
+
+    assert_eq!(bytes.encode(), ENCODED);
+}
+
+#[test]
+fn totp() {
+    let secret = JsSecret::decode(ENCODED).unwrap();
+
+    let totp = JsTotp::new(&secret, Some("SHA256".to_owned()), Some(8), Some(60), None).unwrap();
+
+    let code = totp.generate_at(TIME);
+
+    assert_eq!(code, totp.get().generate_string_at(TIME));
+
+    assert!(totp.verify_at(TIME, &code));
+}
+
+#[test]
+fn auth() {
+    let auth = JsAuth::parse(URL).unwrap();
+
+    assert_eq!(auth.issuer().as_deref(), Some("MelodyKit"));
+    assert_eq!(auth.user(), "nekitdev");
+
+    let totp = auth.totp().unwrap();
+
+    assert_eq!(totp.get().period.get(), 60);
+
+    assert!(Auth::parse_url(auth.to_url())
+        .unwrap()
+        .same_parameters(auth.get()));
+}
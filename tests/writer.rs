@@ -0,0 +1,107 @@
+use otp_std::{
+    auth::{
+        scheme::SchemePolicy,
+        writer::{to_uri, write_uri_with},
+    },
+    testing::builders::{build_hotp, build_label, build_totp, USER},
+    Auth, Counter,
+};
+
+#[test]
+fn totp() {
+    let totp = build_totp();
+
+    let auth = Auth::builder()
+        .otp(totp)
+        .label(build_label(Some("MelodyKit"), "nekitdev"))
+        .build();
+
+    assert_eq!(to_uri(&auth), auth.build_url().as_str());
+}
+
+#[test]
+fn hotp() {
+    let hotp = build_hotp(Counter::new(13));
+
+    let auth = Auth::builder()
+        .otp(hotp)
+        .label(build_label(Some("MelodyKit"), "nekitdev"))
+        .build();
+
+    assert_eq!(to_uri(&auth), auth.build_url().as_str());
+}
+
+#[test]
+fn reserved() {
+    let totp = build_totp();
+
+    let auth = Auth::builder()
+        .otp(totp)
+        .label(build_label(
+            Some("Melody Kit & Co"),
+            "cn=nekit/ou=dev?#100%~*",
+        ))
+        .build();
+
+    let uri = to_uri(&auth);
+
+    assert_eq!(uri, auth.build_url().as_str());
+
+    assert!(Auth::parse_url(uri).unwrap().same_parameters(&auth));
+}
+
+#[test]
+fn extensions() {
+    let url = "otpauth://totp/MelodyKit:nekitdev?secret=JEQDYMZAN5YGK3RAONXXK4TDMU\
+        &issuer=MelodyKit&image=https%3A%2F%2Fexample.com%2Ficon.png&vendor=some+value";
+
+    let auth = Auth::parse_url(url).unwrap();
+
+    assert_eq!(to_uri(&auth), auth.build_url().as_str());
+}
+
+#[test]
+fn scheme() {
+    let totp = build_totp();
+
+    let auth = Auth::builder()
+        .otp(totp)
+        .label(build_label(None, USER))
+        .build();
+
+    let policy = SchemePolicy::new("otpauth-test").unwrap();
+
+    let mut string = String::new();
+
+    write_uri_with(&policy, &auth, &mut string).unwrap();
+
+    assert_eq!(string, auth.build_url_with(&policy).as_str());
+}
+
+#[test]
+fn mixed_case_scheme() {
+    let totp = build_totp();
+
+    let auth = Auth::builder()
+        .otp(totp)
+        .label(build_label(None, USER))
+        .build();
+
+    let policy = SchemePolicy::new("MyApp").unwrap();
+
+    let mut string = String::new();
+
+    write_uri_with(&policy, &auth, &mut string).unwrap();
+
+    let url = auth.build_url_with(&policy);
+
+    assert_eq!(url.scheme(), "myapp");
+    assert_eq!(string, url.as_str());
+
+    assert!(Auth::parse_url_with(url, &policy)
+        .unwrap()
+        .same_parameters(&auth));
+    assert!(Auth::parse_url_with(string, &policy)
+        .unwrap()
+        .same_parameters(&auth));
+}
@@ -0,0 +1,41 @@
+use otp_std::{testing::builders::build_secret, OwnedSecret, Secret};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+const fn assert_zeroize_on_drop<T: ZeroizeOnDrop>() {}
+
+#[test]
+fn zeroize_on_drop() {
+    assert_zeroize_on_drop::<Secret<'_>>();
+    assert_zeroize_on_drop::<OwnedSecret>();
+}
+
+#[test]
+fn zeroize_owned() {
+    let mut secret = build_secret();
+
+    secret.zeroize();
+
+    assert!(secret.as_bytes().is_empty());
+}
+
+#[test]
+fn zeroize_borrowed() {
+    let bytes = build_secret().as_bytes().to_vec();
+
+    let mut secret = Secret::borrowed(&bytes).unwrap();
+
+    secret.zeroize();
+
+    assert!(secret.as_bytes().is_empty());
+
+    assert!(!bytes.is_empty());
+}
+
+#[test]
+fn get_after_drop_impl() {
+    let secret = build_secret();
+
+    let expected = secret.as_bytes().to_vec();
+
+    assert_eq!(secret.get().as_ref(), expected);
+}